@@ -0,0 +1,4 @@
+// /assets/js/dashboard.js — démarre le SPA Yew (la CSP interdit les scripts inline)
+import init from "/assets/dashboard/dashboard-spa.js";
+
+init();
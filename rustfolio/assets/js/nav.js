@@ -0,0 +1,12 @@
+// /assets/js/nav.js — chargé par base.html (la CSP interdit les scripts inline)
+
+// Toggle navbar selon /auth/session
+fetch('/auth/session', { credentials: 'include' })
+  .then(r => r.json())
+  .then(d => {
+    if (d.authenticated) {
+      document.getElementById('nav-public')?.classList.add('hidden');
+      document.getElementById('nav-auth')?.classList.remove('hidden');
+    }
+  })
+  .catch(() => {});
@@ -2,6 +2,7 @@
 
 // Helpers
 const $ = (s)=>document.querySelector(s);
+const esc = (s)=>String(s ?? '').replace(/[&<>"']/g, c => ({ '&':'&amp;', '<':'&lt;', '>':'&gt;', '"':'&quot;', "'":'&#39;' }[c]));
 const paramsFromForm = (formSel)=>{
   const fd = new FormData($(formSel));
   const p = new URLSearchParams();
@@ -19,60 +20,81 @@ const paramsFromForm = (formSel)=>{
   }
 })();
 
-async function loadProjects(){
-  const qs = location.search;
-  const url = '/api/projects' + (qs || '');
+let nextCursor = null;
+
+function card(p){
+  const hasImg  = typeof p.image     === 'string' && p.image.length > 0;
+  const hasRepo = typeof p.repo_link === 'string' && p.repo_link.length > 0;
+  const hasPdf  = typeof p.pdf_link  === 'string' && p.pdf_link.length  > 0;
+
+  const article = document.createElement('article');
+  article.className = 'card project-card';
+  article.innerHTML = `
+    ${hasImg ? `<img src="${esc(p.image)}" alt="${esc(p.title)}" loading="lazy">` : ``}
+    <h3>${esc(p.title)}</h3>
+    ${p.category ? `<p class="meta">${esc(p.category)}</p>` : ``}
+    ${p.description ? `<p class="max-w-prose">${esc(p.description)}</p>` : ``}
+    ${
+      Array.isArray(p.technologies) && p.technologies.length
+      ? `<div class="tags">${p.technologies.map(t=>`<span class="tag">${esc(t)}</span>`).join('')}</div>`
+      : ``
+    }
+    <div class="actions">
+      ${hasRepo ? `<a class="btn" href="${esc(p.repo_link)}" target="_blank" rel="noopener">Code</a>` : ``}
+      ${hasPdf  ? `<a class="btn ghost" href="${esc(p.pdf_link)}" target="_blank" rel="noopener">PDF</a>` : ``}
+    </div>
+  `;
+  return article;
+}
+
+// Facettes → suggestions des champs, avec le nombre de projets
+function fillFacets(facets){
+  const fill = (sel, list)=>{
+    $(sel).innerHTML = (list || []).map(f=>`<option value="${esc(f.value)}">${esc(f.value)} (${f.count})</option>`).join('');
+  };
+  fill('#categories', facets?.categories);
+  fill('#technologies', facets?.technologies);
+}
+
+async function loadProjects(append = false){
+  const p = new URLSearchParams(location.search);
+  if (append && nextCursor) p.set('cursor', nextCursor);
+  const url = '/api/projects' + (p.toString() ? '?' + p.toString() : '');
   const grid = $('#grid');
+  const more = $('#more');
 
   grid.setAttribute('aria-busy', 'true');
-  grid.innerHTML = `
-    <article class="card project-card" role="status">
-      <p class="max-w-prose">Chargement…</p>
-    </article>`;
+  more.hidden = true;
+  if (!append){
+    grid.innerHTML = `
+      <article class="card project-card" role="status">
+        <p class="max-w-prose">Chargement…</p>
+      </article>`;
+  }
 
   try{
     const res = await fetch(url, { headers: { 'Accept': 'application/json' } });
-    if(!res.ok) throw new Error('HTTP '+res.status);
-    const items = await res.json();
+    if(!res.ok) throw new Error('HTTP '+res.status+' '+(await res.text()));
+    const page = await res.json();
+
+    fillFacets(page.facets);
+    $('#count').textContent = page.total + (page.total > 1 ? ' projets' : ' projet');
+    nextCursor = page.next_cursor;
+    more.hidden = !nextCursor;
 
-    if (!Array.isArray(items) || items.length === 0){
+    if (!append) grid.innerHTML = '';
+    if (!append && page.items.length === 0){
       grid.innerHTML = `
         <article class="card project-card">
           <p class="max-w-prose">Aucun projet trouvé avec ces filtres.</p>
         </article>`;
       return;
     }
-
-    grid.innerHTML = '';
-    for (const p of items){
-      const hasImg  = typeof p.image    === 'string' && p.image.length > 0;
-      const hasRepo = typeof p.repoLink === 'string' && p.repoLink.length > 0;
-      const hasPdf  = typeof p.pdfLink  === 'string' && p.pdfLink.length  > 0;
-
-      const article = document.createElement('article');
-      article.className = 'card project-card';
-
-      article.innerHTML = `
-        ${hasImg ? `<img src="${p.image}" alt="${p.title}" loading="lazy">` : ``}
-        <h3>${p.title}</h3>
-        ${p.category ? `<p class="meta">${p.category}</p>` : ``}
-        ${p.description ? `<p class="max-w-prose">${p.description}</p>` : ``}
-        ${
-          Array.isArray(p.technologies) && p.technologies.length
-          ? `<div class="tags">${p.technologies.map(t=>`<span class="tag">${t}</span>`).join('')}</div>`
-          : ``
-        }
-        <div class="actions">
-          ${hasRepo ? `<a class="btn" href="${p.repoLink}" target="_blank" rel="noopener">Code</a>` : ``}
-          ${hasPdf  ? `<a class="btn ghost" href="${p.pdfLink}" target="_blank" rel="noopener">PDF</a>` : ``}
-        </div>
-      `;
-      grid.appendChild(article);
-    }
+    for (const item of page.items) grid.appendChild(card(item));
   }catch(err){
     grid.innerHTML = `
       <article class="card project-card">
-        <p class="max-w-prose">Erreur de chargement : ${String(err).replace(/</g,'&lt;')}</p>
+        <p class="max-w-prose">Erreur de chargement : ${esc(err)}</p>
       </article>`;
   }finally{
     grid.setAttribute('aria-busy', 'false');
@@ -92,6 +114,7 @@ $('#reset').addEventListener('click', ()=>{
   $('#filters').reset();
   loadProjects();
 });
+$('#more').addEventListener('click', ()=>loadProjects(true));
 
 // Initial load
 loadProjects();
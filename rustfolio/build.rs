@@ -0,0 +1,4 @@
+// `sqlx::migrate!` lit migrations/ à la compilation : recompiler quand un fichier y change.
+fn main() {
+    println!("cargo:rerun-if-changed=migrations");
+}
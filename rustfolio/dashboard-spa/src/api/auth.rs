@@ -0,0 +1,12 @@
+//! `/auth/*` (compte de l'utilisateur connecté).
+
+use super::{get, patch, ApiResult, Me};
+
+pub async fn me() -> ApiResult<Me> {
+    get("/auth/me").await
+}
+
+/// Langue de l'interface ; `None` : revenir à celle du navigateur.
+pub async fn set_locale(locale: Option<&str>) -> ApiResult<Me> {
+    patch("/auth/me", &serde_json::json!({ "locale": locale })).await
+}
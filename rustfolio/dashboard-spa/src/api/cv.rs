@@ -0,0 +1,77 @@
+//! `/api/cv/*` : contenu du CV (profil, expériences et leurs tâches, compétences,
+//! projets) et catalogue de technologies.
+
+use super::{
+    delete, get, get_query, patch, post, put_unit, ApiResult, CatalogEntry, CvData, Experience, Profile,
+    SkillIn, SkillOut, TaskItem,
+};
+
+/// Tout le contenu, dans la langue par défaut (avec les traductions).
+pub async fn bulk() -> ApiResult<CvData> {
+    get("/api/cv/bulk").await
+}
+
+/* --------------------------- profil --------------------------- */
+
+pub async fn profile() -> ApiResult<Profile> {
+    get("/api/cv/profile").await
+}
+
+pub async fn save_profile(profile: &Profile) -> ApiResult<()> {
+    put_unit("/api/cv/profile", profile).await
+}
+
+/* ------------------------- expériences ------------------------- */
+
+pub async fn experiences() -> ApiResult<Vec<Experience>> {
+    get("/api/cv/experiences").await
+}
+
+pub async fn create_experience(exp: &Experience) -> ApiResult<Experience> {
+    post("/api/cv/experiences", exp).await
+}
+
+/// Remplace l'expérience (tâches comprises, dans l'ordre de `exp.tasks`).
+pub async fn update_experience(id: i64, exp: &Experience) -> ApiResult<()> {
+    put_unit(&format!("/api/cv/experiences/{id}"), exp).await
+}
+
+pub async fn delete_experience(id: i64) -> ApiResult<()> {
+    delete(&format!("/api/cv/experiences/{id}")).await
+}
+
+pub async fn tasks(exp: i64) -> ApiResult<Vec<TaskItem>> {
+    get(&format!("/api/cv/experiences/{exp}/tasks")).await
+}
+
+pub async fn add_task(exp: i64, task: &str) -> ApiResult<TaskItem> {
+    post(&format!("/api/cv/experiences/{exp}/tasks"), &serde_json::json!({ "task": task })).await
+}
+
+/// Modifie le texte en place (l'id, donc l'ordre, est conservé).
+pub async fn update_task(exp: i64, id: i64, task: &str) -> ApiResult<TaskItem> {
+    patch(&format!("/api/cv/experiences/{exp}/tasks/{id}"), &serde_json::json!({ "task": task })).await
+}
+
+pub async fn delete_task(exp: i64, id: i64) -> ApiResult<()> {
+    delete(&format!("/api/cv/experiences/{exp}/tasks/{id}")).await
+}
+
+/* ------------------------- compétences ------------------------- */
+
+pub async fn skills() -> ApiResult<Vec<SkillOut>> {
+    get("/api/cv/skills").await
+}
+
+pub async fn create_skill(skill: &SkillIn) -> ApiResult<SkillOut> {
+    post("/api/cv/skills", skill).await
+}
+
+pub async fn delete_skill(id: i64) -> ApiResult<()> {
+    delete(&format!("/api/cv/skills/{id}")).await
+}
+
+/// Autocomplétion des noms de compétences / technologies.
+pub async fn catalog_search(q: &str, limit: u8) -> ApiResult<Vec<CatalogEntry>> {
+    get_query("/api/catalog/search", &[("q", q), ("limit", &limit.to_string())]).await
+}
@@ -0,0 +1,45 @@
+//! `/api/cvs/*` : CV nommés de l'utilisateur, leur layout, thème et éléments masqués.
+
+use super::{delete, get, patch, post, post_empty, put, ApiResult, CvDetail, CvSummary, HiddenItems, Layout, Theme};
+
+const CVS_URL: &str = "/api/cvs";
+
+pub async fn list() -> ApiResult<Vec<CvSummary>> {
+    get(CVS_URL).await
+}
+
+pub async fn get_cv(id: i64) -> ApiResult<CvDetail> {
+    get(&format!("{CVS_URL}/{id}")).await
+}
+
+pub async fn create(name: &str) -> ApiResult<CvDetail> {
+    post(CVS_URL, &serde_json::json!({ "name": name })).await
+}
+
+/// Copie nommée « <nom> (copie) » par le serveur.
+pub async fn duplicate(id: i64) -> ApiResult<CvDetail> {
+    post_empty(&format!("{CVS_URL}/{id}/duplicate")).await
+}
+
+/// 409 si le nom est déjà pris.
+pub async fn rename(id: i64, name: &str) -> ApiResult<CvDetail> {
+    patch(&format!("{CVS_URL}/{id}"), &serde_json::json!({ "name": name })).await
+}
+
+pub async fn set_hidden(id: i64, hidden: &HiddenItems) -> ApiResult<CvDetail> {
+    patch(&format!("{CVS_URL}/{id}"), &serde_json::json!({ "hidden": hidden })).await
+}
+
+/// Thème complet (le serveur le valide : 422 avec le détail sinon).
+pub async fn set_theme(id: i64, theme: &Theme) -> ApiResult<CvDetail> {
+    patch(&format!("{CVS_URL}/{id}"), &serde_json::json!({ "theme": theme })).await
+}
+
+pub async fn delete_cv(id: i64) -> ApiResult<()> {
+    delete(&format!("{CVS_URL}/{id}")).await
+}
+
+/// Renvoie le layout tel que stocké (normalisé par le serveur).
+pub async fn save_layout(id: i64, layout: &Layout) -> ApiResult<Layout> {
+    put(&format!("{CVS_URL}/{id}/layout"), layout).await
+}
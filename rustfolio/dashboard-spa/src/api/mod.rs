@@ -1,114 +1,132 @@
-// dashboard-spa/src/api/mod.rs
-use gloo_net::http::Request;
-use serde::{Deserialize, Serialize};
-
-/// Adapte si nécessaire (en dev: back = http://localhost:8080, sinon reverse-proxy => "")
-const API_BASE: &str = "http://localhost:8080";
-
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub struct Profile {
-    pub first_name: String,
-    pub last_name: String,
-    pub title: String,
-    pub email: String,
-    pub phone: String,
-    pub location: String,
-    pub about: String,
-    pub website: Option<String>,
-    pub github: Option<String>,
-    pub linkedin: Option<String>,
-    pub twitter: Option<String>,
-    pub picture: Option<String>,
+//! Client de l'API du serveur : seul module du SPA qui fait des requêtes HTTP.
+//!
+//! - URLs relatives (le dashboard est servi par le serveur lui-même, `/dashboard`) ;
+//! - cookie de session toujours envoyé (`credentials: include`) ;
+//! - erreurs uniformes ([`ApiError`], message traduit par [`ApiError::message`]) : un 401
+//!   renvoie vers la page de connexion ;
+//! - corps typés : ceux du serveur, partagés par le crate `rustfolio-types`.
+//!
+//! Les appels sont regroupés comme les routes du serveur : [`cv`] (contenu du CV),
+//! [`cvs`] (CV nommés, layout, thème), [`auth`] (compte).
+
+use gloo_net::http::{Request, RequestBuilder, Response};
+use serde::{de::DeserializeOwned, Serialize};
+use web_sys::RequestCredentials;
+
+use crate::i18n::Tr;
+
+pub mod auth;
+pub mod cv;
+pub mod cvs;
+
+pub use rustfolio_layout::{Layout, Theme};
+pub use rustfolio_types::*;
+
+/// Page vers laquelle un 401 redirige.
+const LOGIN_URL: &str = "/auth/login";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ApiError {
+    /// 401 : session absente ou expirée ; la redirection vers la connexion est lancée.
+    Unauthorized,
+    /// Autre réponse non 2xx, avec le corps (détail d'un 422, conflit d'un 409…).
+    Http { status: u16, message: String },
+    /// Requête non aboutie (serveur injoignable…).
+    Network(String),
+    /// Corps illisible (ou non sérialisable à l'envoi).
+    Decode(String),
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub struct Experience {
-    pub date: String,
-    #[serde(rename = "type")]
-    pub r#type: String,
-    pub title: String,
-    pub company: String,
-    pub location: String,
-    pub tasks: Vec<String>,
+impl ApiError {
+    /// Message affiché à l'utilisateur, dans la langue de l'interface.
+    pub fn message(&self, tr: &Tr) -> String {
+        match self {
+            ApiError::Unauthorized => tr.t("dash-error-session-expired"),
+            ApiError::Http { status, message } if message.is_empty() => {
+                tr.t_args("dash-error-http", &[("status", &status.to_string())])
+            }
+            ApiError::Http { status, message } => {
+                tr.t_args("dash-error-http-detail", &[("status", &status.to_string()), ("message", message)])
+            }
+            ApiError::Network(e) => tr.t_args("dash-error-network", &[("error", e)]),
+            ApiError::Decode(e) => tr.t_args("dash-error-decode", &[("error", e)]),
+        }
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub struct Skill {
-    pub name: String,
-    pub percentage: u8,
-    pub logo: String,
-    pub category: String,
+pub type ApiResult<T> = Result<T, ApiError>;
+
+fn redirect_to_login() {
+    if let Some(window) = web_sys::window() {
+        let _ = window.location().set_href(LOGIN_URL);
+    }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub struct Project {
-    pub title: String,
-    pub description: String,
-    pub category: String,
-    pub repo_link: Option<String>,
-    pub pdf_link: Option<String>,
-    pub image: Option<String>,
-    pub technologies: Vec<String>,
+/// Statut → erreur ; le corps d'une réponse 2xx reste à lire.
+async fn check(resp: Response) -> ApiResult<Response> {
+    match resp.status() {
+        200..=299 => Ok(resp),
+        401 => {
+            redirect_to_login();
+            Err(ApiError::Unauthorized)
+        }
+        status => Err(ApiError::Http { status, message: resp.text().await.unwrap_or_default() }),
+    }
 }
 
-// -------- GET ----------
+async fn send(req: RequestBuilder) -> ApiResult<Response> {
+    let resp = req
+        .credentials(RequestCredentials::Include)
+        .send()
+        .await
+        .map_err(|e| ApiError::Network(e.to_string()))?;
+    check(resp).await
+}
 
-pub async fn get_profile() -> Result<Profile, String> {
-    Request::get(&format!("{}/api/cv/profile", API_BASE))
-        .send().await.map_err(to_err)?
-        .json::<Profile>().await.map_err(to_err)
+async fn send_json<B: Serialize + ?Sized>(req: RequestBuilder, body: &B) -> ApiResult<Response> {
+    let req = req
+        .credentials(RequestCredentials::Include)
+        .json(body)
+        .map_err(|e| ApiError::Decode(e.to_string()))?;
+    let resp = req.send().await.map_err(|e| ApiError::Network(e.to_string()))?;
+    check(resp).await
 }
 
-pub async fn get_experiences() -> Result<Vec<Experience>, String> {
-    Request::get(&format!("{}/api/cv/experiences", API_BASE))
-        .send().await.map_err(to_err)?
-        .json::<Vec<Experience>>().await.map_err(to_err)
+async fn read<T: DeserializeOwned>(resp: Response) -> ApiResult<T> {
+    resp.json::<T>().await.map_err(|e| ApiError::Decode(e.to_string()))
 }
 
-pub async fn get_skills() -> Result<Vec<Skill>, String> {
-    Request::get(&format!("{}/api/cv/skills", API_BASE))
-        .send().await.map_err(to_err)?
-        .json::<Vec<Skill>>().await.map_err(to_err)
+pub(crate) async fn get<T: DeserializeOwned>(path: &str) -> ApiResult<T> {
+    read(send(Request::get(path)).await?).await
 }
 
-pub async fn get_projects() -> Result<Vec<Project>, String> {
-    Request::get(&format!("{}/api/cv/projects", API_BASE))
-        .send().await.map_err(to_err)?
-        .json::<Vec<Project>>().await.map_err(to_err)
+pub(crate) async fn get_query<T: DeserializeOwned>(path: &str, query: &[(&str, &str)]) -> ApiResult<T> {
+    read(send(Request::get(path).query(query.iter().copied())).await?).await
 }
 
-// -------- PUT (remplacement entier) ----------
+pub(crate) async fn post<B: Serialize + ?Sized, T: DeserializeOwned>(path: &str, body: &B) -> ApiResult<T> {
+    read(send_json(Request::post(path), body).await?).await
+}
 
-pub async fn put_profile(payload: &Profile) -> Result<(), String> {
-    Request::put(&format!("{}/api/cv/profile", API_BASE))
-        .header("Content-Type", "application/json")
-        .json(payload).map_err(to_err)?
-        .send().await.map_err(to_err)?
-        .ok().then_some(()).ok_or("PUT /profile failed".into())
+/// POST sans corps (`/duplicate`…).
+pub(crate) async fn post_empty<T: DeserializeOwned>(path: &str) -> ApiResult<T> {
+    read(send(Request::post(path)).await?).await
 }
 
-pub async fn put_experiences(list: &[Experience]) -> Result<(), String> {
-    Request::put(&format!("{}/api/cv/experiences", API_BASE))
-        .header("Content-Type", "application/json")
-        .json(list).map_err(to_err)?
-        .send().await.map_err(to_err)?
-        .ok().then_some(()).ok_or("PUT /experiences failed".into())
+pub(crate) async fn put<B: Serialize + ?Sized, T: DeserializeOwned>(path: &str, body: &B) -> ApiResult<T> {
+    read(send_json(Request::put(path), body).await?).await
 }
 
-pub async fn put_skills(list: &[Skill]) -> Result<(), String> {
-    Request::put(&format!("{}/api/cv/skills", API_BASE))
-        .header("Content-Type", "application/json")
-        .json(list).map_err(to_err)?
-        .send().await.map_err(to_err)?
-        .ok().then_some(()).ok_or("PUT /skills failed".into())
+/// PUT dont la réponse (`{"ok": true}`…) n'est pas lue.
+pub(crate) async fn put_unit<B: Serialize + ?Sized>(path: &str, body: &B) -> ApiResult<()> {
+    send_json(Request::put(path), body).await.map(drop)
 }
 
-pub async fn put_projects(list: &[Project]) -> Result<(), String> {
-    Request::put(&format!("{}/api/cv/projects", API_BASE))
-        .header("Content-Type", "application/json")
-        .json(list).map_err(to_err)?
-        .send().await.map_err(to_err)?
-        .ok().then_some(()).ok_or("PUT /projects failed".into())
+pub(crate) async fn patch<B: Serialize + ?Sized, T: DeserializeOwned>(path: &str, body: &B) -> ApiResult<T> {
+    read(send_json(Request::patch(path), body).await?).await
 }
 
-fn to_err<E: std::fmt::Display>(e: E) -> String { e.to_string() }
+/// DELETE : seul le statut compte.
+pub(crate) async fn delete(path: &str) -> ApiResult<()> {
+    send(Request::delete(path)).await.map(drop)
+}
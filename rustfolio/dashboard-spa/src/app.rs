@@ -1,6 +1,7 @@
 use yew::prelude::*;
 use yew_router::prelude::*;
 
+use crate::i18n::LangProvider;
 use crate::pages::nav::Nav;
 use crate::router::{switch, Route};
 
@@ -14,12 +15,14 @@ impl Component for App {
 
     fn view(&self, _ctx: &Context<Self>) -> Html {
         html! {
-            <BrowserRouter>
-                <Nav/>
-                <main class="container py-4">
-                    <Switch<Route> render={switch} />
-                </main>
-            </BrowserRouter>
+            <LangProvider>
+                <BrowserRouter>
+                    <Nav/>
+                    <main class="container py-4">
+                        <Switch<Route> render={switch} />
+                    </main>
+                </BrowserRouter>
+            </LangProvider>
         }
     }
 }
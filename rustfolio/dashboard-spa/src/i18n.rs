@@ -0,0 +1,40 @@
+//! Langue de l'interface du SPA : catalogues partagés avec le serveur
+//! (`rustfolio-i18n`), langue initiale = `<html lang>` du shell Askama, déjà
+//! négociée côté serveur (préférence enregistrée, sinon `Accept-Language`).
+
+use yew::prelude::*;
+
+pub use rustfolio_i18n::{Lang, Tr};
+
+/// Langue posée par le serveur sur `<html lang>`.
+pub fn initial() -> Tr {
+    let lang = web_sys::window()
+        .and_then(|w| w.document())
+        .and_then(|d| d.document_element())
+        .and_then(|e| e.get_attribute("lang"))
+        .and_then(|l| Lang::parse(&l))
+        .unwrap_or_default();
+    Tr(lang)
+}
+
+#[derive(Properties, PartialEq)]
+pub struct LangProviderProps {
+    pub children: Children,
+}
+
+/// Fournit la langue courante (modifiable depuis la page Compte).
+#[function_component(LangProvider)]
+pub fn lang_provider(props: &LangProviderProps) -> Html {
+    let tr = use_state(initial);
+    html! {
+        <ContextProvider<UseStateHandle<Tr>> context={tr}>
+            { props.children.clone() }
+        </ContextProvider<UseStateHandle<Tr>>>
+    }
+}
+
+/// Traducteur courant (langue par défaut hors de [`LangProvider`]).
+#[hook]
+pub fn use_tr() -> Tr {
+    use_context::<UseStateHandle<Tr>>().map(|h| *h).unwrap_or_default()
+}
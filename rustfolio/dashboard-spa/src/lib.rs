@@ -1,7 +1,10 @@
+pub mod api;
 pub mod components;
 pub mod pages;
+pub use rustfolio_types::rich_text;
 pub mod router;
 pub mod store;
 pub mod store_builder;
 pub mod store_cv;  
-pub mod app;
\ No newline at end of file
+pub mod app;
+pub mod i18n;
\ No newline at end of file
@@ -1,12 +1,85 @@
+use wasm_bindgen_futures::spawn_local;
+use web_sys::HtmlSelectElement;
 use yew::prelude::*;
-//use crate::store::{AppStore, Row, Column, Widget};
+
+use crate::api::{self, ApiError, Me};
+use crate::i18n::{use_tr, Lang, Tr};
 
 #[function_component(Account)]
 pub fn account() -> Html {
+    let tr = use_tr();
+    let lang_ctx = use_context::<UseStateHandle<Tr>>();
+    let me = use_state(Me::default);
+    let error = use_state(|| Option::<String>::None);
+
+    {
+        let me = me.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                if let Ok(v) = api::auth::me().await {
+                    me.set(v);
+                }
+            });
+            || ()
+        });
+    }
+
+    // "" : revenir à la négociation du navigateur
+    let on_locale = {
+        let me = me.clone();
+        let error = error.clone();
+        Callback::from(move |e: Event| {
+            let select: HtmlSelectElement = e.target_unchecked_into();
+            let value = select.value();
+            let locale = (!value.is_empty()).then_some(value);
+            let me = me.clone();
+            let error = error.clone();
+            let lang_ctx = lang_ctx.clone();
+            spawn_local(async move {
+                error.set(None);
+                match api::auth::set_locale(locale.as_deref()).await {
+                    Ok(v) => {
+                        let lang = Lang::parse(&v.ui_locale).unwrap_or_default();
+                        if let Some(root) = web_sys::window()
+                            .and_then(|w| w.document())
+                            .and_then(|d| d.document_element())
+                        {
+                            let _ = root.set_attribute("lang", lang.code());
+                        }
+                        if let Some(ctx) = &lang_ctx {
+                            ctx.set(Tr(lang));
+                        }
+                        me.set(v);
+                    }
+                    // 422 : message déjà traduit par le serveur
+                    Err(ApiError::Http { message, .. }) if !message.is_empty() => error.set(Some(message)),
+                    Err(e) => error.set(Some(e.message(&tr))),
+                }
+            });
+        })
+    };
+
+    let current = me.locale.clone().unwrap_or_default();
+
     html! {
         <>
-            <h3>{ "Options du compte" }</h3>
-            <p>{ "Ici tu gèreras email, mot de passe, suppression du compte, etc. a venir plus tard..." }</p>
+            <h3>{ tr.t("dash-account-title") }</h3>
+
+            if let Some(err) = (*error).clone() {
+                <p class="dash-error">{err}</p>
+            }
+
+            <label>
+                { tr.t("dash-account-language") }{ " " }
+                <select class="dash-input" onchange={on_locale}>
+                    <option value="" selected={current.is_empty()}>{ tr.t("dash-account-language-auto") }</option>
+                    { for Lang::ALL.iter().map(|l| html!{
+                        <option value={l.code()} selected={current == l.code()}>{ l.native_name() }</option>
+                    }) }
+                </select>
+            </label>
+
+            <p>{ tr.t("dash-account-soon") }</p>
         </>
     }
 }
@@ -1,16 +1,205 @@
+use gloo::events::EventListener;
+use gloo::timers::callback::Timeout;
+use wasm_bindgen::JsCast;
+use web_sys::{HtmlInputElement, HtmlTextAreaElement, KeyboardEvent};
 use yew::prelude::*;
 use yewdux::prelude::*;
 use wasm_bindgen_futures::spawn_local;
 
+use crate::api::{self, Project, Skill, DEFAULT_LOCALE};
 use crate::components::add_row_placeholder::AddRowPlaceholder;
+use crate::i18n::{use_tr, Lang, Tr};
+use crate::pages::builder_cv_picker::{CvAction, CvPicker};
 use crate::pages::builder_sidebar::BuilderSidebar;
-use crate::store_builder::{BuilderLayout, WidgetKind};
+use crate::pages::builder_theme_panel::ThemePanel;
+use crate::rich_text;
+use crate::store_builder::{
+    self, Align, BuilderLayout, CvDetail, CvList, Edit, HiddenItems, Layout, LayoutSync, SaveStatus, SkillDisplay, Theme, Widget,
+    WidgetRef,
+};
 use crate::store_cv::CVStore;
 
+/// Délai sans modification avant l'enregistrement automatique.
+const AUTOSAVE_MS: u32 = 1_500;
+/// CV créé au premier passage dans le builder (même nom que côté serveur).
+const DEFAULT_CV_NAME: &str = "Principal";
+
 #[function_component(Builder)]
 pub fn builder() -> Html {
+    let tr = use_tr();
     let (layout, dispatch) = use_store::<BuilderLayout>();
     let (cv, cv_dispatch) = use_store::<CVStore>();
+    let (sync, sync_dispatch) = use_store::<LayoutSync>();
+    let (cvs, cvs_dispatch) = use_store::<CvList>();
+
+    // Widget sélectionné (panneau de propriétés) et widget en cours de glisser-déposer
+    let selected_widget = use_state(|| None::<WidgetRef>);
+    let dragging = use_state(|| None::<WidgetRef>);
+
+    // Ouvre un CV : son layout (avec son historique local) et ses éléments masqués
+    let open_cv = {
+        let dispatch = dispatch.clone();
+        let sync_dispatch = sync_dispatch.clone();
+        let cvs_dispatch = cvs_dispatch.clone();
+        let selected_widget = selected_widget.clone();
+        Callback::from(move |detail: CvDetail| {
+            selected_widget.set(None);
+            dispatch.set(BuilderLayout::loaded(detail.cv.id, detail.layout.clone()));
+            sync_dispatch.set(LayoutSync { status: SaveStatus::Saved, saved: Some(detail.layout.clone()) });
+            cvs_dispatch.reduce_mut(|l| l.opened(&detail));
+        })
+    };
+
+    // CV enregistré au montage : le dernier ouvert, ou un premier CV créé pour l'occasion
+    {
+        let open_cv = open_cv.clone();
+        let sync_dispatch = sync_dispatch.clone();
+        let cvs_dispatch = cvs_dispatch.clone();
+        use_effect_with((), move |_| {
+            spawn_local(async move {
+                let opened = async {
+                    let list = api::cvs::list().await?;
+                    cvs_dispatch.reduce_mut(|l| l.cvs = list);
+                    match cvs_dispatch.get().initial() {
+                        Some(id) => api::cvs::get_cv(id).await,
+                        None => api::cvs::create(DEFAULT_CV_NAME).await,
+                    }
+                };
+                match opened.await {
+                    Ok(detail) => open_cv.emit(detail),
+                    Err(e) => {
+                        let error = tr.t_args("dash-builder-load-error", &[("error", &e.message(&tr))]);
+                        sync_dispatch.reduce_mut(|s| s.status = SaveStatus::Error(error));
+                    }
+                }
+            });
+            || ()
+        });
+    }
+
+    let save = {
+        let dispatch = dispatch.clone();
+        let sync_dispatch = sync_dispatch.clone();
+        Callback::from(move |(cv, l): (i64, Layout)| {
+            let dispatch = dispatch.clone();
+            let sync_dispatch = sync_dispatch.clone();
+            sync_dispatch.reduce_mut(|s| s.status = SaveStatus::Saving);
+            spawn_local(async move {
+                let result = api::cvs::save_layout(cv, &l).await;
+                // un autre CV a été ouvert entre-temps : son état ne dépend pas de cet envoi
+                if dispatch.get().cv != Some(cv) {
+                    return;
+                }
+                match result {
+                    Ok(saved) => sync_dispatch.reduce_mut(|s| {
+                        s.saved = Some(saved);
+                        s.status = SaveStatus::Saved;
+                    }),
+                    Err(e) => sync_dispatch.reduce_mut(|s| s.status = SaveStatus::Error(e.message(&tr))),
+                }
+            });
+        })
+    };
+
+    // Autosave : relancé à chaque modification, le Timeout précédent est annulé (drop)
+    {
+        let save = save.clone();
+        let sync_dispatch = sync_dispatch.clone();
+        use_effect_with((layout.cv, layout.layout.clone(), sync.saved.clone()), move |(cv, layout, saved)| {
+            let timer = match (cv, saved) {
+                (Some(cv), Some(saved)) if !layout.same_content(saved) => {
+                    sync_dispatch.reduce_mut(|s| if s.status != SaveStatus::Saving { s.status = SaveStatus::Dirty });
+                    let arg = (*cv, layout.clone());
+                    Some(Timeout::new(AUTOSAVE_MS, move || save.emit(arg)))
+                }
+                _ => None,
+            };
+            move || drop(timer)
+        });
+    }
+
+    // Sélecteur de CV : le CV ouvert est enregistré avant d'en changer
+    let on_cv_action = {
+        let dispatch = dispatch.clone();
+        let sync_dispatch = sync_dispatch.clone();
+        let cvs_dispatch = cvs_dispatch.clone();
+        let open_cv = open_cv.clone();
+        Callback::from(move |action: CvAction| {
+            let dispatch = dispatch.clone();
+            let sync_dispatch = sync_dispatch.clone();
+            let cvs_dispatch = cvs_dispatch.clone();
+            let open_cv = open_cv.clone();
+            spawn_local(async move {
+                let Some(current) = dispatch.get().cv else { return };
+                let result = async {
+                    let (b, s) = (dispatch.get(), sync_dispatch.get());
+                    if s.saved.as_ref().is_some_and(|saved| !b.layout.same_content(saved)) {
+                        api::cvs::save_layout(current, &b.layout).await?;
+                    }
+                    match action {
+                        CvAction::Open(id) => api::cvs::get_cv(id).await.map(Some),
+                        CvAction::Create(name) => api::cvs::create(&name).await.map(Some),
+                        CvAction::Duplicate => api::cvs::duplicate(current).await.map(Some),
+                        CvAction::Rename(name) => {
+                            let detail = api::cvs::rename(current, &name).await?;
+                            cvs_dispatch.reduce_mut(|l| l.opened(&detail));
+                            Ok(None)
+                        }
+                        CvAction::Delete => {
+                            api::cvs::delete_cv(current).await?;
+                            cvs_dispatch.reduce_mut(|l| l.cvs.retain(|c| c.id != current));
+                            match cvs_dispatch.get().initial() {
+                                Some(id) => api::cvs::get_cv(id).await.map(Some),
+                                None => api::cvs::create(DEFAULT_CV_NAME).await.map(Some),
+                            }
+                        }
+                    }
+                };
+                match result.await {
+                    Ok(Some(detail)) => open_cv.emit(detail),
+                    Ok(None) => {}
+                    Err(e) => sync_dispatch.reduce_mut(|s| s.status = SaveStatus::Error(e.message(&tr))),
+                }
+            });
+        })
+    };
+
+    let on_hidden = {
+        let dispatch = dispatch.clone();
+        let sync_dispatch = sync_dispatch.clone();
+        let cvs_dispatch = cvs_dispatch.clone();
+        Callback::from(move |hidden: HiddenItems| {
+            let Some(cv) = dispatch.get().cv else { return };
+            let sync_dispatch = sync_dispatch.clone();
+            let cvs_dispatch = cvs_dispatch.clone();
+            // affichage immédiat, le serveur confirme
+            cvs_dispatch.reduce_mut(|l| l.hidden = hidden.clone());
+            spawn_local(async move {
+                match api::cvs::set_hidden(cv, &hidden).await {
+                    Ok(detail) => cvs_dispatch.reduce_mut(|l| l.opened(&detail)),
+                    Err(e) => sync_dispatch.reduce_mut(|s| s.status = SaveStatus::Error(e.message(&tr))),
+                }
+            });
+        })
+    };
+
+    let on_theme = {
+        let dispatch = dispatch.clone();
+        let sync_dispatch = sync_dispatch.clone();
+        let cvs_dispatch = cvs_dispatch.clone();
+        Callback::from(move |theme: Theme| {
+            let Some(cv) = dispatch.get().cv else { return };
+            let sync_dispatch = sync_dispatch.clone();
+            let cvs_dispatch = cvs_dispatch.clone();
+            cvs_dispatch.reduce_mut(|l| l.theme = theme.clone());
+            spawn_local(async move {
+                match api::cvs::set_theme(cv, &theme).await {
+                    Ok(detail) => cvs_dispatch.reduce_mut(|l| l.opened(&detail)),
+                    Err(e) => sync_dispatch.reduce_mut(|s| s.status = SaveStatus::Error(e.message(&tr))),
+                }
+            });
+        })
+    };
 
     // Fetch DB au montage (garde une démo + message si ça échoue)
     {
@@ -20,7 +209,7 @@ pub fn builder() -> Html {
                 match CVStore::fetch_all().await {
                     Ok(data) => cv_dispatch.set(data),
                     Err(e) => cv_dispatch.reduce_mut(|st| {
-                        st.last_error = Some(e);
+                        st.last_error = Some(e.message(&tr));
                         st.source = Some("demo".into());
                         st.load_demo();
                     }),
@@ -30,10 +219,27 @@ pub fn builder() -> Html {
         });
     }
 
+    // Historique gardé en local après chaque changement (une fois le layout chargé)
+    {
+        use_effect_with(((*layout).clone(), sync.saved.is_some()), |(layout, loaded)| {
+            if *loaded {
+                store_builder::persist_history(layout);
+            }
+            || ()
+        });
+    }
+
     // Actions
-    let on_add_row = {
+    let on_edit = {
         let dispatch = dispatch.clone();
-        Callback::from(move |_| dispatch.reduce_mut(|st| st.add_row()))
+        let selected_widget = selected_widget.clone();
+        Callback::from(move |edit: Edit| {
+            // les positions changent : on garde la sélection seulement pour une mise à jour
+            if !matches!(edit, Edit::UpdateWidget { .. }) {
+                selected_widget.set(None);
+            }
+            dispatch.reduce_mut(|st| { st.edit(edit); });
+        })
     };
     let on_select_row = {
         let dispatch = dispatch.clone();
@@ -43,20 +249,85 @@ pub fn builder() -> Html {
         let dispatch = dispatch.clone();
         Callback::from(move |col_id: usize| dispatch.reduce_mut(|st| st.select_column(col_id)))
     };
-    let on_split = {
+    let on_split = on_edit.reform(Edit::SplitSelectedRow);
+    let on_add_widget = on_edit.reform(Edit::AddWidget);
+    let on_widget_change = {
+        let on_edit = on_edit.clone();
+        let selected_widget = selected_widget.clone();
+        Callback::from(move |widget: Widget| {
+            if let Some(at) = *selected_widget {
+                on_edit.emit(Edit::UpdateWidget { at, widget });
+            }
+        })
+    };
+    let on_undo = {
         let dispatch = dispatch.clone();
-        Callback::from(move |n: usize| dispatch.reduce_mut(|st| st.split_selected_row(n)))
+        let selected_widget = selected_widget.clone();
+        Callback::from(move |_: ()| { selected_widget.set(None); dispatch.reduce_mut(|st| { st.undo(); }); })
     };
-    let on_add_widget = {
+    let on_redo = {
         let dispatch = dispatch.clone();
-        Callback::from(move |w: WidgetKind| dispatch.reduce_mut(|st| st.add_widget_to_selected_column(w.clone())))
+        let selected_widget = selected_widget.clone();
+        Callback::from(move |_: ()| { selected_widget.set(None); dispatch.reduce_mut(|st| { st.redo(); }); })
     };
+
+    // Ctrl+Z / Ctrl+Shift+Z (ou Ctrl+Y) hors des champs de saisie, qui gardent
+    // l'annulation native du navigateur
+    {
+        let on_undo = on_undo.clone();
+        let on_redo = on_redo.clone();
+        use_effect_with((), move |_| {
+            let document = web_sys::window().and_then(|w| w.document());
+            let listener = document.map(|doc| EventListener::new(&doc, "keydown", move |e| {
+                let Some(e) = e.dyn_ref::<KeyboardEvent>() else { return };
+                if !(e.ctrl_key() || e.meta_key()) { return; }
+                let in_field = e.target().is_some_and(|t| {
+                    t.dyn_ref::<HtmlInputElement>().is_some() || t.dyn_ref::<HtmlTextAreaElement>().is_some()
+                });
+                if in_field { return; }
+                match (e.key().to_lowercase().as_str(), e.shift_key()) {
+                    ("z", false) => { e.prevent_default(); on_undo.emit(()); }
+                    ("z", true) | ("y", _) => { e.prevent_default(); on_redo.emit(()); }
+                    _ => {}
+                }
+            }));
+            move || drop(listener)
+        });
+    }
+
     let on_save = {
         let layout = layout.clone();
-        Callback::from(move |_| {
-            web_sys::console::log_1(&format!("[DEV] Layout rows={}", layout.rows.len()).into());
+        Callback::from(move |_| if let Some(cv) = layout.cv { save.emit((cv, layout.layout.clone())) })
+    };
+
+    // Petit bouton d'outil : n'active pas la sélection de la ligne/colonne parente
+    let tool = |label: &'static str, title: String, edit: Edit| {
+        let on_edit = on_edit.clone();
+        html! {
+            <button title={title} style="padding:0 6px;font-size:.8rem;"
+                onclick={Callback::from(move |e: MouseEvent| { e.stop_propagation(); on_edit.emit(edit.clone()); })}>
+                { label }
+            </button>
+        }
+    };
+    // Dépôt à la position `to` du widget glissé
+    let drop_at = |to: WidgetRef| {
+        let on_edit = on_edit.clone();
+        let dragging = dragging.clone();
+        Callback::from(move |e: DragEvent| {
+            e.prevent_default();
+            e.stop_propagation();
+            if let Some(from) = *dragging {
+                on_edit.emit(Edit::MoveWidget { from, to });
+            }
+            dragging.set(None);
         })
     };
+    let allow_drop = Callback::from(|e: DragEvent| e.prevent_default());
+
+    let visible = visible_items(&cv, &cvs.hidden);
+    // Jetons du thème du CV ouvert, repris par les aperçus des widgets
+    let preview_style = format!("{}font-family:var(--font-body);", cvs.theme.css_vars());
 
     // --------- BANNIÈRE D'ÉTAT (calculée hors du html!) ----------
     let banner: Html = {
@@ -67,12 +338,12 @@ pub fn builder() -> Html {
                 <div style="margin-bottom:10px;padding:8px 10px;border:1px solid #2a3552;border-radius:8px;background:#10192e;">
                     {
                         if let Some(s) = src {
-                            html!{ <span style="opacity:.85;">{ format!("Source des données: {}", s) }</span> }
+                            html!{ <span style="opacity:.85;">{ tr.t_args("dash-builder-data-source", &[("source", &s)]) }</span> }
                         } else { Html::default() }
                     }
                     {
                         if let Some(e) = err {
-                            html!{ <span style="color:#ff9f9f;margin-left:6px;">{ format!("— Erreur: {}", e) }</span> }
+                            html!{ <span style="color:#ff9f9f;margin-left:6px;">{ tr.t_args("dash-builder-data-error", &[("error", &e)]) }</span> }
                         } else { Html::default() }
                     }
                 </div>
@@ -85,13 +356,29 @@ pub fn builder() -> Html {
 
     html! {
         <div style="display:grid;grid-template-columns:280px 1fr;gap:18px;">
-            <aside>
+            <aside style="display:flex;flex-direction:column;gap:14px;">
+                <CvPicker
+                    cvs={cvs.cvs.clone()}
+                    current={cvs.current}
+                    on_action={on_cv_action}
+                    hidden={cvs.hidden.clone()}
+                    on_hidden={on_hidden}
+                    items={(*cv).clone()}
+                />
+                <ThemePanel cv={cvs.current} theme={cvs.theme.clone()} on_change={on_theme} />
                 <BuilderSidebar
                     on_save={on_save}
                     selected_row={layout.selected_row}
                     selected_column={layout.selected_column}
                     on_split={on_split}
                     on_add_widget={on_add_widget}
+                    status={sync.status.clone()}
+                    selected_widget={(*selected_widget).and_then(|at| layout.widget(at).cloned())}
+                    on_widget_change={on_widget_change}
+                    can_undo={layout.history.can_undo()}
+                    can_redo={layout.history.can_redo()}
+                    on_undo={on_undo}
+                    on_redo={on_redo}
                 />
             </aside>
 
@@ -99,10 +386,11 @@ pub fn builder() -> Html {
                 { banner }
 
                 <AddRowPlaceholder on_add={Callback::from({
-                    let on_add_row = on_add_row.clone();
-                    move |_| on_add_row.emit(())
+                    let on_edit = on_edit.clone();
+                    move |_| on_edit.emit(Edit::AddRow)
                 })} />
 
+                <div style={preview_style}>
                 {
                     for layout.rows.iter().map(|row| {
                         let selected_row = layout.selected_row == Some(row.id);
@@ -115,22 +403,27 @@ pub fn builder() -> Html {
 
                         let n = row.columns.len().max(1);
                         let grid_style = format!(
-                            "display:grid;grid-template-columns:repeat({},minmax(0,1fr));gap:12px;padding:14px;",
-                            n
+                            "display:grid;grid-template-columns:{};gap:12px;padding:14px;",
+                            row.columns.iter().map(|c| format!("minmax(0,{}fr)", c.width)).collect::<Vec<_>>().join(" ")
                         );
+                        let last_column = row.columns.len() <= 1;
 
                         html! {
                             <section style="margin:18px 0;">
                                 <div
                                     style={format!(
-                                        "background:#0e1523;border:1px solid {};border-radius:12px;overflow:hidden;{}",
+                                        "background:var(--bg);border:1px solid {};border-radius:12px;overflow:hidden;{}",
                                         if selected_row { "#3a5bff" } else { "#25304a" },
                                         if selected_row { "box-shadow:0 0 0 2px rgba(58,91,255,.35) inset;" } else { "" }
                                     )}
                                     onclick={on_row_click}
                                 >
-                                    <div style="padding:10px 14px;font-weight:600;border-bottom:1px dashed #2a3552;">
-                                        { format!("Ligne #{}", row.id) } {" · "} { format!("{} colonne(s)", n) }
+                                    <div style="padding:10px 14px;font-weight:600;border-bottom:1px dashed #2a3552;display:flex;align-items:center;gap:6px;">
+                                        <span style="flex:1;">{ tr.t_args("dash-builder-row-heading", &[("id", &row.id.to_string()), ("count", &n.to_string())]) }</span>
+                                        { tool("↑", tr.t("dash-builder-row-up"), Edit::MoveRow { row: row.id, by: -1 }) }
+                                        { tool("↓", tr.t("dash-builder-row-down"), Edit::MoveRow { row: row.id, by: 1 }) }
+                                        { tool("⧉", tr.t("dash-builder-row-duplicate"), Edit::DuplicateRow(row.id)) }
+                                        { tool("✕", tr.t("dash-builder-row-delete"), Edit::DeleteRow(row.id)) }
                                     </div>
 
                                     <div style={grid_style}>
@@ -147,6 +440,7 @@ pub fn builder() -> Html {
                                                         on_select_column.emit(id);
                                                     })
                                                 };
+                                                let end = WidgetRef { column: col.id, index: col.widgets.len() };
 
                                                 html!{
                                                     <div
@@ -156,21 +450,64 @@ pub fn builder() -> Html {
                                                             if selected_col { "box-shadow:0 0 0 2px rgba(58,91,255,.25) inset;" } else { "" }
                                                         )}
                                                         onclick={on_col_click}
+                                                        ondragover={allow_drop.clone()}
+                                                        ondrop={drop_at(end)}
                                                     >
-                                                        <div style="display:flex;align-items:center;justify-content:space-between;margin-bottom:8px;opacity:.9;">
-                                                            <span style="font-size:.85rem;">{ format!("Colonne #{}", col.id) }</span>
-                                                            <span style="opacity:.6;font-size:.85rem;">{ "+" }</span>
+                                                        <div style="display:flex;align-items:center;gap:4px;margin-bottom:8px;opacity:.9;">
+                                                            <span style="font-size:.85rem;flex:1;">{ tr.t_args("dash-builder-column-heading", &[("id", &col.id.to_string()), ("width", &col.width.to_string())]) }</span>
+                                                            { tool("−", tr.t("dash-builder-column-narrow"), Edit::SetColumnWidth { column: col.id, width: col.width.saturating_sub(1) }) }
+                                                            { tool("+", tr.t("dash-builder-column-widen"), Edit::SetColumnWidth { column: col.id, width: col.width.saturating_add(1) }) }
+                                                            { tool("←", tr.t("dash-builder-column-left"), Edit::MoveColumn { column: col.id, by: -1 }) }
+                                                            { tool("→", tr.t("dash-builder-column-right"), Edit::MoveColumn { column: col.id, by: 1 }) }
+                                                            { tool("⧉", tr.t("dash-builder-column-duplicate"), Edit::DuplicateColumn(col.id)) }
+                                                            { if last_column { Html::default() } else { tool("✕", tr.t("dash-builder-column-delete"), Edit::DeleteColumn(col.id)) } }
                                                         </div>
 
-                                                        <div style="display:flex;flex-direction:column;gap:8px;">
+                                                        <div style="display:flex;flex-direction:column;gap:calc(var(--space) * 2);color:var(--text);">
                                                             {
-                                                                for col.widgets.iter().map(|w| {
-                                                                    render_widget_preview_with_cv(w, &cv)
+                                                                for col.widgets.iter().enumerate().map(|(index, w)| {
+                                                                    let at = WidgetRef { column: col.id, index };
+                                                                    let is_selected = *selected_widget == Some(at);
+                                                                    let on_pick = {
+                                                                        let selected_widget = selected_widget.clone();
+                                                                        Callback::from(move |e: MouseEvent| { e.stop_propagation(); selected_widget.set(Some(at)); })
+                                                                    };
+                                                                    let on_drag_start = {
+                                                                        let dragging = dragging.clone();
+                                                                        Callback::from(move |e: DragEvent| {
+                                                                            // Firefox ne démarre pas le glisser sans données
+                                                                            if let Some(dt) = e.data_transfer() { let _ = dt.set_data("text/plain", "widget"); }
+                                                                            dragging.set(Some(at));
+                                                                        })
+                                                                    };
+                                                                    let on_drag_end = {
+                                                                        let dragging = dragging.clone();
+                                                                        Callback::from(move |_e: DragEvent| dragging.set(None))
+                                                                    };
+                                                                    html! {
+                                                                        <div draggable="true"
+                                                                            style={format!("border-radius:8px;cursor:grab;{}",
+                                                                                if is_selected { "outline:2px solid #3a5bff;" } else { "" })}
+                                                                            onclick={on_pick}
+                                                                            ondragstart={on_drag_start}
+                                                                            ondragend={on_drag_end}
+                                                                            ondragover={allow_drop.clone()}
+                                                                            ondrop={drop_at(at)}
+                                                                        >
+                                                                            <div style="display:flex;justify-content:flex-end;gap:4px;margin-bottom:2px;">
+                                                                                { tool("↑", tr.t("dash-builder-widget-up"), Edit::ShiftWidget { at, by: -1 }) }
+                                                                                { tool("↓", tr.t("dash-builder-widget-down"), Edit::ShiftWidget { at, by: 1 }) }
+                                                                                { tool("⧉", tr.t("dash-builder-widget-duplicate"), Edit::DuplicateWidget(at)) }
+                                                                                { tool("✕", tr.t("dash-builder-widget-delete"), Edit::DeleteWidget(at)) }
+                                                                            </div>
+                                                                            { render_widget_preview_with_cv(&tr, w, &visible, &cvs.theme) }
+                                                                        </div>
+                                                                    }
                                                                 })
                                                             }
                                                             {
                                                                 if col.widgets.is_empty() {
-                                                                    html!{ <div style="opacity:.6;font-size:.9rem;">{ "Aucun contenu. Sélectionne la colonne puis ajoute un widget (sidebar)." }</div> }
+                                                                    html!{ <div style="opacity:.6;font-size:.9rem;">{ tr.t("dash-builder-column-empty") }</div> }
                                                                 } else { Html::default() }
                                                             }
                                                         </div>
@@ -184,49 +521,130 @@ pub fn builder() -> Html {
                         }
                     })
                 }
+                </div>
             </main>
         </div>
     }
 }
 
-fn render_widget_preview_with_cv(w: &WidgetKind, cv: &CVStore) -> Html {
+/// Données du CV ouvert : les éléments masqués n'apparaissent pas dans l'aperçu.
+fn visible_items(cv: &CVStore, hidden: &HiddenItems) -> CVStore {
+    let shown = |id: Option<i64>, ids: &[i64]| !id.is_some_and(|id| ids.contains(&id));
+    let mut visible = cv.clone();
+    visible.experiences.retain(|e| shown(e.id, &hidden.experiences));
+    visible.skills.retain(|s| shown(s.id, &hidden.skills));
+    visible.projects.retain(|p| shown(p.id, &hidden.projects));
+    visible
+}
+
+/// Cartes de l'aperçu : mêmes jetons de thème que l'export (`var(--surface)`…).
+const CARD: &str = "background:var(--surface);border:1px solid var(--border);border-radius:var(--radius);padding:var(--space);";
+
+/// Niveau d'une compétence en cinq points (comme l'export).
+fn dots(percentage: u8) -> Html {
+    let filled = (usize::from(percentage) * 5).div_ceil(100);
+    html! {
+        <span>
+            { for (0..5).map(|i| html! {
+                <span style={format!("display:inline-block;width:8px;height:8px;border-radius:50%;margin-left:3px;background:{};",
+                    if i < filled { "var(--accent)" } else { "var(--border)" })}></span>
+            }) }
+        </span>
+    }
+}
+
+fn render_widget_preview_with_cv(tr: &Tr, w: &Widget, cv: &CVStore, theme: &Theme) -> Html {
+    // titres par défaut : contenu du CV, identiques à l'export (pas la langue de l'interface) ;
+    // l'aperçu montre le contenu de base, donc la langue par défaut du contenu
+    let content = Tr(Lang::parse(DEFAULT_LOCALE).unwrap_or_default());
+    let heading = |text: String| html! { <div style={theme.heading_style()}>{ w.heading().map_or(text, str::to_string) }</div> };
     match w {
-        WidgetKind::Text(t) => html! {
-            <div style="background:#111a2d;border:1px solid #22304f;border-radius:8px;padding:8px;">
-                <div style="font-size:.8rem;opacity:.7;margin-bottom:4px;">{ "Texte" }</div>
-                <div style="font-size:.95rem;color:#c8d1e6;">{ t }</div>
+        Widget::Text { text } => html! {
+            <div style={CARD}>
+                <div style="font-size:.8rem;color:var(--muted);margin-bottom:4px;">{ tr.t("dash-widget-field-text") }</div>
+                <div style="font-size:.95rem;white-space:pre-wrap;">{ text }</div>
             </div>
         },
-        WidgetKind::ProfileBasic => {
+        Widget::Title { text, level, bold, align } => {
+            let align = match align {
+                Some(Align::Center) => "center",
+                Some(Align::Right) => "right",
+                _ => "left",
+            };
+            let size = 1.6 - 0.15 * f32::from((*level).clamp(1, 6) - 1);
+            html! {
+                <div style={format!(
+                    "font-family:var(--font-heading);font-size:{size:.2}rem;font-weight:{};text-align:{align};",
+                    if *bold { 700 } else { 400 }
+                )}>{ text }</div>
+            }
+        },
+        Widget::Photo { url, rounded } => html! {
+            <img src={url.clone()} alt=""
+                style={format!("max-width:100%;border-radius:{};", if *rounded { "50%" } else { "var(--radius)" })} />
+        },
+        Widget::ProfileBasic { .. } => {
             if let Some(p) = &cv.profile {
+                // ligne de contact : champs visibles et renseignés
+                let location = [p.city.as_str(), p.country.as_str()]
+                    .into_iter()
+                    .filter(|v| !v.is_empty())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let contact: Vec<&str> = [
+                    ("location", location.as_str()),
+                    ("email", p.email.as_str()),
+                    ("phone", p.phone.as_str()),
+                    ("website", p.website.as_str()),
+                ]
+                    .into_iter()
+                    .filter(|(f, v)| w.shows(f) && !v.is_empty())
+                    .map(|(_, v)| v)
+                    .collect();
                 html! {
-                    <div style="background:#111a2d;border:1px solid #22304f;border-radius:8px;padding:10px;">
-                        <div style="font-size:1rem;font-weight:700;color:#e4e9f9;">{ format!("{} {}", p.first_name, p.last_name) }</div>
-                        <div style="font-size:.95rem;opacity:.85;color:#c8d1e6;">{ &p.title }</div>
-                        <div style="margin-top:6px;font-size:.85rem;opacity:.7;">
-                            { &p.location }{ " · " }{ &p.email }
-                            { if let Some(w) = &p.website { html!{ <>{" · "}{ w }</> } } else { Html::default() } }
-                        </div>
+                    <div>
+                        { if w.shows("name") { html!{ <div style="font-family:var(--font-heading);font-size:1.2rem;font-weight:700;">{ format!("{} {}", p.first_name, p.last_name) }</div> } } else { Html::default() } }
+                        { if w.shows("title") { html!{ <div style="font-size:.95rem;">{ &p.title }</div> } } else { Html::default() } }
+                        <div style="margin-top:6px;font-size:.85rem;color:var(--muted);">{ contact.join(" · ") }</div>
+                        { if w.shows("summary") && !p.summary.is_empty() { html!{ <div style="margin-top:6px;font-size:.9rem;white-space:pre-wrap;">{ &p.summary }</div> } } else { Html::default() } }
                     </div>
                 }
             } else {
-                html! { <div style="opacity:.7;">{ "Profil non chargé" }</div> }
+                html! { <div style="color:var(--muted);">{ tr.t("dash-builder-profile-missing") }</div> }
             }
         },
-        WidgetKind::ExperienceTimeline => {
+        Widget::ExperienceTimeline { filter_type, .. } => {
             html! {
-                <div style="background:#111a2d;border:1px solid #22304f;border-radius:8px;padding:10px;">
-                    <div style="font-size:.9rem;opacity:.85;margin-bottom:6px;">{ "Expériences" }</div>
-                    <div style="display:flex;flex-direction:column;gap:8px;">
+                <div>
+                    { heading(content.t("cv-section-experiences")) }
+                    <div style="display:flex;flex-direction:column;gap:var(--space);">
                         {
-                            for cv.experiences.iter().map(|e| {
+                            for cv.experiences.iter()
+                                .filter(|e| filter_type.as_ref().map_or(true, |k| &e.kind == k))
+                                .take(w.limit())
+                                .map(|e| {
+                                let title = if w.shows("company") { format!("{} · {}", e.title, e.company) } else { e.title.clone() };
+                                let dates = match (e.date_start.as_str(), e.date_end.as_str()) {
+                                    ("", _) => String::new(),
+                                    (start, "") => format!("{start} – {}", content.t("cv-date-present")),
+                                    (start, end) => format!("{start} – {end}"),
+                                };
+                                let meta: Vec<&str> = [("date", dates.as_str()), ("location", e.location.as_str())]
+                                    .into_iter()
+                                    .filter(|(f, v)| w.shows(f) && !v.is_empty())
+                                    .map(|(_, v)| v)
+                                    .collect();
                                 html!{
-                                    <div style="border-left:3px solid #3a5bff;padding-left:10px;">
-                                        <div style="font-weight:600;color:#e4e9f9;">{ format!("{} — {}", e.title, e.company) }</div>
-                                        <div style="font-size:.85rem;opacity:.8;">{ &e.date }</div>
+                                    <div>
+                                        <div style="font-weight:600;">{ title }</div>
+                                        <div style="font-size:.85rem;color:var(--muted);">{ meta.join(" · ") }</div>
                                         {
-                                            if !e.tasks.is_empty() {
-                                                html!{ <div style="font-size:.9rem;color:#c8d1e6;margin-top:4px;">{ e.tasks.join(" • ") }</div> }
+                                            if w.shows("tasks") && !e.tasks.is_empty() {
+                                                html!{
+                                                    <ul style="margin:4px 0 0;padding-left:1.2em;font-size:.9rem;">
+                                                        { for e.tasks.iter().map(|t| html! { <li>{ Html::from_html_unchecked(rich_text::render_inline(t).into()) }</li> }) }
+                                                    </ul>
+                                                }
                                             } else { Html::default() }
                                         }
                                     </div>
@@ -237,38 +655,69 @@ fn render_widget_preview_with_cv(w: &WidgetKind, cv: &CVStore) -> Html {
                 </div>
             }
         },
-        WidgetKind::SkillsGrid => {
-            html! {
-                <div style="background:#111a2d;border:1px solid #22304f;border-radius:8px;padding:10px;">
-                    <div style="font-size:.9rem;opacity:.85;margin-bottom:6px;">{ "Compétences" }</div>
-                    <div style="display:flex;flex-wrap:wrap;gap:8px;">
-                        {
-                            for cv.skills.iter().map(|s| {
-                                html!{
-                                    <span style="font-size:.8rem;opacity:.9;border:1px solid #2a3552;border-radius:6px;padding:2px 6px;">
-                                        { format!("{} ({}%)", s.name, s.percentage) }
-                                    </span>
-                                }
-                            })
-                        }
+        Widget::SkillsGrid { category, .. } => {
+            let skills: Vec<_> = cv.skills.iter()
+                .filter(|s| category.as_ref().map_or(true, |c| &s.category == c))
+                .take(w.limit())
+                .collect();
+            let logo = |s: &Skill| match s.logo_url.as_deref() {
+                Some(url) if w.shows("logo") && !url.is_empty() => html!{ <img src={url.to_string()} alt="" style="width:16px;height:16px;margin-right:6px;vertical-align:-3px;" /> },
+                _ => Html::default(),
+            };
+            let body = match theme.skill_display {
+                SkillDisplay::Tags => html! {
+                    <div style="display:flex;flex-wrap:wrap;gap:6px;">
+                        { for skills.iter().map(|s| html! {
+                            <span style="background:var(--surface);border:1px solid var(--border);border-radius:999px;padding:2px 10px;font-size:.9em;">
+                                { logo(s) }{ &s.name }
+                            </span>
+                        }) }
                     </div>
-                </div>
-            }
+                },
+                SkillDisplay::Bars => html! {
+                    <div>
+                        { for skills.iter().map(|s| html! {
+                            <div style="margin-bottom:var(--space);">
+                                { logo(s) }{ &s.name }
+                                { match s.percent() {
+                                    Some(pct) if w.shows("percentage") => html! {
+                                        <div style="height:6px;background:var(--border);border-radius:3px;overflow:hidden;margin-top:3px;">
+                                            <span style={format!("display:block;height:100%;width:{pct}%;background:var(--accent);")}></span>
+                                        </div>
+                                    },
+                                    _ => Html::default(),
+                                } }
+                            </div>
+                        }) }
+                    </div>
+                },
+                SkillDisplay::Dots => html! {
+                    <div>
+                        { for skills.iter().map(|s| html! {
+                            <div style="margin-bottom:var(--space);">
+                                { logo(s) }{ &s.name }
+                                { match s.percent() { Some(pct) if w.shows("percentage") => dots(pct), _ => Html::default() } }
+                            </div>
+                        }) }
+                    </div>
+                },
+            };
+            html! { <div>{ heading(content.t("cv-section-skills")) }{ body }</div> }
+        },
+        Widget::ProjectCard { index } => match cv.projects.get(*index) {
+            Some(p) => project_card(content, w, p),
+            None => html! { <div style="color:var(--muted);">{ tr.t_args("dash-builder-project-missing", &[("n", &(index + 1).to_string())]) }</div> },
         },
-        WidgetKind::ProjectsList => {
+        Widget::ProjectsList { category, .. } => {
             html! {
-                <div style="background:#111a2d;border:1px solid #22304f;border-radius:8px;padding:10px;">
-                    <div style="font-size:.9rem;opacity:.85;margin-bottom:6px;">{ "Projets" }</div>
-                    <div style="display:flex;flex-direction:column;gap:8px;">
+                <div>
+                    { heading(content.t("cv-section-projects")) }
+                    <div style="display:flex;flex-direction:column;gap:var(--space);">
                         {
-                            for cv.projects.iter().map(|p| {
-                                html!{
-                                    <div style="border:1px dashed #22304f;border-radius:8px;padding:8px;">
-                                        <div style="font-weight:600;color:#e4e9f9;">{ &p.title }</div>
-                                        <div style="font-size:.9rem;color:#c8d1e6;">{ &p.description }</div>
-                                    </div>
-                                }
-                            })
+                            for cv.projects.iter()
+                                .filter(|p| category.as_ref().map_or(true, |c| &p.category == c))
+                                .take(w.limit())
+                                .map(|p| project_card(content, w, p))
                         }
                     </div>
                 </div>
@@ -276,3 +725,27 @@ fn render_widget_preview_with_cv(w: &WidgetKind, cv: &CVStore) -> Html {
         },
     }
 }
+
+fn project_card(content: Tr, w: &Widget, p: &Project) -> Html {
+    // mêmes liens que l'export
+    let links: Vec<(String, &str)> = [
+        (content.t("cv-link-code"), p.repo_link.as_str()),
+        (content.t("cv-link-pdf"), p.pdf_link.as_str()),
+    ]
+        .into_iter()
+        .filter(|(_, url)| w.shows("links") && !url.is_empty())
+        .collect();
+    html! {
+        <div style={CARD}>
+            { if w.shows("image") && !p.image.is_empty() { html!{ <img src={p.image.clone()} alt="" style="max-width:100%;border-radius:calc(var(--radius) / 2);" /> } } else { Html::default() } }
+            <div style="font-weight:600;">{ &p.title }</div>
+            { if w.shows("description") { html!{ <div style="font-size:.9rem;white-space:pre-wrap;">{ &p.description }</div> } } else { Html::default() } }
+            { if w.shows("technologies") && !p.technologies.is_empty() { html!{ <div style="font-size:.85rem;color:var(--muted);">{ p.technologies.join(" · ") }</div> } } else { Html::default() } }
+            { if links.is_empty() { Html::default() } else { html!{
+                <div style="font-size:.85rem;display:flex;gap:8px;">
+                    { for links.into_iter().map(|(label, url)| html! { <a href={url.to_string()} style="color:var(--accent);">{ label }</a> }) }
+                </div>
+            } } }
+        </div>
+    }
+}
@@ -0,0 +1,133 @@
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use yew::prelude::*;
+
+use crate::i18n::use_tr;
+use crate::store_builder::{CvSummary, HiddenItems};
+use crate::store_cv::CVStore;
+
+/// Action demandée depuis le sélecteur ; le builder enregistre d'abord le CV ouvert.
+#[derive(Clone, PartialEq, Debug)]
+pub enum CvAction {
+    Open(i64),
+    Create(String),
+    /// Copie du CV ouvert.
+    Duplicate,
+    Rename(String),
+    Delete,
+}
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub cvs: Vec<CvSummary>,
+    pub current: Option<i64>,
+    pub on_action: Callback<CvAction>,
+    pub hidden: HiddenItems,
+    pub on_hidden: Callback<HiddenItems>,
+    /// Éléments proposés (expériences, compétences, projets de l'utilisateur).
+    pub items: CVStore,
+}
+
+/// Nom saisi via `window.prompt` ; `None` si annulé ou vide.
+fn ask_name(message: &str, default: &str) -> Option<String> {
+    let answer = web_sys::window()?.prompt_with_message_and_default(message, default).ok()??;
+    Some(answer.trim().to_string()).filter(|n| !n.is_empty())
+}
+
+fn confirm(message: &str) -> bool {
+    web_sys::window().and_then(|w| w.confirm_with_message(message).ok()).unwrap_or(false)
+}
+
+#[function_component(CvPicker)]
+pub fn cv_picker(props: &Props) -> Html {
+    let tr = use_tr();
+    let current_name = props.cvs.iter().find(|c| Some(c.id) == props.current).map(|c| c.name.clone()).unwrap_or_default();
+
+    let on_select = {
+        let cb = props.on_action.clone();
+        Callback::from(move |e: Event| {
+            if let Ok(id) = e.target_unchecked_into::<HtmlSelectElement>().value().parse() {
+                cb.emit(CvAction::Open(id));
+            }
+        })
+    };
+    let on_create = {
+        let cb = props.on_action.clone();
+        Callback::from(move |_| if let Some(name) = ask_name(&tr.t("dash-cv-new-name"), "") { cb.emit(CvAction::Create(name)) })
+    };
+    let on_duplicate = props.on_action.reform(|_| CvAction::Duplicate);
+    let on_rename = {
+        let cb = props.on_action.clone();
+        let current = current_name.clone();
+        Callback::from(move |_| if let Some(name) = ask_name(&tr.t("dash-cv-rename-prompt"), &current) { cb.emit(CvAction::Rename(name)) })
+    };
+    let on_delete = {
+        let cb = props.on_action.clone();
+        let current = current_name.clone();
+        Callback::from(move |_| if confirm(&tr.t_args("dash-cv-delete-confirm", &[("name", &current)])) { cb.emit(CvAction::Delete) })
+    };
+
+    // Case cochée = élément affiché dans ce CV
+    let toggle = |pick: fn(&mut HiddenItems) -> &mut Vec<i64>, id: i64| {
+        let hidden = props.hidden.clone();
+        let cb = props.on_hidden.clone();
+        Callback::from(move |e: Event| {
+            let shown = e.target_unchecked_into::<HtmlInputElement>().checked();
+            let mut hidden = hidden.clone();
+            let ids = pick(&mut hidden);
+            ids.retain(|i| *i != id);
+            if !shown {
+                ids.push(id);
+            }
+            cb.emit(hidden);
+        })
+    };
+    let section = |title: String, entries: Vec<(i64, String, bool, Callback<Event>)>| {
+        if entries.is_empty() {
+            return Html::default();
+        }
+        html! {
+            <fieldset style="border:1px solid #25304a;border-radius:8px;padding:6px 10px;">
+                <legend style="font-size:.85rem;opacity:.8;">{ title }</legend>
+                { for entries.into_iter().map(|(id, label, shown, onchange)| html! {
+                    <label key={id} style="display:block;font-size:.9rem;">
+                        <input type="checkbox" checked={shown} {onchange} />{ format!(" {label}") }
+                    </label>
+                }) }
+            </fieldset>
+        }
+    };
+    let h = &props.hidden;
+    let experiences = props.items.experiences.iter().filter_map(|e| {
+        let id = e.id?;
+        Some((id, format!("{} — {}", e.title, e.company), !h.experiences.contains(&id), toggle(|h| &mut h.experiences, id)))
+    }).collect();
+    let skills = props.items.skills.iter().filter_map(|s| {
+        let id = s.id?;
+        Some((id, s.name.clone(), !h.skills.contains(&id), toggle(|h| &mut h.skills, id)))
+    }).collect();
+    let projects = props.items.projects.iter().filter_map(|p| {
+        let id = p.id?;
+        Some((id, p.title.clone(), !h.projects.contains(&id), toggle(|h| &mut h.projects, id)))
+    }).collect();
+
+    let none = props.current.is_none();
+    html! {
+        <div style="background:#0e1523;border:1px solid #25304a;border-radius:12px;padding:12px;display:flex;flex-direction:column;gap:8px;">
+            <div style="font-weight:700;">{ tr.t("dash-cv-title") }</div>
+            <select onchange={on_select} disabled={none}>
+                { for props.cvs.iter().map(|c| html! {
+                    <option value={c.id.to_string()} selected={Some(c.id) == props.current}>{ c.name.clone() }</option>
+                }) }
+            </select>
+            <div style="display:flex;gap:6px;flex-wrap:wrap;">
+                <button onclick={on_create}>{ tr.t("dash-cv-new") }</button>
+                <button onclick={on_duplicate} disabled={none}>{ tr.t("dash-cv-duplicate") }</button>
+                <button onclick={on_rename} disabled={none}>{ tr.t("dash-cv-rename") }</button>
+                <button onclick={on_delete} disabled={none || props.cvs.len() <= 1} title={tr.t("dash-cv-delete-last")}>{ tr.t("dash-cv-delete") }</button>
+            </div>
+            { section(tr.t("dash-cv-shown-experiences"), experiences) }
+            { section(tr.t("dash-cv-shown-skills"), skills) }
+            { section(tr.t("dash-cv-shown-projects"), projects) }
+        </div>
+    }
+}
@@ -1,5 +1,7 @@
 use yew::prelude::*;
-use crate::store_builder::WidgetKind;
+use crate::i18n::{use_tr, Tr};
+use crate::pages::builder_widget_panel::{widget_label, WidgetPanel};
+use crate::store_builder::{SaveStatus, Widget};
 
 #[derive(Properties, PartialEq)]
 pub struct Props {
@@ -7,11 +9,20 @@ pub struct Props {
     pub selected_row: Option<usize>,
     pub selected_column: Option<usize>,
     pub on_split: Callback<usize>,
-    pub on_add_widget: Callback<WidgetKind>,
+    pub on_add_widget: Callback<Widget>,
+    pub status: SaveStatus,
+    /// Widget sélectionné dans l'aperçu : panneau de propriétés.
+    pub selected_widget: Option<Widget>,
+    pub on_widget_change: Callback<Widget>,
+    pub can_undo: bool,
+    pub can_redo: bool,
+    pub on_undo: Callback<()>,
+    pub on_redo: Callback<()>,
 }
 
 #[function_component(BuilderSidebar)]
 pub fn builder_sidebar(props: &Props) -> Html {
+    let tr = use_tr();
     let on_save_click = {
         let cb = props.on_save.clone();
         Callback::from(move |_| cb.emit(()))
@@ -30,7 +41,7 @@ pub fn builder_sidebar(props: &Props) -> Html {
         }
     };
 
-    let add = |w: WidgetKind| {
+    let add = |w: Widget| {
         let cb = props.on_add_widget.clone();
         Callback::from(move |_| cb.emit(w.clone()))
     };
@@ -38,55 +49,75 @@ pub fn builder_sidebar(props: &Props) -> Html {
     html! {
         <div style="display:flex;flex-direction:column;gap:14px;">
             <div style="background:#0e1523;border:1px solid #25304a;border-radius:12px;padding:12px;">
-                <div style="font-weight:700;margin-bottom:8px;">{ "Ligne" }</div>
+                <div style="font-weight:700;margin-bottom:8px;">{ tr.t("dash-builder-row") }</div>
                 {
                     if let Some(id) = props.selected_row {
                         html! {
                             <>
-                                <p style="opacity:.7;margin-bottom:8px;">{ format!("Sélection : Ligne #{}", id) }</p>
+                                <p style="opacity:.7;margin-bottom:8px;">{ tr.t_args("dash-builder-row-selected", &[("id", &id.to_string())]) }</p>
                                 <div style="display:flex;gap:8px;flex-wrap:wrap;">
-                                    <span style="opacity:.7;font-size:.9rem;line-height:28px;">{ "Colonnes :" }</span>
+                                    <span style="opacity:.7;font-size:.9rem;line-height:28px;">{ tr.t("dash-builder-columns") }</span>
                                     { split_btn(1) }{ split_btn(2) }{ split_btn(3) }
                                     { split_btn(4) }{ split_btn(5) }{ split_btn(6) }
                                 </div>
                             </>
                         }
                     } else {
-                        html! { <p style="opacity:.7;">{ "Clique une ligne pour la modifier." }</p> }
+                        html! { <p style="opacity:.7;">{ tr.t("dash-builder-row-hint") }</p> }
                     }
                 }
             </div>
 
             <div style="background:#0e1523;border:1px solid #25304a;border-radius:12px;padding:12px;">
-                <div style="font-weight:700;margin-bottom:8px;">{ "Contenu de colonne" }</div>
+                <div style="font-weight:700;margin-bottom:8px;">{ tr.t("dash-builder-column-content") }</div>
                 {
                     if let Some(col_id) = props.selected_column {
                         html! {
                             <>
-                                <p style="opacity:.7;margin-bottom:8px;">{ format!("Sélection : Colonne #{}", col_id) }</p>
+                                <p style="opacity:.7;margin-bottom:8px;">{ tr.t_args("dash-builder-column-selected", &[("id", &col_id.to_string())]) }</p>
                                 <div style="display:flex;flex-direction:column;gap:8px;">
-                                    <button onclick={add(WidgetKind::Text("Texte libre…".into()))}>{ "➕ Texte libre" }</button>
-                                    <button onclick={add(WidgetKind::ProfileBasic)}>{ "➕ Profil (nom + titre)" }</button>
-                                    <button onclick={add(WidgetKind::ExperienceTimeline)}>{ "➕ Expériences (timeline)" }</button>
-                                    <button onclick={add(WidgetKind::SkillsGrid)}>{ "➕ Compétences (grille)" }</button>
-                                    <button onclick={add(WidgetKind::ProjectsList)}>{ "➕ Projets (liste)" }</button>
+                                    { for Widget::palette().into_iter().map(|w| {
+                                        let label = format!("➕ {}", widget_label(&tr, &w));
+                                        html! { <button onclick={add(w)}>{ label }</button> }
+                                    }) }
                                 </div>
                                 <p style="opacity:.6;font-size:.85rem;margin-top:8px;">
-                                    { "Ces widgets utiliseront tes données DB (profil, expériences, compétences, projets)." }
+                                    { tr.t("dash-builder-widgets-hint") }
                                 </p>
                             </>
                         }
                     } else {
-                        html! { <p style="opacity:.7;">{ "Clique une colonne (bordure bleue), puis ajoute un widget." }</p> }
+                        html! { <p style="opacity:.7;">{ tr.t("dash-builder-column-hint") }</p> }
                     }
                 }
             </div>
 
+            {
+                if let Some(w) = props.selected_widget.clone() {
+                    html! { <WidgetPanel widget={w} on_change={props.on_widget_change.clone()} /> }
+                } else { Html::default() }
+            }
+
             <div style="background:#0e1523;border:1px solid #25304a;border-radius:12px;padding:12px;">
-                <div style="font-weight:700;margin-bottom:8px;">{ "Actions" }</div>
-                <button onclick={on_save_click}>{ "💾 Enregistrer le layout" }</button>
-                <p style="opacity:.6;font-size:.85rem;">{ "(Autosave à venir)" }</p>
+                <div style="font-weight:700;margin-bottom:8px;">{ tr.t("dash-builder-actions") }</div>
+                <div style="display:flex;gap:6px;margin-bottom:8px;">
+                    <button title="Ctrl+Z" disabled={!props.can_undo} onclick={props.on_undo.reform(|_| ())}>{ tr.t("dash-builder-undo") }</button>
+                    <button title="Ctrl+Shift+Z" disabled={!props.can_redo} onclick={props.on_redo.reform(|_| ())}>{ tr.t("dash-builder-redo") }</button>
+                </div>
+                <button onclick={on_save_click} disabled={props.status == SaveStatus::Saving}>{ tr.t("dash-builder-save") }</button>
+                { status_line(&tr, &props.status) }
             </div>
         </div>
     }
 }
+
+fn status_line(tr: &Tr, status: &SaveStatus) -> Html {
+    let (text, color) = match status {
+        SaveStatus::Loading => (tr.t("dash-builder-loading"), "#8b93a7"),
+        SaveStatus::Saved => (tr.t("dash-builder-saved"), "#7fd1a0"),
+        SaveStatus::Dirty => (tr.t("dash-builder-dirty"), "#e0c36a"),
+        SaveStatus::Saving => (tr.t("dash-saving"), "#8b93a7"),
+        SaveStatus::Error(e) => (tr.t_args("dash-builder-error", &[("error", e)]), "#ff9f9f"),
+    };
+    html! { <p style={format!("font-size:.85rem;color:{color};")}>{ text }</p> }
+}
@@ -0,0 +1,133 @@
+use serde::de::DeserializeOwned;
+use web_sys::{HtmlInputElement, HtmlSelectElement};
+use yew::prelude::*;
+
+use crate::i18n::{use_tr, Tr};
+use crate::store_builder::{Font, SectionHeader, SkillDisplay, Theme, MAX_SPACING};
+
+/// Thème du CV ouvert : départ depuis un thème intégré puis retouches. Chaque
+/// changement émet le thème complet ; le serveur le valide à l'enregistrement.
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    /// CV ouvert (liens d'export).
+    pub cv: Option<i64>,
+    pub theme: Theme,
+    pub on_change: Callback<Theme>,
+}
+
+/// Valeur d'une option (`"serif"`…) → variante, via la même forme serde que le serveur.
+fn parse<T: DeserializeOwned>(v: String) -> Option<T> {
+    serde_json::from_value(serde_json::Value::String(v)).ok()
+}
+
+fn key<T: serde::Serialize>(v: T) -> String {
+    serde_json::to_value(v).ok().and_then(|v| v.as_str().map(str::to_string)).unwrap_or_default()
+}
+
+fn font_label(tr: &Tr, f: Font) -> String {
+    match f {
+        Font::Sans => tr.t("dash-font-sans"),
+        Font::Serif => tr.t("dash-font-serif"),
+        Font::Mono => tr.t("dash-font-mono"),
+        Font::Rounded => tr.t("dash-font-rounded"),
+    }
+}
+
+fn section_header_label(tr: &Tr, h: SectionHeader) -> String {
+    match h {
+        SectionHeader::Plain => tr.t("dash-section-plain"),
+        SectionHeader::Underline => tr.t("dash-section-underline"),
+        SectionHeader::Bar => tr.t("dash-section-bar"),
+        SectionHeader::Caps => tr.t("dash-section-caps"),
+    }
+}
+
+fn skill_display_label(tr: &Tr, d: SkillDisplay) -> String {
+    match d {
+        SkillDisplay::Bars => tr.t("dash-skills-bars"),
+        SkillDisplay::Tags => tr.t("dash-skills-tags"),
+        SkillDisplay::Dots => tr.t("dash-skills-dots"),
+    }
+}
+
+#[function_component(ThemePanel)]
+pub fn theme_panel(props: &Props) -> Html {
+    let tr = use_tr();
+    // Modifie une copie du thème puis l'émet
+    let patch = |f: fn(&mut Theme, String)| {
+        let theme = props.theme.clone();
+        let cb = props.on_change.clone();
+        Callback::from(move |v: String| {
+            let mut theme = theme.clone();
+            f(&mut theme, v);
+            cb.emit(theme);
+        })
+    };
+    // `change` et non `input` : le sélecteur de couleur émet à chaque déplacement
+    let on_value = |cb: Callback<String>| Callback::from(move |e: Event| cb.emit(e.target_unchecked_into::<HtmlInputElement>().value()));
+    let on_select = |cb: Callback<String>| Callback::from(move |e: Event| cb.emit(e.target_unchecked_into::<HtmlSelectElement>().value()));
+
+    let on_base = {
+        let cb = props.on_change.clone();
+        on_select(Callback::from(move |base: String| if let Some(t) = Theme::builtin(&base) { cb.emit(t) }))
+    };
+
+    let t = &props.theme;
+    let color = |label: String, value: &str, f: fn(&mut Theme, String)| html! {
+        <label style="display:flex;align-items:center;justify-content:space-between;gap:8px;">{ label }
+            <input type="color" value={value.to_string()} onchange={on_value(patch(f))} />
+        </label>
+    };
+    let font = |label: String, current: Font, f: fn(&mut Theme, String)| html! {
+        <label>{ label }
+            <select onchange={on_select(patch(f))}>
+                { for Font::ALL.into_iter().map(|v| html! { <option value={key(v)} selected={v == current}>{ font_label(&tr, v) }</option> }) }
+            </select>
+        </label>
+    };
+
+    html! {
+        <div style="background:#0e1523;border:1px solid #25304a;border-radius:12px;padding:12px;display:flex;flex-direction:column;gap:8px;">
+            <div style="font-weight:700;">{ tr.t("dash-theme-title") }</div>
+            <label>{ tr.t("dash-theme-base") }
+                <select onchange={on_base}>
+                    { for Theme::builtins().into_iter().map(|b| html! {
+                        <option value={b.base.clone()} selected={b.base == t.base}>{ b.base.clone() }</option>
+                    }) }
+                </select>
+            </label>
+            { color(tr.t("dash-theme-accent"), &t.colors.accent, |t, v| t.colors.accent = v) }
+            { color(tr.t("dash-theme-background"), &t.colors.background, |t, v| t.colors.background = v) }
+            { color(tr.t("dash-theme-surface"), &t.colors.surface, |t, v| t.colors.surface = v) }
+            { color(tr.t("dash-theme-text"), &t.colors.text, |t, v| t.colors.text = v) }
+            { color(tr.t("dash-theme-muted"), &t.colors.muted, |t, v| t.colors.muted = v) }
+            { color(tr.t("dash-theme-border"), &t.colors.border, |t, v| t.colors.border = v) }
+            { font(tr.t("dash-theme-body-font"), t.fonts.body, |t, v| if let Some(f) = parse(v) { t.fonts.body = f }) }
+            { font(tr.t("dash-theme-heading-font"), t.fonts.heading, |t, v| if let Some(f) = parse(v) { t.fonts.heading = f }) }
+            <label>{ tr.t("dash-theme-section-header") }
+                <select onchange={on_select(patch(|t, v| if let Some(h) = parse(v) { t.section_header = h }))}>
+                    { for SectionHeader::ALL.into_iter().map(|v| html! { <option value={key(v)} selected={v == t.section_header}>{ section_header_label(&tr, v) }</option> }) }
+                </select>
+            </label>
+            <label>{ tr.t("dash-theme-skills") }
+                <select onchange={on_select(patch(|t, v| if let Some(s) = parse(v) { t.skill_display = s }))}>
+                    { for SkillDisplay::ALL.into_iter().map(|v| html! { <option value={key(v)} selected={v == t.skill_display}>{ skill_display_label(&tr, v) }</option> }) }
+                </select>
+            </label>
+            <label>{ tr.t("dash-theme-spacing") }
+                <input type="number" min="1" max={MAX_SPACING.to_string()} value={t.spacing.to_string()}
+                    onchange={on_value(patch(|t, v| if let Ok(n) = v.parse::<u8>() { t.spacing = n.clamp(1, MAX_SPACING) }))} />
+            </label>
+            {
+                if let Some(id) = props.cv {
+                    html! {
+                        <div style="display:flex;gap:10px;font-size:.9rem;">
+                            <a href={format!("/api/cvs/{id}/export")} target="_blank">{ tr.t("dash-theme-preview") }</a>
+                            <a href={format!("/api/cvs/{id}/export?download=true")}>{ tr.t("dash-theme-download") }</a>
+                        </div>
+                    }
+                } else { Html::default() }
+            }
+        </div>
+    }
+}
@@ -0,0 +1,203 @@
+use web_sys::{HtmlInputElement, HtmlSelectElement, HtmlTextAreaElement};
+use yew::prelude::*;
+
+use crate::i18n::{use_tr, Tr};
+use crate::store_builder::{Align, Widget};
+
+/// Nom du widget dans la langue de l'interface (palette, panneau de propriétés).
+pub fn widget_label(tr: &Tr, w: &Widget) -> String {
+    match w {
+        Widget::Text { .. } => tr.t("dash-widget-text"),
+        Widget::Title { .. } => tr.t("dash-widget-title"),
+        Widget::ProfileBasic { .. } => tr.t("dash-widget-profile"),
+        Widget::ExperienceTimeline { .. } => tr.t("dash-widget-experiences"),
+        Widget::SkillsGrid { .. } => tr.t("dash-widget-skills"),
+        Widget::ProjectsList { .. } => tr.t("dash-widget-projects"),
+        Widget::ProjectCard { .. } => tr.t("dash-widget-project-card"),
+        Widget::Photo { .. } => tr.t("dash-widget-photo"),
+    }
+}
+
+/// Propriétés du widget sélectionné. Chaque saisie émet le widget modifié ; la
+/// validation fine (bornes, longueurs) est faite par le serveur à l'enregistrement.
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub widget: Widget,
+    pub on_change: Callback<Widget>,
+}
+
+#[function_component(WidgetPanel)]
+pub fn widget_panel(props: &Props) -> Html {
+    let tr = use_tr();
+    // Modifie une copie du widget puis l'émet
+    let patch = |f: fn(&mut Widget, String)| {
+        let w = props.widget.clone();
+        let cb = props.on_change.clone();
+        Callback::from(move |v: String| {
+            let mut w = w.clone();
+            f(&mut w, v);
+            cb.emit(w);
+        })
+    };
+    let on_input = |cb: Callback<String>| Callback::from(move |e: InputEvent| cb.emit(e.target_unchecked_into::<HtmlInputElement>().value()));
+    let on_area = |cb: Callback<String>| Callback::from(move |e: InputEvent| cb.emit(e.target_unchecked_into::<HtmlTextAreaElement>().value()));
+    let on_select = |cb: Callback<String>| Callback::from(move |e: Event| cb.emit(e.target_unchecked_into::<HtmlSelectElement>().value()));
+    let on_check = |cb: Callback<String>| {
+        Callback::from(move |e: Event| cb.emit(e.target_unchecked_into::<HtmlInputElement>().checked().to_string()))
+    };
+
+    let specific: Html = match &props.widget {
+        Widget::Text { text } => html! {
+            <label>{ tr.t("dash-widget-field-text") }
+                <textarea rows="5" value={text.clone()}
+                    oninput={on_area(patch(|w, v| if let Widget::Text { text } = w { *text = v }))} />
+            </label>
+        },
+        Widget::Title { text, level, bold, align } => {
+            let align = match align { Some(Align::Center) => "center", Some(Align::Right) => "right", Some(Align::Left) => "left", None => "" };
+            html! {
+                <>
+                    <label>{ tr.t("dash-widget-field-text") }
+                        <input value={text.clone()} oninput={on_input(patch(|w, v| if let Widget::Title { text, .. } = w { *text = v }))} />
+                    </label>
+                    <label>{ tr.t("dash-widget-level") }
+                        <select onchange={on_select(patch(|w, v| if let Widget::Title { level, .. } = w { *level = v.parse().unwrap_or(1) }))}>
+                            { for (1..=6u8).map(|n| html! { <option value={n.to_string()} selected={*level == n}>{ format!("h{n}") }</option> }) }
+                        </select>
+                    </label>
+                    <label>
+                        <input type="checkbox" checked={*bold} onchange={on_check(patch(|w, v| if let Widget::Title { bold, .. } = w { *bold = v == "true" }))} />
+                        { " " }{ tr.t("dash-widget-bold") }
+                    </label>
+                    <label>{ tr.t("dash-widget-align") }
+                        <select onchange={on_select(patch(|w, v| if let Widget::Title { align, .. } = w {
+                            *align = match v.as_str() { "left" => Some(Align::Left), "center" => Some(Align::Center), "right" => Some(Align::Right), _ => None };
+                        }))}>
+                            { for [
+                                ("", tr.t("dash-widget-align-default")),
+                                ("left", tr.t("dash-widget-align-left")),
+                                ("center", tr.t("dash-widget-align-center")),
+                                ("right", tr.t("dash-widget-align-right")),
+                            ].into_iter().map(|(v, l)| html! {
+                                <option value={v} selected={align == v}>{ l }</option>
+                            }) }
+                        </select>
+                    </label>
+                </>
+            }
+        }
+        Widget::ProjectCard { index } => html! {
+            <label>{ tr.t("dash-widget-project-number") }
+                <input type="number" min="1" value={(index + 1).to_string()}
+                    oninput={on_input(patch(|w, v| if let Widget::ProjectCard { index } = w {
+                        *index = v.parse::<usize>().unwrap_or(1).saturating_sub(1);
+                    }))} />
+            </label>
+        },
+        Widget::Photo { url, rounded } => html! {
+            <>
+                <label>{ tr.t("dash-widget-image-url") }
+                    <input value={url.clone()} oninput={on_input(patch(|w, v| if let Widget::Photo { url, .. } = w { *url = v }))} />
+                </label>
+                <label>
+                    <input type="checkbox" checked={*rounded} onchange={on_check(patch(|w, v| if let Widget::Photo { rounded, .. } = w { *rounded = v == "true" }))} />
+                    { " " }{ tr.t("dash-widget-rounded") }
+                </label>
+            </>
+        },
+        _ => Html::default(),
+    };
+
+    // Options communes aux widgets de liste
+    let mut probe = props.widget.clone();
+    let filter = probe.filter_mut().map(|f| f.clone().unwrap_or_default());
+    let heading = probe.heading_mut().map(|h| h.clone().unwrap_or_default());
+    let limit = probe.limit_mut().map(|l| l.map(|n| n.to_string()).unwrap_or_default());
+    let filter_label = if matches!(props.widget, Widget::ExperienceTimeline { .. }) {
+        tr.t("dash-widget-filter-kind")
+    } else {
+        tr.t("dash-widget-filter-category")
+    };
+    let blank = |v: String| Some(v.trim().to_string()).filter(|v| !v.is_empty());
+
+    let field_names = props.widget.field_names();
+    let fields: Html = if field_names.is_empty() {
+        Html::default()
+    } else {
+        html! {
+            <fieldset style="border:1px solid #25304a;border-radius:8px;padding:6px 10px;">
+                <legend style="font-size:.85rem;opacity:.8;">{ tr.t("dash-widget-fields") }</legend>
+                {
+                    for field_names.iter().map(|&name| {
+                        let w = props.widget.clone();
+                        let cb = props.on_change.clone();
+                        let onchange = Callback::from(move |e: Event| {
+                            let on = e.target_unchecked_into::<HtmlInputElement>().checked();
+                            let mut w = w.clone();
+                            let all = w.field_names();
+                            let current: Vec<String> = all.iter().filter(|f| w.shows(f)).map(|f| f.to_string()).collect();
+                            let next: Vec<String> = all.iter()
+                                .filter(|&&f| if f == name { on } else { current.iter().any(|c| c == f) })
+                                .map(|f| f.to_string())
+                                .collect();
+                            if let Some(fields) = w.fields_mut() {
+                                // tous cochés : retour au défaut (pas d'option stockée)
+                                *fields = (next.len() != all.len()).then_some(next);
+                            }
+                            cb.emit(w);
+                        });
+                        html! {
+                            <label style="display:block;font-size:.9rem;">
+                                <input type="checkbox" checked={props.widget.shows(name)} {onchange} />{ format!(" {name}") }
+                            </label>
+                        }
+                    })
+                }
+            </fieldset>
+        }
+    };
+
+    let label = widget_label(&tr, &props.widget);
+    let all = tr.t("dash-widget-all");
+
+    html! {
+        <div style="background:#0e1523;border:1px solid #25304a;border-radius:12px;padding:12px;display:flex;flex-direction:column;gap:8px;">
+            <div style="font-weight:700;">{ tr.t_args("dash-widget-properties", &[("widget", &label)]) }</div>
+            { specific }
+            {
+                if let Some(v) = heading {
+                    html! { <label>{ tr.t("dash-widget-heading") }
+                        <input value={v} placeholder={label.clone()}
+                            oninput={on_input({
+                                let w = props.widget.clone(); let cb = props.on_change.clone();
+                                Callback::from(move |v: String| { let mut w = w.clone(); if let Some(h) = w.heading_mut() { *h = blank(v) } cb.emit(w); })
+                            })} />
+                    </label> }
+                } else { Html::default() }
+            }
+            {
+                if let Some(v) = filter {
+                    html! { <label>{ filter_label }
+                        <input value={v} placeholder={all.clone()}
+                            oninput={on_input({
+                                let w = props.widget.clone(); let cb = props.on_change.clone();
+                                Callback::from(move |v: String| { let mut w = w.clone(); if let Some(f) = w.filter_mut() { *f = blank(v) } cb.emit(w); })
+                            })} />
+                    </label> }
+                } else { Html::default() }
+            }
+            {
+                if let Some(v) = limit {
+                    html! { <label>{ tr.t("dash-widget-limit") }
+                        <input type="number" min="1" max="100" value={v} placeholder={all.clone()}
+                            oninput={on_input({
+                                let w = props.widget.clone(); let cb = props.on_change.clone();
+                                Callback::from(move |v: String| { let mut w = w.clone(); if let Some(l) = w.limit_mut() { *l = v.trim().parse().ok().filter(|n| *n > 0) } cb.emit(w); })
+                            })} />
+                    </label> }
+                } else { Html::default() }
+            }
+            { fields }
+        </div>
+    }
+}
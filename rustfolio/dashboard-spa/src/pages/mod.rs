@@ -1,9 +1,11 @@
 pub mod account;
 pub mod overview;
 pub mod profile;
-pub mod cv_form;
 pub mod nav;
 pub mod builder_sidebar;
+pub mod builder_widget_panel;
+pub mod builder_cv_picker;
+pub mod builder_theme_panel;
 pub mod builder;  
 
 
@@ -1,17 +1,19 @@
 use yew::prelude::*;
 use yew_router::prelude::*;
+use crate::i18n::use_tr;
 use crate::router::Route;
 
 #[function_component(Nav)]
 pub fn nav() -> Html {
+    let tr = use_tr();
     html! {
         <nav class="subnav">
             //<a class="navbar__brand" href="/">{"Dashboard"}</a>
             <div class="navbar__spacer"></div>
-            <Link<Route> classes="navbar__link" to={Route::Overview}>{ "Overview" }</Link<Route>>
-            <Link<Route> classes="navbar__link" to={Route::Profile}>{ "Profile" }</Link<Route>>
-            <Link<Route> classes="navbar__link" to={Route::Account}>{ "Account" }</Link<Route>>
-            <Link<Route> classes="navbar__link" to={Route::Builder}>{ "Builder" }</Link<Route>>
+            <Link<Route> classes="navbar__link" to={Route::Overview}>{ tr.t("dash-nav-overview") }</Link<Route>>
+            <Link<Route> classes="navbar__link" to={Route::Profile}>{ tr.t("dash-nav-profile") }</Link<Route>>
+            <Link<Route> classes="navbar__link" to={Route::Account}>{ tr.t("dash-nav-account") }</Link<Route>>
+            <Link<Route> classes="navbar__link" to={Route::Builder}>{ tr.t("dash-nav-builder") }</Link<Route>>
         </nav>
     }
 }
\ No newline at end of file
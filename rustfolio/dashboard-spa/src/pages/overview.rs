@@ -1,12 +1,15 @@
 use yew::prelude::*;
+
+use crate::i18n::use_tr;
 //use crate::store::{AppStore, Row, Column, Widget};
 
 #[function_component(Overview)]
 pub fn overview() -> Html {
+    let tr = use_tr();
     html! {
             <section class="p-8">
-                <h2 class="text-2xl font-bold mb-4">{"Overview"}</h2>
-                <p>{"Bienvenue sur ton dashboard."}</p>
+                <h2 class="text-2xl font-bold mb-4">{ tr.t("dash-overview-title") }</h2>
+                <p>{ tr.t("dash-overview-welcome") }</p>
             </section>
     }
 }
\ No newline at end of file
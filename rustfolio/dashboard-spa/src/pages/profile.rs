@@ -2,40 +2,56 @@ use yew::prelude::*;
 use yew::events::InputEvent;
 use yew::TargetCast;
 
-use gloo::net::http::Request;
 use wasm_bindgen_futures::spawn_local;
-use web_sys::{HtmlInputElement, HtmlTextAreaElement, RequestCredentials, HtmlSelectElement};
+use web_sys::{HtmlInputElement, HtmlTextAreaElement, HtmlSelectElement};
 
-use serde::{Serialize, Deserialize};
+use std::collections::BTreeMap;
+use std::ops::{Deref, DerefMut};
 use std::rc::Rc;
 
+use crate::api::{
+    self, CatalogEntry, Experience, Profile as ProfileData, ProfileText, SkillIn, SkillLevel,
+    SkillOut, TaskItem, DEFAULT_LOCALE,
+};
+use crate::i18n::{use_tr, Tr};
+use crate::rich_text;
+
 /* ===================== PROFILE ===================== */
 
-#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
-#[serde(default)]
-struct ProfileData {
-    first_name: String,
-    last_name:  String,
-    title:      String,
-    email:      String,
-    phone:      String,
-    address:    String,
-    city:       String,
-    country:    String,
-    website:    String,
-    photo_url:  String,
-
-    #[serde(skip_serializing_if = "Option::is_none")]
-    location: Option<String>, // compat ancien front
+/// Code de langue saisi → forme du serveur (`en`, `pt-BR`), `None` si invalide.
+fn normalize_locale(tag: &str) -> Option<String> {
+    let mut parts = tag.trim().split(['-', '_']);
+    let lang = parts.next()?;
+    if !(2..=3).contains(&lang.len()) || !lang.bytes().all(|b| b.is_ascii_alphabetic()) {
+        return None;
+    }
+    let mut out = lang.to_ascii_lowercase();
+    match (parts.next(), parts.next()) {
+        (None, _) => {}
+        (Some(r), None) if r.len() == 2 && r.bytes().all(|b| b.is_ascii_alphabetic()) => {
+            out.push('-');
+            out.push_str(&r.to_ascii_uppercase());
+        }
+        _ => return None,
+    }
+    (out != DEFAULT_LOCALE).then_some(out)
+}
+
+/// Valeur traduite : `None` si vide (le serveur retombe sur le français).
+fn non_empty(v: String) -> Option<String> {
+    (!v.trim().is_empty()).then_some(v)
 }
 
 #[function_component(Profile)]
 pub fn profile() -> Html {
+    let tr = use_tr();
     let data    = use_state(ProfileData::default);
     let loading = use_state(|| false);
     let saving  = use_state(|| false);
     let error   = use_state(|| Option::<String>::None);
     let ok      = use_state(|| false);
+    // langue éditée à côté du français
+    let lang    = use_state(|| "en".to_string());
 
     // fetch au montage
     {
@@ -46,20 +62,9 @@ pub fn profile() -> Html {
             spawn_local(async move {
                 loading.set(true);
                 error.set(None);
-                let resp = Request::get("/api/cv/profile")
-                    .credentials(RequestCredentials::Include)
-                    .send()
-                    .await;
-
-                match resp {
-                    Ok(r) if r.ok() => {
-                        match r.json::<ProfileData>().await {
-                            Ok(json) => data.set(json),
-                            Err(e)   => error.set(Some(format!("JSON error: {e}"))),
-                        }
-                    }
-                    Ok(r) => error.set(Some(format!("HTTP {}", r.status()))),
-                    Err(e) => error.set(Some(format!("Network error: {e}"))),
+                match api::cv::profile().await {
+                    Ok(profile) => data.set(profile),
+                    Err(e) => error.set(Some(e.message(&tr))),
                 }
 
                 loading.set(false);
@@ -103,6 +108,47 @@ pub fn profile() -> Html {
     let on_country    = update_text(|s, v| s.country    = v);
     let on_website    = update_text(|s, v| s.website    = v);
     let on_photo_url  = update_textarea(|s, v| s.photo_url  = v);
+    let on_summary    = update_textarea(|s, v| s.summary    = v);
+
+    let on_lang = {
+        let lang = lang.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            if let Some(l) = normalize_locale(&input.value()) {
+                lang.set(l);
+            }
+        })
+    };
+
+    // champ traduit dans la langue choisie
+    let update_translation = {
+        let data = data.clone();
+        let lang = lang.clone();
+        move |f: fn(&mut ProfileText, Option<String>)| {
+            let data = data.clone();
+            let lang = lang.clone();
+            Callback::from(move |e: InputEvent| {
+                let value = match e.target_dyn_into::<HtmlTextAreaElement>() {
+                    Some(t) => t.value(),
+                    None => e.target_unchecked_into::<HtmlInputElement>().value(),
+                };
+                let mut v = (*data).clone();
+                let translations = v.translations.get_or_insert_with(BTreeMap::new);
+                f(translations.entry((*lang).clone()).or_default(), non_empty(value));
+                translations.retain(|_, t| t.title.is_some() || t.summary.is_some());
+                data.set(v);
+            })
+        }
+    };
+    let on_title_tr   = update_translation(|t, v| t.title   = v);
+    let on_summary_tr = update_translation(|t, v| t.summary = v);
+    let current_tr = data
+        .translations
+        .as_ref()
+        .and_then(|t| t.get(&*lang))
+        .cloned()
+        .unwrap_or_default();
+    let known_langs: Vec<String> = data.translations.iter().flat_map(|t| t.keys().cloned()).collect();
 
     let on_save = {
         let saving = saving.clone();
@@ -120,17 +166,9 @@ pub fn profile() -> Html {
                 ok.set(false);
                 error.set(None);
 
-                let resp = Request::put("/api/cv/profile")
-                    .header("Content-Type", "application/json")
-                    .credentials(RequestCredentials::Include)
-                    .json(&body).unwrap()
-                    .send()
-                    .await;
-
-                match resp {
-                    Ok(r) if r.ok() => ok.set(true),
-                    Ok(r) => error.set(Some(format!("HTTP {}", r.status()))),
-                    Err(e) => error.set(Some(format!("Network error: {e}"))),
+                match api::cv::save_profile(&body).await {
+                    Ok(()) => ok.set(true),
+                    Err(e) => error.set(Some(e.message(&tr))),
                 }
 
                 saving.set(false);
@@ -141,12 +179,12 @@ pub fn profile() -> Html {
     html! {
         <>
         <section class="dash-section">
-            <h2 class="dash-title">{ "Profile" }</h2>
+            <h2 class="dash-title">{ tr.t("dash-profile-title") }</h2>
 
             if let Some(err) = (*error).clone() {
                 <p class="dash-error">{err}</p>
             } else if *ok {
-                <p class="dash-ok">{ "Saved ✅" }</p>
+                <p class="dash-ok">{ tr.t("dash-saved") }</p>
             }
 
             <div class="dash-form">
@@ -154,8 +192,6 @@ pub fn profile() -> Html {
                     value={data.first_name.clone()} oninput={on_first_name} />
                 <input class="dash-input" type="text" placeholder="Last name"
                     value={data.last_name.clone()} oninput={on_last_name} />
-                <input class="dash-input" type="text" placeholder="Title"
-                    value={data.title.clone()} oninput={on_title} />
                 <input class="dash-input" type="email" placeholder="Email"
                     value={data.email.clone()} oninput={on_email} />
                 <input class="dash-input" type="tel" placeholder="Phone"
@@ -174,12 +210,37 @@ pub fn profile() -> Html {
                     value={data.photo_url.clone()} oninput={on_photo_url} rows={3} />
             </div>
 
+            <h3>{ tr.t("dash-languages") }</h3>
+            <label>
+                { tr.t("dash-translate-to") }{ " " }
+                <input class="dash-input" type="text" list="profile-langs" style="width: 6rem;"
+                    placeholder="en, de, pt-BR…" value={(*lang).clone()} onchange={on_lang} />
+                <datalist id="profile-langs">
+                    { for known_langs.iter().map(|l| html!{ <option value={l.clone()} /> }) }
+                </datalist>
+            </label>
+            // champ vide dans une langue = repli sur le français
+            <div class="dash-form" style="display: grid; grid-template-columns: 1fr 1fr; gap: .75rem;">
+                <strong>{ tr.t_args("dash-default-language", &[("code", DEFAULT_LOCALE)]) }</strong>
+                <strong>{ (*lang).clone() }</strong>
+
+                <input class="dash-input" type="text" placeholder="Title"
+                    value={data.title.clone()} oninput={on_title} />
+                <input class="dash-input" type="text" placeholder={data.title.clone()}
+                    value={current_tr.title.clone().unwrap_or_default()} oninput={on_title_tr} />
+
+                <textarea class="dash-input dash-textarea" placeholder="Summary" rows={4}
+                    value={data.summary.clone()} oninput={on_summary} />
+                <textarea class="dash-input dash-textarea" placeholder={data.summary.clone()} rows={4}
+                    value={current_tr.summary.clone().unwrap_or_default()} oninput={on_summary_tr} />
+            </div>
+
             <button class="dash-btn" onclick={on_save} disabled={*saving || *loading}>
-                { if *saving { "Saving..." } else if *loading { "Loading..." } else { "Save" } }
+                { if *saving { tr.t("dash-saving") } else if *loading { tr.t("dash-loading") } else { tr.t("dash-save") } }
             </button>
         </section>
 
-        <ExperiencesSection />
+        <ExperiencesSection lang={(*lang).clone()} />
         <SkillsSection />
         </>
     }
@@ -187,94 +248,52 @@ pub fn profile() -> Html {
 
 /* ===================== EXPERIENCES ===================== */
 
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
-struct TaskItem {
-    id: i64,
-    task: String,
-}
-
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
+/// Expérience en cours d'édition : ses tâches gardent leur id (endpoints `/tasks`) ;
+/// les autres champs sont ceux de l'API, via `Deref`.
+#[derive(Clone, Debug, PartialEq, Default)]
 struct ExperienceData {
-    id: Option<i64>,
-    date_start: String,
-    date_end: String,
-    kind: String,
-    title: String,
-    company: String,
-    location: String,
-    website: String,
-
-    #[serde(default, skip_deserializing)]
+    exp: Experience,
     tasks: Vec<TaskItem>,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Default)]
-struct ExperiencePayload {
-    id: Option<i64>,
-    date_start: String,
-    date_end: String,
-    kind: String,
-    title: String,
-    company: String,
-    location: String,
-    website: String,
-    tasks: Vec<String>, 
-}
-
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
-pub struct Skill {
-    pub id: i64,
-    pub name: String,
-    pub percentage: Option<u8>,
-    pub logo_url: Option<String>,
-    pub category: String,
+impl Deref for ExperienceData {
+    type Target = Experience;
+    fn deref(&self) -> &Experience { &self.exp }
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
-pub struct SkillPayload {
-    pub name: String,
-    pub percentage: Option<u8>,
-    pub logo_url: Option<String>,
-    pub category: String,
+impl DerefMut for ExperienceData {
+    fn deref_mut(&mut self) -> &mut Experience { &mut self.exp }
 }
 
-#[derive(serde::Deserialize, serde::Serialize, Clone, PartialEq, Debug)]
-pub struct SkillOut {
-    pub id: i64,
-    pub name: String,
-    pub percentage: Option<u8>,
-    pub logo_url: Option<String>,
-    pub category: Option<String>,
-}
+impl ExperienceData {
+    fn new(exp: Experience, tasks: Vec<TaskItem>) -> Self {
+        Self { exp, tasks }
+    }
 
-#[derive(serde::Deserialize, serde::Serialize, Clone, PartialEq, Debug)]
-pub struct SkillIn {
-    pub name: String,
-    pub percentage: Option<u8>,
-    pub logo_url: Option<String>,
-    pub category: Option<String>,
+    /// Corps du PUT : les tâches dans l'ordre affiché.
+    fn to_api(&self) -> Experience {
+        Experience { tasks: self.tasks.iter().map(|t| t.task.clone()).collect(), ..self.exp.clone() }
+    }
 }
 
-// conversion front -> payload
-impl From<&ExperienceData> for ExperiencePayload {
-    fn from(e: &ExperienceData) -> Self {
-        Self {
-            id: e.id,
-            date_start: e.date_start.clone(),
-            date_end: e.date_end.clone(),
-            kind: e.kind.clone(),
-            title: e.title.clone(),
-            company: e.company.clone(),
-            location: e.location.clone(),
-            website: e.website.clone(),
-            tasks: e.tasks.iter().map(|t| t.task.clone()).collect(),
-        }
+fn level_label(tr: &Tr, level: SkillLevel) -> String {
+    match level {
+        SkillLevel::Beginner => tr.t("dash-skill-level-beginner"),
+        SkillLevel::Intermediate => tr.t("dash-skill-level-intermediate"),
+        SkillLevel::Advanced => tr.t("dash-skill-level-advanced"),
+        SkillLevel::Expert => tr.t("dash-skill-level-expert"),
     }
 }
 
+#[derive(Properties, PartialEq)]
+struct ExperiencesSectionProps {
+    /// Langue éditée à côté du français.
+    pub lang: String,
+}
 
 #[function_component(ExperiencesSection)]
-fn experiences_section() -> Html {
+fn experiences_section(props: &ExperiencesSectionProps) -> Html {
+    let tr = use_tr();
     let list = use_state(|| Vec::<ExperienceData>::new());
     let loading = use_state(|| false);
     let error = use_state(|| Option::<String>::None);
@@ -290,42 +309,19 @@ fn experiences_section() -> Html {
                 loading.set(true);
                 error.set(None);
 
-                let resp = Request::get("/api/cv/experiences")
-                    .credentials(RequestCredentials::Include)
-                    .send().await;
-
-                match resp {
-                    Ok(r) if r.ok() => {
-                        let mut exps: Vec<ExperienceData> = match r.json::<Vec<ExperienceData>>().await {
-                            Ok(mut v) => {
-                                for e in &mut v { e.tasks = vec![]; }
-                                v
-                            }
-                            Err(e) => {
-                                error.set(Some(format!("JSON error: {e}")));
-                                vec![]
-                            }
-                        };
-
-                        for e in &mut exps {
-                            if let Some(id) = e.id {
-                                if let Ok(resp) = Request::get(&format!("/api/cv/experiences/{id}/tasks"))
-                                    .credentials(RequestCredentials::Include)
-                                    .send().await
-                                {
-                                    if resp.ok() {
-                                        if let Ok(tasks) = resp.json::<Vec<TaskItem>>().await {
-                                            e.tasks = tasks;
-                                        }
-                                    }
-                                }
-                            }
+                match api::cv::experiences().await {
+                    Ok(exps) => {
+                        let mut v = Vec::with_capacity(exps.len());
+                        for e in exps {
+                            let tasks = match e.id {
+                                Some(id) => api::cv::tasks(id).await.unwrap_or_default(),
+                                None => vec![],
+                            };
+                            v.push(ExperienceData::new(e, tasks));
                         }
-
-                        list.set(exps);
+                        list.set(v);
                     }
-                    Ok(r) => error.set(Some(format!("HTTP {}", r.status()))),
-                    Err(e) => error.set(Some(format!("Network error: {e}"))),
+                    Err(e) => error.set(Some(e.message(&tr))),
                 }
 
                 loading.set(false);
@@ -344,38 +340,17 @@ fn experiences_section() -> Html {
             let error = error.clone();
             let loading = loading.clone();
 
-            let payload = ExperiencePayload {
-                date_start: "".into(),
-                date_end:   "".into(),
-                kind:       "".into(),
-                title:      "".into(),
-                company:    "".into(),
-                location:   "".into(),
-                website:    "".into(),
-                ..Default::default()
-            };
-
             spawn_local(async move {
                 loading.set(true);
                 error.set(None);
 
-                let resp = Request::post("/api/cv/experiences")
-                    .header("Content-Type", "application/json")
-                    .credentials(RequestCredentials::Include)
-                    .json(&payload).unwrap()
-                    .send().await;
-
-                match resp {
-                    Ok(r) if r.ok() => {
-                        if let Ok(mut created) = r.json::<ExperienceData>().await {
-                            created.tasks = vec![];
-                            let mut v = (*list).clone();
-                            v.push(created);
-                            list.set(v);
-                        }
+                match api::cv::create_experience(&Experience::default()).await {
+                    Ok(created) => {
+                        let mut v = (*list).clone();
+                        v.push(ExperienceData::new(created, vec![]));
+                        list.set(v);
                     }
-                    Ok(r) => error.set(Some(format!("HTTP {}", r.status()))),
-                    Err(e) => error.set(Some(format!("Network error: {e}"))),
+                    Err(e) => error.set(Some(e.message(&tr))),
                 }
 
                 loading.set(false);
@@ -398,20 +373,15 @@ fn experiences_section() -> Html {
                 loading.set(true);
                 error.set(None);
 
-                let resp = Request::delete(&format!("/api/cv/experiences/{exp_id}"))
-                    .credentials(RequestCredentials::Include)
-                    .send().await;
-
-                match resp {
-                    Ok(r) if r.ok() => {
+                match api::cv::delete_experience(exp_id).await {
+                    Ok(()) => {
                         let v = (*list).clone()
                             .into_iter()
                             .filter(|e| e.id != Some(exp_id))
                             .collect::<Vec<_>>();
                         list.set(v);
                     }
-                    Ok(r) => error.set(Some(format!("HTTP {}", r.status()))),
-                    Err(e) => error.set(Some(format!("Network error: {e}"))),
+                    Err(e) => error.set(Some(e.message(&tr))),
                 }
 
                 loading.set(false);
@@ -430,25 +400,16 @@ fn experiences_section() -> Html {
             let loading = loading.clone();
             let saved_id_state = saved_id_state.clone();
             let id = exp.id.expect("exp id manquant");
-            let payload: ExperiencePayload = (&exp).into();
+            let payload = exp.to_api();
 
             spawn_local(async move {
                 loading.set(true);
                 error.set(None);
                 saved_id_state.set(None);
 
-                let resp = Request::put(&format!("/api/cv/experiences/{id}"))
-                    .header("Content-Type", "application/json")
-                    .credentials(RequestCredentials::Include)
-                    .json(&payload).unwrap()
-                    .send().await;
-
-                match resp {
-                    Ok(r) if r.ok() => {
-                        saved_id_state.set(Some(id)); // ✅
-                    }
-                    Ok(r) => error.set(Some(format!("HTTP {}", r.status()))),
-                    Err(e) => error.set(Some(format!("Network error: {e}"))),
+                match api::cv::update_experience(id, &payload).await {
+                    Ok(()) => saved_id_state.set(Some(id)), // ✅
+                    Err(e) => error.set(Some(e.message(&tr))),
                 }
 
                 loading.set(false);
@@ -478,6 +439,33 @@ fn experiences_section() -> Html {
         })
     };
 
+    // traductions locales (titre ; tâches une par ligne)
+    let on_change_translation = {
+        let list = list.clone();
+        let lang = props.lang.clone();
+        Callback::from(move |(id, field, value): (i64, &'static str, String)| {
+            let mut v = (*list).clone();
+            if let Some(item) = v.iter_mut().find(|e| e.id == Some(id)) {
+                let translations = item.translations.get_or_insert_with(BTreeMap::new);
+                let t = translations.entry(lang.clone()).or_default();
+                match field {
+                    "title" => t.title = non_empty(value),
+                    "tasks" => {
+                        let tasks: Vec<String> = value
+                            .lines()
+                            .map(|l| l.trim().to_string())
+                            .filter(|l| !l.is_empty())
+                            .collect();
+                        t.tasks = (!tasks.is_empty()).then_some(tasks);
+                    }
+                    _ => {}
+                }
+                translations.retain(|_, t| t.title.is_some() || t.tasks.is_some());
+            }
+            list.set(v);
+        })
+    };
+
     // tasks +
     let on_add_task = {
         let list = list.clone();
@@ -487,25 +475,41 @@ fn experiences_section() -> Html {
             let error = error.clone();
 
             spawn_local(async move {
-                let resp = Request::post(&format!("/api/cv/experiences/{exp_id}/tasks"))
-                    .header("Content-Type", "application/json")
-                    .credentials(RequestCredentials::Include)
-                    .json(&serde_json::json!({ "task": text })).unwrap()
-                    .send()
-                    .await;
-
-                match resp {
-                    Ok(r) if r.ok() => {
-                        if let Ok(created) = r.json::<TaskItem>().await {
-                            let mut v = (*list).clone();
-                            if let Some(e) = v.iter_mut().find(|e| e.id == Some(exp_id)) {
-                                e.tasks.push(created);
+                match api::cv::add_task(exp_id, &text).await {
+                    Ok(created) => {
+                        let mut v = (*list).clone();
+                        if let Some(e) = v.iter_mut().find(|e| e.id == Some(exp_id)) {
+                            e.tasks.push(created);
+                        }
+                        list.set(v);
+                    }
+                    Err(e) => error.set(Some(e.message(&tr))),
+                }
+            });
+        })
+    };
+
+    // édition d'une task en place (PATCH, garde l'id donc l'ordre)
+    let on_update_task = {
+        let list = list.clone();
+        let error = error.clone();
+
+        Callback::from(move |(exp_id, task_id, text): (i64, i64, String)| {
+            let list = list.clone();
+            let error = error.clone();
+
+            spawn_local(async move {
+                match api::cv::update_task(exp_id, task_id, &text).await {
+                    Ok(updated) => {
+                        let mut v = (*list).clone();
+                        if let Some(e) = v.iter_mut().find(|e| e.id == Some(exp_id)) {
+                            if let Some(t) = e.tasks.iter_mut().find(|t| t.id == task_id) {
+                                *t = updated;
                             }
-                            list.set(v);
                         }
+                        list.set(v);
                     }
-                    Ok(r) => error.set(Some(format!("HTTP {}", r.status()))),
-                    Err(e) => error.set(Some(format!("Network error: {e}"))),
+                    Err(e) => error.set(Some(e.message(&tr))),
                 }
             });
         })
@@ -520,20 +524,15 @@ fn experiences_section() -> Html {
             let error = error.clone();
 
             spawn_local(async move {
-                let resp = Request::delete(&format!("/api/cv/experiences/{exp_id}/tasks/{task_id}"))
-                    .credentials(RequestCredentials::Include)
-                    .send().await;
-
-                match resp {
-                    Ok(r) if r.ok() => {
+                match api::cv::delete_task(exp_id, task_id).await {
+                    Ok(()) => {
                         let mut v = (*list).clone();
                         if let Some(e) = v.iter_mut().find(|e| e.id == Some(exp_id)) {
                             e.tasks.retain(|t| t.id != task_id);
                         }
                         list.set(v);
                     }
-                    Ok(r) => error.set(Some(format!("HTTP {}", r.status()))),
-                    Err(e) => error.set(Some(format!("Network error: {e}"))),
+                    Err(e) => error.set(Some(e.message(&tr))),
                 }
             });
         })
@@ -541,14 +540,14 @@ fn experiences_section() -> Html {
 
     html! {
         <section class="dash-section">
-            <h2 class="dash-title">{ "Experiences" }</h2>
+            <h2 class="dash-title">{ tr.t("dash-experiences-title") }</h2>
 
             if let Some(err) = (*error).clone() {
                 <p class="dash-error">{err}</p>
             }
 
             <button class="dash-btn" onclick={on_add.clone()} disabled={*loading}>
-                { if *loading { "..." } else { "+ Add experience" } }
+                { if *loading { "...".to_string() } else { tr.t("dash-add-experience") } }
             </button>
 
             <div class="exp-list">
@@ -559,9 +558,12 @@ fn experiences_section() -> Html {
                             key={key}
                             exp={Rc::new(e.clone())}
                             on_change_field={on_change_field.clone()}
+                            lang={props.lang.clone()}
+                            on_change_translation={on_change_translation.clone()}
                             on_save={on_save_exp.clone()}
                             on_delete={on_delete_exp.clone()}
                             on_add_task={on_add_task.clone()}
+                            on_update_task={on_update_task.clone()}
                             on_delete_task={on_delete_task.clone()}
                             saved_id={(*saved_id).clone()}
                         />
@@ -577,19 +579,25 @@ fn experiences_section() -> Html {
 struct ExpItemProps {
     pub exp: Rc<ExperienceData>,
     pub on_change_field: Callback<(i64, &'static str, String)>,
+    pub lang: String,
+    pub on_change_translation: Callback<(i64, &'static str, String)>,
     pub on_save: Callback<ExperienceData>,
     pub on_delete: Callback<i64>,
     pub on_add_task: Callback<(i64, String)>,
+    pub on_update_task: Callback<(i64, i64, String)>,
     pub on_delete_task: Callback<(i64, i64)>,
     pub saved_id: Option<i64>, // ✅
 }
 
 #[function_component(ExpItem)]
 fn exp_item(props: &ExpItemProps) -> Html {
+    let tr = use_tr();
     let exp = (*props.exp).clone();
     let id = exp.id.unwrap_or_default();
 
     let new_task = use_state(String::default);
+    // task en cours d'édition: (task_id, texte)
+    let editing = use_state(|| Option::<(i64, String)>::None);
 
     let on_input_factory = {
         let cb = props.on_change_field.clone();
@@ -610,6 +618,28 @@ fn exp_item(props: &ExpItemProps) -> Html {
     let on_location   = on_input_factory("location");
     let on_website    = on_input_factory("website");
 
+    let translation = exp
+        .translations
+        .as_ref()
+        .and_then(|t| t.get(&props.lang))
+        .cloned()
+        .unwrap_or_default();
+    let on_title_tr = {
+        let cb = props.on_change_translation.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            cb.emit((id, "title", input.value()));
+        })
+    };
+    // appliqué à la sortie du champ, pour garder les lignes vides pendant la saisie
+    let on_tasks_tr = {
+        let cb = props.on_change_translation.clone();
+        Callback::from(move |e: Event| {
+            let input: HtmlTextAreaElement = e.target_unchecked_into();
+            cb.emit((id, "tasks", input.value()));
+        })
+    };
+
     let do_save = {
         let on_save = props.on_save.clone();
         let exp = exp.clone();
@@ -644,13 +674,22 @@ fn exp_item(props: &ExpItemProps) -> Html {
     html! {
         <div class="exp-card">
             <div class="exp-grid">
-                <input class="dash-input" type="text" placeholder="Start (YYYY[-MM[-DD]])" value={exp.date_start} oninput={on_date_start} />
-                <input class="dash-input" type="text" placeholder="End (YYYY[-MM[-DD]])"   value={exp.date_end}   oninput={on_date_end} />
-                <input class="dash-input" type="text" placeholder="Kind"    value={exp.kind}    oninput={on_kind} />
-                <input class="dash-input" type="text" placeholder="Title"   value={exp.title}   oninput={on_title} />
-                <input class="dash-input" type="text" placeholder="Company" value={exp.company} oninput={on_company} />
-                <input class="dash-input" type="text" placeholder="Location" value={exp.location}oninput={on_location} />
-                <input class="dash-input" type="url"  placeholder="Website" value={exp.website} oninput={on_website} />
+                <input class="dash-input" type="text" placeholder="Start (YYYY[-MM[-DD]])" value={exp.date_start.clone()} oninput={on_date_start} />
+                <input class="dash-input" type="text" placeholder="End (YYYY[-MM[-DD]])"   value={exp.date_end.clone()}   oninput={on_date_end} />
+                <input class="dash-input" type="text" placeholder="Kind"    value={exp.kind.clone()}    oninput={on_kind} />
+                <input class="dash-input" type="text" placeholder="Title"   value={exp.title.clone()}   oninput={on_title} />
+                <input class="dash-input" type="text" placeholder="Company" value={exp.company.clone()} oninput={on_company} />
+                <input class="dash-input" type="text" placeholder="Location" value={exp.location.clone()}oninput={on_location} />
+                <input class="dash-input" type="url"  placeholder="Website" value={exp.website.clone()} oninput={on_website} />
+            </div>
+
+            <div class="exp-grid">
+                <input class="dash-input" type="text"
+                    placeholder={format!("Title ({}) — {}", props.lang, exp.title)}
+                    value={translation.title.clone().unwrap_or_default()} oninput={on_title_tr} />
+                <textarea class="dash-input dash-textarea" rows={3}
+                    placeholder={format!("Tasks ({}), one per line", props.lang)}
+                    value={translation.tasks.clone().unwrap_or_default().join("\n")} onchange={on_tasks_tr} />
             </div>
 
             <div class="tasks">
@@ -661,47 +700,90 @@ fn exp_item(props: &ExpItemProps) -> Html {
 
                 <ul class="tasks-list">
                     { for exp.tasks.iter().map(|t| {
+                        let tid = t.id;
                         let del = {
                             let cb = props.on_delete_task.clone();
-                            let tid = t.id;
                             Callback::from(move |_| cb.emit((id, tid)))
                         };
-                        html!{
-                            <li class="task-item">
-                                <span>{ &t.task }</span>
-                                <button class="dash-btn dash-btn-danger" onclick={del.clone()}>{ "–" }</button>
-                            </li>
+
+                        match &*editing {
+                            Some((edit_id, text)) if *edit_id == tid => {
+                                let on_edit_input = {
+                                    let editing = editing.clone();
+                                    Callback::from(move |e: InputEvent| {
+                                        let input: HtmlInputElement = e.target_unchecked_into();
+                                        editing.set(Some((tid, input.value())));
+                                    })
+                                };
+                                let do_commit = {
+                                    let editing = editing.clone();
+                                    let cb = props.on_update_task.clone();
+                                    let text = text.clone();
+                                    Callback::from(move |_| {
+                                        if !text.trim().is_empty() {
+                                            cb.emit((id, tid, text.clone()));
+                                        }
+                                        editing.set(None);
+                                    })
+                                };
+                                let do_cancel = {
+                                    let editing = editing.clone();
+                                    Callback::from(move |_| editing.set(None))
+                                };
+                                html!{
+                                    <li class="task-item">
+                                        <input class="dash-input" type="text" value={text.clone()} oninput={on_edit_input} />
+                                        <button class="dash-btn" onclick={do_commit}>{ "✔" }</button>
+                                        <button class="dash-btn" onclick={do_cancel}>{ "✕" }</button>
+                                    </li>
+                                }
+                            }
+                            _ => {
+                                let do_edit = {
+                                    let editing = editing.clone();
+                                    let text = t.task.clone();
+                                    Callback::from(move |_| editing.set(Some((tid, text.clone()))))
+                                };
+                                html!{
+                                    <li class="task-item">
+                                        <span>{ rich_text_html(&t.task) }</span>
+                                        <button class="dash-btn" onclick={do_edit} title="**gras**, *italique*, [lien](https://…)">{ "✎" }</button>
+                                        <button class="dash-btn dash-btn-danger" onclick={del.clone()}>{ "–" }</button>
+                                    </li>
+                                }
+                            }
                         }
                     })}
                 </ul>
             </div>
 
             <div class="exp-actions">
-                <button class="dash-btn" onclick={do_save}>{ "Save experience" }</button>
+                <button class="dash-btn" onclick={do_save}>{ tr.t("dash-save-experience") }</button>
                 { if props.saved_id == Some(id) {
-                    html!{ <span class="dash-ok" style="margin-left: .75rem;">{ "Saved ✅" }</span> }
+                    html!{ <span class="dash-ok" style="margin-left: .75rem;">{ tr.t("dash-saved") }</span> }
                 } else { html!{} } }
-                <button class="dash-btn dash-btn-danger" onclick={do_delete} style="margin-left: .5rem;">{ "– Delete" }</button>
+                <button class="dash-btn dash-btn-danger" onclick={do_delete} style="margin-left: .5rem;">{ tr.t("dash-delete") }</button>
             </div>
         </div>
     }
 }
 
+/// Puce Markdown-lite → Html (le rendu est déjà échappé par `rich_text`).
+fn rich_text_html(src: &str) -> Html {
+    Html::from_html_unchecked(AttrValue::from(rich_text::render_inline(src)))
+}
 
 #[function_component(SkillsSection)]
 fn skills_section() -> Html {
-    let list = use_state(|| Vec::<Skill>::new());
+    let tr = use_tr();
+    let list = use_state(|| Vec::<SkillOut>::new());
     let loading = use_state(|| false);
     let error = use_state(|| Option::<String>::None);
     let adding = use_state(|| false);
-    let new_skill = use_state(|| SkillPayload {
-        name: "".into(),
-        percentage: None,
-        logo_url: None,
-        category: "".into(),
-    });
+    let new_skill = use_state(SkillIn::default);
 
     let selected_category = use_state(|| "all".to_string());
+    let suggestions = use_state(Vec::<CatalogEntry>::new);
 
     // Charger les skills au montage
     {
@@ -713,19 +795,9 @@ fn skills_section() -> Html {
                 loading.set(true);
                 error.set(None);
 
-                let resp = Request::get("/api/cv/skills")
-                    .credentials(RequestCredentials::Include)
-                    .send().await;
-
-                match resp {
-                    Ok(r) if r.ok() => {
-                        match r.json::<Vec<Skill>>().await {
-                            Ok(skills) => list.set(skills),
-                            Err(e) => error.set(Some(format!("JSON error: {e}"))),
-                        }
-                    }
-                    Ok(r) => error.set(Some(format!("HTTP {}", r.status()))),
-                    Err(e) => error.set(Some(format!("Network error: {e}"))),
+                match api::cv::skills().await {
+                    Ok(skills) => list.set(skills),
+                    Err(e) => error.set(Some(e.message(&tr))),
                 }
                 loading.set(false);
             });
@@ -751,6 +823,7 @@ fn skills_section() -> Html {
             match field {
                 "name" => s.name = value,
                 "percentage" => s.percentage = value.parse().ok(),
+                "level" => s.level = SkillLevel::parse(&value),
                 "logo_url" => s.logo_url = if value.is_empty() { None } else { Some(value) },
                 "category" => s.category = value,
                 _ => {}
@@ -759,6 +832,28 @@ fn skills_section() -> Html {
         })
     };
 
+    // Autocomplete du nom via le catalogue partagé
+    let on_name_input = {
+        let on_change = on_change.clone();
+        let suggestions = suggestions.clone();
+        Callback::from(move |e: InputEvent| {
+            let input: HtmlInputElement = e.target_unchecked_into();
+            let q = input.value();
+            on_change.emit(("name", q.clone()));
+
+            let suggestions = suggestions.clone();
+            spawn_local(async move {
+                if q.trim().is_empty() {
+                    suggestions.set(vec![]);
+                    return;
+                }
+                if let Ok(found) = api::cv::catalog_search(&q, 8).await {
+                    suggestions.set(found);
+                }
+            });
+        })
+    };
+
     let on_save = {
         let new_skill = new_skill.clone();
         let list = list.clone();
@@ -775,23 +870,14 @@ fn skills_section() -> Html {
                 loading.set(true);
                 error.set(None);
 
-                let resp = Request::post("/api/cv/skills")
-                    .header("Content-Type", "application/json")
-                    .credentials(RequestCredentials::Include)
-                    .json(&skill).unwrap()
-                    .send().await;
-
-                match resp {
-                    Ok(r) if r.ok() => {
-                        if let Ok(created) = r.json::<Skill>().await {
-                            let mut v = (*list).clone();
-                            v.push(created);
-                            list.set(v);
-                            adding.set(false);
-                        }
+                match api::cv::create_skill(&skill).await {
+                    Ok(created) => {
+                        let mut v = (*list).clone();
+                        v.push(created);
+                        list.set(v);
+                        adding.set(false);
                     }
-                    Ok(r) => error.set(Some(format!("HTTP {}", r.status()))),
-                    Err(e) => error.set(Some(format!("Network error: {e}"))),
+                    Err(e) => error.set(Some(e.message(&tr))),
                 }
                 loading.set(false);
             });
@@ -810,16 +896,12 @@ fn skills_section() -> Html {
             spawn_local(async move {
                 loading.set(true);
                 error.set(None);
-                let resp = Request::delete(&format!("/api/cv/skills/{id}"))
-                    .credentials(RequestCredentials::Include)
-                    .send().await;
-                match resp {
-                    Ok(r) if r.ok() => {
+                match api::cv::delete_skill(id).await {
+                    Ok(()) => {
                         let v = (*list).clone().into_iter().filter(|s| s.id != id).collect();
                         list.set(v);
                     }
-                    Ok(r) => error.set(Some(format!("HTTP {}", r.status()))),
-                    Err(e) => error.set(Some(format!("Network error: {e}"))),
+                    Err(e) => error.set(Some(e.message(&tr))),
                 }
                 loading.set(false);
             });
@@ -844,7 +926,7 @@ fn skills_section() -> Html {
         })
     };
 
-    let filtered_skills: Vec<Skill> = if *selected_category == "all" {
+    let filtered_skills: Vec<SkillOut> = if *selected_category == "all" {
         (*list).clone()
     } else {
         (*list).clone().into_iter()
@@ -853,7 +935,7 @@ fn skills_section() -> Html {
     };
     html! {
         <section class="dash-section">
-            <h2 class="dash-title">{ "Skills" }</h2>
+            <h2 class="dash-title">{ tr.t("dash-skills-title") }</h2>
             if let Some(err) = (*error).clone() {
                 <p class="dash-error">{err}</p>
             }
@@ -862,16 +944,15 @@ fn skills_section() -> Html {
             </button>
             if *adding {
                 <div class="skill-form">
-                    <input class="dash-input" type="text" placeholder="Name"
+                    <input class="dash-input" type="text" placeholder="Name" list="tech-catalog"
                         value={new_skill.name.clone()}
-                        oninput={Callback::from({
-                            let on_change = on_change.clone();
-                            move |e: InputEvent| {
-                                let input: HtmlInputElement = e.target_unchecked_into();
-                                on_change.emit(("name", input.value()));
-                            }
-                        })}
+                        oninput={on_name_input}
                     />
+                    <datalist id="tech-catalog">
+                        { for suggestions.iter().map(|c| html!{
+                            <option value={c.name.clone()}>{ &c.category }</option>
+                        }) }
+                    </datalist>
                     <input class="dash-input" type="number" min="0" max="100" placeholder="Percentage"
                         value={new_skill.percentage.map(|p| p.to_string()).unwrap_or_default()}
                         oninput={Callback::from({
@@ -882,6 +963,20 @@ fn skills_section() -> Html {
                             }
                         })}
                     />
+                    <select class="dash-input"
+                        onchange={Callback::from({
+                            let on_change = on_change.clone();
+                            move |e: Event| {
+                                let input: HtmlSelectElement = e.target_unchecked_into();
+                                on_change.emit(("level", input.value()));
+                            }
+                        })}
+                    >
+                        <option value="" selected={new_skill.level.is_none()}>{ tr.t("dash-skill-level") }</option>
+                        { for SkillLevel::ALL.into_iter().map(|k| html!{
+                            <option value={k.as_str()} selected={new_skill.level == Some(k)}>{ level_label(&tr, k) }</option>
+                        }) }
+                    </select>
                     <input class="dash-input" type="text" placeholder="Logo URL"
                         value={new_skill.logo_url.clone().unwrap_or_default()}
                         oninput={Callback::from({
@@ -902,8 +997,8 @@ fn skills_section() -> Html {
                             }
                         })}
                     />
-                    <button class="dash-btn" onclick={on_save} disabled={*loading}>{ "Save skill" }</button>
-                    <button class="dash-btn dash-btn-danger" onclick={on_cancel} disabled={*loading}>{ "Cancel" }</button>
+                    <button class="dash-btn" onclick={on_save} disabled={*loading}>{ tr.t("dash-save-skill") }</button>
+                    <button class="dash-btn dash-btn-danger" onclick={on_cancel} disabled={*loading}>{ tr.t("dash-cancel") }</button>
                 </div>
             }
             <div class="skills-filter-row">
@@ -933,14 +1028,23 @@ fn skills_section() -> Html {
                             <div class="skill-category" style="margin-bottom:0.5rem;text-align:left;">
                                 { &skill.category }
                             </div>
-                            <div class="skill-progress-row">
-                                <div class="skill-progress-bar">
-                                    <div class="skill-progress-inner"
-                                        style={format!("width: {}%;", skill.percentage.unwrap_or(0))}>
-                                    </div>
-                                </div>
-                                <span class="skill-percentage">{ format!("{}%", skill.percentage.unwrap_or(0)) }</span>
-                            </div>
+                            {
+                                match (skill.percentage, skill.level) {
+                                    (None, Some(level)) => html!{
+                                        <div class="skill-level">{ level_label(&tr, level) }</div>
+                                    },
+                                    (pct, _) => html!{
+                                        <div class="skill-progress-row">
+                                            <div class="skill-progress-bar">
+                                                <div class="skill-progress-inner"
+                                                    style={format!("width: {}%;", pct.unwrap_or(0))}>
+                                                </div>
+                                            </div>
+                                            <span class="skill-percentage">{ format!("{}%", pct.unwrap_or(0)) }</span>
+                                        </div>
+                                    },
+                                }
+                            }
                             <button class="dash-btn dash-btn-danger skill-delete-btn"
                                 style="margin-top:0.8rem;align-self:flex-end;"
                                 onclick={let on_delete_skill = on_delete_skill.clone(); Callback::from(move |_| on_delete_skill.emit(skill_id))}>
@@ -1,106 +1,143 @@
+use std::ops::{Deref, DerefMut};
+
+use gloo::storage::{LocalStorage, Storage};
 use serde::{Deserialize, Serialize};
 use yewdux::store::Store;
 
-/// Widgets disponibles dans une colonne.
-/// Plus tard, on ajoutera des options par widget (ex: nombre d'items, champs visibles, etc.)
-#[derive(Clone, PartialEq, Serialize, Deserialize, Debug)]
-pub enum WidgetKind {
-    Text(String),
-    ProfileBasic,
-    ExperienceTimeline,
-    SkillsGrid,
-    ProjectsList,
-}
+pub use crate::api::{CvDetail, CvSummary, HiddenItems};
+pub use rustfolio_layout::{Align, Column, Edit, Font, History, Layout, Row, SectionHeader, SkillDisplay, Theme, Widget, WidgetRef, MAX_SPACING};
 
-#[derive(Clone, PartialEq, Serialize, Deserialize, Debug)]
-pub struct Column {
-    pub id: usize,
-    pub widgets: Vec<WidgetKind>,
-}
-impl Default for Column {
-    fn default() -> Self { Self { id: 0, widgets: vec![] } }
+/// Layout partagé avec le serveur (`rustfolio_layout`) et son historique
+/// annuler/rétablir. Les modifications de contenu passent par [`BuilderLayout::edit`] ;
+/// la sélection (`select_row`…) s'obtient directement via `DerefMut`.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct BuilderLayout {
+    /// CV édité ; `None` tant qu'aucun n'est chargé.
+    pub cv: Option<i64>,
+    pub layout: Layout,
+    pub history: History,
 }
 
-#[derive(Clone, PartialEq, Serialize, Deserialize, Debug)]
-pub struct Row {
-    pub id: usize,
-    pub columns: Vec<Column>,
-}
-impl Default for Row {
-    fn default() -> Self { Self { id: 0, columns: vec![Column::default()] } }
+impl Deref for BuilderLayout {
+    type Target = Layout;
+    fn deref(&self) -> &Layout { &self.layout }
 }
 
-#[derive(Clone, PartialEq, Serialize, Deserialize, Debug, Default)]
-pub struct BuilderLayout {
-    pub rows: Vec<Row>,
-    pub selected_row: Option<usize>,
-    pub selected_column: Option<usize>,
-    pub next_row_id: usize,
-    pub next_col_id: usize,
+impl DerefMut for BuilderLayout {
+    fn deref_mut(&mut self) -> &mut Layout { &mut self.layout }
 }
 
 impl BuilderLayout {
-    pub fn add_row(&mut self) {
-        self.next_row_id += 1;
-        self.next_col_id += 1;
-        self.rows.push(Row {
-            id: self.next_row_id,
-            columns: vec![Column { id: self.next_col_id, widgets: vec![] }],
-        });
-        // UX: on sélectionne la ligne créée
-        self.selected_row = Some(self.next_row_id);
-        self.selected_column = None;
+    /// Layout du CV `cv` chargé du serveur ; l'historique local est repris s'il y mène.
+    pub fn loaded(cv: i64, layout: Layout) -> Self {
+        let history = restore_history(cv, &layout).unwrap_or_default();
+        Self { cv: Some(cv), layout, history }
     }
 
-    pub fn select_row(&mut self, row_id: usize) {
-        self.selected_row = Some(row_id);
-        self.selected_column = None; // reset la colonne quand on change de ligne
+    pub fn edit(&mut self, edit: Edit) -> bool {
+        self.history.apply(&mut self.layout, edit, js_sys::Date::now() as u64)
     }
 
-    pub fn select_column(&mut self, col_id: usize) {
-        self.selected_column = Some(col_id);
-    }
+    pub fn undo(&mut self) -> bool { self.history.undo(&mut self.layout) }
+    pub fn redo(&mut self) -> bool { self.history.redo(&mut self.layout) }
+}
 
-    pub fn split_selected_row(&mut self, n: usize) {
-        if n == 0 { return; }
-        let Some(sel) = self.selected_row else { return; };
-        if let Some(row) = self.rows.iter_mut().find(|r| r.id == sel) {
-            // récupérer tous les widgets pour les redistribuer ensuite
-            let mut old_widgets = Vec::new();
-            for c in &mut row.columns { old_widgets.append(&mut c.widgets); }
-
-            // recréer n colonnes vides avec de nouveaux IDs
-            row.columns = (0..n).map(|_| {
-                self.next_col_id += 1;
-                Column { id: self.next_col_id, widgets: vec![] }
-            }).collect();
-
-            // redistribution round-robin
-            for (i, w) in old_widgets.into_iter().enumerate() {
-                row.columns[i % n].widgets.push(w);
-            }
-
-            // si la colonne sélectionnée n'existe plus → désélection
-            if let Some(cid) = self.selected_column {
-                if !row.columns.iter().any(|c| c.id == cid) {
-                    self.selected_column = None;
-                }
-            }
-        }
-    }
+impl Store for BuilderLayout {
+    fn new(_ctx: &yewdux::Context) -> Self { Self::default() }
+    fn should_notify(&self, old: &Self) -> bool { self != old }
+}
 
-    pub fn add_widget_to_selected_column(&mut self, w: WidgetKind) {
-        let Some(col_id) = self.selected_column else { return; };
-        for row in &mut self.rows {
-            if let Some(col) = row.columns.iter_mut().find(|c| c.id == col_id) {
-                col.widgets.push(w);
-                break;
-            }
-        }
+// ================== HISTORIQUE (stockage local) ==================
+
+/// Un historique par CV.
+fn history_key(cv: i64) -> String {
+    format!("rustfolio.builder.history.{cv}")
+}
+
+/// L'historique n'a de sens qu'à partir de l'état où il a été enregistré.
+#[derive(Serialize, Deserialize)]
+struct StoredHistory {
+    present: Layout,
+    history: History,
+}
+
+pub fn persist_history(b: &BuilderLayout) {
+    let Some(cv) = b.cv else { return };
+    let stored = StoredHistory { present: b.layout.clone(), history: b.history.clone() };
+    // quota dépassé, navigation privée… : l'historique reste en mémoire
+    if let Err(e) = LocalStorage::set(history_key(cv), &stored) {
+        web_sys::console::warn_1(&format!("[builder] historique non sauvegardé : {e}").into());
     }
 }
 
-impl Store for BuilderLayout {
+/// Historique enregistré, seulement s'il aboutit au layout du serveur (sinon le layout
+/// a changé ailleurs : autre onglet, autre appareil).
+fn restore_history(cv: i64, server: &Layout) -> Option<History> {
+    let stored: StoredHistory = LocalStorage::get(history_key(cv)).ok()?;
+    stored.present.same_content(server).then(|| stored.history.bounded())
+}
+
+// ================== SYNCHRO SERVEUR ==================
+
+#[derive(Clone, PartialEq, Debug, Default)]
+pub enum SaveStatus {
+    #[default]
+    Loading,
+    Saved,
+    /// Modifié depuis le dernier enregistrement, autosave programmé.
+    Dirty,
+    Saving,
+    Error(String),
+}
+
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct LayoutSync {
+    pub status: SaveStatus,
+    /// Dernière version acceptée par le serveur ; `None` tant que le chargement n'a pas
+    /// abouti (pas d'autosave : on écraserait le layout du serveur par un layout vide).
+    pub saved: Option<Layout>,
+}
+
+impl Store for LayoutSync {
     fn new(_ctx: &yewdux::Context) -> Self { Self::default() }
     fn should_notify(&self, old: &Self) -> bool { self != old }
 }
+
+// ================== CV NOMMÉS ==================
+
+/// Dernier CV ouvert, rouvert au prochain chargement du builder.
+const CURRENT_CV_KEY: &str = "rustfolio.builder.cv";
+
+/// Liste des CV (sélecteur du builder), éléments masqués et thème du CV ouvert.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct CvList {
+    pub cvs: Vec<CvSummary>,
+    pub current: Option<i64>,
+    pub hidden: HiddenItems,
+    pub theme: Theme,
+}
+
+impl Store for CvList {
+    fn new(_ctx: &yewdux::Context) -> Self { Self::default() }
+    fn should_notify(&self, old: &Self) -> bool { self != old }
+}
+
+impl CvList {
+    /// CV à ouvrir : le dernier ouvert s'il existe encore, sinon le plus ancien.
+    pub fn initial(&self) -> Option<i64> {
+        let last: Option<i64> = LocalStorage::get(CURRENT_CV_KEY).ok();
+        last.filter(|id| self.cvs.iter().any(|c| c.id == *id))
+            .or_else(|| self.cvs.first().map(|c| c.id))
+    }
+
+    pub fn opened(&mut self, detail: &CvDetail) {
+        self.current = Some(detail.cv.id);
+        self.hidden = detail.hidden.clone();
+        self.theme = detail.theme.clone();
+        match self.cvs.iter_mut().find(|c| c.id == detail.cv.id) {
+            Some(c) => *c = detail.cv.clone(),
+            None => self.cvs.push(detail.cv.clone()),
+        }
+        let _ = LocalStorage::set(CURRENT_CV_KEY, detail.cv.id);
+    }
+}
@@ -0,0 +1,219 @@
+# UI catalog — English.
+# Every key must exist in every catalog (`catalogs` test).
+
+## Navigation (base.html)
+nav-home = Home
+nav-dashboard = Dashboard
+nav-login = Log in
+nav-signup = Sign up
+nav-logout = Log out
+
+## Home
+home-page-title = Engagez-moi.com — Create, edit and publish your resume
+home-title = Create your resume. Edit it freely. Publish it here.
+home-intro =
+    Engagez-moi.com lets you build a modern resume, update it in a few clicks,
+    then host it so recruiters can easily find it.
+    First and foremost, this project is a learning ground and a centerpiece
+    of my personal portfolio: I explore Rust, the modern web and design while
+    offering a useful, elegant tool to share your profile.
+home-hero-alt = Resume and builder illustration
+home-how-title = How does it work?
+home-step1-title = 1. Create your account
+home-step1-text = Sign up and secure your access. Come back to edit your resume whenever you like.
+home-step2-title = 2. Fill in your details
+home-step2-text = Profile, experience, projects, skills… or import an existing JSON file.
+home-step3-title = 3. Build & publish
+home-step3-text = Use the builder to arrange your blocks and publish your resume.
+home-why-title = Why here?
+home-why1-title = An openly learning project
+home-why1-text =
+    The goal is also to improve in Rust and polish my own portfolio — while keeping
+    real product quality for you.
+home-why2-title = Simple, clear, extensible
+home-why2-text = A light design, portable data (JSON) and a “sections & widgets” builder.
+
+## Log in / sign up
+form-email = Email
+form-password = Password
+login-page-title = Log in • Rustfolio
+login-title = Log in
+login-subtitle = Access your dashboard.
+login-create-account = Create an account
+login-submit = Log in
+signup-page-title = Create an account • Rustfolio
+signup-title = Create an account
+signup-subtitle = Join Rustfolio and build your resume.
+signup-display-name = Display name
+signup-password-help = 8+ characters, ideally a passphrase.
+signup-have-account = Already registered?
+signup-submit = Create my account
+
+## Authentication errors
+auth-invalid-credentials = Invalid credentials
+auth-email-taken = Email already in use
+auth-not-logged-in = Not logged in
+auth-session-expired = Session expired
+auth-verify-invalid-title = Invalid or expired link
+auth-verify-invalid-text = Request a new email.
+auth-locale-unsupported = Unsupported language: { $locale }
+
+## Portfolio
+portfolio-filters = Portfolio filters
+portfolio-type = Type
+portfolio-all = All
+portfolio-search = Search
+portfolio-search-placeholder = Search a title/description
+portfolio-filter = Filter
+portfolio-reset = Reset
+portfolio-empty = No results.
+portfolio-close = Close
+
+## CV (export, public page, builder preview): content language
+cv-section-experiences = Experience
+cv-section-skills = Skills
+cv-section-projects = Projects
+cv-date-present = present
+cv-link-code = Code
+cv-link-pdf = PDF
+
+## Dashboard (SPA)
+dash-nav-overview = Overview
+dash-nav-profile = Profile
+dash-nav-account = Account
+dash-nav-builder = Builder
+dash-overview-title = Overview
+dash-overview-welcome = Welcome to your dashboard.
+dash-account-title = Account settings
+dash-account-language = Interface language
+dash-account-language-auto = Automatic (browser)
+dash-account-soon = Email, password and account deletion are coming soon.
+dash-save = Save
+dash-saving = Saving…
+dash-loading = Loading…
+dash-saved = Saved ✅
+dash-cancel = Cancel
+dash-delete = – Delete
+dash-profile-title = Profile
+dash-languages = Languages
+dash-translate-to = Translate to
+dash-default-language = French ({ $code }, default)
+dash-experiences-title = Experience
+dash-add-experience = + Add experience
+dash-save-experience = Save experience
+dash-skills-title = Skills
+dash-save-skill = Save skill
+dash-skill-level = Level (or %)
+dash-skill-level-beginner = Beginner
+dash-skill-level-intermediate = Intermediate
+dash-skill-level-advanced = Advanced
+dash-skill-level-expert = Expert
+dash-error-session-expired = session expired
+dash-error-http = http { $status }
+dash-error-http-detail = http { $status }: { $message }
+dash-error-network = network: { $error }
+dash-error-decode = json: { $error }
+
+## Builder (SPA)
+dash-builder-load-error = loading: { $error }
+dash-builder-data-source = Data source: { $source }
+dash-builder-data-error = — Error: { $error }
+dash-builder-row = Row
+dash-builder-row-heading = Row #{ $id } · { $count } column(s)
+dash-builder-row-selected = Selected: row #{ $id }
+dash-builder-row-hint = Click a row to edit it.
+dash-builder-row-up = Move row up
+dash-builder-row-down = Move row down
+dash-builder-row-duplicate = Duplicate row
+dash-builder-row-delete = Delete row
+dash-builder-columns = Columns:
+dash-builder-column-content = Column content
+dash-builder-column-heading = Column #{ $id } · { $width }fr
+dash-builder-column-selected = Selected: column #{ $id }
+dash-builder-column-hint = Click a column (blue border), then add a widget.
+dash-builder-column-empty = No content yet. Select the column and add a widget from the sidebar, or drop a widget here.
+dash-builder-column-narrow = Narrow
+dash-builder-column-widen = Widen
+dash-builder-column-left = Move left
+dash-builder-column-right = Move right
+dash-builder-column-duplicate = Duplicate column
+dash-builder-column-delete = Delete column
+dash-builder-widget-up = Move up
+dash-builder-widget-down = Move down
+dash-builder-widget-duplicate = Duplicate
+dash-builder-widget-delete = Delete
+dash-builder-widgets-hint = These widgets use your saved data (profile, experience, skills, projects).
+dash-builder-actions = Actions
+dash-builder-undo = ↶ Undo
+dash-builder-redo = ↷ Redo
+dash-builder-save = 💾 Save layout
+dash-builder-loading = Loading layout…
+dash-builder-saved = ✔ Saved
+dash-builder-dirty = Unsaved changes (autosave)
+dash-builder-error = ⚠ Error: { $error }
+dash-builder-profile-missing = Profile not loaded
+dash-builder-project-missing = Project #{ $n } not found
+dash-cv-title = CV
+dash-cv-new = ➕ New
+dash-cv-duplicate = ⧉ Duplicate
+dash-cv-rename = ✎ Rename
+dash-cv-delete = ✕ Delete
+dash-cv-delete-last = The last CV cannot be deleted
+dash-cv-new-name = Name of the new CV
+dash-cv-rename-prompt = New name for the CV
+dash-cv-delete-confirm = Delete the CV “{ $name }”?
+dash-cv-shown-experiences = Shown experience
+dash-cv-shown-skills = Shown skills
+dash-cv-shown-projects = Shown projects
+dash-theme-title = Theme
+dash-theme-base = Starting theme
+dash-theme-accent = Accent
+dash-theme-background = Background
+dash-theme-surface = Cards
+dash-theme-text = Text
+dash-theme-muted = Secondary text
+dash-theme-border = Borders
+dash-theme-body-font = Body font
+dash-theme-heading-font = Heading font
+dash-theme-section-header = Section headings
+dash-theme-skills = Skills
+dash-theme-spacing = Spacing (px)
+dash-theme-preview = HTML / PDF preview
+dash-theme-download = Download
+dash-font-sans = Sans-serif
+dash-font-serif = Serif
+dash-font-mono = Monospace
+dash-font-rounded = Rounded
+dash-section-plain = Plain
+dash-section-underline = Underlined
+dash-section-bar = Side bar
+dash-section-caps = Capitals
+dash-skills-bars = Bars
+dash-skills-tags = Tags
+dash-skills-dots = Dots
+dash-widget-text = Free text
+dash-widget-title = Heading
+dash-widget-profile = Profile
+dash-widget-experiences = Experience
+dash-widget-skills = Skills
+dash-widget-projects = Projects
+dash-widget-project-card = Project (card)
+dash-widget-photo = Photo
+dash-widget-properties = Properties: { $widget }
+dash-widget-field-text = Text
+dash-widget-level = Level
+dash-widget-bold = Bold
+dash-widget-align = Alignment
+dash-widget-align-default = Default
+dash-widget-align-left = Left
+dash-widget-align-center = Centered
+dash-widget-align-right = Right
+dash-widget-project-number = Project no.
+dash-widget-image-url = Image URL
+dash-widget-rounded = Rounded
+dash-widget-filter-kind = Type (work, education…)
+dash-widget-filter-category = Category
+dash-widget-fields = Displayed fields
+dash-widget-heading = Section heading
+dash-widget-all = All
+dash-widget-limit = Max. number of items
@@ -0,0 +1,219 @@
+# Catalogue de l'interface — français (langue par défaut).
+# Chaque clé doit exister dans tous les catalogues (test `catalogs`).
+
+## Navigation (base.html)
+nav-home = Accueil
+nav-dashboard = Tableau de bord
+nav-login = Connexion
+nav-signup = Créer un compte
+nav-logout = Déconnexion
+
+## Accueil
+home-page-title = Engagez-moi.com — Crée, modifie et publie ton CV
+home-title = Crée ton CV. Modifie-le librement. Publie-le ici.
+home-intro =
+    Engagez-moi.com te permet de construire un CV moderne, de le mettre à jour en quelques clics,
+    puis de l’héberger pour qu’il soit facilement découvrable par les recruteurs.
+    À la base, ce projet est surtout un terrain d’apprentissage et une pièce maîtresse
+    de mon portfolio personnel : j’y explore Rust, le web moderne et le design, tout en
+    offrant un outil utile et élégant pour partager ton profil.
+home-hero-alt = Illustration de CV et builder
+home-how-title = Comment ça marche ?
+home-step1-title = 1. Crée ton compte
+home-step1-text = Inscris-toi et sécurise ton accès. Tu pourras revenir modifier ton CV quand tu veux.
+home-step2-title = 2. Renseigne tes infos
+home-step2-text = Profil, expériences, projets, compétences… ou importe un JSON existant.
+home-step3-title = 3. Construis & publie
+home-step3-text = Utilise le builder pour agencer tes blocs et publier ton CV.
+home-why-title = Pourquoi ici ?
+home-why1-title = Un projet d’apprentissage assumé
+home-why1-text =
+    Le but est aussi de progresser en Rust et d’embellir mon propre portfolio — en gardant une
+    qualité produit réelle pour toi.
+home-why2-title = Simple, clair, extensible
+home-why2-text = Un design léger, des données portables (JSON) et un builder façon « sections & widgets ».
+
+## Connexion / inscription
+form-email = Email
+form-password = Mot de passe
+login-page-title = Connexion • Rustfolio
+login-title = Connexion
+login-subtitle = Accède à ton tableau de bord.
+login-create-account = Créer un compte
+login-submit = Se connecter
+signup-page-title = Créer un compte • Rustfolio
+signup-title = Créer un compte
+signup-subtitle = Rejoins Rustfolio et construis ton CV.
+signup-display-name = Nom affiché
+signup-password-help = 8+ caractères, idéalement une passphrase.
+signup-have-account = Déjà inscrit ?
+signup-submit = Créer mon compte
+
+## Erreurs d'authentification
+auth-invalid-credentials = Identifiants invalides
+auth-email-taken = Email déjà utilisé
+auth-not-logged-in = Non connecté
+auth-session-expired = Session expirée
+auth-verify-invalid-title = Lien invalide ou expiré
+auth-verify-invalid-text = Demande un nouvel e-mail.
+auth-locale-unsupported = Langue non prise en charge : { $locale }
+
+## Portfolio
+portfolio-filters = Filtres portfolio
+portfolio-type = Type
+portfolio-all = Tous
+portfolio-search = Recherche
+portfolio-search-placeholder = Rechercher un titre/description
+portfolio-filter = Filtrer
+portfolio-reset = Réinitialiser
+portfolio-empty = Aucun résultat.
+portfolio-close = Fermer
+
+## CV (export, page publique, aperçu du builder) : langue du contenu
+cv-section-experiences = Expériences
+cv-section-skills = Compétences
+cv-section-projects = Projets
+cv-date-present = aujourd'hui
+cv-link-code = Code
+cv-link-pdf = PDF
+
+## Tableau de bord (SPA)
+dash-nav-overview = Vue d’ensemble
+dash-nav-profile = Profil
+dash-nav-account = Compte
+dash-nav-builder = Builder
+dash-overview-title = Vue d’ensemble
+dash-overview-welcome = Bienvenue sur ton tableau de bord.
+dash-account-title = Options du compte
+dash-account-language = Langue de l’interface
+dash-account-language-auto = Automatique (navigateur)
+dash-account-soon = Email, mot de passe et suppression du compte arrivent bientôt.
+dash-save = Enregistrer
+dash-saving = Enregistrement…
+dash-loading = Chargement…
+dash-saved = Enregistré ✅
+dash-cancel = Annuler
+dash-delete = – Supprimer
+dash-profile-title = Profil
+dash-languages = Langues
+dash-translate-to = Traduire en
+dash-default-language = Français ({ $code }, par défaut)
+dash-experiences-title = Expériences
+dash-add-experience = + Ajouter une expérience
+dash-save-experience = Enregistrer l’expérience
+dash-skills-title = Compétences
+dash-save-skill = Enregistrer la compétence
+dash-skill-level = Niveau (ou %)
+dash-skill-level-beginner = Débutant
+dash-skill-level-intermediate = Intermédiaire
+dash-skill-level-advanced = Avancé
+dash-skill-level-expert = Expert
+dash-error-session-expired = session expirée
+dash-error-http = http { $status }
+dash-error-http-detail = http { $status } : { $message }
+dash-error-network = réseau : { $error }
+dash-error-decode = json : { $error }
+
+## Builder (SPA)
+dash-builder-load-error = chargement : { $error }
+dash-builder-data-source = Source des données : { $source }
+dash-builder-data-error = — Erreur : { $error }
+dash-builder-row = Ligne
+dash-builder-row-heading = Ligne #{ $id } · { $count } colonne(s)
+dash-builder-row-selected = Sélection : Ligne #{ $id }
+dash-builder-row-hint = Clique une ligne pour la modifier.
+dash-builder-row-up = Monter la ligne
+dash-builder-row-down = Descendre la ligne
+dash-builder-row-duplicate = Dupliquer la ligne
+dash-builder-row-delete = Supprimer la ligne
+dash-builder-columns = Colonnes :
+dash-builder-column-content = Contenu de colonne
+dash-builder-column-heading = Colonne #{ $id } · { $width }fr
+dash-builder-column-selected = Sélection : Colonne #{ $id }
+dash-builder-column-hint = Clique une colonne (bordure bleue), puis ajoute un widget.
+dash-builder-column-empty = Aucun contenu. Sélectionne la colonne puis ajoute un widget (sidebar), ou dépose un widget ici.
+dash-builder-column-narrow = Réduire
+dash-builder-column-widen = Élargir
+dash-builder-column-left = Vers la gauche
+dash-builder-column-right = Vers la droite
+dash-builder-column-duplicate = Dupliquer la colonne
+dash-builder-column-delete = Supprimer la colonne
+dash-builder-widget-up = Monter
+dash-builder-widget-down = Descendre
+dash-builder-widget-duplicate = Dupliquer
+dash-builder-widget-delete = Supprimer
+dash-builder-widgets-hint = Ces widgets utiliseront tes données DB (profil, expériences, compétences, projets).
+dash-builder-actions = Actions
+dash-builder-undo = ↶ Annuler
+dash-builder-redo = ↷ Rétablir
+dash-builder-save = 💾 Enregistrer le layout
+dash-builder-loading = Chargement du layout…
+dash-builder-saved = ✔ Enregistré
+dash-builder-dirty = Modifications non enregistrées (autosave)
+dash-builder-error = ⚠ Erreur : { $error }
+dash-builder-profile-missing = Profil non chargé
+dash-builder-project-missing = Projet #{ $n } introuvable
+dash-cv-title = CV
+dash-cv-new = ➕ Nouveau
+dash-cv-duplicate = ⧉ Dupliquer
+dash-cv-rename = ✎ Renommer
+dash-cv-delete = ✕ Supprimer
+dash-cv-delete-last = Le dernier CV ne peut pas être supprimé
+dash-cv-new-name = Nom du nouveau CV
+dash-cv-rename-prompt = Nouveau nom du CV
+dash-cv-delete-confirm = Supprimer le CV « { $name } » ?
+dash-cv-shown-experiences = Expériences affichées
+dash-cv-shown-skills = Compétences affichées
+dash-cv-shown-projects = Projets affichés
+dash-theme-title = Thème
+dash-theme-base = Thème de départ
+dash-theme-accent = Accent
+dash-theme-background = Fond
+dash-theme-surface = Cartes
+dash-theme-text = Texte
+dash-theme-muted = Texte secondaire
+dash-theme-border = Bordures
+dash-theme-body-font = Police du texte
+dash-theme-heading-font = Police des titres
+dash-theme-section-header = Titres de section
+dash-theme-skills = Compétences
+dash-theme-spacing = Espacement (px)
+dash-theme-preview = Aperçu HTML / PDF
+dash-theme-download = Télécharger
+dash-font-sans = Sans empattement
+dash-font-serif = Avec empattement
+dash-font-mono = Chasse fixe
+dash-font-rounded = Arrondie
+dash-section-plain = Simple
+dash-section-underline = Souligné
+dash-section-bar = Barre latérale
+dash-section-caps = Capitales
+dash-skills-bars = Barres
+dash-skills-tags = Étiquettes
+dash-skills-dots = Points
+dash-widget-text = Texte libre
+dash-widget-title = Titre
+dash-widget-profile = Profil
+dash-widget-experiences = Expériences
+dash-widget-skills = Compétences
+dash-widget-projects = Projets
+dash-widget-project-card = Projet (carte)
+dash-widget-photo = Photo
+dash-widget-properties = Propriétés : { $widget }
+dash-widget-field-text = Texte
+dash-widget-level = Niveau
+dash-widget-bold = Gras
+dash-widget-align = Alignement
+dash-widget-align-default = Par défaut
+dash-widget-align-left = Gauche
+dash-widget-align-center = Centré
+dash-widget-align-right = Droite
+dash-widget-project-number = Projet n°
+dash-widget-image-url = URL de l'image
+dash-widget-rounded = Arrondie
+dash-widget-filter-kind = Type (work, education…)
+dash-widget-filter-category = Catégorie
+dash-widget-fields = Champs affichés
+dash-widget-heading = Titre de section
+dash-widget-all = Tous
+dash-widget-limit = Nombre max. d'éléments
@@ -0,0 +1,157 @@
+//! Messages de l'interface (pages Askama, erreurs d'authentification, SPA), un
+//! catalogue Fluent par langue dans `locales/`, embarqué à la compilation.
+//!
+//! Distinct de la langue du *contenu* des CV (traductions saisies par l'utilisateur) :
+//! ici, seules les langues livrées avec l'application ([`Lang::ALL`]) existent.
+//!
+//! ```
+//! use rustfolio_i18n::{Lang, Tr};
+//! let tr = Tr(Lang::negotiate("en-GB,en;q=0.9,fr;q=0.5").unwrap_or_default());
+//! assert_eq!(tr.t("nav-login"), "Log in");
+//! ```
+
+use std::sync::OnceLock;
+
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::{FluentArgs, FluentResource};
+
+/// Langue de l'interface.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Lang {
+    #[default]
+    Fr,
+    En,
+}
+
+impl Lang {
+    /// Langues livrées, langue par défaut d'abord.
+    pub const ALL: [Lang; 2] = [Lang::Fr, Lang::En];
+
+    pub fn code(self) -> &'static str {
+        match self {
+            Lang::Fr => "fr",
+            Lang::En => "en",
+        }
+    }
+
+    /// Nom de la langue dans cette langue (sélecteurs de langue).
+    pub fn native_name(self) -> &'static str {
+        match self {
+            Lang::Fr => "Français",
+            Lang::En => "English",
+        }
+    }
+
+    /// Langue d'un code (`en`, `en-GB`, `FR_ca`…) : seule la langue primaire compte.
+    pub fn parse(tag: &str) -> Option<Lang> {
+        let primary = tag.trim().split(['-', '_']).next()?;
+        Self::ALL.into_iter().find(|l| l.code().eq_ignore_ascii_case(primary))
+    }
+
+    /// Meilleure langue livrée pour un en-tête `Accept-Language` (poids `q`, puis
+    /// ordre d'apparition). `None` si aucune ne convient (`*` compris).
+    pub fn negotiate(accept_language: &str) -> Option<Lang> {
+        let mut ranges: Vec<(f32, &str)> = accept_language
+            .split(',')
+            .filter_map(|part| {
+                let mut it = part.split(';');
+                let tag = it.next()?.trim();
+                let q = it
+                    .filter_map(|p| p.trim().strip_prefix("q="))
+                    .find_map(|q| q.trim().parse::<f32>().ok())
+                    .unwrap_or(1.0);
+                (!tag.is_empty() && q > 0.0).then_some((q, tag))
+            })
+            .collect();
+        // tri stable : à poids égal, l'ordre du client
+        ranges.sort_by(|a, b| b.0.total_cmp(&a.0));
+        ranges.into_iter().find_map(|(_, tag)| Self::parse(tag))
+    }
+
+    /// Source Fluent du catalogue.
+    pub fn source(self) -> &'static str {
+        match self {
+            Lang::Fr => include_str!("../locales/fr.ftl"),
+            Lang::En => include_str!("../locales/en.ftl"),
+        }
+    }
+
+    fn bundle(self) -> &'static FluentBundle<FluentResource> {
+        static BUNDLES: OnceLock<Vec<FluentBundle<FluentResource>>> = OnceLock::new();
+        let bundles = BUNDLES.get_or_init(|| {
+            Self::ALL
+                .into_iter()
+                .map(|lang| {
+                    let id = lang.code().parse().expect("code de langue valide");
+                    let mut bundle = FluentBundle::new_concurrent(vec![id]);
+                    // pas de marques d'isolation Unicode autour des variables (HTML, attributs)
+                    bundle.set_use_isolating(false);
+                    let res = FluentResource::try_new(lang.source().to_string())
+                        .unwrap_or_else(|(res, _)| res);
+                    let _ = bundle.add_resource(res);
+                    bundle
+                })
+                .collect()
+        });
+        &bundles[Self::ALL.iter().position(|l| *l == self).unwrap_or(0)]
+    }
+}
+
+/// Traducteur d'une langue : `tr.t("id")` dans le code comme dans les gabarits.
+/// Clé absente : message de la langue par défaut, sinon la clé elle-même.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Tr(pub Lang);
+
+impl Tr {
+    pub fn lang(&self) -> Lang {
+        self.0
+    }
+
+    pub fn code(&self) -> &'static str {
+        self.0.code()
+    }
+
+    pub fn t(&self, id: &str) -> String {
+        self.t_args(id, &[])
+    }
+
+    /// Message avec variables Fluent (`{ $email }`).
+    pub fn t_args(&self, id: &str, args: &[(&str, &str)]) -> String {
+        let mut fargs = FluentArgs::new();
+        for (k, v) in args {
+            fargs.set(*k, *v);
+        }
+        [self.0, Lang::default()]
+            .into_iter()
+            .find_map(|lang| format(lang.bundle(), id, &fargs))
+            .unwrap_or_else(|| id.to_string())
+    }
+}
+
+fn format(bundle: &FluentBundle<FluentResource>, id: &str, args: &FluentArgs) -> Option<String> {
+    let pattern = bundle.get_message(id)?.value()?;
+    let mut errors = Vec::new();
+    Some(bundle.format_pattern(pattern, Some(args), &mut errors).into_owned())
+}
+
+/// Identifiants des messages d'un catalogue, dans l'ordre du fichier.
+pub fn message_ids(lang: Lang) -> Vec<String> {
+    let res = match FluentResource::try_new(lang.source().to_string()) {
+        Ok(res) => res,
+        Err((res, _)) => res,
+    };
+    res.entries()
+        .filter_map(|e| match e {
+            fluent_syntax::ast::Entry::Message(m) => Some(m.id.name.to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Erreurs de syntaxe d'un catalogue (vide si le fichier est valide).
+pub fn syntax_errors(lang: Lang) -> Vec<String> {
+    match FluentResource::try_new(lang.source().to_string()) {
+        Ok(_) => Vec::new(),
+        Err((_, errors)) => errors.iter().map(|e| format!("{e:?}")).collect(),
+    }
+}
@@ -0,0 +1,75 @@
+use std::collections::BTreeSet;
+use std::path::{Path, PathBuf};
+
+use rustfolio_i18n::{message_ids, syntax_errors, Lang, Tr};
+
+#[test]
+fn catalogs_parse_without_errors() {
+    for lang in Lang::ALL {
+        assert_eq!(syntax_errors(lang), Vec::<String>::new(), "catalogue {}", lang.code());
+    }
+}
+
+#[test]
+fn every_catalog_has_every_key() {
+    let all: BTreeSet<String> = Lang::ALL.into_iter().flat_map(message_ids).collect();
+    for lang in Lang::ALL {
+        let ids: BTreeSet<String> = message_ids(lang).into_iter().collect();
+        let missing: Vec<_> = all.difference(&ids).collect();
+        assert!(missing.is_empty(), "clés absentes de {}.ftl : {missing:?}", lang.code());
+    }
+}
+
+/// `tr.t("…")` / `tr.t_args("…", …)` dans les gabarits, le serveur et le SPA.
+fn used_keys(dir: &Path, out: &mut BTreeSet<(String, PathBuf)>) {
+    for entry in std::fs::read_dir(dir).unwrap().flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            used_keys(&path, out);
+            continue;
+        }
+        if !matches!(path.extension().and_then(|e| e.to_str()), Some("rs" | "html")) {
+            continue;
+        }
+        let src = std::fs::read_to_string(&path).unwrap();
+        for call in [".t(\"", ".t_args(\""] {
+            for (i, _) in src.match_indices(call) {
+                let rest = &src[i + call.len()..];
+                if let Some(end) = rest.find('"') {
+                    out.insert((rest[..end].to_string(), path.clone()));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn keys_used_by_the_ui_exist_in_every_catalog() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    let mut used = BTreeSet::new();
+    for dir in ["templates", "src", "dashboard-spa/src"] {
+        used_keys(&root.join(dir), &mut used);
+    }
+    assert!(used.len() > 10, "aucun appel à `t(…)` trouvé");
+    for lang in Lang::ALL {
+        let ids: BTreeSet<String> = message_ids(lang).into_iter().collect();
+        let missing: Vec<_> = used.iter().filter(|(k, _)| !ids.contains(k)).collect();
+        assert!(missing.is_empty(), "clés utilisées absentes de {}.ftl : {missing:?}", lang.code());
+    }
+}
+
+#[test]
+fn accept_language_picks_the_best_shipped_language() {
+    assert_eq!(Lang::negotiate("en-GB,en;q=0.9,fr;q=0.8"), Some(Lang::En));
+    assert_eq!(Lang::negotiate("de-DE, fr;q=0.5, en;q=0.7"), Some(Lang::En));
+    assert_eq!(Lang::negotiate("de, en;q=0"), None);
+    assert_eq!(Lang::negotiate("*"), None);
+    assert_eq!(Lang::negotiate(""), None);
+    assert_eq!(Lang::parse("FR_ca"), Some(Lang::Fr));
+
+    let en = Tr(Lang::En);
+    assert_eq!(en.t("auth-invalid-credentials"), "Invalid credentials");
+    assert_eq!(en.t_args("auth-locale-unsupported", &[("locale", "de")]), "Unsupported language: de");
+    // clé inconnue : la clé elle-même
+    assert_eq!(en.t("no-such-key"), "no-such-key");
+}
@@ -0,0 +1,301 @@
+//! Opérations d'édition du builder.
+//!
+//! Lignes et colonnes sont désignées par leur id, les widgets par leur position
+//! ([`WidgetRef`]). Une opération impossible (id inconnu, ligne pleine, dernière colonne
+//! d'une ligne…) ne change rien et renvoie `false` : le layout reste valide.
+
+use crate::{Column, Layout, Row, Widget, MAX_COLUMNS, MAX_ROWS, MAX_WIDGETS, MAX_WIDTH};
+
+/// Position d'un widget : colonne (id) et rang dans la colonne.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WidgetRef {
+    pub column: usize,
+    pub index: usize,
+}
+
+/// Modification du contenu (la sélection n'en fait pas partie).
+#[derive(Debug, Clone, PartialEq)]
+pub enum Edit {
+    AddRow,
+    DeleteRow(usize),
+    DuplicateRow(usize),
+    /// Décale la ligne de `by` rangs (négatif : vers le haut).
+    MoveRow { row: usize, by: isize },
+    /// Redécoupe la ligne sélectionnée en n colonnes de même largeur.
+    SplitSelectedRow(usize),
+    DeleteColumn(usize),
+    DuplicateColumn(usize),
+    /// Décale la colonne de `by` rangs dans sa ligne (négatif : vers la gauche).
+    MoveColumn { column: usize, by: isize },
+    SetColumnWidth { column: usize, width: u8 },
+    /// Ajoute à la fin de la colonne sélectionnée.
+    AddWidget(Widget),
+    DeleteWidget(WidgetRef),
+    DuplicateWidget(WidgetRef),
+    /// Décale le widget de `by` rangs dans sa colonne.
+    ShiftWidget { at: WidgetRef, by: isize },
+    /// Glisser-déposer : `to.index` est la position d'insertion avant retrait
+    /// (`to.index == len` : à la fin).
+    MoveWidget { from: WidgetRef, to: WidgetRef },
+    UpdateWidget { at: WidgetRef, widget: Widget },
+}
+
+impl Layout {
+    /// Applique `edit` ; `false` si rien n'a changé.
+    pub fn apply(&mut self, edit: Edit) -> bool {
+        match edit {
+            Edit::AddRow => self.add_row(),
+            Edit::DeleteRow(id) => self.delete_row(id),
+            Edit::DuplicateRow(id) => self.duplicate_row(id),
+            Edit::MoveRow { row, by } => self.move_row(row, by),
+            Edit::SplitSelectedRow(n) => self.split_selected_row(n),
+            Edit::DeleteColumn(id) => self.delete_column(id),
+            Edit::DuplicateColumn(id) => self.duplicate_column(id),
+            Edit::MoveColumn { column, by } => self.move_column(column, by),
+            Edit::SetColumnWidth { column, width } => self.set_column_width(column, width),
+            Edit::AddWidget(w) => self.add_widget_to_selected_column(w),
+            Edit::DeleteWidget(at) => self.delete_widget(at),
+            Edit::DuplicateWidget(at) => self.duplicate_widget(at),
+            Edit::ShiftWidget { at, by } => self.shift_widget(at, by),
+            Edit::MoveWidget { from, to } => self.move_widget(from, to).is_some(),
+            Edit::UpdateWidget { at, widget } => self.update_widget(at, widget),
+        }
+    }
+
+    /// Même contenu (lignes, colonnes, widgets) ; la sélection n'est pas une modification.
+    pub fn same_content(&self, other: &Self) -> bool {
+        self.rows == other.rows && self.next_row_id == other.next_row_id && self.next_col_id == other.next_col_id
+    }
+
+    pub fn select_row(&mut self, row_id: usize) {
+        self.selected_row = Some(row_id);
+        self.selected_column = None; // reset la colonne quand on change de ligne
+    }
+
+    pub fn select_column(&mut self, col_id: usize) {
+        self.selected_column = Some(col_id);
+    }
+
+    pub fn widget(&self, at: WidgetRef) -> Option<&Widget> {
+        self.column(at.column)?.widgets.get(at.index)
+    }
+
+    fn column(&self, id: usize) -> Option<&Column> {
+        self.rows.iter().flat_map(|r| &r.columns).find(|c| c.id == id)
+    }
+
+    fn column_mut(&mut self, id: usize) -> Option<&mut Column> {
+        self.rows.iter_mut().flat_map(|r| &mut r.columns).find(|c| c.id == id)
+    }
+
+    /// Ligne contenant la colonne `col_id`.
+    fn row_of_column_mut(&mut self, col_id: usize) -> Option<&mut Row> {
+        self.rows.iter_mut().find(|r| r.columns.iter().any(|c| c.id == col_id))
+    }
+
+    fn new_column(&mut self) -> Column {
+        self.next_col_id += 1;
+        Column::new(self.next_col_id)
+    }
+
+    /// Copie avec de nouveaux ids.
+    fn fresh_column(&mut self, from: &Column) -> Column {
+        Column { widgets: from.widgets.clone(), width: from.width, ..self.new_column() }
+    }
+
+    // ------------------------------------------------------------------ lignes
+
+    pub fn add_row(&mut self) -> bool {
+        if self.rows.len() >= MAX_ROWS { return false; }
+        self.next_row_id += 1;
+        let column = self.new_column();
+        self.rows.push(Row { id: self.next_row_id, columns: vec![column] });
+        // UX: on sélectionne la ligne créée
+        self.selected_row = Some(self.next_row_id);
+        self.selected_column = None;
+        true
+    }
+
+    pub fn delete_row(&mut self, id: usize) -> bool {
+        let Some(i) = self.rows.iter().position(|r| r.id == id) else { return false };
+        let row = self.rows.remove(i);
+        if self.selected_row == Some(id) {
+            self.selected_row = None;
+        }
+        if self.selected_column.is_some_and(|c| row.columns.iter().any(|col| col.id == c)) {
+            self.selected_column = None;
+        }
+        true
+    }
+
+    /// Copie insérée juste après l'originale, puis sélectionnée.
+    pub fn duplicate_row(&mut self, id: usize) -> bool {
+        if self.rows.len() >= MAX_ROWS { return false; }
+        let Some(i) = self.rows.iter().position(|r| r.id == id) else { return false };
+        let source = self.rows[i].columns.clone();
+        let columns = source.iter().map(|c| self.fresh_column(c)).collect();
+        self.next_row_id += 1;
+        self.rows.insert(i + 1, Row { id: self.next_row_id, columns });
+        self.select_row(self.next_row_id);
+        true
+    }
+
+    pub fn move_row(&mut self, id: usize, by: isize) -> bool {
+        let Some(i) = self.rows.iter().position(|r| r.id == id) else { return false };
+        shift(&mut self.rows, i, by)
+    }
+
+    /// Redécoupe en `n` colonnes (nouveaux ids, largeurs égales) ; les widgets sont
+    /// répartis à tour de rôle entre les nouvelles colonnes.
+    pub fn split_selected_row(&mut self, n: usize) -> bool {
+        if n == 0 || n > MAX_COLUMNS { return false; }
+        let Some(sel) = self.selected_row else { return false };
+        let Some(i) = self.rows.iter().position(|r| r.id == sel) else { return false };
+
+        // récupérer tous les widgets pour les redistribuer ensuite
+        let mut old_widgets = Vec::new();
+        for c in &mut self.rows[i].columns { old_widgets.append(&mut c.widgets); }
+
+        // recréer n colonnes vides avec de nouveaux IDs
+        let mut columns: Vec<Column> = (0..n).map(|_| self.new_column()).collect();
+
+        // redistribution round-robin
+        for (k, w) in old_widgets.into_iter().enumerate() {
+            columns[k % n].widgets.push(w);
+        }
+        self.rows[i].columns = columns;
+
+        // les anciennes colonnes n'existent plus → désélection
+        if self.selected_column.is_some_and(|cid| self.column(cid).is_none()) {
+            self.selected_column = None;
+        }
+        true
+    }
+
+    // ---------------------------------------------------------------- colonnes
+
+    /// Refusé pour la dernière colonne d'une ligne (supprimer la ligne).
+    pub fn delete_column(&mut self, id: usize) -> bool {
+        let Some(row) = self.row_of_column_mut(id) else { return false };
+        if row.columns.len() <= 1 { return false; }
+        row.columns.retain(|c| c.id != id);
+        if self.selected_column == Some(id) {
+            self.selected_column = None;
+        }
+        true
+    }
+
+    /// Copie insérée à droite de l'originale, puis sélectionnée.
+    pub fn duplicate_column(&mut self, id: usize) -> bool {
+        let Some(row) = self.rows.iter().position(|r| r.columns.iter().any(|c| c.id == id)) else { return false };
+        if self.rows[row].columns.len() >= MAX_COLUMNS { return false; }
+        let i = self.rows[row].columns.iter().position(|c| c.id == id).expect("colonne trouvée ci-dessus");
+        let source = self.rows[row].columns[i].clone();
+        let copy = self.fresh_column(&source);
+        let new_id = copy.id;
+        self.rows[row].columns.insert(i + 1, copy);
+        self.selected_row = Some(self.rows[row].id);
+        self.selected_column = Some(new_id);
+        true
+    }
+
+    pub fn move_column(&mut self, id: usize, by: isize) -> bool {
+        let Some(row) = self.row_of_column_mut(id) else { return false };
+        let i = row.columns.iter().position(|c| c.id == id).expect("colonne de cette ligne");
+        shift(&mut row.columns, i, by)
+    }
+
+    /// Largeur bornée à 1..=[`MAX_WIDTH`].
+    pub fn set_column_width(&mut self, id: usize, width: u8) -> bool {
+        let width = width.clamp(1, MAX_WIDTH);
+        match self.column_mut(id) {
+            Some(c) if c.width != width => {
+                c.width = width;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    // ----------------------------------------------------------------- widgets
+
+    pub fn add_widget_to_selected_column(&mut self, w: Widget) -> bool {
+        let Some(col_id) = self.selected_column else { return false };
+        match self.column_mut(col_id) {
+            Some(col) if col.widgets.len() < MAX_WIDGETS => {
+                col.widgets.push(w);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn delete_widget(&mut self, at: WidgetRef) -> bool {
+        match self.column_mut(at.column) {
+            Some(col) if at.index < col.widgets.len() => {
+                col.widgets.remove(at.index);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Copie insérée juste après l'original.
+    pub fn duplicate_widget(&mut self, at: WidgetRef) -> bool {
+        match self.column_mut(at.column) {
+            Some(col) if at.index < col.widgets.len() && col.widgets.len() < MAX_WIDGETS => {
+                let copy = col.widgets[at.index].clone();
+                col.widgets.insert(at.index + 1, copy);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    pub fn shift_widget(&mut self, at: WidgetRef, by: isize) -> bool {
+        match self.column_mut(at.column) {
+            Some(col) if at.index < col.widgets.len() => shift(&mut col.widgets, at.index, by),
+            _ => false,
+        }
+    }
+
+    /// Déplace un widget, éventuellement vers une autre colonne ; renvoie sa nouvelle
+    /// position. Refusé si la colonne cible est pleine.
+    pub fn move_widget(&mut self, from: WidgetRef, to: WidgetRef) -> Option<WidgetRef> {
+        self.widget(from)?;
+        let target_len = self.column(to.column)?.widgets.len();
+        if from.column != to.column && target_len >= MAX_WIDGETS {
+            return None;
+        }
+        let mut index = to.index.min(target_len);
+        if from.column == to.column && index > from.index {
+            index -= 1; // le retrait décale les suivants
+        }
+        if from.column == to.column && index == from.index {
+            return None;
+        }
+
+        let w = self.column_mut(from.column)?.widgets.remove(from.index);
+        self.column_mut(to.column)?.widgets.insert(index, w);
+        Some(WidgetRef { column: to.column, index })
+    }
+
+    pub fn update_widget(&mut self, at: WidgetRef, widget: Widget) -> bool {
+        match self.column_mut(at.column).and_then(|c| c.widgets.get_mut(at.index)) {
+            Some(w) if *w != widget => {
+                *w = widget;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Déplace `v[i]` de `by` rangs, borné aux extrémités.
+fn shift<T>(v: &mut Vec<T>, i: usize, by: isize) -> bool {
+    let j = i.saturating_add_signed(by).min(v.len() - 1);
+    if j == i { return false; }
+    let item = v.remove(i);
+    v.insert(j, item);
+    true
+}
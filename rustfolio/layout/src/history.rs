@@ -0,0 +1,88 @@
+//! Historique annuler/rétablir du builder.
+//!
+//! Chaque [`Edit`] appliqué via [`History::apply`] mémorise l'état précédent (le layout
+//! entier : quelques Ko, plus simple et plus sûr que des opérations inverses). Les
+//! modifications successives d'un même widget à moins de [`COALESCE_MS`] d'intervalle
+//! (saisie dans le panneau de propriétés) ne forment qu'une entrée.
+
+use std::collections::VecDeque;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{Edit, Layout, WidgetRef};
+
+/// États mémorisés au plus ; les plus anciens sont oubliés.
+pub const HISTORY_LIMIT: usize = 50;
+pub const COALESCE_MS: u64 = 1_000;
+
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct History {
+    past: VecDeque<Layout>,
+    future: Vec<Layout>,
+    /// Dernière modification de widget (position, horodatage) pour le regroupement.
+    #[serde(skip)]
+    last_update: Option<(WidgetRef, u64)>,
+}
+
+impl History {
+    /// Applique `edit` à `layout` ; `now_ms` : horloge quelconque en millisecondes.
+    pub fn apply(&mut self, layout: &mut Layout, edit: Edit, now_ms: u64) -> bool {
+        let before = layout.clone();
+        let update = match &edit {
+            Edit::UpdateWidget { at, .. } => Some(*at),
+            _ => None,
+        };
+        if !layout.apply(edit) {
+            return false;
+        }
+
+        let coalesce = match (update, self.last_update) {
+            (Some(at), Some((last, t))) => at == last && now_ms.saturating_sub(t) < COALESCE_MS,
+            _ => false,
+        };
+        if !coalesce {
+            self.past.push_back(before);
+            if self.past.len() > HISTORY_LIMIT {
+                self.past.pop_front();
+            }
+        }
+        self.last_update = update.map(|at| (at, now_ms));
+        self.future.clear();
+        true
+    }
+
+    /// Revient à l'état précédent (sélection comprise).
+    pub fn undo(&mut self, layout: &mut Layout) -> bool {
+        let Some(previous) = self.past.pop_back() else { return false };
+        self.future.push(std::mem::replace(layout, previous));
+        self.last_update = None;
+        true
+    }
+
+    pub fn redo(&mut self, layout: &mut Layout) -> bool {
+        let Some(next) = self.future.pop() else { return false };
+        self.past.push_back(std::mem::replace(layout, next));
+        self.last_update = None;
+        true
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.past.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.future.is_empty()
+    }
+
+    /// Historique relu (stockage local) : les entrées au-delà de la limite sont oubliées.
+    pub fn bounded(mut self) -> Self {
+        while self.past.len() > HISTORY_LIMIT {
+            self.past.pop_front();
+        }
+        // pile : le prochain état à rétablir est à la fin
+        let excess = self.future.len().saturating_sub(HISTORY_LIMIT);
+        self.future.drain(..excess);
+        self
+    }
+}
@@ -0,0 +1,386 @@
+//! Mise en page du CV composée dans le builder, partagée par le serveur (validation,
+//! stockage) et le SPA (édition, aperçu).
+//!
+//! Forme JSON (version [`CURRENT_VERSION`]) : widgets étiquetés par `type`,
+//!
+//! ```json
+//! { "version": 2, "rows": [{ "id": 1, "columns": [{ "id": 1, "widgets": [
+//!     { "type": "Title", "text": "Alice", "level": 1 },
+//!     { "type": "SkillsGrid", "category": "Backend" }
+//! ] }] }], "selected_row": 1, "selected_column": null, "next_row_id": 1, "next_col_id": 1 }
+//! ```
+//!
+//! Les anciens formats se lisent avec [`migrate`]. Le thème du CV ([`Theme`]) est
+//! stocké à part, avec les mêmes garanties de validation.
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+mod edit;
+mod history;
+mod migrate;
+mod theme;
+
+pub use edit::{Edit, WidgetRef};
+pub use history::{History, COALESCE_MS, HISTORY_LIMIT};
+pub use migrate::{migrate, MigrateError};
+pub use theme::{Colors, Font, Fonts, SectionHeader, SkillDisplay, Theme, MAX_RADIUS, MAX_SPACING};
+
+/// Version écrite par ce code. Historique dans [`migrate`].
+pub const CURRENT_VERSION: u32 = 2;
+
+pub const MAX_ROWS: usize = 50;
+/// Le builder propose de 1 à 6 colonnes.
+pub const MAX_COLUMNS: usize = 6;
+pub const MAX_WIDGETS: usize = 20;
+/// Caractères d'un widget texte ou titre.
+pub const MAX_TEXT: usize = 5_000;
+pub const MAX_URL: usize = 2_048;
+/// Titres de section et filtres (catégorie, type d'expérience).
+pub const MAX_LABEL: usize = 200;
+pub const MAX_WIDTH: u8 = 12;
+/// Option `limit` des widgets de liste.
+pub const MAX_ITEMS: usize = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Align {
+    Left,
+    Center,
+    Right,
+}
+
+/// Contenu d'une colonne. Les widgets de données (profil, expériences…) lisent le CV de
+/// l'utilisateur au rendu ; options absentes (`None`) : pas de filtre, pas de limite,
+/// titre par défaut, tous les champs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", deny_unknown_fields)]
+pub enum Widget {
+    Text {
+        text: String,
+    },
+    Title {
+        text: String,
+        /// 1 (`h1`) à 6.
+        #[serde(default = "default_level")]
+        level: u8,
+        #[serde(default)]
+        bold: bool,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        align: Option<Align>,
+    },
+    ProfileBasic {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        fields: Option<Vec<String>>,
+    },
+    ExperienceTimeline {
+        /// `kind` des expériences gardées (`work`, `education`…).
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        filter_type: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        heading: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        limit: Option<usize>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        fields: Option<Vec<String>>,
+    },
+    SkillsGrid {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        category: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        heading: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        limit: Option<usize>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        fields: Option<Vec<String>>,
+    },
+    ProjectsList {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        category: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        heading: Option<String>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        limit: Option<usize>,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        fields: Option<Vec<String>>,
+    },
+    /// Un seul projet, par position dans la liste.
+    ProjectCard {
+        index: usize,
+    },
+    Photo {
+        url: String,
+        #[serde(default = "default_true")]
+        rounded: bool,
+    },
+}
+
+fn default_level() -> u8 {
+    1
+}
+
+fn default_true() -> bool {
+    true
+}
+
+impl Widget {
+    /// Un widget de chaque sorte, options par défaut (palette du builder).
+    pub fn palette() -> Vec<Widget> {
+        vec![
+            Widget::Text { text: "Texte libre…".into() },
+            Widget::Title { text: "Titre".into(), level: 2, bold: true, align: None },
+            Widget::ProfileBasic { fields: None },
+            Widget::ExperienceTimeline { filter_type: None, heading: None, limit: None, fields: None },
+            Widget::SkillsGrid { category: None, heading: None, limit: None, fields: None },
+            Widget::ProjectsList { category: None, heading: None, limit: None, fields: None },
+            Widget::ProjectCard { index: 0 },
+            Widget::Photo { url: String::new(), rounded: true },
+        ]
+    }
+
+    /// Champs que l'option `fields` peut lister ; vide si le widget n'a pas cette option.
+    pub fn field_names(&self) -> &'static [&'static str] {
+        match self {
+            Widget::ProfileBasic { .. } => &["name", "title", "location", "email", "phone", "website", "summary"],
+            Widget::ExperienceTimeline { .. } => &["date", "company", "location", "tasks"],
+            Widget::SkillsGrid { .. } => &["percentage", "logo"],
+            Widget::ProjectsList { .. } => &["description", "technologies", "links", "image"],
+            _ => &[],
+        }
+    }
+
+    /// `field` est-il affiché ? (sans option `fields` : oui)
+    pub fn shows(&self, field: &str) -> bool {
+        match self.fields() {
+            Some(fields) => fields.iter().any(|f| f == field),
+            None => true,
+        }
+    }
+
+    fn fields(&self) -> Option<&Vec<String>> {
+        match self {
+            Widget::ProfileBasic { fields }
+            | Widget::ExperienceTimeline { fields, .. }
+            | Widget::SkillsGrid { fields, .. }
+            | Widget::ProjectsList { fields, .. } => fields.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Option `fields`, si le widget l'a (panneau de propriétés).
+    pub fn fields_mut(&mut self) -> Option<&mut Option<Vec<String>>> {
+        match self {
+            Widget::ProfileBasic { fields }
+            | Widget::ExperienceTimeline { fields, .. }
+            | Widget::SkillsGrid { fields, .. }
+            | Widget::ProjectsList { fields, .. } => Some(fields),
+            _ => None,
+        }
+    }
+
+    /// Filtre des widgets de liste : `filter_type` des expériences, `category` sinon.
+    pub fn filter_mut(&mut self) -> Option<&mut Option<String>> {
+        match self {
+            Widget::ExperienceTimeline { filter_type: f, .. }
+            | Widget::SkillsGrid { category: f, .. }
+            | Widget::ProjectsList { category: f, .. } => Some(f),
+            _ => None,
+        }
+    }
+
+    pub fn heading_mut(&mut self) -> Option<&mut Option<String>> {
+        match self {
+            Widget::ExperienceTimeline { heading, .. }
+            | Widget::SkillsGrid { heading, .. }
+            | Widget::ProjectsList { heading, .. } => Some(heading),
+            _ => None,
+        }
+    }
+
+    pub fn limit_mut(&mut self) -> Option<&mut Option<usize>> {
+        match self {
+            Widget::ExperienceTimeline { limit, .. }
+            | Widget::SkillsGrid { limit, .. }
+            | Widget::ProjectsList { limit, .. } => Some(limit),
+            _ => None,
+        }
+    }
+
+    /// Titre de section affiché au-dessus d'une liste.
+    pub fn heading(&self) -> Option<&str> {
+        match self {
+            Widget::ExperienceTimeline { heading, .. }
+            | Widget::SkillsGrid { heading, .. }
+            | Widget::ProjectsList { heading, .. } => heading.as_deref(),
+            _ => None,
+        }
+    }
+
+    /// Nombre maximal d'éléments d'une liste (`usize::MAX` sans limite).
+    pub fn limit(&self) -> usize {
+        match self {
+            Widget::ExperienceTimeline { limit, .. }
+            | Widget::SkillsGrid { limit, .. }
+            | Widget::ProjectsList { limit, .. } => limit.unwrap_or(usize::MAX),
+            _ => usize::MAX,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Column {
+    pub id: usize,
+    /// Part de la largeur de la ligne (unités `fr` d'une grille CSS), 1 à [`MAX_WIDTH`].
+    #[serde(default = "default_width")]
+    pub width: u8,
+    #[serde(default)]
+    pub widgets: Vec<Widget>,
+}
+
+fn default_width() -> u8 {
+    1
+}
+
+impl Column {
+    pub fn new(id: usize) -> Self {
+        Self { id, width: default_width(), widgets: Vec::new() }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Row {
+    pub id: usize,
+    pub columns: Vec<Column>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, default)]
+pub struct Layout {
+    pub version: u32,
+    pub rows: Vec<Row>,
+    pub selected_row: Option<usize>,
+    pub selected_column: Option<usize>,
+    /// Derniers ids attribués : les suivants doivent rester libres.
+    pub next_row_id: usize,
+    pub next_col_id: usize,
+}
+
+impl Default for Layout {
+    fn default() -> Self {
+        Self {
+            version: CURRENT_VERSION,
+            rows: Vec::new(),
+            selected_row: None,
+            selected_column: None,
+            next_row_id: 0,
+            next_col_id: 0,
+        }
+    }
+}
+
+impl Layout {
+    /// Toutes les erreurs, préfixées par leur chemin (`rows[0].columns[1]`).
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+        if self.version != CURRENT_VERSION {
+            errors.push(format!("version : {} (attendu {CURRENT_VERSION})", self.version));
+        }
+        if self.rows.len() > MAX_ROWS {
+            errors.push(format!("rows : {} lignes (max {MAX_ROWS})", self.rows.len()));
+        }
+
+        let (mut row_ids, mut col_ids) = (HashSet::new(), HashSet::new());
+        for (i, row) in self.rows.iter().enumerate() {
+            let at = format!("rows[{i}]");
+            if !row_ids.insert(row.id) {
+                errors.push(format!("{at}.id : {} en double", row.id));
+            }
+            if row.id > self.next_row_id {
+                errors.push(format!("{at}.id : {} > next_row_id ({})", row.id, self.next_row_id));
+            }
+            if row.columns.is_empty() || row.columns.len() > MAX_COLUMNS {
+                errors.push(format!("{at}.columns : {} colonnes (1 à {MAX_COLUMNS})", row.columns.len()));
+            }
+
+            for (j, col) in row.columns.iter().enumerate() {
+                let at = format!("{at}.columns[{j}]");
+                if !col_ids.insert(col.id) {
+                    errors.push(format!("{at}.id : {} en double", col.id));
+                }
+                if col.id > self.next_col_id {
+                    errors.push(format!("{at}.id : {} > next_col_id ({})", col.id, self.next_col_id));
+                }
+                if !(1..=MAX_WIDTH).contains(&col.width) {
+                    errors.push(format!("{at}.width : {} (1 à {MAX_WIDTH})", col.width));
+                }
+                if col.widgets.len() > MAX_WIDGETS {
+                    errors.push(format!("{at}.widgets : {} widgets (max {MAX_WIDGETS})", col.widgets.len()));
+                }
+                for (k, w) in col.widgets.iter().enumerate() {
+                    validate_widget(w, &format!("{at}.widgets[{k}]"), &mut errors);
+                }
+            }
+        }
+
+        if let Some(id) = self.selected_row {
+            if !row_ids.contains(&id) {
+                errors.push(format!("selected_row : ligne {id} inexistante"));
+            }
+        }
+        if let Some(id) = self.selected_column {
+            if !col_ids.contains(&id) {
+                errors.push(format!("selected_column : colonne {id} inexistante"));
+            }
+        }
+
+        if errors.is_empty() { Ok(()) } else { Err(errors) }
+    }
+}
+
+fn validate_widget(w: &Widget, at: &str, errors: &mut Vec<String>) {
+    match w {
+        Widget::Text { text } | Widget::Title { text, .. } if text.chars().count() > MAX_TEXT => {
+            errors.push(format!("{at} : texte trop long (max {MAX_TEXT} caractères)"));
+        }
+        Widget::Photo { url, .. } if url.len() > MAX_URL => {
+            errors.push(format!("{at}.url : trop longue (max {MAX_URL})"));
+        }
+        _ => {}
+    }
+    if let Widget::Title { level, .. } = w {
+        if !(1..=6).contains(level) {
+            errors.push(format!("{at}.level : {level} (1 à 6)"));
+        }
+    }
+
+    let labels = match w {
+        Widget::ExperienceTimeline { filter_type, heading, .. } => [("filter_type", filter_type), ("heading", heading)],
+        Widget::SkillsGrid { category, heading, .. } | Widget::ProjectsList { category, heading, .. } => {
+            [("category", category), ("heading", heading)]
+        }
+        _ => [("", &None), ("", &None)],
+    };
+    for (name, value) in labels {
+        if value.as_ref().is_some_and(|v| v.chars().count() > MAX_LABEL) {
+            errors.push(format!("{at}.{name} : trop long (max {MAX_LABEL} caractères)"));
+        }
+    }
+
+    if let Widget::ExperienceTimeline { limit: Some(n), .. }
+    | Widget::SkillsGrid { limit: Some(n), .. }
+    | Widget::ProjectsList { limit: Some(n), .. } = w
+    {
+        if !(1..=MAX_ITEMS).contains(n) {
+            errors.push(format!("{at}.limit : {n} (1 à {MAX_ITEMS})"));
+        }
+    }
+    if let Some(fields) = w.fields() {
+        let allowed = w.field_names();
+        for f in fields.iter().filter(|f| !allowed.contains(&f.as_str())) {
+            errors.push(format!("{at}.fields : « {f} » inconnu ({})", allowed.join(", ")));
+        }
+    }
+}
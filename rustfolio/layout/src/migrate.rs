@@ -0,0 +1,143 @@
+//! Lecture des layouts écrits par d'anciennes versions.
+//!
+//! - **v0** : `store::Layout` du premier SPA, sans ids ni compteurs ; widgets étiquetés
+//!   par `type` (`Title`, `ExperienceList`, `SkillsGrid`, `ProjectCard`, `Photo`).
+//! - **v1** : `store_builder::BuilderLayout`, sans champ `version` ; widgets en
+//!   représentation serde externe (`"SkillsGrid"`, `{"Text": "…"}`).
+//! - **v2** : [`Layout`] (champ `version`).
+//!
+//! Sans champ `version`, un layout qui porte des ids (`rows[].id`) ou des compteurs
+//! (`next_row_id`) est un v1, sinon un v0. Chaque étape réécrit le JSON vers la
+//! suivante ; seule la dernière passe par serde.
+
+use std::fmt;
+
+use serde_json::{json, Map, Value};
+
+use crate::{Layout, CURRENT_VERSION};
+
+#[derive(Debug)]
+pub enum MigrateError {
+    /// Plus récente que ce code (ou négative, ou pas un entier).
+    UnknownVersion(Value),
+    NotAnObject,
+    Invalid(serde_json::Error),
+}
+
+impl fmt::Display for MigrateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownVersion(v) => write!(f, "version de layout inconnue : {v}"),
+            Self::NotAnObject => f.write_str("le layout doit être un objet JSON"),
+            Self::Invalid(e) => write!(f, "layout illisible : {e}"),
+        }
+    }
+}
+
+impl std::error::Error for MigrateError {}
+
+/// Layout courant à partir du JSON de n'importe quelle version. Ne valide pas :
+/// appeler [`Layout::validate`] ensuite.
+pub fn migrate(value: Value) -> Result<Layout, MigrateError> {
+    let Value::Object(obj) = value else { return Err(MigrateError::NotAnObject) };
+    let version = match obj.get("version") {
+        None => detect(&obj),
+        Some(v) => v
+            .as_u64()
+            .filter(|n| *n <= u64::from(CURRENT_VERSION))
+            .ok_or_else(|| MigrateError::UnknownVersion(v.clone()))?,
+    };
+
+    let mut obj = obj;
+    if version == 0 {
+        obj = v0_to_v1(obj);
+    }
+    if version <= 1 {
+        obj = v1_to_v2(obj);
+    }
+    serde_json::from_value(Value::Object(obj)).map_err(MigrateError::Invalid)
+}
+
+fn detect(obj: &Map<String, Value>) -> u64 {
+    let has_ids = obj.get("rows").and_then(Value::as_array).is_some_and(|rows| rows.iter().any(|r| r.get("id").is_some()));
+    if has_ids || obj.contains_key("next_row_id") { 1 } else { 0 }
+}
+
+fn array(v: Option<&Value>) -> Vec<Value> {
+    v.and_then(Value::as_array).cloned().unwrap_or_default()
+}
+
+/// Numérote lignes et colonnes dans l'ordre ; widgets renommés mais toujours étiquetés.
+fn v0_to_v1(obj: Map<String, Value>) -> Map<String, Value> {
+    let (mut row_id, mut col_id) = (0, 0);
+    let rows: Vec<Value> = array(obj.get("rows"))
+        .into_iter()
+        .map(|row| {
+            row_id += 1;
+            let columns: Vec<Value> = array(row.get("columns"))
+                .into_iter()
+                .map(|col| {
+                    col_id += 1;
+                    let widgets: Vec<Value> = array(col.get("widgets")).into_iter().filter_map(v0_widget).collect();
+                    json!({ "id": col_id, "widgets": widgets })
+                })
+                .collect();
+            json!({ "id": row_id, "columns": columns })
+        })
+        .collect();
+
+    let mut out = Map::new();
+    out.insert("rows".into(), rows.into());
+    out.insert("next_row_id".into(), row_id.into());
+    out.insert("next_col_id".into(), col_id.into());
+    out
+}
+
+/// Reste étiqueté par `type` (v1 n'a pas d'équivalent) ; `v1_to_v2` le laisse passer.
+/// Les types inconnus étaient déjà ignorés par l'ancien `layout_from_json`.
+fn v0_widget(w: Value) -> Option<Value> {
+    let mut w = w.as_object()?.clone();
+    let kind = w.get("type")?.as_str()?.to_string();
+    match kind.as_str() {
+        "ExperienceList" => {
+            w.insert("type".into(), "ExperienceTimeline".into());
+        }
+        "Title" => {
+            // alignement libre en v0 : valeur inconnue → pas d'alignement
+            let align = w.get("align").and_then(Value::as_str).map(str::to_lowercase);
+            let align = align.filter(|a| matches!(a.as_str(), "left" | "center" | "right"));
+            w.insert("align".into(), align.into());
+        }
+        "SkillsGrid" | "ProjectCard" | "Photo" => {}
+        _ => return None,
+    }
+    Some(Value::Object(w))
+}
+
+/// Ajoute `version` et passe les widgets en représentation étiquetée.
+fn v1_to_v2(mut obj: Map<String, Value>) -> Map<String, Value> {
+    if let Some(Value::Array(rows)) = obj.get_mut("rows") {
+        for row in rows {
+            let Some(Value::Array(columns)) = row.get_mut("columns") else { continue };
+            for col in columns {
+                let Some(Value::Array(widgets)) = col.get_mut("widgets") else { continue };
+                for w in widgets.iter_mut() {
+                    *w = v1_widget(w.take());
+                }
+            }
+        }
+    }
+    obj.insert("version".into(), CURRENT_VERSION.into());
+    obj
+}
+
+/// `"SkillsGrid"` → `{"type":"SkillsGrid"}`, `{"Text":"…"}` → `{"type":"Text","text":"…"}`.
+/// Un widget déjà étiqueté (issu de v0) est rendu tel quel ; le reste échouera à la
+/// désérialisation avec un message serde.
+fn v1_widget(w: Value) -> Value {
+    match w {
+        Value::String(kind) => json!({ "type": kind }),
+        Value::Object(o) if o.len() == 1 && o.contains_key("Text") => json!({ "type": "Text", "text": o["Text"] }),
+        other => other,
+    }
+}
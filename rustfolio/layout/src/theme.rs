@@ -0,0 +1,266 @@
+//! Thèmes des CV : couleurs, polices, espacements et rendu des sections, stockés avec
+//! chaque CV.
+//!
+//! Les mêmes jetons servent partout : [`Theme::css_vars`] donne les variables CSS
+//! (`--bg`, `--accent`… comme `assets/css/style.css`) posées sur l'aperçu du builder,
+//! l'export HTML et les pages publiques ; [`Theme::heading_style`] le style des titres de
+//! section. Seules des valeurs contrôlées (couleurs `#rrggbb`, polices d'une liste
+//! fermée, nombres bornés) entrent dans le CSS produit.
+
+use serde::{Deserialize, Serialize};
+
+use crate::MAX_LABEL;
+
+/// Espacement de base et arrondi, en px.
+pub const MAX_SPACING: u8 = 32;
+pub const MAX_RADIUS: u8 = 32;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields, default)]
+pub struct Theme {
+    /// Thème intégré de départ (les valeurs sont copiées : le modifier ne change pas
+    /// les CV existants).
+    pub base: String,
+    pub colors: Colors,
+    pub fonts: Fonts,
+    /// Espacement de base ; les sections sont séparées de deux fois cette valeur.
+    pub spacing: u8,
+    pub radius: u8,
+    pub section_header: SectionHeader,
+    pub skill_display: SkillDisplay,
+}
+
+/// Couleurs `#rrggbb`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Colors {
+    pub background: String,
+    /// Fond des cartes (projets, compétences en étiquettes).
+    pub surface: String,
+    pub text: String,
+    pub muted: String,
+    pub border: String,
+    pub accent: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Fonts {
+    pub body: Font,
+    pub heading: Font,
+}
+
+/// Familles disponibles (piles de polices système : rien à télécharger, même rendu à
+/// l'écran et à l'impression).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Font {
+    Sans,
+    Serif,
+    Mono,
+    Rounded,
+}
+
+/// Titre des sections (expériences, compétences, projets).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SectionHeader {
+    Plain,
+    Underline,
+    /// Barre d'accent à gauche.
+    Bar,
+    /// Petites capitales espacées.
+    Caps,
+}
+
+/// Présentation des compétences.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SkillDisplay {
+    Bars,
+    Tags,
+    /// Niveau sur cinq points.
+    Dots,
+}
+
+impl Font {
+    pub const ALL: [Font; 4] = [Font::Sans, Font::Serif, Font::Mono, Font::Rounded];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Font::Sans => "Sans empattement",
+            Font::Serif => "Avec empattement",
+            Font::Mono => "Chasse fixe",
+            Font::Rounded => "Arrondie",
+        }
+    }
+
+    pub fn css(self) -> &'static str {
+        match self {
+            Font::Sans => r#"ui-sans-serif, system-ui, -apple-system, "Segoe UI", Roboto, "Helvetica Neue", Arial, sans-serif"#,
+            Font::Serif => r#"Charter, "Bitstream Charter", "Sitka Text", Cambria, Georgia, serif"#,
+            Font::Mono => r#"ui-monospace, "Cascadia Code", "Source Code Pro", Menlo, Consolas, monospace"#,
+            Font::Rounded => r#"ui-rounded, "SF Pro Rounded", "Nunito", "Varela Round", system-ui, sans-serif"#,
+        }
+    }
+}
+
+impl SectionHeader {
+    pub const ALL: [SectionHeader; 4] = [SectionHeader::Plain, SectionHeader::Underline, SectionHeader::Bar, SectionHeader::Caps];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            SectionHeader::Plain => "Simple",
+            SectionHeader::Underline => "Souligné",
+            SectionHeader::Bar => "Barre latérale",
+            SectionHeader::Caps => "Capitales",
+        }
+    }
+}
+
+impl SkillDisplay {
+    pub const ALL: [SkillDisplay; 3] = [SkillDisplay::Bars, SkillDisplay::Tags, SkillDisplay::Dots];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            SkillDisplay::Bars => "Barres",
+            SkillDisplay::Tags => "Étiquettes",
+            SkillDisplay::Dots => "Points",
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme::builtin("clair").expect("thème intégré « clair »")
+    }
+}
+
+impl Theme {
+    /// Thèmes proposés par le builder ; le premier est celui des nouveaux CV.
+    pub fn builtins() -> Vec<Theme> {
+        let theme = |base: &str, [background, surface, text, muted, border, accent]: [&str; 6], body, heading, section_header, skill_display| Theme {
+            base: base.into(),
+            colors: Colors {
+                background: background.into(),
+                surface: surface.into(),
+                text: text.into(),
+                muted: muted.into(),
+                border: border.into(),
+                accent: accent.into(),
+            },
+            fonts: Fonts { body, heading },
+            spacing: 12,
+            radius: 12,
+            section_header,
+            skill_display,
+        };
+        vec![
+            // mêmes couleurs que `assets/css/style.css`
+            theme("clair", ["#ffffff", "#f7f7f8", "#111418", "#6b7280", "#e5e7eb", "#2563eb"], Font::Sans, Font::Sans, SectionHeader::Underline, SkillDisplay::Bars),
+            // l'ancien aperçu du builder
+            theme("nuit", ["#0b1120", "#0e1523", "#e5e7eb", "#9ca3af", "#25304a", "#3a5bff"], Font::Sans, Font::Sans, SectionHeader::Bar, SkillDisplay::Bars),
+            Theme { radius: 2, ..theme("classique", ["#fffdf8", "#f4efe6", "#2b2621", "#7a6f63", "#ded4c4", "#8b3a2b"], Font::Serif, Font::Serif, SectionHeader::Caps, SkillDisplay::Dots) },
+            Theme { spacing: 8, radius: 4, ..theme("terminal", ["#0d1117", "#161b22", "#c9d1d9", "#8b949e", "#30363d", "#3fb950"], Font::Mono, Font::Mono, SectionHeader::Plain, SkillDisplay::Tags) },
+            Theme { radius: 20, ..theme("pastel", ["#fdfcff", "#f3f0ff", "#2e2a3d", "#777190", "#e4defa", "#7c5cff"], Font::Rounded, Font::Rounded, SectionHeader::Underline, SkillDisplay::Tags) },
+        ]
+    }
+
+    pub fn builtin(base: &str) -> Option<Theme> {
+        Self::builtins().into_iter().find(|t| t.base == base)
+    }
+
+    /// Erreurs lisibles, chemin compris (`colors.accent`…).
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let mut errors = Vec::new();
+        if self.base.chars().count() > MAX_LABEL {
+            errors.push(format!("base : {MAX_LABEL} caractères au plus"));
+        }
+        let c = &self.colors;
+        let colors = [
+            ("background", &c.background),
+            ("surface", &c.surface),
+            ("text", &c.text),
+            ("muted", &c.muted),
+            ("border", &c.border),
+            ("accent", &c.accent),
+        ];
+        for (name, value) in colors {
+            if parse_hex(value).is_none() {
+                errors.push(format!("colors.{name} : couleur #rrggbb attendue, pas « {value} »"));
+            }
+        }
+        if self.spacing == 0 || self.spacing > MAX_SPACING {
+            errors.push(format!("spacing : entre 1 et {MAX_SPACING}"));
+        }
+        if self.radius > MAX_RADIUS {
+            errors.push(format!("radius : {MAX_RADIUS} au plus"));
+        }
+        if errors.is_empty() { Ok(()) } else { Err(errors) }
+    }
+
+    /// Déclarations CSS (`--bg:#fff;--accent:…;`) à poser sur l'élément racine du rendu.
+    /// Le thème doit être valide (sinon les couleurs fautives sont omises).
+    pub fn css_vars(&self) -> String {
+        let c = &self.colors;
+        let mut css = String::new();
+        let colors = [
+            ("bg", c.background.clone()),
+            ("surface", c.surface.clone()),
+            ("text", c.text.clone()),
+            ("muted", c.muted.clone()),
+            ("border", c.border.clone()),
+            ("accent", c.accent.clone()),
+            ("accent-600", mix(&c.accent, "#000000", 0.15)),
+            ("accent-50", mix(&c.accent, &c.background, 0.9)),
+        ];
+        for (name, value) in colors {
+            if parse_hex(&value).is_some() {
+                css.push_str(&format!("--{name}:{value};"));
+            }
+        }
+        css.push_str(&format!("--radius:{}px;", self.radius.min(MAX_RADIUS)));
+        css.push_str(&format!("--space:{}px;", self.spacing.clamp(1, MAX_SPACING)));
+        css.push_str(&format!("--font-body:{};", self.fonts.body.css()));
+        css.push_str(&format!("--font-heading:{};", self.fonts.heading.css()));
+        css
+    }
+
+    /// Style en ligne des titres de section (selon [`SectionHeader`]).
+    pub fn heading_style(&self) -> &'static str {
+        match self.section_header {
+            SectionHeader::Plain => "font-family:var(--font-heading);margin:0 0 var(--space);",
+            SectionHeader::Underline => {
+                "font-family:var(--font-heading);margin:0 0 var(--space);padding-bottom:4px;border-bottom:2px solid var(--accent);"
+            }
+            SectionHeader::Bar => "font-family:var(--font-heading);margin:0 0 var(--space);padding-left:10px;border-left:4px solid var(--accent);",
+            SectionHeader::Caps => {
+                "font-family:var(--font-heading);margin:0 0 var(--space);text-transform:uppercase;letter-spacing:.12em;font-size:.9em;color:var(--accent);"
+            }
+        }
+    }
+}
+
+/// `#rrggbb` (ou `#rgb`) → composantes.
+fn parse_hex(s: &str) -> Option<[u8; 3]> {
+    let hex = s.strip_prefix('#')?;
+    if !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    let channel = |s: &str| u8::from_str_radix(s, 16).ok();
+    match hex.len() {
+        6 => Some([channel(&hex[0..2])?, channel(&hex[2..4])?, channel(&hex[4..6])?]),
+        3 => {
+            let short = |i: usize| channel(&hex[i..i + 1]).map(|v| v * 17);
+            Some([short(0)?, short(1)?, short(2)?])
+        }
+        _ => None,
+    }
+}
+
+/// `a` mélangé à `b` (`t` = part de `b`) ; `a` tel quel si l'une des couleurs est invalide.
+fn mix(a: &str, b: &str, t: f32) -> String {
+    let (Some(x), Some(y)) = (parse_hex(a), parse_hex(b)) else { return a.to_string() };
+    let c = |i: usize| (x[i] as f32 * (1.0 - t) + y[i] as f32 * t).round() as u8;
+    format!("#{:02x}{:02x}{:02x}", c(0), c(1), c(2))
+}
@@ -0,0 +1,131 @@
+use rustfolio_layout::{Edit, Layout, Widget, WidgetRef, MAX_COLUMNS, MAX_WIDTH};
+
+fn text(t: &str) -> Widget {
+    Widget::Text { text: t.into() }
+}
+
+fn texts<const N: usize>(ts: [&str; N]) -> Vec<Widget> {
+    ts.map(text).into()
+}
+
+fn column(l: &Layout, id: usize) -> &[Widget] {
+    &l.rows.iter().flat_map(|r| &r.columns).find(|c| c.id == id).unwrap().widgets
+}
+
+/// Une ligne de deux colonnes (ids 2 et 3) : ["a", "b", "c"] et ["d"].
+fn two_columns() -> Layout {
+    let mut l = Layout::default();
+    l.apply(Edit::AddRow);
+    l.apply(Edit::SplitSelectedRow(2));
+    l.select_column(2);
+    for t in ["a", "b", "c"] {
+        l.apply(Edit::AddWidget(text(t)));
+    }
+    l.select_column(3);
+    l.apply(Edit::AddWidget(text("d")));
+    l
+}
+
+#[test]
+fn rows_move_duplicate_and_delete() {
+    let mut l = two_columns();
+    assert!(l.apply(Edit::AddRow));
+    assert_eq!(l.rows.iter().map(|r| r.id).collect::<Vec<_>>(), [1, 2]);
+
+    assert!(l.apply(Edit::MoveRow { row: 2, by: -5 }));
+    assert_eq!(l.rows.iter().map(|r| r.id).collect::<Vec<_>>(), [2, 1]);
+    assert!(!l.apply(Edit::MoveRow { row: 2, by: -1 }), "déjà en tête");
+
+    assert!(l.apply(Edit::DuplicateRow(1)));
+    let copy = &l.rows[2];
+    assert_eq!(copy.id, 3);
+    assert_eq!(copy.columns.iter().map(|c| c.id).collect::<Vec<_>>(), [5, 6]);
+    assert_eq!(column(&l, 5), texts(["a", "b", "c"]));
+    assert_eq!(l.selected_row, Some(3));
+
+    l.select_column(6);
+    assert!(l.apply(Edit::DeleteRow(3)));
+    assert_eq!((l.selected_row, l.selected_column), (None, None));
+    assert!(!l.apply(Edit::DeleteRow(3)));
+    assert!(l.validate().is_ok());
+}
+
+#[test]
+fn columns_keep_at_least_one_and_at_most_six() {
+    let mut l = two_columns();
+    assert!(l.apply(Edit::MoveColumn { column: 3, by: -1 }));
+    assert_eq!(l.rows[0].columns.iter().map(|c| c.id).collect::<Vec<_>>(), [3, 2]);
+
+    assert!(l.apply(Edit::SetColumnWidth { column: 2, width: 40 }));
+    assert_eq!(l.rows[0].columns[1].width, MAX_WIDTH);
+    assert!(!l.apply(Edit::SetColumnWidth { column: 2, width: MAX_WIDTH }));
+
+    assert!(l.apply(Edit::DuplicateColumn(2)));
+    assert_eq!(column(&l, 4), texts(["a", "b", "c"]));
+    assert_eq!(l.rows[0].columns[2].width, MAX_WIDTH);
+    assert_eq!(l.selected_column, Some(4));
+    while l.rows[0].columns.len() < MAX_COLUMNS {
+        assert!(l.apply(Edit::DuplicateColumn(3)));
+    }
+    assert!(!l.apply(Edit::DuplicateColumn(3)), "ligne pleine");
+
+    let ids: Vec<usize> = l.rows[0].columns.iter().map(|c| c.id).collect();
+    for id in &ids[1..] {
+        assert!(l.apply(Edit::DeleteColumn(*id)));
+    }
+    assert!(!l.apply(Edit::DeleteColumn(ids[0])), "dernière colonne");
+    assert!(l.validate().is_ok());
+}
+
+#[test]
+fn widgets_shift_duplicate_delete_and_drag_between_columns() {
+    let mut l = two_columns();
+    let at = |column, index| WidgetRef { column, index };
+
+    assert!(l.apply(Edit::ShiftWidget { at: at(2, 0), by: 1 }));
+    assert_eq!(column(&l, 2), texts(["b", "a", "c"]));
+    assert!(l.apply(Edit::DuplicateWidget(at(2, 2))));
+    assert_eq!(column(&l, 2), texts(["b", "a", "c", "c"]));
+    assert!(l.apply(Edit::DeleteWidget(at(2, 3))));
+    assert!(!l.apply(Edit::DeleteWidget(at(2, 3))));
+
+    // même colonne : l'index cible compte le widget déplacé
+    assert_eq!(l.move_widget(at(2, 0), at(2, 3)), Some(at(2, 2)));
+    assert_eq!(column(&l, 2), texts(["a", "c", "b"]));
+    assert_eq!(l.move_widget(at(2, 1), at(2, 2)), None, "déjà à cette place");
+
+    // vers une autre colonne, index borné à la fin
+    assert_eq!(l.move_widget(at(2, 0), at(3, 0)), Some(at(3, 0)));
+    assert_eq!(l.move_widget(at(2, 0), at(3, 99)), Some(at(3, 2)));
+    assert_eq!(column(&l, 2), texts(["b"]));
+    assert_eq!(column(&l, 3), texts(["a", "d", "c"]));
+
+    let skills = Widget::SkillsGrid { category: Some("Backend".into()), heading: Some("Stack".into()), limit: Some(5), fields: None };
+    assert!(l.apply(Edit::UpdateWidget { at: at(3, 1), widget: skills.clone() }));
+    assert_eq!(l.widget(at(3, 1)), Some(&skills));
+    assert!(l.validate().is_ok());
+}
+
+#[test]
+fn widget_options_are_validated() {
+    let mut l = two_columns();
+    let at = WidgetRef { column: 3, index: 0 };
+    let bad = Widget::ProjectsList {
+        category: None,
+        heading: Some("x".repeat(300)),
+        limit: Some(0),
+        fields: Some(vec!["description".into(), "budget".into()]),
+    };
+    l.apply(Edit::UpdateWidget { at, widget: bad });
+    l.rows[0].columns[0].width = 0;
+
+    let errors = l.validate().unwrap_err();
+    assert_eq!(errors.len(), 4, "{errors:?}");
+    assert!(errors[0].starts_with("rows[0].columns[0].width"));
+    assert!(errors[1].starts_with("rows[0].columns[1].widgets[0].heading"));
+    assert!(errors[2].starts_with("rows[0].columns[1].widgets[0].limit"));
+    assert!(errors[3].contains("« budget » inconnu"));
+
+    let shown = Widget::ProjectsList { category: None, heading: None, limit: None, fields: Some(vec!["links".into()]) };
+    assert!(shown.shows("links") && !shown.shows("description"));
+}
@@ -0,0 +1,72 @@
+use rustfolio_layout::{Edit, History, Layout, Widget, WidgetRef, COALESCE_MS, HISTORY_LIMIT};
+
+fn with_text_widget() -> (Layout, History) {
+    let (mut l, mut h) = (Layout::default(), History::default());
+    h.apply(&mut l, Edit::AddRow, 0);
+    l.select_column(1);
+    h.apply(&mut l, Edit::AddWidget(Widget::Text { text: String::new() }), 0);
+    (l, h)
+}
+
+fn typed(text: &str) -> Edit {
+    Edit::UpdateWidget { at: WidgetRef { column: 1, index: 0 }, widget: Widget::Text { text: text.into() } }
+}
+
+#[test]
+fn undo_split_restores_widget_placement() {
+    let (mut l, mut h) = with_text_widget();
+    h.apply(&mut l, Edit::SplitSelectedRow(3), 10);
+    let after_first_split = l.clone();
+    h.apply(&mut l, Edit::SplitSelectedRow(1), 20);
+
+    assert!(h.undo(&mut l));
+    assert_eq!(l, after_first_split);
+    assert!(h.undo(&mut l));
+    assert_eq!(l.rows[0].columns.len(), 1);
+    assert_eq!(l.rows[0].columns[0].id, 1);
+
+    assert!(h.redo(&mut l) && h.redo(&mut l));
+    assert!(!h.can_redo());
+    assert_eq!(l.rows[0].columns.len(), 1);
+}
+
+#[test]
+fn rapid_edits_of_one_widget_are_one_step() {
+    let (mut l, mut h) = with_text_widget();
+    for (i, text) in ["B", "Bo", "Bon", "Bonj"].into_iter().enumerate() {
+        h.apply(&mut l, typed(text), 1_000 + i as u64 * 200);
+    }
+    // pause : nouvelle entrée
+    h.apply(&mut l, typed("Bonjour"), 1_600 + COALESCE_MS);
+
+    assert!(h.undo(&mut l));
+    assert_eq!(l.widget(WidgetRef { column: 1, index: 0 }), Some(&Widget::Text { text: "Bonj".into() }));
+    assert!(h.undo(&mut l));
+    assert_eq!(l.widget(WidgetRef { column: 1, index: 0 }), Some(&Widget::Text { text: String::new() }));
+}
+
+#[test]
+fn new_edit_clears_redo_and_history_is_bounded() {
+    let (mut l, mut h) = with_text_widget();
+    h.apply(&mut l, Edit::AddRow, 0);
+    assert!(h.undo(&mut l));
+    assert!(h.can_redo());
+    h.apply(&mut l, Edit::DuplicateRow(1), 0);
+    assert!(!h.can_redo());
+    assert!(!h.apply(&mut l, Edit::DeleteRow(99), 0), "sans effet : pas d'entrée");
+
+    for _ in 0..HISTORY_LIMIT + 10 {
+        h.apply(&mut l, Edit::MoveRow { row: 1, by: 1 }, 0);
+        h.apply(&mut l, Edit::MoveRow { row: 1, by: -1 }, 0);
+    }
+    let mut undone = 0;
+    while h.undo(&mut l) {
+        undone += 1;
+    }
+    assert_eq!(undone, HISTORY_LIMIT);
+
+    // relu depuis le stockage local
+    let json = serde_json::to_string(&h).unwrap();
+    let back: History = serde_json::from_str::<History>(&json).unwrap().bounded();
+    assert_eq!(back.can_redo(), h.can_redo());
+}
@@ -0,0 +1,88 @@
+use rustfolio_layout::{migrate, Align, Layout, MigrateError, Widget, CURRENT_VERSION};
+use serde_json::json;
+
+#[test]
+fn v0_store_layout_gets_ids_and_new_widget_names() {
+    let v0 = json!({ "rows": [
+        { "columns": [
+            { "widgets": [
+                { "type": "Title", "text": "Alice", "level": 2, "bold": true, "align": "Center" },
+                { "type": "ExperienceList", "filter_type": "work" }
+            ] },
+            { "widgets": [{ "type": "Photo", "url": "/p.webp", "rounded": false }, { "type": "Gone" }] }
+        ] },
+        { "columns": [{ "widgets": [{ "type": "SkillsGrid", "category": null }, { "type": "ProjectCard", "index": 3 }] }] }
+    ] });
+
+    let l = migrate(v0).unwrap();
+    assert_eq!(l.version, CURRENT_VERSION);
+    assert_eq!((l.next_row_id, l.next_col_id), (2, 3));
+    assert_eq!(l.rows.iter().map(|r| r.id).collect::<Vec<_>>(), [1, 2]);
+    assert_eq!(l.rows[1].columns[0].id, 3);
+    assert_eq!(
+        l.rows[0].columns[0].widgets,
+        [
+            Widget::Title { text: "Alice".into(), level: 2, bold: true, align: Some(Align::Center) },
+            Widget::ExperienceTimeline { filter_type: Some("work".into()), heading: None, limit: None, fields: None },
+        ]
+    );
+    // types inconnus ignorés, comme l'ancien `layout_from_json`
+    assert_eq!(l.rows[0].columns[1].widgets, [Widget::Photo { url: "/p.webp".into(), rounded: false }]);
+    assert_eq!(l.rows[1].columns[0].widgets[1], Widget::ProjectCard { index: 3 });
+    assert!(l.validate().is_ok());
+}
+
+#[test]
+fn v1_builder_layout_keeps_ids_and_selection() {
+    let v1 = json!({
+        "rows": [{ "id": 4, "columns": [{ "id": 7, "widgets": ["ProfileBasic", "SkillsGrid", { "Text": "Bio" }] }] }],
+        "selected_row": 4, "selected_column": 7, "next_row_id": 4, "next_col_id": 7
+    });
+
+    let l = migrate(v1).unwrap();
+    assert_eq!((l.selected_row, l.selected_column), (Some(4), Some(7)));
+    assert_eq!(
+        l.rows[0].columns[0].widgets,
+        [
+            Widget::ProfileBasic { fields: None },
+            Widget::SkillsGrid { category: None, heading: None, limit: None, fields: None },
+            Widget::Text { text: "Bio".into() },
+        ]
+    );
+    assert!(l.validate().is_ok());
+}
+
+#[test]
+fn current_version_round_trips() {
+    let mut l = Layout::default();
+    l.add_row();
+    l.split_selected_row(2);
+    l.select_column(3);
+    l.add_widget_to_selected_column(Widget::Title { text: "CV".into(), level: 1, bold: false, align: None });
+    l.add_widget_to_selected_column(Widget::ProjectsList { category: None, heading: None, limit: None, fields: None });
+
+    let json = serde_json::to_value(&l).unwrap();
+    assert_eq!(json["version"], CURRENT_VERSION);
+    assert_eq!(json["rows"][0]["columns"][1]["widgets"][1], json!({ "type": "ProjectsList" }));
+    assert_eq!(migrate(json).unwrap(), l);
+    assert!(l.validate().is_ok());
+}
+
+#[test]
+fn unknown_versions_and_bad_shapes_are_errors() {
+    assert!(matches!(migrate(json!({ "version": CURRENT_VERSION + 1 })), Err(MigrateError::UnknownVersion(_))));
+    assert!(matches!(migrate(json!({ "version": "2" })), Err(MigrateError::UnknownVersion(_))));
+    assert!(matches!(migrate(json!([])), Err(MigrateError::NotAnObject)));
+    let extra = json!({ "version": 2, "rows": [], "theme": "dark" });
+    assert!(matches!(migrate(extra), Err(MigrateError::Invalid(_))));
+}
+
+#[test]
+fn validation_checks_widget_options() {
+    let mut l = Layout::default();
+    l.add_row();
+    l.select_column(1);
+    l.add_widget_to_selected_column(Widget::Title { text: "x".into(), level: 9, bold: false, align: None });
+    let errors = l.validate().unwrap_err();
+    assert_eq!(errors, ["rows[0].columns[0].widgets[0].level : 9 (1 à 6)"]);
+}
@@ -0,0 +1,57 @@
+use rustfolio_layout::{Font, SectionHeader, SkillDisplay, Theme};
+use serde_json::json;
+
+#[test]
+fn builtins_are_valid_and_distinct() {
+    let themes = Theme::builtins();
+    assert!(themes.len() >= 4);
+    for t in &themes {
+        assert_eq!(t.validate(), Ok(()), "{}", t.base);
+        assert_eq!(Theme::builtin(&t.base).as_ref(), Some(t));
+    }
+    let mut names: Vec<_> = themes.iter().map(|t| t.base.as_str()).collect();
+    names.sort();
+    names.dedup();
+    assert_eq!(names.len(), themes.len());
+    assert_eq!(Theme::default(), themes[0]);
+}
+
+#[test]
+fn stored_json_round_trips_and_defaults() {
+    // CV créé avant les thèmes : `{}` stocké
+    assert_eq!(serde_json::from_value::<Theme>(json!({})).unwrap(), Theme::default());
+
+    let mut t = Theme::builtin("classique").unwrap();
+    t.colors.accent = "#123456".into();
+    t.skill_display = SkillDisplay::Tags;
+    let v = serde_json::to_value(&t).unwrap();
+    assert_eq!(v["fonts"], json!({ "body": "serif", "heading": "serif" }));
+    assert_eq!(v["section_header"], "caps");
+    assert_eq!(serde_json::from_value::<Theme>(v).unwrap(), t);
+
+    assert!(serde_json::from_value::<Theme>(json!({ "css": "body{}" })).is_err());
+    assert!(serde_json::from_value::<Theme>(json!({ "fonts": { "body": "Comic Sans", "heading": "sans" } })).is_err());
+}
+
+#[test]
+fn only_checked_values_reach_the_css() {
+    let mut t = Theme::default();
+    t.colors.text = "red;} body{display:none".into();
+    t.colors.accent = "#abc".into();
+    t.spacing = 0;
+    let errors = t.validate().unwrap_err();
+    assert_eq!(errors.len(), 2, "{errors:?}");
+    assert!(errors[0].starts_with("colors.text"), "{errors:?}");
+    assert!(errors[1].starts_with("spacing"), "{errors:?}");
+
+    let css = t.css_vars();
+    assert!(!css.contains("display:none"), "{css}");
+    assert!(css.contains("--accent:#abc;"), "{css}");
+    assert!(css.contains("--bg:#ffffff;"), "{css}");
+    assert!(css.contains("--font-body:ui-sans-serif"), "{css}");
+
+    t.fonts.heading = Font::Mono;
+    t.section_header = SectionHeader::Bar;
+    assert!(t.css_vars().contains("--font-heading:ui-monospace"));
+    assert!(t.heading_style().contains("border-left"));
+}
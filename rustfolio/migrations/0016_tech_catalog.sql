@@ -0,0 +1,184 @@
+-- Catalogue partagé des technologies (nom canonique, alias, catégorie, logo embarqué)
+CREATE TABLE IF NOT EXISTS tech_catalog (
+  id       INTEGER PRIMARY KEY AUTOINCREMENT,
+  slug     TEXT NOT NULL UNIQUE,
+  name     TEXT NOT NULL,
+  category TEXT NOT NULL DEFAULT '',
+  logo_url TEXT NOT NULL DEFAULT ''
+);
+
+-- alias normalisés (minuscules, sans espaces/points/tirets), nom canonique inclus
+CREATE TABLE IF NOT EXISTS tech_aliases (
+  alias   TEXT PRIMARY KEY,
+  tech_id INTEGER NOT NULL REFERENCES tech_catalog(id) ON DELETE CASCADE
+);
+CREATE INDEX IF NOT EXISTS idx_tech_aliases_tech ON tech_aliases(tech_id);
+
+ALTER TABLE skills ADD COLUMN tech_id INTEGER NULL REFERENCES tech_catalog(id) ON DELETE SET NULL;
+ALTER TABLE project_technologies ADD COLUMN tech_id INTEGER NULL REFERENCES tech_catalog(id) ON DELETE SET NULL;
+CREATE INDEX IF NOT EXISTS idx_skills_tech ON skills(tech_id);
+CREATE INDEX IF NOT EXISTS idx_project_tech_tech ON project_technologies(tech_id);
+
+INSERT INTO tech_catalog (slug, name, category, logo_url) VALUES
+  ('rust', 'Rust', 'Langages', '/assets/img/tech/rust.svg'),
+  ('c', 'C', 'Langages', '/assets/img/tech/c.svg'),
+  ('cpp', 'C++', 'Langages', '/assets/img/tech/cpp.svg'),
+  ('javascript', 'JavaScript', 'Langages', '/assets/img/tech/javascript.svg'),
+  ('typescript', 'TypeScript', 'Langages', '/assets/img/tech/typescript.svg'),
+  ('python', 'Python', 'Langages', '/assets/img/tech/python.svg'),
+  ('php', 'PHP', 'Langages', '/assets/img/tech/php.svg'),
+  ('html', 'HTML', 'Frontend', '/assets/img/tech/html.svg'),
+  ('css', 'CSS', 'Frontend', '/assets/img/tech/css.svg'),
+  ('react', 'React', 'Frontend', '/assets/img/tech/react.svg'),
+  ('nextjs', 'Next.js', 'Frontend', '/assets/img/tech/nextjs.svg'),
+  ('tailwind', 'Tailwind CSS', 'Frontend', '/assets/img/tech/tailwind.svg'),
+  ('mui', 'MUI', 'Frontend', '/assets/img/tech/mui.svg'),
+  ('yew', 'Yew', 'Frontend', '/assets/img/tech/yew.svg'),
+  ('django', 'Django', 'Backend', '/assets/img/tech/django.svg'),
+  ('axum', 'Axum', 'Backend', '/assets/img/tech/axum.svg'),
+  ('docker', 'Docker', 'DevOps', '/assets/img/tech/docker.svg'),
+  ('nginx', 'NGINX', 'DevOps', '/assets/img/tech/nginx.svg'),
+  ('git', 'Git', 'DevOps', '/assets/img/tech/git.svg'),
+  ('unix', 'Unix', 'DevOps', '/assets/img/tech/unix.svg'),
+  ('mariadb', 'MariaDB', 'Bases de données', '/assets/img/tech/mariadb.svg'),
+  ('sqlite', 'SQLite', 'Bases de données', '/assets/img/tech/sqlite.svg'),
+  ('postgresql', 'PostgreSQL', 'Bases de données', '/assets/img/tech/postgresql.svg'),
+  ('wordpress', 'WordPress', 'CMS', '/assets/img/tech/wordpress.svg'),
+  ('figma', 'Figma', 'Design', '/assets/img/tech/figma.svg'),
+  ('photoshop', 'Photoshop', 'Design', '/assets/img/tech/photoshop.svg'),
+  ('illustrator', 'Illustrator', 'Design', '/assets/img/tech/illustrator.svg'),
+  ('indesign', 'InDesign', 'Design', '/assets/img/tech/indesign.svg'),
+  ('acrobat', 'Adobe Acrobat', 'Design', '/assets/img/tech/acrobat.svg');
+
+INSERT INTO tech_aliases (alias, tech_id)
+  SELECT 'rust', id FROM tech_catalog WHERE slug = 'rust'
+  UNION ALL
+  SELECT 'rustlang', id FROM tech_catalog WHERE slug = 'rust'
+  UNION ALL
+  SELECT 'c', id FROM tech_catalog WHERE slug = 'c'
+  UNION ALL
+  SELECT 'ansic', id FROM tech_catalog WHERE slug = 'c'
+  UNION ALL
+  SELECT 'c99', id FROM tech_catalog WHERE slug = 'c'
+  UNION ALL
+  SELECT 'cpp', id FROM tech_catalog WHERE slug = 'cpp'
+  UNION ALL
+  SELECT 'c++', id FROM tech_catalog WHERE slug = 'cpp'
+  UNION ALL
+  SELECT 'cplusplus', id FROM tech_catalog WHERE slug = 'cpp'
+  UNION ALL
+  SELECT 'c++98', id FROM tech_catalog WHERE slug = 'cpp'
+  UNION ALL
+  SELECT 'javascript', id FROM tech_catalog WHERE slug = 'javascript'
+  UNION ALL
+  SELECT 'js', id FROM tech_catalog WHERE slug = 'javascript'
+  UNION ALL
+  SELECT 'ecmascript', id FROM tech_catalog WHERE slug = 'javascript'
+  UNION ALL
+  SELECT 'es6', id FROM tech_catalog WHERE slug = 'javascript'
+  UNION ALL
+  SELECT 'typescript', id FROM tech_catalog WHERE slug = 'typescript'
+  UNION ALL
+  SELECT 'ts', id FROM tech_catalog WHERE slug = 'typescript'
+  UNION ALL
+  SELECT 'python', id FROM tech_catalog WHERE slug = 'python'
+  UNION ALL
+  SELECT 'py', id FROM tech_catalog WHERE slug = 'python'
+  UNION ALL
+  SELECT 'python3', id FROM tech_catalog WHERE slug = 'python'
+  UNION ALL
+  SELECT 'php', id FROM tech_catalog WHERE slug = 'php'
+  UNION ALL
+  SELECT 'html', id FROM tech_catalog WHERE slug = 'html'
+  UNION ALL
+  SELECT 'html5', id FROM tech_catalog WHERE slug = 'html'
+  UNION ALL
+  SELECT 'css', id FROM tech_catalog WHERE slug = 'css'
+  UNION ALL
+  SELECT 'css3', id FROM tech_catalog WHERE slug = 'css'
+  UNION ALL
+  SELECT 'react', id FROM tech_catalog WHERE slug = 'react'
+  UNION ALL
+  SELECT 'reactjs', id FROM tech_catalog WHERE slug = 'react'
+  UNION ALL
+  SELECT 'nextjs', id FROM tech_catalog WHERE slug = 'nextjs'
+  UNION ALL
+  SELECT 'next', id FROM tech_catalog WHERE slug = 'nextjs'
+  UNION ALL
+  SELECT 'tailwind', id FROM tech_catalog WHERE slug = 'tailwind'
+  UNION ALL
+  SELECT 'tailwindcss', id FROM tech_catalog WHERE slug = 'tailwind'
+  UNION ALL
+  SELECT 'mui', id FROM tech_catalog WHERE slug = 'mui'
+  UNION ALL
+  SELECT 'materialui', id FROM tech_catalog WHERE slug = 'mui'
+  UNION ALL
+  SELECT 'yew', id FROM tech_catalog WHERE slug = 'yew'
+  UNION ALL
+  SELECT 'django', id FROM tech_catalog WHERE slug = 'django'
+  UNION ALL
+  SELECT 'axum', id FROM tech_catalog WHERE slug = 'axum'
+  UNION ALL
+  SELECT 'docker', id FROM tech_catalog WHERE slug = 'docker'
+  UNION ALL
+  SELECT 'dockercompose', id FROM tech_catalog WHERE slug = 'docker'
+  UNION ALL
+  SELECT 'nginx', id FROM tech_catalog WHERE slug = 'nginx'
+  UNION ALL
+  SELECT 'git', id FROM tech_catalog WHERE slug = 'git'
+  UNION ALL
+  SELECT 'github', id FROM tech_catalog WHERE slug = 'git'
+  UNION ALL
+  SELECT 'gitlab', id FROM tech_catalog WHERE slug = 'git'
+  UNION ALL
+  SELECT 'unix', id FROM tech_catalog WHERE slug = 'unix'
+  UNION ALL
+  SELECT 'linux', id FROM tech_catalog WHERE slug = 'unix'
+  UNION ALL
+  SELECT 'bash', id FROM tech_catalog WHERE slug = 'unix'
+  UNION ALL
+  SELECT 'shell', id FROM tech_catalog WHERE slug = 'unix'
+  UNION ALL
+  SELECT 'mariadb', id FROM tech_catalog WHERE slug = 'mariadb'
+  UNION ALL
+  SELECT 'mysql', id FROM tech_catalog WHERE slug = 'mariadb'
+  UNION ALL
+  SELECT 'sqlite', id FROM tech_catalog WHERE slug = 'sqlite'
+  UNION ALL
+  SELECT 'postgresql', id FROM tech_catalog WHERE slug = 'postgresql'
+  UNION ALL
+  SELECT 'postgres', id FROM tech_catalog WHERE slug = 'postgresql'
+  UNION ALL
+  SELECT 'psql', id FROM tech_catalog WHERE slug = 'postgresql'
+  UNION ALL
+  SELECT 'wordpress', id FROM tech_catalog WHERE slug = 'wordpress'
+  UNION ALL
+  SELECT 'wp', id FROM tech_catalog WHERE slug = 'wordpress'
+  UNION ALL
+  SELECT 'figma', id FROM tech_catalog WHERE slug = 'figma'
+  UNION ALL
+  SELECT 'photoshop', id FROM tech_catalog WHERE slug = 'photoshop'
+  UNION ALL
+  SELECT 'adobephotoshop', id FROM tech_catalog WHERE slug = 'photoshop'
+  UNION ALL
+  SELECT 'illustrator', id FROM tech_catalog WHERE slug = 'illustrator'
+  UNION ALL
+  SELECT 'adobeillustrator', id FROM tech_catalog WHERE slug = 'illustrator'
+  UNION ALL
+  SELECT 'indesign', id FROM tech_catalog WHERE slug = 'indesign'
+  UNION ALL
+  SELECT 'adobeindesign', id FROM tech_catalog WHERE slug = 'indesign'
+  UNION ALL
+  SELECT 'acrobat', id FROM tech_catalog WHERE slug = 'acrobat'
+  UNION ALL
+  SELECT 'adobeacrobat', id FROM tech_catalog WHERE slug = 'acrobat'
+  UNION ALL
+  SELECT 'acrobatpro', id FROM tech_catalog WHERE slug = 'acrobat';
+
+-- rattache les lignes existantes au catalogue
+UPDATE skills
+   SET tech_id = (SELECT tech_id FROM tech_aliases
+                   WHERE alias = lower(replace(replace(replace(replace(trim(skills.name), ' ', ''), '.', ''), '-', ''), '_', '')));
+UPDATE project_technologies
+   SET tech_id = (SELECT tech_id FROM tech_aliases
+                   WHERE alias = lower(replace(replace(replace(replace(trim(project_technologies.tech), ' ', ''), '.', ''), '-', ''), '_', '')));
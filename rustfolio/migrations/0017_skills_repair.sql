@@ -0,0 +1,33 @@
+-- Réparation du schéma skills : 0009 et 0014 déclaraient deux tables différentes
+-- (0014 était ignorée par IF NOT EXISTS) puis 0015 ajoutait logo_url à côté de logo.
+-- On reconstruit une seule table : percentage nullable, niveau optionnel, logo fusionné dans logo_url.
+CREATE TABLE skills_new (
+  id         INTEGER PRIMARY KEY AUTOINCREMENT,
+  user_id    TEXT    NOT NULL,
+  name       TEXT    NOT NULL,
+  percentage INTEGER NULL CHECK (percentage BETWEEN 0 AND 100),
+  level      TEXT    NULL CHECK (level IN ('beginner', 'intermediate', 'advanced', 'expert')),
+  logo_url   TEXT    NULL,
+  category   TEXT    NOT NULL DEFAULT '',
+  tech_id    INTEGER NULL REFERENCES tech_catalog(id) ON DELETE SET NULL,
+  updated_at TEXT    NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+
+INSERT INTO skills_new (id, user_id, name, percentage, level, logo_url, category, tech_id, updated_at)
+SELECT id,
+       CAST(user_id AS TEXT),
+       name,
+       percentage,
+       NULL,
+       COALESCE(NULLIF(logo_url, ''), NULLIF(logo, '')),
+       COALESCE(category, ''),
+       tech_id,
+       COALESCE(updated_at, CURRENT_TIMESTAMP)
+  FROM skills;
+
+DROP TABLE skills;
+ALTER TABLE skills_new RENAME TO skills;
+
+CREATE INDEX IF NOT EXISTS idx_skills_user     ON skills(user_id);
+CREATE INDEX IF NOT EXISTS idx_skills_user_cat ON skills(user_id, category);
+CREATE INDEX IF NOT EXISTS idx_skills_tech     ON skills(tech_id);
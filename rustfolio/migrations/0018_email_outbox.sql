@@ -0,0 +1,15 @@
+-- File d'attente des e-mails : écrits ici dans le handler, envoyés par le worker (avec retries)
+CREATE TABLE IF NOT EXISTS email_outbox (
+  id              INTEGER PRIMARY KEY AUTOINCREMENT,
+  to_addr         TEXT NOT NULL,
+  subject         TEXT NOT NULL,
+  text_body       TEXT NOT NULL,
+  html_body       TEXT NOT NULL,
+  attempts        INTEGER NOT NULL DEFAULT 0,
+  last_error      TEXT NULL,
+  -- NULL une fois envoyé ou abandonné
+  next_attempt_at TEXT NULL DEFAULT CURRENT_TIMESTAMP,
+  sent_at         TEXT NULL,
+  created_at      TEXT NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+CREATE INDEX IF NOT EXISTS idx_email_outbox_due ON email_outbox(next_attempt_at);
@@ -0,0 +1,15 @@
+-- Images téléversées (photo de profil, image de projet, logo de compétence).
+-- Les fichiers vivent sur disque (`<uploads.dir>/<user_id>/<hash>-<variante>.<ext>`) ;
+-- la table sert à dédupliquer et à retrouver les fichiers orphelins.
+CREATE TABLE IF NOT EXISTS uploads (
+  id           INTEGER PRIMARY KEY AUTOINCREMENT,
+  user_id      TEXT    NOT NULL,
+  hash         TEXT    NOT NULL,
+  -- format de repli à côté du WebP : 'jpg' (opaque) ou 'png' (transparence)
+  fallback_ext TEXT    NOT NULL CHECK (fallback_ext IN ('jpg', 'png')),
+  width        INTEGER NOT NULL,
+  height       INTEGER NOT NULL,
+  bytes        INTEGER NOT NULL,
+  created_at   TEXT    NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  UNIQUE (user_id, hash)
+);
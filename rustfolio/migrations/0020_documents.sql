@@ -0,0 +1,18 @@
+-- PDF téléversés pour les projets. Comme les images (0019), un document n'est lié
+-- à son projet que par l'URL stockée dans `projects.pdf_link` : PUT /cv/bulk
+-- recrée les projets avec de nouveaux ids, une clé étrangère emporterait les fichiers.
+CREATE TABLE IF NOT EXISTS documents (
+  id             INTEGER PRIMARY KEY AUTOINCREMENT,
+  user_id        TEXT    NOT NULL,
+  hash           TEXT    NOT NULL,
+  -- nom d'origine nettoyé, renvoyé dans Content-Disposition
+  file_name      TEXT    NOT NULL,
+  title          TEXT    NULL,
+  page_count     INTEGER NOT NULL,
+  bytes          INTEGER NOT NULL,
+  -- NULL si aucun aperçu n'a pu être extrait de la première page
+  preview_width  INTEGER NULL,
+  preview_height INTEGER NULL,
+  created_at     TEXT    NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  UNIQUE (user_id, hash)
+);
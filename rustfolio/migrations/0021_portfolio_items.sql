@@ -0,0 +1,15 @@
+-- Portfolio graphique (remplace data/graphicPortfolio.json) : une œuvre par ligne,
+-- regroupées en albums par `kind` (journal, feuillet…), triées par `position`.
+CREATE TABLE IF NOT EXISTS portfolio_items (
+  id          INTEGER PRIMARY KEY AUTOINCREMENT,
+  user_id     TEXT    NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+  title       TEXT    NOT NULL,
+  description TEXT    NOT NULL DEFAULT '',
+  kind        TEXT    NOT NULL DEFAULT '',
+  -- URL : image téléversée (/assets/uploads/…), asset statique ou PDF
+  image       TEXT    NOT NULL DEFAULT '',
+  position    INTEGER NOT NULL DEFAULT 0,
+  created_at  TEXT    NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  updated_at  TEXT    NOT NULL DEFAULT CURRENT_TIMESTAMP
+);
+CREATE INDEX IF NOT EXISTS idx_portfolio_items_user ON portfolio_items(user_id, position);
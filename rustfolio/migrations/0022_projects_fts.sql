@@ -0,0 +1,47 @@
+-- Recherche plein texte sur les projets (GET /api/projects).
+-- remove_diacritics 2 : « modelisation » trouve « Modélisation ».
+-- rowid = projects.id ; la table est tenue à jour par les triggers ci-dessous.
+CREATE VIRTUAL TABLE IF NOT EXISTS projects_fts USING fts5(
+  title, description, category, technologies,
+  tokenize = 'unicode61 remove_diacritics 2'
+);
+
+INSERT INTO projects_fts (rowid, title, description, category, technologies)
+  SELECT p.id, p.title, COALESCE(p.description, ''), COALESCE(p.category, ''),
+         COALESCE((SELECT group_concat(t.tech, ' ') FROM project_technologies t WHERE t.project_id = p.id), '')
+    FROM projects p;
+
+CREATE TRIGGER IF NOT EXISTS projects_fts_ai AFTER INSERT ON projects BEGIN
+  INSERT INTO projects_fts (rowid, title, description, category, technologies)
+  VALUES (new.id, new.title, COALESCE(new.description, ''), COALESCE(new.category, ''), '');
+END;
+
+CREATE TRIGGER IF NOT EXISTS projects_fts_au AFTER UPDATE OF title, description, category ON projects BEGIN
+  UPDATE projects_fts
+     SET title = new.title, description = COALESCE(new.description, ''), category = COALESCE(new.category, '')
+   WHERE rowid = new.id;
+END;
+
+CREATE TRIGGER IF NOT EXISTS projects_fts_ad AFTER DELETE ON projects BEGIN
+  DELETE FROM projects_fts WHERE rowid = old.id;
+END;
+
+CREATE TRIGGER IF NOT EXISTS project_tech_fts_ai AFTER INSERT ON project_technologies BEGIN
+  UPDATE projects_fts
+     SET technologies = COALESCE((SELECT group_concat(tech, ' ') FROM project_technologies WHERE project_id = new.project_id), '')
+   WHERE rowid = new.project_id;
+END;
+
+CREATE TRIGGER IF NOT EXISTS project_tech_fts_au AFTER UPDATE ON project_technologies BEGIN
+  UPDATE projects_fts
+     SET technologies = COALESCE((SELECT group_concat(tech, ' ') FROM project_technologies WHERE project_id = new.project_id), '')
+   WHERE rowid = new.project_id;
+END;
+
+CREATE TRIGGER IF NOT EXISTS project_tech_fts_ad AFTER DELETE ON project_technologies BEGIN
+  UPDATE projects_fts
+     SET technologies = COALESCE((SELECT group_concat(tech, ' ') FROM project_technologies WHERE project_id = old.project_id), '')
+   WHERE rowid = old.project_id;
+END;
+
+CREATE INDEX IF NOT EXISTS idx_project_tech_name ON project_technologies(tech COLLATE NOCASE);
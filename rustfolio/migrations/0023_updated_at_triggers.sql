@@ -0,0 +1,72 @@
+-- ETags des listes (/api/cv/*, /api/portfolio) : empreinte des couples id@updated_at.
+-- Pour que l'empreinte change à chaque écriture, updated_at devient strictement croissant
+-- (précision milliseconde, au moins ancien + 1 ms) et les lignes enfants (tâches, technos)
+-- « touchent » leur parent.
+-- Les triggers ne portent que sur les colonnes de contenu : la mise à jour de updated_at
+-- qu'ils font eux-mêmes ne les redéclenche pas.
+
+CREATE TRIGGER IF NOT EXISTS experiences_touch
+AFTER UPDATE OF date, kind, title, company, location, date_start, date_end, website ON experiences BEGIN
+  UPDATE experiences
+     SET updated_at = max(strftime('%Y-%m-%d %H:%M:%f', 'now'), strftime('%Y-%m-%d %H:%M:%f', old.updated_at, '+0.001 seconds'))
+   WHERE id = new.id;
+END;
+
+CREATE TRIGGER IF NOT EXISTS projects_touch
+AFTER UPDATE OF title, description, category, repo_link, pdf_link, image ON projects BEGIN
+  UPDATE projects
+     SET updated_at = max(strftime('%Y-%m-%d %H:%M:%f', 'now'), strftime('%Y-%m-%d %H:%M:%f', old.updated_at, '+0.001 seconds'))
+   WHERE id = new.id;
+END;
+
+CREATE TRIGGER IF NOT EXISTS skills_touch
+AFTER UPDATE OF name, percentage, level, logo_url, category, tech_id ON skills BEGIN
+  UPDATE skills
+     SET updated_at = max(strftime('%Y-%m-%d %H:%M:%f', 'now'), strftime('%Y-%m-%d %H:%M:%f', old.updated_at, '+0.001 seconds'))
+   WHERE id = new.id;
+END;
+
+CREATE TRIGGER IF NOT EXISTS portfolio_items_touch
+AFTER UPDATE OF title, description, kind, image, position ON portfolio_items BEGIN
+  UPDATE portfolio_items
+     SET updated_at = max(strftime('%Y-%m-%d %H:%M:%f', 'now'), strftime('%Y-%m-%d %H:%M:%f', old.updated_at, '+0.001 seconds'))
+   WHERE id = new.id;
+END;
+
+-- Tâches → expérience
+CREATE TRIGGER IF NOT EXISTS experience_tasks_touch_ai AFTER INSERT ON experience_tasks BEGIN
+  UPDATE experiences
+     SET updated_at = max(strftime('%Y-%m-%d %H:%M:%f', 'now'), strftime('%Y-%m-%d %H:%M:%f', updated_at, '+0.001 seconds'))
+   WHERE id = new.experience_id;
+END;
+
+CREATE TRIGGER IF NOT EXISTS experience_tasks_touch_au AFTER UPDATE ON experience_tasks BEGIN
+  UPDATE experiences
+     SET updated_at = max(strftime('%Y-%m-%d %H:%M:%f', 'now'), strftime('%Y-%m-%d %H:%M:%f', updated_at, '+0.001 seconds'))
+   WHERE id = new.experience_id;
+END;
+
+CREATE TRIGGER IF NOT EXISTS experience_tasks_touch_ad AFTER DELETE ON experience_tasks BEGIN
+  UPDATE experiences
+     SET updated_at = max(strftime('%Y-%m-%d %H:%M:%f', 'now'), strftime('%Y-%m-%d %H:%M:%f', updated_at, '+0.001 seconds'))
+   WHERE id = old.experience_id;
+END;
+
+-- Technos → projet
+CREATE TRIGGER IF NOT EXISTS project_technologies_touch_ai AFTER INSERT ON project_technologies BEGIN
+  UPDATE projects
+     SET updated_at = max(strftime('%Y-%m-%d %H:%M:%f', 'now'), strftime('%Y-%m-%d %H:%M:%f', updated_at, '+0.001 seconds'))
+   WHERE id = new.project_id;
+END;
+
+CREATE TRIGGER IF NOT EXISTS project_technologies_touch_au AFTER UPDATE ON project_technologies BEGIN
+  UPDATE projects
+     SET updated_at = max(strftime('%Y-%m-%d %H:%M:%f', 'now'), strftime('%Y-%m-%d %H:%M:%f', updated_at, '+0.001 seconds'))
+   WHERE id = new.project_id;
+END;
+
+CREATE TRIGGER IF NOT EXISTS project_technologies_touch_ad AFTER DELETE ON project_technologies BEGIN
+  UPDATE projects
+     SET updated_at = max(strftime('%Y-%m-%d %H:%M:%f', 'now'), strftime('%Y-%m-%d %H:%M:%f', updated_at, '+0.001 seconds'))
+   WHERE id = old.project_id;
+END;
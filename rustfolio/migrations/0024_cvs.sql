@@ -0,0 +1,46 @@
+-- Plusieurs CV nommés par utilisateur (un par type de candidature) : chacun a sa mise
+-- en page et masque les expériences / compétences / projets qu'il ne montre pas.
+CREATE TABLE IF NOT EXISTS cvs (
+  id          INTEGER PRIMARY KEY AUTOINCREMENT,
+  user_id     TEXT    NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+  name        TEXT    NOT NULL,
+  layout      TEXT    NOT NULL DEFAULT '{}',
+  created_at  TEXT    NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  updated_at  TEXT    NOT NULL DEFAULT CURRENT_TIMESTAMP,
+  UNIQUE (user_id, name)
+);
+
+-- Masqués plutôt qu'inclus : un élément ajouté plus tard apparaît dans tous les CV.
+CREATE TABLE IF NOT EXISTS cv_hidden_items (
+  cv_id    INTEGER NOT NULL REFERENCES cvs(id) ON DELETE CASCADE,
+  kind     TEXT    NOT NULL CHECK (kind IN ('experience', 'skill', 'project')),
+  item_id  INTEGER NOT NULL,
+  PRIMARY KEY (cv_id, kind, item_id)
+);
+
+-- `item_id` vise trois tables (pas de clé étrangère) : nettoyage à la suppression
+CREATE TRIGGER IF NOT EXISTS experiences_unhide AFTER DELETE ON experiences BEGIN
+  DELETE FROM cv_hidden_items WHERE kind = 'experience' AND item_id = OLD.id;
+END;
+CREATE TRIGGER IF NOT EXISTS skills_unhide AFTER DELETE ON skills BEGIN
+  DELETE FROM cv_hidden_items WHERE kind = 'skill' AND item_id = OLD.id;
+END;
+CREATE TRIGGER IF NOT EXISTS projects_unhide AFTER DELETE ON projects BEGIN
+  DELETE FROM cv_hidden_items WHERE kind = 'project' AND item_id = OLD.id;
+END;
+
+-- Reprise : le layout unique de chaque utilisateur devient son CV « Principal »
+-- (les lignes de l'ancien utilisateur fictif « 1 » sont abandonnées).
+INSERT INTO cvs (user_id, name, layout, updated_at)
+SELECT user_id, 'Principal', layout, COALESCE(updated_at, CURRENT_TIMESTAMP)
+FROM cv_layout
+WHERE user_id IN (SELECT id FROM users);
+
+DROP TABLE IF EXISTS cv_layout;
+
+CREATE TRIGGER IF NOT EXISTS cvs_touch
+AFTER UPDATE OF name, layout ON cvs BEGIN
+  UPDATE cvs
+     SET updated_at = max(strftime('%Y-%m-%d %H:%M:%f', 'now'), strftime('%Y-%m-%d %H:%M:%f', old.updated_at, '+0.001 seconds'))
+   WHERE id = new.id;
+END;
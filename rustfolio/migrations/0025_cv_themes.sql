@@ -0,0 +1,10 @@
+-- Thème de chaque CV (JSON `rustfolio_layout::Theme`) ; `{}` : thème par défaut.
+ALTER TABLE cvs ADD COLUMN theme TEXT NOT NULL DEFAULT '{}';
+
+DROP TRIGGER IF EXISTS cvs_touch;
+CREATE TRIGGER IF NOT EXISTS cvs_touch
+AFTER UPDATE OF name, layout, theme ON cvs BEGIN
+  UPDATE cvs
+     SET updated_at = max(strftime('%Y-%m-%d %H:%M:%f', 'now'), strftime('%Y-%m-%d %H:%M:%f', old.updated_at, '+0.001 seconds'))
+   WHERE id = new.id;
+END;
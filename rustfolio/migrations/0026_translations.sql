@@ -0,0 +1,58 @@
+-- Contenu multilingue : les colonnes existantes portent la langue par défaut (fr), les
+-- traductions vivent à côté, champ par champ (NULL = repli sur la langue par défaut).
+-- Champs traduisibles : titre et résumé du profil, titre et tâches des expériences,
+-- description des projets.
+
+ALTER TABLE profiles ADD COLUMN summary TEXT DEFAULT '';
+-- Adresse publique `/u/<slug>/<langue>` ; NULL = CV non publié.
+ALTER TABLE profiles ADD COLUMN slug TEXT;
+CREATE UNIQUE INDEX IF NOT EXISTS idx_profiles_slug ON profiles(slug);
+
+CREATE TABLE IF NOT EXISTS profile_translations (
+  user_id  TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+  locale   TEXT NOT NULL,
+  title    TEXT,
+  summary  TEXT,
+  PRIMARY KEY (user_id, locale)
+);
+
+CREATE TABLE IF NOT EXISTS experience_translations (
+  experience_id INTEGER NOT NULL REFERENCES experiences(id) ON DELETE CASCADE,
+  locale        TEXT    NOT NULL,
+  title         TEXT,
+  -- liste complète des tâches dans cette langue (tableau JSON)
+  tasks         TEXT,
+  PRIMARY KEY (experience_id, locale)
+);
+
+CREATE TABLE IF NOT EXISTS project_translations (
+  project_id  INTEGER NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+  locale      TEXT    NOT NULL,
+  description TEXT,
+  PRIMARY KEY (project_id, locale)
+);
+
+-- Une traduction modifiée change l'ETag des listes, comme les tâches et les technos
+CREATE TRIGGER IF NOT EXISTS experience_translations_touch_ai AFTER INSERT ON experience_translations BEGIN
+  UPDATE experiences
+     SET updated_at = max(strftime('%Y-%m-%d %H:%M:%f', 'now'), strftime('%Y-%m-%d %H:%M:%f', updated_at, '+0.001 seconds'))
+   WHERE id = new.experience_id;
+END;
+
+CREATE TRIGGER IF NOT EXISTS experience_translations_touch_ad AFTER DELETE ON experience_translations BEGIN
+  UPDATE experiences
+     SET updated_at = max(strftime('%Y-%m-%d %H:%M:%f', 'now'), strftime('%Y-%m-%d %H:%M:%f', updated_at, '+0.001 seconds'))
+   WHERE id = old.experience_id;
+END;
+
+CREATE TRIGGER IF NOT EXISTS project_translations_touch_ai AFTER INSERT ON project_translations BEGIN
+  UPDATE projects
+     SET updated_at = max(strftime('%Y-%m-%d %H:%M:%f', 'now'), strftime('%Y-%m-%d %H:%M:%f', updated_at, '+0.001 seconds'))
+   WHERE id = new.project_id;
+END;
+
+CREATE TRIGGER IF NOT EXISTS project_translations_touch_ad AFTER DELETE ON project_translations BEGIN
+  UPDATE projects
+     SET updated_at = max(strftime('%Y-%m-%d %H:%M:%f', 'now'), strftime('%Y-%m-%d %H:%M:%f', updated_at, '+0.001 seconds'))
+   WHERE id = old.project_id;
+END;
@@ -0,0 +1,3 @@
+-- Langue de l'interface choisie par l'utilisateur (`fr`, `en`…).
+-- NULL : négociée à chaque requête depuis `Accept-Language`.
+ALTER TABLE users ADD COLUMN locale TEXT;
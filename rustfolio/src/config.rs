@@ -0,0 +1,561 @@
+//! Configuration typée, chargée une seule fois au démarrage puis partagée via `AppState`.
+//!
+//! Priorité (la dernière gagne) : valeurs par défaut < fichier TOML
+//! (`--config <fichier>` ou `RUSTFOLIO_CONFIG`) < variables d'environnement < flags CLI.
+//! Voir `rustfolio.example.toml` et `.env.example` pour la liste des clés.
+
+use std::{fmt, net::SocketAddr, path::PathBuf, str::FromStr};
+
+use axum_extra::extract::cookie::SameSite;
+use serde::Deserialize;
+
+/* =============================================================================
+   Config validée
+============================================================================= */
+
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub bind_addr:       SocketAddr,
+    /// Sans slash final, ex. `https://engagez-moi.com`.
+    pub public_base_url: String,
+    pub database_url:    String,
+    pub run_migrations:  bool,
+    pub dev_reset_db_on_migration_mismatch: bool,
+    pub cookie:          CookieConfig,
+    pub session_ttl:     time::Duration,
+    pub email:           EmailConfig,
+    pub uploads:         UploadConfig,
+    pub log_format:      LogFormat,
+    /// `/health/ready` échoue sous ce seuil d'espace libre sur le volume de la DB.
+    pub min_free_disk_bytes: u64,
+    /// Origines autorisées en CORS (avec cookies). Vide : même origine uniquement.
+    pub cors_allowed_origins: Vec<String>,
+    pub content_security_policy: String,
+    /// Délai laissé aux requêtes en cours après SIGTERM avant l'arrêt forcé.
+    pub shutdown_grace: std::time::Duration,
+    /// Compte dont les pages publiques (`/portfolio`…) affichent le contenu.
+    /// `None` : le plus ancien compte (déploiement mono-utilisateur).
+    pub site_owner_email: Option<String>,
+}
+
+/// CSP par défaut : tout en `'self'`, WebAssembly autorisé pour le dashboard Yew,
+/// styles inline tolérés (attributs `style=` des templates).
+pub const DEFAULT_CSP: &str = "default-src 'self'; script-src 'self' 'wasm-unsafe-eval'; \
+style-src 'self' 'unsafe-inline'; img-src 'self' data: https:; font-src 'self' data:; \
+connect-src 'self'; object-src 'none'; base-uri 'self'; form-action 'self'; frame-ancestors 'none'";
+
+/// Format des logs ; le niveau se règle avec `RUST_LOG`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogFormat {
+    Pretty,
+    Json,
+}
+
+#[derive(Debug, Clone)]
+pub struct CookieConfig {
+    pub secure:    bool,
+    pub same_site: SameSite,
+}
+
+#[derive(Debug, Clone)]
+pub struct EmailConfig {
+    pub from:      String,
+    pub transport: EmailTransport,
+}
+
+#[derive(Debug, Clone)]
+pub enum EmailTransport {
+    /// Dev : le mail est écrit sur stderr, rien n'est envoyé.
+    Log,
+    /// Dev/tests : chaque mail devient un fichier `.eml` dans ce dossier.
+    File(PathBuf),
+    Smtp(SmtpConfig),
+}
+
+#[derive(Debug, Clone)]
+pub struct SmtpConfig {
+    pub host:       String,
+    pub port:       u16,
+    pub user:       String,
+    pub pass:       String,
+    /// Nom annoncé au HELO/EHLO (par défaut l'hôte de `public_base_url`).
+    pub hello_name: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct UploadConfig {
+    pub dir:             PathBuf,
+    pub max_image_bytes: u64,
+    pub max_pdf_bytes:   u64,
+}
+
+/* =============================================================================
+   Sources brutes (TOML + env + CLI)
+============================================================================= */
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct RawConfig {
+    bind_addr:       Option<String>,
+    public_base_url: Option<String>,
+    database_url:    Option<String>,
+    run_migrations:  Option<bool>,
+    dev_reset_db_on_migration_mismatch: Option<bool>,
+    cookie:          RawCookie,
+    session:         RawSession,
+    email:           RawEmail,
+    uploads:         RawUploads,
+    log_format:      Option<String>,
+    health:          RawHealth,
+    cors:            RawCors,
+    security:        RawSecurity,
+    site:            RawSite,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct RawSite {
+    owner_email: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct RawCors {
+    allowed_origins: Option<Vec<String>>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct RawSecurity {
+    content_security_policy: Option<String>,
+    shutdown_grace_secs:     Option<u64>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct RawHealth {
+    min_free_disk_mb: Option<u64>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct RawCookie {
+    secure:    Option<bool>,
+    same_site: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct RawSession {
+    ttl_days: Option<i64>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct RawEmail {
+    mode:     Option<String>,
+    from:     Option<String>,
+    file_dir: Option<String>,
+    smtp:     RawSmtp,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct RawSmtp {
+    host:       Option<String>,
+    port:       Option<u16>,
+    user:       Option<String>,
+    pass:       Option<String>,
+    hello_name: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct RawUploads {
+    dir:          Option<String>,
+    max_image_mb: Option<u64>,
+    max_pdf_mb:   Option<u64>,
+}
+
+/// Flags de la ligne de commande qui surchargent la config.
+#[derive(Debug, Default, Clone)]
+pub struct CliArgs {
+    pub config_path: Option<String>,
+    pub bind:        Option<String>,
+    pub base_url:    Option<String>,
+    pub migrate:     bool,
+}
+
+impl CliArgs {
+    /// Consomme les `--flags` reconnus et renvoie les arguments restants (la sous-commande).
+    pub fn parse(args: &[String]) -> Result<(Self, Vec<String>), String> {
+        let mut cli = CliArgs::default();
+        let mut rest = Vec::new();
+        let mut it = args.iter();
+        while let Some(a) = it.next() {
+            let (flag, inline) = match a.split_once('=') {
+                Some((f, v)) if f.starts_with("--") => (f, Some(v.to_string())),
+                _ => (a.as_str(), None),
+            };
+            let mut value = |name: &str| {
+                inline.clone().or_else(|| it.next().cloned()).ok_or(format!("{name} attend une valeur"))
+            };
+            match flag {
+                "--config" => cli.config_path = Some(value("--config")?),
+                "--bind" => cli.bind = Some(value("--bind")?),
+                "--base-url" => cli.base_url = Some(value("--base-url")?),
+                "--migrate" => cli.migrate = true,
+                f if f.starts_with("--") && f != "--help" => return Err(format!("option inconnue : {f}")),
+                _ => rest.push(a.clone()),
+            }
+        }
+        Ok((cli, rest))
+    }
+}
+
+/* =============================================================================
+   Rapport d'erreurs
+============================================================================= */
+
+/// Toutes les valeurs manquantes/invalides, pour les corriger en une fois.
+#[derive(Debug, Default)]
+pub struct ConfigReport {
+    pub problems: Vec<String>,
+}
+
+impl ConfigReport {
+    fn push(&mut self, env: &str, key: &str, msg: impl fmt::Display) {
+        self.problems.push(format!("{env} ({key}) : {msg}"));
+    }
+}
+
+impl fmt::Display for ConfigReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Configuration invalide :")?;
+        for p in &self.problems {
+            writeln!(f, "  - {p}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ConfigReport {}
+
+/* =============================================================================
+   Chargement
+============================================================================= */
+
+const DEFAULT_BIND: &str = "0.0.0.0:8080";
+const DEFAULT_BASE_URL: &str = "http://localhost:8080";
+const DEFAULT_FROM: &str = "rustfolio@localhost";
+
+impl Config {
+    /// Charge depuis le processus : fichier TOML éventuel + env + flags.
+    pub fn load(cli: &CliArgs) -> Result<Self, ConfigReport> {
+        let path = cli.config_path.clone().or_else(|| std::env::var("RUSTFOLIO_CONFIG").ok());
+        let toml_src = match &path {
+            Some(p) => match std::fs::read_to_string(p) {
+                Ok(s) => Some(s),
+                Err(e) => {
+                    let mut report = ConfigReport::default();
+                    report.push("RUSTFOLIO_CONFIG", "--config", format!("lecture de {p} impossible : {e}"));
+                    return Err(report);
+                }
+            },
+            None => None,
+        };
+        Self::from_sources(toml_src.as_deref(), |k| std::env::var(k).ok(), cli)
+    }
+
+    /// Variante testable : `env` remplace `std::env::var`.
+    pub fn from_sources(
+        toml_src: Option<&str>,
+        env: impl Fn(&str) -> Option<String>,
+        cli: &CliArgs,
+    ) -> Result<Self, ConfigReport> {
+        let mut report = ConfigReport::default();
+
+        let mut raw: RawConfig = match toml_src.map(toml::from_str) {
+            None => RawConfig::default(),
+            Some(Ok(r)) => r,
+            Some(Err(e)) => {
+                report.problems.push(format!("fichier de config : {}", e.message()));
+                RawConfig::default()
+            }
+        };
+
+        // --- env ---
+        let text = |name: &str, slot: &mut Option<String>| {
+            if let Some(v) = env(name).filter(|v| !v.trim().is_empty()) {
+                *slot = Some(v.trim().to_string());
+            }
+        };
+        text("BIND_ADDR", &mut raw.bind_addr);
+        text("PUBLIC_BASE_URL", &mut raw.public_base_url);
+        text("DATABASE_URL", &mut raw.database_url);
+        text("COOKIE_SAME_SITE", &mut raw.cookie.same_site);
+        text("EMAIL_MODE", &mut raw.email.mode);
+        text("EMAIL_FROM", &mut raw.email.from);
+        text("EMAIL_FILE_DIR", &mut raw.email.file_dir);
+        text("SMTP_HOST", &mut raw.email.smtp.host);
+        text("SMTP_USER", &mut raw.email.smtp.user);
+        text("SMTP_PASS", &mut raw.email.smtp.pass);
+        text("SMTP_HELO", &mut raw.email.smtp.hello_name);
+        text("UPLOAD_DIR", &mut raw.uploads.dir);
+        text("LOG_FORMAT", &mut raw.log_format);
+        text("CONTENT_SECURITY_POLICY", &mut raw.security.content_security_policy);
+        text("SITE_OWNER_EMAIL", &mut raw.site.owner_email);
+        if let Some(v) = env("CORS_ALLOWED_ORIGINS") {
+            raw.cors.allowed_origins = Some(v.split(',').map(|o| o.trim().to_string()).filter(|o| !o.is_empty()).collect());
+        }
+
+        let mut flag = |name: &str, key: &str, slot: &mut Option<bool>| {
+            if let Some(v) = env(name).filter(|v| !v.trim().is_empty()) {
+                match v.trim().to_ascii_lowercase().as_str() {
+                    "1" | "true" | "yes" | "on" => *slot = Some(true),
+                    "0" | "false" | "no" | "off" => *slot = Some(false),
+                    _ => report.push(name, key, format!("'{v}' n'est pas un booléen (true/false)")),
+                }
+            }
+        };
+        flag("RUN_MIGRATIONS", "run_migrations", &mut raw.run_migrations);
+        flag("DEV_RESET_DB_ON_MIGRATION_MISMATCH", "dev_reset_db_on_migration_mismatch", &mut raw.dev_reset_db_on_migration_mismatch);
+        flag("COOKIE_SECURE", "cookie.secure", &mut raw.cookie.secure);
+
+        number(&env, &mut report, "SESSION_TTL_DAYS", "session.ttl_days", &mut raw.session.ttl_days);
+        number(&env, &mut report, "SMTP_PORT", "email.smtp.port", &mut raw.email.smtp.port);
+        number(&env, &mut report, "UPLOAD_MAX_IMAGE_MB", "uploads.max_image_mb", &mut raw.uploads.max_image_mb);
+        number(&env, &mut report, "UPLOAD_MAX_PDF_MB", "uploads.max_pdf_mb", &mut raw.uploads.max_pdf_mb);
+        number(&env, &mut report, "HEALTH_MIN_FREE_DISK_MB", "health.min_free_disk_mb", &mut raw.health.min_free_disk_mb);
+        number(&env, &mut report, "SHUTDOWN_GRACE_SECS", "security.shutdown_grace_secs", &mut raw.security.shutdown_grace_secs);
+
+        // --- CLI ---
+        if cli.bind.is_some() {
+            raw.bind_addr = cli.bind.clone();
+        }
+        if cli.base_url.is_some() {
+            raw.public_base_url = cli.base_url.clone();
+        }
+        if cli.migrate {
+            raw.run_migrations = Some(true);
+        }
+
+        let cfg = validate(raw, &mut report);
+        match cfg {
+            Some(cfg) if report.problems.is_empty() => Ok(cfg),
+            _ => Err(report),
+        }
+    }
+}
+
+fn number<T: FromStr>(
+    env: &impl Fn(&str) -> Option<String>,
+    report: &mut ConfigReport,
+    name: &str,
+    key: &str,
+    slot: &mut Option<T>,
+) {
+    if let Some(v) = env(name).filter(|v| !v.trim().is_empty()) {
+        match v.trim().parse() {
+            Ok(n) => *slot = Some(n),
+            Err(_) => report.push(name, key, format!("'{v}' n'est pas un nombre valide")),
+        }
+    }
+}
+
+fn validate(raw: RawConfig, report: &mut ConfigReport) -> Option<Config> {
+    let bind = raw.bind_addr.as_deref().unwrap_or(DEFAULT_BIND);
+    let bind_addr = bind
+        .parse::<SocketAddr>()
+        .map_err(|_| report.push("BIND_ADDR", "bind_addr", format!("'{bind}' invalide (attendu ip:port)")))
+        .ok();
+
+    let base = raw.public_base_url.as_deref().unwrap_or(DEFAULT_BASE_URL).trim_end_matches('/').to_string();
+    if !(base.starts_with("http://") || base.starts_with("https://")) {
+        report.push("PUBLIC_BASE_URL", "public_base_url", format!("'{base}' doit commencer par http:// ou https://"));
+    }
+
+    let database_url = raw.database_url.unwrap_or_default();
+    if database_url.is_empty() {
+        report.push("DATABASE_URL", "database_url", "manquant (ex. sqlite://data/app.db)");
+    } else if !database_url.starts_with("sqlite:") {
+        report.push("DATABASE_URL", "database_url", format!("'{database_url}' : seul SQLite (sqlite:…) est supporté"));
+    }
+
+    let same_site = match raw.cookie.same_site.as_deref().map(str::to_ascii_lowercase).as_deref() {
+        None | Some("lax") => SameSite::Lax,
+        Some("strict") => SameSite::Strict,
+        Some("none") => SameSite::None,
+        Some(other) => {
+            report.push("COOKIE_SAME_SITE", "cookie.same_site", format!("'{other}' invalide (lax|strict|none)"));
+            SameSite::Lax
+        }
+    };
+    let secure = raw.cookie.secure.unwrap_or(false);
+    if same_site == SameSite::None && !secure {
+        report.push("COOKIE_SAME_SITE", "cookie.same_site", "SameSite=None exige COOKIE_SECURE=true");
+    }
+
+    let ttl_days = raw.session.ttl_days.unwrap_or(30);
+    if !(1..=365).contains(&ttl_days) {
+        report.push("SESSION_TTL_DAYS", "session.ttl_days", format!("{ttl_days} hors bornes (1..=365)"));
+    }
+
+    let email = validate_email(raw.email, &base, report);
+
+    let cors_allowed_origins = raw.cors.allowed_origins.unwrap_or_default();
+    for o in &cors_allowed_origins {
+        let (scheme, host) = o.split_once("://").unwrap_or(("", ""));
+        if o == "*" {
+            report.push("CORS_ALLOWED_ORIGINS", "cors.allowed_origins", "'*' interdit (les cookies de session sont envoyés) : liste les origines");
+        } else if !matches!(scheme, "http" | "https") || host.is_empty() || host.contains('/') || !o.is_ascii() {
+            report.push("CORS_ALLOWED_ORIGINS", "cors.allowed_origins", format!("'{o}' invalide (attendu scheme://hôte[:port], sans chemin)"));
+        }
+    }
+
+    let content_security_policy = raw.security.content_security_policy.unwrap_or_else(|| DEFAULT_CSP.into());
+    if !content_security_policy.is_ascii() || content_security_policy.contains(['\r', '\n']) {
+        report.push("CONTENT_SECURITY_POLICY", "security.content_security_policy", "doit tenir sur une ligne ASCII");
+    }
+
+    let log_format = match raw.log_format.as_deref().map(str::to_ascii_lowercase).as_deref() {
+        None | Some("pretty") => LogFormat::Pretty,
+        Some("json") => LogFormat::Json,
+        Some(other) => {
+            report.push("LOG_FORMAT", "log_format", format!("'{other}' invalide (pretty|json)"));
+            LogFormat::Pretty
+        }
+    };
+
+    let site_owner_email = raw.site.owner_email.map(|e| e.trim().to_string());
+    if let Some(e) = site_owner_email.as_deref().filter(|e| !e.contains('@')) {
+        report.push("SITE_OWNER_EMAIL", "site.owner_email", format!("'{e}' n'est pas une adresse e-mail"));
+    }
+
+    let max_image_mb = raw.uploads.max_image_mb.unwrap_or(5);
+    let max_pdf_mb = raw.uploads.max_pdf_mb.unwrap_or(20);
+    for (name, key, v) in [
+        ("UPLOAD_MAX_IMAGE_MB", "uploads.max_image_mb", max_image_mb),
+        ("UPLOAD_MAX_PDF_MB", "uploads.max_pdf_mb", max_pdf_mb),
+    ] {
+        if v == 0 {
+            report.push(name, key, "doit être > 0");
+        }
+    }
+    // en octets ; un dépassement refuse la config (sinon panique en debug, valeur tronquée en release)
+    let mut bytes = |name: &str, key: &str, mb: u64| {
+        mb.checked_mul(1024 * 1024).unwrap_or_else(|| {
+            report.push(name, key, format!("{mb} Mo : valeur trop grande"));
+            0
+        })
+    };
+    let max_image_bytes = bytes("UPLOAD_MAX_IMAGE_MB", "uploads.max_image_mb", max_image_mb);
+    let max_pdf_bytes = bytes("UPLOAD_MAX_PDF_MB", "uploads.max_pdf_mb", max_pdf_mb);
+    let min_free_disk_bytes =
+        bytes("HEALTH_MIN_FREE_DISK_MB", "health.min_free_disk_mb", raw.health.min_free_disk_mb.unwrap_or(100));
+
+    Some(Config {
+        bind_addr: bind_addr?,
+        public_base_url: base,
+        database_url,
+        run_migrations: raw.run_migrations.unwrap_or(false),
+        dev_reset_db_on_migration_mismatch: raw.dev_reset_db_on_migration_mismatch.unwrap_or(false),
+        cookie: CookieConfig { secure, same_site },
+        session_ttl: time::Duration::days(ttl_days),
+        email: email?,
+        uploads: UploadConfig {
+            dir: PathBuf::from(raw.uploads.dir.as_deref().unwrap_or("data/uploads")),
+            max_image_bytes,
+            max_pdf_bytes,
+        },
+        log_format,
+        min_free_disk_bytes,
+        cors_allowed_origins,
+        content_security_policy,
+        shutdown_grace: std::time::Duration::from_secs(raw.security.shutdown_grace_secs.unwrap_or(30)),
+        site_owner_email,
+    })
+}
+
+fn validate_email(raw: RawEmail, base_url: &str, report: &mut ConfigReport) -> Option<EmailConfig> {
+    let mode = raw.mode.as_deref().unwrap_or("log").to_ascii_lowercase();
+    let from = raw.from.unwrap_or_else(|| DEFAULT_FROM.into());
+    if from.parse::<lettre::message::Mailbox>().is_err() {
+        report.push("EMAIL_FROM", "email.from", format!("'{from}' n'est pas une adresse valide"));
+    }
+
+    let transport = match mode.as_str() {
+        "log" => EmailTransport::Log,
+        "file" => EmailTransport::File(PathBuf::from(raw.file_dir.as_deref().unwrap_or("data/mails"))),
+        "smtp" => {
+            let s = raw.smtp;
+            let mut need = |v: Option<String>, env: &str, key: &str| {
+                if v.is_none() {
+                    report.push(env, key, "requis quand EMAIL_MODE=smtp");
+                }
+                v.unwrap_or_default()
+            };
+            let host = need(s.host, "SMTP_HOST", "email.smtp.host");
+            let user = need(s.user, "SMTP_USER", "email.smtp.user");
+            let pass = need(s.pass, "SMTP_PASS", "email.smtp.pass");
+            let hello_name = s.hello_name.unwrap_or_else(|| host_of(base_url).to_string());
+            EmailTransport::Smtp(SmtpConfig { host, port: s.port.unwrap_or(587), user, pass, hello_name })
+        }
+        other => {
+            report.push("EMAIL_MODE", "email.mode", format!("'{other}' invalide (log|file|smtp)"));
+            return None;
+        }
+    };
+    Some(EmailConfig { from, transport })
+}
+
+fn host_of(url: &str) -> &str {
+    let rest = url.split_once("://").map(|(_, r)| r).unwrap_or(url);
+    let host = rest.split(['/', ':']).next().unwrap_or(rest);
+    if host.is_empty() { "localhost" } else { host }
+}
+
+/* =============================================================================
+   Affichage (`rustfolio config`) — secrets masqués
+============================================================================= */
+
+impl fmt::Display for Config {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "bind_addr        = {}", self.bind_addr)?;
+        writeln!(f, "public_base_url  = {}", self.public_base_url)?;
+        writeln!(f, "database_url     = {}", self.database_url)?;
+        writeln!(f, "run_migrations   = {}", self.run_migrations)?;
+        writeln!(f, "dev_reset_db     = {}", self.dev_reset_db_on_migration_mismatch)?;
+        writeln!(f, "cookie           = secure={} same_site={:?}", self.cookie.secure, self.cookie.same_site)?;
+        writeln!(f, "session_ttl      = {} jours", self.session_ttl.whole_days())?;
+        match &self.email.transport {
+            EmailTransport::Log => writeln!(f, "email            = log (from {})", self.email.from)?,
+            EmailTransport::File(dir) => {
+                writeln!(f, "email            = fichiers .eml dans {} (from {})", dir.display(), self.email.from)?
+            }
+            EmailTransport::Smtp(s) => writeln!(
+                f,
+                "email            = smtp {}@{}:{} pass=*** helo={} (from {})",
+                s.user, s.host, s.port, s.hello_name, self.email.from
+            )?,
+        }
+        writeln!(
+            f,
+            "uploads          = {} (images ≤ {} Mo, PDF ≤ {} Mo)",
+            self.uploads.dir.display(),
+            self.uploads.max_image_bytes / (1024 * 1024),
+            self.uploads.max_pdf_bytes / (1024 * 1024)
+        )?;
+        writeln!(f, "log_format       = {:?}", self.log_format)?;
+        writeln!(f, "min_free_disk    = {} Mo", self.min_free_disk_bytes / (1024 * 1024))?;
+        let origins = if self.cors_allowed_origins.is_empty() {
+            "(même origine)".to_string()
+        } else {
+            self.cors_allowed_origins.join(", ")
+        };
+        writeln!(f, "cors             = {origins}")?;
+        writeln!(f, "csp              = {}", self.content_security_policy)?;
+        writeln!(f, "site_owner       = {}", self.site_owner_email.as_deref().unwrap_or("(plus ancien compte)"))?;
+        write!(f, "shutdown_grace   = {} s", self.shutdown_grace.as_secs())
+    }
+}
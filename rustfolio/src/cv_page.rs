@@ -0,0 +1,276 @@
+//! Rendu HTML d'un CV nommé (mise en page + données + thème), pour l'export.
+//!
+//! Même présentation que l'aperçu du builder : mêmes options de widgets
+//! (`fields`, `limit`, filtres) et mêmes jetons de thème ([`Theme::css_vars`],
+//! [`Theme::heading_style`]). Le document est autonome (styles en ligne) et prévu pour
+//! l'impression : « Imprimer → PDF » depuis le navigateur donne le même rendu.
+//! Il sert aussi de page publique (`/u/<slug>/<langue>`), avec ses liens `hreflang`.
+
+use askama::Template;
+use rustfolio_layout::{Layout, SkillDisplay, Theme, Widget};
+
+use crate::i18n::{Lang, Tr};
+use crate::locale::{Localize, DEFAULT_LOCALE};
+use crate::repo::{HiddenItems, RepoResult};
+use crate::state::AppState;
+use crate::types::{Experience, Profile, Project, SkillOut};
+
+/// Données affichées par les widgets, dans une langue, éléments masqués du CV déjà
+/// retirés.
+#[derive(Debug, Clone, Default)]
+pub struct CvContent {
+    pub profile:     Profile,
+    pub experiences: Vec<Experience>,
+    pub skills:      Vec<SkillOut>,
+    pub projects:    Vec<Project>,
+}
+
+impl CvContent {
+    /// `locale` : `None` = langue par défaut.
+    pub async fn load(st: &AppState, user_id: &str, hidden: &HiddenItems, locale: Option<&str>) -> RepoResult<Self> {
+        let locale = locale.unwrap_or(DEFAULT_LOCALE);
+        let shown = |id: Option<i64>, ids: &[i64]| !id.is_some_and(|id| ids.contains(&id));
+        let mut experiences = st.repos.experiences.list(user_id).await?;
+        experiences.retain(|e| shown(e.id, &hidden.experiences));
+        experiences.localize(locale);
+        let mut skills = st.repos.skills.list(user_id).await?;
+        skills.retain(|s| shown(Some(s.id), &hidden.skills));
+        let mut projects = st.repos.projects.list(user_id).await?;
+        projects.retain(|p| shown(p.id, &hidden.projects));
+        projects.localize(locale);
+        let mut profile = st.repos.profiles.get(user_id).await?;
+        profile.localize(locale);
+        Ok(Self { profile, experiences, skills, projects })
+    }
+}
+
+#[derive(Template)]
+#[template(path = "cv.html")]
+pub struct CvTpl {
+    pub name:    String,
+    /// Attribut `lang` du document.
+    pub lang:    String,
+    /// Versions du même CV dans les autres langues (page publique) : liens
+    /// `hreflang` et sélecteur de langue.
+    pub alternates: Vec<Alternate>,
+    /// Variables CSS du thème (attribut `style` de la racine).
+    pub vars:    String,
+    pub heading: &'static str,
+    /// Présentation des compétences : `bars`, `tags` ou `dots`.
+    pub skills:  &'static str,
+    pub rows:    Vec<RowView>,
+}
+
+pub struct Alternate {
+    /// Code de langue, ou `x-default`.
+    pub hreflang: String,
+    pub href:     String,
+}
+
+pub struct RowView {
+    /// `grid-template-columns`
+    pub template: String,
+    pub columns:  Vec<Vec<Block>>,
+}
+
+pub enum Block {
+    Text(String),
+    Title(TitleView),
+    Profile(ProfileView),
+    Experiences(Section<ExperienceView>),
+    Skills(Section<SkillView>),
+    Projects(Section<ProjectView>),
+    Photo(PhotoView),
+}
+
+pub struct TitleView {
+    pub text:  String,
+    pub level: u8,
+    pub style: String,
+}
+
+pub struct ProfileView {
+    pub lines:   Vec<String>,
+    pub summary: Option<String>,
+}
+
+pub struct Section<T> {
+    pub heading: String,
+    pub items:   Vec<T>,
+}
+
+pub struct ExperienceView {
+    pub title:    String,
+    pub company:  Option<String>,
+    pub date:     Option<String>,
+    pub location: Option<String>,
+    /// HTML déjà échappé (`rich_text`).
+    pub tasks:    Vec<String>,
+}
+
+pub struct SkillView {
+    pub name:    String,
+    pub logo:    Option<String>,
+    /// `None` : niveau inconnu ou masqué.
+    pub percent: Option<u8>,
+    /// `Dots` : cinq points, pleins jusqu'au niveau.
+    pub dots:    Vec<bool>,
+}
+
+pub struct ProjectView {
+    pub title:        String,
+    pub description:  Option<String>,
+    pub technologies: Vec<String>,
+    pub links:        Vec<(String, String)>,
+    pub image:        Option<String>,
+}
+
+pub struct PhotoView {
+    pub url:     String,
+    pub rounded: bool,
+}
+
+impl CvTpl {
+    pub fn new(name: &str, lang: &str, layout: &Layout, theme: &Theme, content: &CvContent) -> Self {
+        // titres par défaut et libellés dans la langue du contenu, pas de l'interface
+        let tr = Tr(Lang::parse(lang).unwrap_or_default());
+        let rows = layout
+            .rows
+            .iter()
+            .map(|row| RowView {
+                template: row.columns.iter().map(|c| format!("minmax(0,{}fr)", c.width)).collect::<Vec<_>>().join(" "),
+                columns: row
+                    .columns
+                    .iter()
+                    .map(|c| c.widgets.iter().filter_map(|w| block(tr, w, content)).collect())
+                    .collect(),
+            })
+            .collect();
+        let skills = match theme.skill_display {
+            SkillDisplay::Bars => "bars",
+            SkillDisplay::Tags => "tags",
+            SkillDisplay::Dots => "dots",
+        };
+        Self {
+            name: name.to_string(),
+            lang: lang.to_string(),
+            alternates: Vec::new(),
+            vars: theme.css_vars(),
+            heading: theme.heading_style(),
+            skills,
+            rows,
+        }
+    }
+}
+
+fn non_empty(s: &str) -> Option<String> {
+    Some(s.trim().to_string()).filter(|s| !s.is_empty())
+}
+
+/// Liens et images : http(s) ou chemin du site uniquement.
+fn safe_url(s: &str) -> Option<String> {
+    let s = s.trim();
+    let ok = s.starts_with("https://") || s.starts_with("http://") || (s.starts_with('/') && !s.starts_with("//"));
+    ok.then(|| s.to_string())
+}
+
+fn matches(filter: &Option<String>, value: &str) -> bool {
+    filter.as_deref().map(str::trim).filter(|f| !f.is_empty()).is_none_or(|f| f.eq_ignore_ascii_case(value.trim()))
+}
+
+fn block(tr: Tr, w: &Widget, c: &CvContent) -> Option<Block> {
+    let heading = |default: &str| w.heading().map_or_else(|| tr.t(default), str::to_string);
+    let show = |field: &str, value: &str| if w.shows(field) { non_empty(value) } else { None };
+    Some(match w {
+        Widget::Text { text } => Block::Text(text.clone()),
+        Widget::Title { text, level, bold, align } => {
+            let mut style = String::from("font-family:var(--font-heading);margin:0 0 var(--space);");
+            if !bold {
+                style.push_str("font-weight:400;");
+            }
+            if let Some(a) = align {
+                style.push_str(&format!("text-align:{};", serde_json::to_value(a).ok()?.as_str()?));
+            }
+            Block::Title(TitleView { text: text.clone(), level: (*level).clamp(1, 6), style })
+        }
+        Widget::ProfileBasic { .. } => {
+            let p = &c.profile;
+            let location = [p.city.as_str(), p.country.as_str()].into_iter().filter(|s| !s.trim().is_empty()).collect::<Vec<_>>().join(", ");
+            let lines = [
+                show("name", &format!("{} {}", p.first_name, p.last_name)),
+                show("title", &p.title),
+                show("location", &location),
+                show("email", &p.email),
+                show("phone", &p.phone),
+                show("website", &p.website),
+            ];
+            Block::Profile(ProfileView { lines: lines.into_iter().flatten().collect(), summary: show("summary", &p.summary) })
+        }
+        Widget::ExperienceTimeline { filter_type, .. } => Block::Experiences(Section {
+            heading: heading("cv-section-experiences"),
+            items: c
+                .experiences
+                .iter()
+                .filter(|e| matches(filter_type, &e.kind))
+                .take(w.limit())
+                .map(|e| {
+                    let date = match (e.date_start.trim(), e.date_end.trim()) {
+                        ("", _) => String::new(),
+                        (start, "") => format!("{start} – {}", tr.t("cv-date-present")),
+                        (start, end) => format!("{start} – {end}"),
+                    };
+                    ExperienceView {
+                        title: e.title.clone(),
+                        company: show("company", &e.company),
+                        date: show("date", &date),
+                        location: show("location", &e.location),
+                        tasks: if w.shows("tasks") { e.tasks.iter().map(|t| crate::rich_text::render_inline(t)).collect() } else { Vec::new() },
+                    }
+                })
+                .collect(),
+        }),
+        Widget::SkillsGrid { category, .. } => Block::Skills(Section {
+            heading: heading("cv-section-skills"),
+            items: c
+                .skills
+                .iter()
+                .filter(|s| matches(category, &s.category))
+                .take(w.limit())
+                .map(|s| {
+                    let percent = s.percent().filter(|_| w.shows("percentage"));
+                    let filled = percent.map_or(0, |p| (p as usize * 5).div_ceil(100));
+                    SkillView {
+                        name: s.name.clone(),
+                        logo: s.logo_url.as_deref().and_then(|l| show("logo", l)).and_then(|l| safe_url(&l)),
+                        percent,
+                        dots: (0..5).map(|i| i < filled).collect(),
+                    }
+                })
+                .collect(),
+        }),
+        Widget::ProjectsList { category, .. } => Block::Projects(Section {
+            heading: heading("cv-section-projects"),
+            items: c.projects.iter().filter(|p| matches(category, &p.category)).take(w.limit()).map(|p| project(tr, w, p)).collect(),
+        }),
+        Widget::ProjectCard { index } => {
+            let p = c.projects.get(*index)?;
+            Block::Projects(Section { heading: String::new(), items: vec![project(tr, w, p)] })
+        }
+        Widget::Photo { url, rounded } => {
+            let url = non_empty(url).or_else(|| non_empty(&c.profile.photo_url)).and_then(|u| safe_url(&u))?;
+            Block::Photo(PhotoView { url, rounded: *rounded })
+        }
+    })
+}
+
+fn project(tr: Tr, w: &Widget, p: &Project) -> ProjectView {
+    let show = |field: &str, value: &str| if w.shows(field) { non_empty(value) } else { None };
+    let links = [(tr.t("cv-link-code"), show("links", &p.repo_link)), (tr.t("cv-link-pdf"), show("links", &p.pdf_link))];
+    ProjectView {
+        title: p.title.clone(),
+        description: show("description", &p.description),
+        technologies: if w.shows("technologies") { p.technologies.clone() } else { Vec::new() },
+        links: links.into_iter().filter_map(|(label, url)| Some((label, safe_url(&url?)?))).collect(),
+        image: show("image", &p.image).and_then(|u| safe_url(&u)),
+    }
+}
@@ -0,0 +1,144 @@
+//! Connexion SQLite et migrations embarquées dans le binaire (`sqlx::migrate!`).
+//!
+//! Plus besoin de `sqlx-cli` au déploiement : `rustfolio serve --migrate` applique
+//! les migrations au démarrage, `rustfolio migrate status|run` les pilote à la main.
+//!
+//! Les migrations ne sont pas réversibles (pas de `.down.sql`) : pour revenir en
+//! arrière, on restaure une copie du fichier SQLite faite avant `migrate run`. En dev,
+//! `DEV_RESET_DB_ON_MIGRATION_MISMATCH` sauvegarde la base puis la recrée.
+
+use std::{collections::HashMap, path::PathBuf, str::FromStr};
+
+use anyhow::Context;
+use sqlx::{
+    migrate::{Migrate, MigrateError, Migrator},
+    sqlite::SqliteConnectOptions,
+    SqlitePool,
+};
+
+/// Les fichiers de `migrations/` sont lus à la compilation (cf. build.rs).
+pub static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
+
+/// Ouvre le pool (crée le fichier s'il n'existe pas) avec les clés étrangères actives.
+pub async fn connect(url: &str) -> sqlx::Result<SqlitePool> {
+    let opts = SqliteConnectOptions::from_str(url)?
+        .create_if_missing(true)
+        .foreign_keys(true);
+    SqlitePool::connect_with(opts).await
+}
+
+/* =============================================================================
+   Statut
+============================================================================= */
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MigrationState {
+    Applied,
+    Pending,
+    /// Appliquée, mais le fichier a changé depuis (checksum différent).
+    Modified,
+    /// Appliquée en base, mais absente du binaire.
+    Missing,
+}
+
+#[derive(Debug, Clone)]
+pub struct MigrationStatus {
+    pub version:     i64,
+    pub description: String,
+    pub state:       MigrationState,
+}
+
+/// Lecture seule : sans table `_sqlx_migrations` (base vierge), tout est en attente.
+/// Sert aussi à la sonde `/health/ready`, qui ne doit rien créer.
+pub async fn status(db: &SqlitePool) -> Result<Vec<MigrationStatus>, MigrateError> {
+    let mut conn = db.acquire().await?;
+    let has_table: bool = sqlx::query_scalar(
+        "SELECT EXISTS (SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = '_sqlx_migrations')",
+    )
+    .fetch_one(&mut *conn)
+    .await?;
+    let mut applied: HashMap<i64, _> = if has_table {
+        conn.list_applied_migrations()
+            .await?
+            .into_iter()
+            .map(|m| (m.version, m.checksum))
+            .collect()
+    } else {
+        HashMap::new()
+    };
+
+    let mut out: Vec<MigrationStatus> = MIGRATOR
+        .iter()
+        .filter(|m| m.migration_type.is_up_migration())
+        .map(|m| {
+            let state = match applied.remove(&m.version) {
+                None => MigrationState::Pending,
+                Some(sum) if sum == m.checksum => MigrationState::Applied,
+                Some(_) => MigrationState::Modified,
+            };
+            MigrationStatus { version: m.version, description: m.description.to_string(), state }
+        })
+        .collect();
+
+    out.extend(applied.into_keys().map(|version| MigrationStatus {
+        version,
+        description: String::new(),
+        state: MigrationState::Missing,
+    }));
+    out.sort_by_key(|m| m.version);
+    Ok(out)
+}
+
+/* =============================================================================
+   Run
+============================================================================= */
+
+/// Applique les migrations en attente.
+///
+/// Si une migration déjà appliquée a été modifiée et que `dev_reset` est actif
+/// (`DEV_RESET_DB_ON_MIGRATION_MISMATCH=true`), la base est sauvegardée
+/// (`<fichier>.bak-<horodatage>`) puis recréée. Renvoie le pool à utiliser ensuite.
+pub async fn run(url: &str, db: SqlitePool, dev_reset: bool) -> anyhow::Result<SqlitePool> {
+    match MIGRATOR.run(&db).await {
+        Ok(()) => Ok(db),
+        Err(MigrateError::VersionMismatch(v)) if dev_reset => {
+            tracing::warn!(version = v, "migration modifiée détectée (DEV) : sauvegarde puis réinitialisation de la DB");
+            let db = reset_with_backup(url, db).await?;
+            MIGRATOR.run(&db).await.context("migrations après réinitialisation")?;
+            Ok(db)
+        }
+        Err(e @ MigrateError::VersionMismatch(_)) => Err(anyhow::Error::new(e).context(
+            "NE JAMAIS réécrire une migration appliquée : crée-en une nouvelle \
+             (en dev : DEV_RESET_DB_ON_MIGRATION_MISMATCH=1)",
+        )),
+        Err(e) => Err(e.into()),
+    }
+}
+
+async fn reset_with_backup(url: &str, db: SqlitePool) -> anyhow::Result<SqlitePool> {
+    let path: PathBuf = SqliteConnectOptions::from_str(url)?.get_filename().into_owned();
+    anyhow::ensure!(
+        !path.as_os_str().is_empty() && path.as_os_str() != ":memory:",
+        "base en mémoire : rien à réinitialiser"
+    );
+
+    // VACUUM INTO produit une copie cohérente même si des pages sont encore dans le WAL.
+    let stamp = chrono::Utc::now().format("%Y%m%d-%H%M%S");
+    let backup = PathBuf::from(format!("{}.bak-{stamp}", path.display()));
+    sqlx::query("VACUUM INTO ?")
+        .bind(backup.to_string_lossy().into_owned())
+        .execute(&db)
+        .await
+        .with_context(|| format!("sauvegarde vers {}", backup.display()))?;
+    tracing::info!(backup = %backup.display(), "sauvegarde DB");
+
+    db.close().await;
+    for suffix in ["", "-wal", "-shm"] {
+        let f = PathBuf::from(format!("{}{suffix}", path.display()));
+        if f.exists() {
+            std::fs::remove_file(&f).with_context(|| format!("suppression {}", f.display()))?;
+        }
+    }
+
+    Ok(connect(url).await?)
+}
@@ -0,0 +1,58 @@
+//! Langue de l'interface (pages, messages d'erreur) : préférence enregistrée de
+//! l'utilisateur connecté (`users.locale`), sinon `Accept-Language`, sinon la langue
+//! par défaut. Les catalogues sont dans le crate `rustfolio-i18n`, partagé avec le SPA.
+//!
+//! À ne pas confondre avec [`crate::locale`] (langue du *contenu* des CV).
+
+use axum::{
+    async_trait,
+    extract::FromRequestParts,
+    http::{header, request::Parts, HeaderMap},
+};
+use axum_extra::extract::cookie::CookieJar;
+
+pub use rustfolio_i18n::{Lang, Tr};
+
+use crate::state::AppState;
+
+/// Langue négociée depuis les seuls en-têtes (`Accept-Language`).
+pub fn from_headers(headers: &HeaderMap) -> Tr {
+    let lang = headers
+        .get(header::ACCEPT_LANGUAGE)
+        .and_then(|v| v.to_str().ok())
+        .and_then(Lang::negotiate)
+        .unwrap_or_default();
+    Tr(lang)
+}
+
+/// Préférence enregistrée pour la session `sid`, si elle existe.
+async fn preference(st: &AppState, sid: &str) -> Option<Lang> {
+    let locale = sqlx::query_scalar!(
+        r#"SELECT u.locale FROM sessions s JOIN users u ON u.id = s.user_id
+           WHERE s.id = ? AND s.expires_at > CURRENT_TIMESTAMP"#,
+        sid
+    )
+    .fetch_optional(&st.db)
+    .await
+    .ok()??;
+    locale.as_deref().and_then(Lang::parse)
+}
+
+/// Extracteur : traducteur de la requête (n'échoue jamais).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UiLang(pub Tr);
+
+#[async_trait]
+impl FromRequestParts<AppState> for UiLang {
+    type Rejection = std::convert::Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, st: &AppState) -> Result<Self, Self::Rejection> {
+        let jar = CookieJar::from_headers(&parts.headers);
+        if let Some(sid) = jar.get("sid") {
+            if let Some(lang) = preference(st, sid.value()).await {
+                return Ok(Self(Tr(lang)));
+            }
+        }
+        Ok(Self(from_headers(&parts.headers)))
+    }
+}
@@ -0,0 +1,119 @@
+//! Cœur de l'application : modules + construction du `Router`.
+//! `main.rs` ne fait que brancher la DB et écouter ; les tests d'intégration
+//! (`tests/`) montent le même `app()` sur une base `sqlite::memory:`.
+
+pub mod config;
+pub mod db;
+pub mod mail;
+pub mod metrics;
+pub mod state;
+pub mod telemetry;
+pub mod templates;
+pub mod middleware;
+pub mod types;
+pub mod uploads;
+pub use rustfolio_types::rich_text;
+pub mod locale;
+pub mod i18n;
+pub mod cv_page;
+pub mod repo;
+pub mod security;
+pub mod routes {
+    pub mod pages;
+    pub mod api;
+    pub mod health;
+    pub mod auth;
+    pub mod profile;
+    pub mod cv; 
+    pub mod cvs;
+    pub mod public_cv;
+    pub mod cv_normalized;
+    pub mod skills;
+    pub mod catalog;
+    pub mod uploads;
+    pub mod documents;
+    pub mod portfolio;
+    pub mod listing;
+}
+
+use axum::{
+    http::{header, HeaderValue},
+    middleware::{from_fn, from_fn_with_state},
+    routing::get,
+    Router,
+};
+use tower_http::{services::ServeDir, set_header::SetResponseHeaderLayer};
+
+use crate::middleware::{hide_documents, require_auth};
+use crate::routes::{api, auth, catalog, cv, cv_normalized, cvs, documents, health, pages, portfolio, profile, public_cv, skills};
+use crate::state::AppState;
+
+pub fn app(state: AppState) -> Router {
+    // --- Assets statiques globaux ---
+    // Sert /assets depuis le dossier local "assets"
+    // et applique automatiquement le bon Content-Type (dont application/wasm)
+    // + support des fichiers précompressés .br/.gz
+    let assets_router = Router::new()
+        .nest_service(
+            "/assets",
+            ServeDir::new("assets")
+                .precompressed_br()
+                .precompressed_gzip(),
+        );
+
+    // Images téléversées : noms dérivés du contenu, donc cache "immutable" ; les PDF
+    // voisins ne sont servis que par `/documents/…`
+    let uploads_router = Router::new()
+        .nest_service(uploads::PUBLIC_PREFIX, ServeDir::new(&state.config.uploads.dir))
+        .layer(from_fn(hide_documents))
+        .layer(SetResponseHeaderLayer::overriding(
+            header::CACHE_CONTROL,
+            HeaderValue::from_static("public, max-age=31536000, immutable"),
+        ));
+
+    let dashboard_router = Router::new()
+        .route("/dashboard", get(pages::dashboard_shell))
+        .route("/dashboard/*rest", get(pages::dashboard_shell))
+        .route_layer(from_fn_with_state(state.clone(), require_auth));
+
+    let router = Router::new()
+        .route("/", get(pages::home))
+        .route("/portfolio", get(pages::portfolio_page))
+        .route("/api/info", get(api::info_handler))
+        .route("/api/projects", get(api::api_projects))
+        .route("/documents/:id/:name", get(documents::download))
+        .route("/health", get(health::health))
+        .route("/health/live", get(health::live))
+        .route("/health/ready", get(health::ready))
+        .route("/metrics", get(metrics::handler))
+        .nest("/auth", auth::router())
+        .nest("/api", profile::router())
+        .nest("/api", cv::router())
+        .nest("/api", cvs::router())
+        .nest("/api", cv_normalized::router())
+        .nest("/api", public_cv::router())
+        .nest("/api", skills::routes()) 
+        .nest("/api", catalog::router())
+        .nest("/api", routes::uploads::router(&state.config))
+        .nest("/api", documents::router(&state.config))
+        .nest("/api", portfolio::router())
+        .merge(public_cv::pages())
+        .merge(uploads_router)
+        .merge(assets_router)
+        .merge(dashboard_router)
+        // après toutes les routes : MatchedPath n'existe qu'une fois la route choisie
+        .route_layer(from_fn_with_state(state.clone(), metrics::track))
+        .layer(security::compression());
+
+    let router = security::headers(&state.config)
+        .into_iter()
+        .fold(router, |r, h| r.layer(h));
+
+    router
+        .layer(security::cors(&state.config))
+        // la dernière couche ajoutée est la plus externe : l'id est posé avant le span
+        .layer(telemetry::propagate_request_id())
+        .layer(telemetry::trace())
+        .layer(telemetry::set_request_id())
+        .with_state(state)
+}
@@ -0,0 +1,164 @@
+//! Langues du contenu des CV (profil, expériences, projets).
+//!
+//! Les colonnes habituelles portent la langue par défaut ([`DEFAULT_LOCALE`]) ; les
+//! traductions sont rangées par code de langue dans `translations`, champ par champ.
+//! À la lecture d'une langue, chaque champ traduisible prend la première valeur
+//! trouvée parmi `en-GB` → `en` → langue par défaut ([`Localize`]).
+
+use std::collections::BTreeMap;
+
+use axum::{
+    async_trait,
+    extract::{FromRequestParts, Query},
+    http::{request::Parts, StatusCode},
+};
+use serde::Deserialize;
+
+use crate::types::{CvData, Experience, Profile, Project, Translations};
+
+pub const DEFAULT_LOCALE: &str = "fr";
+
+/// Code de langue normalisé (`en`, `pt-BR`) : 2 ou 3 lettres, puis une région
+/// facultative de 2 lettres ou 3 chiffres. `None` si la forme est invalide.
+pub fn normalize(tag: &str) -> Option<String> {
+    let mut parts = tag.trim().split(['-', '_']);
+    let lang = parts.next()?;
+    if !(2..=3).contains(&lang.len()) || !lang.bytes().all(|b| b.is_ascii_alphabetic()) {
+        return None;
+    }
+    let mut out = lang.to_ascii_lowercase();
+    match (parts.next(), parts.next()) {
+        (None, _) => {}
+        (Some(region), None)
+            if (region.len() == 2 && region.bytes().all(|b| b.is_ascii_alphabetic()))
+                || (region.len() == 3 && region.bytes().all(|b| b.is_ascii_digit())) =>
+        {
+            out.push('-');
+            out.push_str(&region.to_ascii_uppercase());
+        }
+        _ => return None,
+    }
+    Some(out)
+}
+
+/// Langues à essayer pour `locale`, de la plus précise à la plus générale (sans la
+/// langue par défaut, qui est dans les colonnes).
+fn candidates(locale: &str) -> Vec<&str> {
+    let mut out = vec![locale];
+    if let Some((lang, _)) = locale.split_once('-') {
+        out.push(lang);
+    }
+    out.retain(|l| *l != DEFAULT_LOCALE);
+    out
+}
+
+/// Première traduction de `field` disponible pour `locale`.
+fn pick<'a, T, V>(translations: &'a Translations<T>, locale: &str, field: impl Fn(&'a T) -> Option<&'a V>) -> Option<&'a V> {
+    candidates(locale).into_iter().find_map(|l| translations.get(l).and_then(&field))
+}
+
+/// Vérifie les clés d'un jeu de traductions : langues valides, normalisées, et jamais
+/// la langue par défaut (elle est dans les colonnes). Message prêt pour un 422.
+pub fn check<T>(translations: Option<Translations<T>>) -> Result<Option<Translations<T>>, String> {
+    let Some(translations) = translations else { return Ok(None) };
+    let mut out = BTreeMap::new();
+    for (tag, text) in translations {
+        let locale = normalize(&tag).ok_or_else(|| format!("langue invalide : « {tag} »"))?;
+        if locale == DEFAULT_LOCALE {
+            return Err(format!("« {DEFAULT_LOCALE} » est la langue par défaut : pas de traduction"));
+        }
+        out.insert(locale, text);
+    }
+    Ok(Some(out))
+}
+
+/// Contenu lisible dans une langue donnée.
+pub trait Localize {
+    /// Remplace les champs traduisibles par leur version dans `locale` (repli champ
+    /// par champ) et retire les traductions.
+    fn localize(&mut self, locale: &str);
+}
+
+impl Localize for Profile {
+    fn localize(&mut self, locale: &str) {
+        if let Some(t) = self.translations.take() {
+            if let Some(v) = pick(&t, locale, |t| t.title.as_ref()) {
+                self.title = v.clone();
+            }
+            if let Some(v) = pick(&t, locale, |t| t.summary.as_ref()) {
+                self.summary = v.clone();
+            }
+        }
+    }
+}
+
+impl Localize for Experience {
+    fn localize(&mut self, locale: &str) {
+        if let Some(t) = self.translations.take() {
+            if let Some(v) = pick(&t, locale, |t| t.title.as_ref()) {
+                self.title = v.clone();
+            }
+            if let Some(v) = pick(&t, locale, |t| t.tasks.as_ref()) {
+                self.tasks = v.clone();
+            }
+        }
+    }
+}
+
+impl Localize for Project {
+    fn localize(&mut self, locale: &str) {
+        if let Some(t) = self.translations.take() {
+            if let Some(v) = pick(&t, locale, |t| t.description.as_ref()) {
+                self.description = v.clone();
+            }
+        }
+    }
+}
+
+impl<T: Localize> Localize for Vec<T> {
+    fn localize(&mut self, locale: &str) {
+        self.iter_mut().for_each(|x| x.localize(locale));
+    }
+}
+
+impl Localize for CvData {
+    fn localize(&mut self, locale: &str) {
+        self.profile.localize(locale);
+        self.experiences.localize(locale);
+        self.projects.localize(locale);
+    }
+}
+
+/// `?locale=` des lectures : absent → langue par défaut et traductions incluses
+/// (éditeur) ; présent → contenu dans cette langue, sans les traductions. 400 si le
+/// code est invalide.
+#[derive(Debug, Clone, Default)]
+pub struct LocaleQuery(pub Option<String>);
+
+impl LocaleQuery {
+    pub fn apply<T: Localize>(&self, mut value: T) -> T {
+        if let Some(locale) = &self.0 {
+            value.localize(locale);
+        }
+        value
+    }
+}
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for LocaleQuery {
+    type Rejection = (StatusCode, String);
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        #[derive(Deserialize)]
+        struct Q {
+            locale: Option<String>,
+        }
+        let Query(q) = Query::<Q>::try_from_uri(&parts.uri).map_err(|e| (StatusCode::BAD_REQUEST, e.body_text()))?;
+        match q.locale.as_deref().map(str::trim).filter(|l| !l.is_empty()) {
+            None => Ok(Self(None)),
+            Some(tag) => normalize(tag)
+                .map(|l| Self(Some(l)))
+                .ok_or_else(|| (StatusCode::BAD_REQUEST, format!("`locale` invalide : {tag}"))),
+        }
+    }
+}
@@ -0,0 +1,42 @@
+//! Envoi d'e-mails.
+//!
+//! Les handlers ne parlent jamais SMTP : ils rendent un [`Email`] (cf. [`templates`])
+//! et le déposent dans l'[`Outbox`] ; le worker l'envoie ensuite via un [`Mailer`]
+//! (SMTP, fichiers `.eml` ou log selon `EMAIL_MODE`) et réessaie en cas d'échec.
+
+use std::sync::Arc;
+
+use axum::async_trait;
+
+use crate::config::{EmailConfig, EmailTransport};
+
+pub mod outbox;
+pub mod templates;
+mod transport;
+
+pub use outbox::Outbox;
+pub use transport::{FileMailer, LogMailer, SmtpMailer};
+
+/// Contenu d'un e-mail déjà rendu (texte + HTML).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Email {
+    pub to:      String,
+    pub subject: String,
+    pub text:    String,
+    pub html:    String,
+}
+
+#[async_trait]
+pub trait Mailer: Send + Sync {
+    async fn send(&self, email: &Email) -> anyhow::Result<()>;
+}
+
+/// Construit le transport choisi dans la config (une seule fois, au démarrage).
+pub fn from_config(cfg: &EmailConfig) -> anyhow::Result<Arc<dyn Mailer>> {
+    let from = cfg.from.parse()?;
+    Ok(match &cfg.transport {
+        EmailTransport::Log => Arc::new(LogMailer::new(from)),
+        EmailTransport::File(dir) => Arc::new(FileMailer::new(dir, from)),
+        EmailTransport::Smtp(smtp) => Arc::new(SmtpMailer::new(smtp, from)?),
+    })
+}
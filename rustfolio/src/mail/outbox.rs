@@ -0,0 +1,102 @@
+//! Outbox persistée : un SMTP capricieux ne fait plus échouer l'inscription.
+//!
+//! `enqueue` écrit en base et réveille le worker ; le worker envoie ce qui est dû,
+//! puis replanifie les échecs avec un backoff exponentiel (30 s, 1 min, 2 min…,
+//! plafonné à 6 h) jusqu'à [`MAX_ATTEMPTS`].
+
+use std::{sync::Arc, time::Duration};
+
+use chrono::{DateTime, Utc};
+use tokio::{sync::Notify, task::JoinHandle};
+
+use super::{Email, Mailer};
+use crate::repo::{OutboxRepo, RepoResult};
+
+/// Au-delà, le message reste en base (avec `last_error`) mais n'est plus retenté.
+pub const MAX_ATTEMPTS: i64 = 8;
+const BASE_DELAY_SECS: i64 = 30;
+const MAX_DELAY_SECS: i64 = 6 * 3600;
+/// Filet de sécurité : relit la table même sans `enqueue` (retries planifiés).
+const POLL_EVERY: Duration = Duration::from_secs(30);
+const BATCH: i64 = 20;
+
+/// Format de `CURRENT_TIMESTAMP` (UTC), comparable en texte avec les colonnes SQLite.
+fn sql_ts(t: DateTime<Utc>) -> String {
+    t.format("%Y-%m-%d %H:%M:%S").to_string()
+}
+
+/// Délai avant la tentative suivante, `attempts` échecs ayant déjà eu lieu.
+pub fn backoff(attempts: i64) -> chrono::Duration {
+    let secs = BASE_DELAY_SECS.saturating_mul(1 << attempts.clamp(0, 20));
+    chrono::Duration::seconds(secs.min(MAX_DELAY_SECS))
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Delivery {
+    pub sent:   usize,
+    pub failed: usize,
+}
+
+#[derive(Clone)]
+pub struct Outbox {
+    repo:   Arc<dyn OutboxRepo>,
+    mailer: Arc<dyn Mailer>,
+    wake:   Arc<Notify>,
+}
+
+impl Outbox {
+    pub fn new(repo: Arc<dyn OutboxRepo>, mailer: Arc<dyn Mailer>) -> Self {
+        Self { repo, mailer, wake: Arc::new(Notify::new()) }
+    }
+
+    /// Persiste l'e-mail puis réveille le worker : l'envoi n'est pas attendu.
+    pub async fn enqueue(&self, email: &Email) -> RepoResult<i64> {
+        let id = self.repo.enqueue(email).await?;
+        self.wake.notify_one();
+        Ok(id)
+    }
+
+    pub async fn deliver_due(&self) -> RepoResult<Delivery> {
+        self.deliver_due_at(Utc::now()).await
+    }
+
+    /// Envoie les messages dus à `now` (paramétrable pour tester les retries).
+    pub async fn deliver_due_at(&self, now: DateTime<Utc>) -> RepoResult<Delivery> {
+        let mut out = Delivery::default();
+        for item in self.repo.due(&sql_ts(now), BATCH).await? {
+            match self.mailer.send(&item.email).await {
+                Ok(()) => {
+                    self.repo.mark_sent(item.id).await?;
+                    out.sent += 1;
+                }
+                Err(e) => {
+                    let attempts = item.attempts + 1;
+                    let retry_at = (attempts < MAX_ATTEMPTS).then(|| sql_ts(now + backoff(item.attempts)));
+                    match &retry_at {
+                        Some(at) => tracing::warn!(id = item.id, to = %item.email.to, error = %format!("{e:#}"), retry_at = %at, "envoi d'e-mail échoué"),
+                        None => tracing::error!(id = item.id, to = %item.email.to, error = %format!("{e:#}"), attempts, "e-mail abandonné"),
+                    }
+                    self.repo.mark_failed(item.id, &format!("{e:#}"), retry_at.as_deref()).await?;
+                    out.failed += 1;
+                }
+            }
+        }
+        Ok(out)
+    }
+
+    /// Boucle d'envoi en tâche de fond (une par process).
+    pub fn spawn_worker(&self) -> JoinHandle<()> {
+        let outbox = self.clone();
+        tokio::spawn(async move {
+            loop {
+                if let Err(e) = outbox.deliver_due().await {
+                    tracing::error!(error = %e, "outbox");
+                }
+                tokio::select! {
+                    _ = outbox.wake.notified() => {}
+                    _ = tokio::time::sleep(POLL_EVERY) => {}
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,27 @@
+//! Gabarits Askama des e-mails : une version HTML (`templates/emails/*.html`, qui étend
+//! `emails/base.html`) et une version texte (`*.txt`, non échappée) par message.
+
+use askama::Template;
+
+use super::Email;
+
+fn render(to: &str, subject: &str, text: impl Template, html: impl Template) -> askama::Result<Email> {
+    Ok(Email { to: to.into(), subject: subject.into(), text: text.render()?, html: html.render()? })
+}
+
+#[derive(Template)]
+#[template(path = "emails/verify.txt")]
+struct VerifyText<'a> {
+    verify_url: &'a str,
+}
+
+#[derive(Template)]
+#[template(path = "emails/verify.html")]
+struct VerifyHtml<'a> {
+    verify_url: &'a str,
+}
+
+/// E-mail de vérification envoyé à l'inscription.
+pub fn verification(to: &str, verify_url: &str) -> askama::Result<Email> {
+    render(to, "Vérifie ton adresse email", VerifyText { verify_url }, VerifyHtml { verify_url })
+}
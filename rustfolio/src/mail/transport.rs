@@ -0,0 +1,105 @@
+use std::path::{Path, PathBuf};
+
+use axum::async_trait;
+use lettre::message::{Mailbox, Message, MultiPart};
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::transport::smtp::extension::ClientId;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Tokio1Executor};
+use uuid::Uuid;
+
+use super::{Email, Mailer};
+use crate::config::SmtpConfig;
+
+/// Message multipart/alternative (texte d'abord, HTML ensuite).
+fn build_message(from: &Mailbox, email: &Email) -> anyhow::Result<Message> {
+    Ok(Message::builder()
+        .from(from.clone())
+        .to(email.to.parse()?)
+        .subject(&email.subject)
+        .multipart(MultiPart::alternative_plain_html(email.text.clone(), email.html.clone()))?)
+}
+
+/* =============================================================================
+   SMTP (STARTTLS), transport async construit une fois et réutilisé
+============================================================================= */
+
+pub struct SmtpMailer {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from:      Mailbox,
+}
+
+impl SmtpMailer {
+    pub fn new(cfg: &SmtpConfig, from: Mailbox) -> anyhow::Result<Self> {
+        let transport = AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&cfg.host)?
+            .port(cfg.port)
+            .credentials(Credentials::new(cfg.user.clone(), cfg.pass.clone()))
+            .hello_name(ClientId::Domain(cfg.hello_name.clone()))
+            .build();
+        Ok(Self { transport, from })
+    }
+}
+
+#[async_trait]
+impl Mailer for SmtpMailer {
+    async fn send(&self, email: &Email) -> anyhow::Result<()> {
+        let resp = self.transport.send(build_message(&self.from, email)?).await?;
+        let msg = resp.message().collect::<Vec<_>>().join(" ");
+        tracing::info!(to = %email.to, code = %resp.code(), "SMTP : {msg}");
+        Ok(())
+    }
+}
+
+/* =============================================================================
+   Fichiers .eml (dev, tests)
+============================================================================= */
+
+pub struct FileMailer {
+    dir:  PathBuf,
+    from: Mailbox,
+}
+
+impl FileMailer {
+    pub fn new(dir: impl AsRef<Path>, from: Mailbox) -> Self {
+        Self { dir: dir.as_ref().to_path_buf(), from }
+    }
+}
+
+#[async_trait]
+impl Mailer for FileMailer {
+    async fn send(&self, email: &Email) -> anyhow::Result<()> {
+        let raw = build_message(&self.from, email)?.formatted();
+        tokio::fs::create_dir_all(&self.dir).await?;
+        // horodatage en tête : `ls` les range dans l'ordre d'envoi
+        let name = format!("{}-{}.eml", chrono::Utc::now().format("%Y%m%d-%H%M%S"), Uuid::new_v4());
+        tokio::fs::write(self.dir.join(name), raw).await?;
+        Ok(())
+    }
+}
+
+/* =============================================================================
+   Log (dev) : rien n'est envoyé
+============================================================================= */
+
+pub struct LogMailer {
+    from: Mailbox,
+}
+
+impl LogMailer {
+    pub fn new(from: Mailbox) -> Self {
+        Self { from }
+    }
+}
+
+#[async_trait]
+impl Mailer for LogMailer {
+    async fn send(&self, email: &Email) -> anyhow::Result<()> {
+        tracing::info!(
+            from = %self.from,
+            to = %email.to,
+            subject = %email.subject,
+            "e-mail (mode log, non envoyé) :\n{}",
+            email.text
+        );
+        Ok(())
+    }
+}
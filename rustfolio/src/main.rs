@@ -1,91 +1,216 @@
-mod data;
-mod state;
-mod templates;
-mod middleware;
-mod types;
-mod routes {
-    pub mod pages;
-    pub mod api;
-    pub mod health;
-    pub mod auth;
-    pub mod profile;
-    pub mod cv; 
-    pub mod cv_normalized;
-    pub mod skills;
-}
+use anyhow::Context;
+use axum::serve;
+use tokio::net::TcpListener;
 
-use std::{net::SocketAddr, sync::Arc};
+use rustfolio::config::{CliArgs, Config};
+use rustfolio::db::{self, MigrationState};
+use rustfolio::mail;
+use rustfolio::state::AppState;
 
-use axum::{
-    middleware::from_fn_with_state,
-    routing::get,
-    serve, Router,
-};
-use sqlx::SqlitePool;
-use tokio::net::TcpListener;
-use tower_http::services::ServeDir;
+const USAGE: &str = "\
+Usage:
+  rustfolio [options] [serve]           démarre le serveur
+  rustfolio [options] config            affiche la configuration résolue (secrets masqués)
+  rustfolio [options] migrate status    liste les migrations (appliquée / en attente / modifiée / absente)
+  rustfolio [options] migrate run       applique les migrations en attente (sauvegarder la base avant :
+                                        pas de retour arrière)
+  rustfolio [options] healthcheck [url] sonde /health/ready (code 0 si prêt) ; pour le HEALTHCHECK Docker
 
-use crate::middleware::require_auth;
-use crate::routes::{api, auth, health, pages, profile};
-use crate::state::AppState;
-use crate::routes::skills;
-use crate::routes::cv_normalized;
+Options (prioritaires sur l'environnement et le fichier de config) :
+  --config <fichier>     fichier TOML (sinon RUSTFOLIO_CONFIG), cf. rustfolio.example.toml
+  --bind <ip:port>       adresse d'écoute (BIND_ADDR)
+  --base-url <url>       URL publique (PUBLIC_BASE_URL)
+  --migrate              applique les migrations avant de servir (RUN_MIGRATIONS)";
 
 #[tokio::main]
 async fn main() {
     let _ = dotenvy::dotenv();
 
-    let db_url = std::env::var("DATABASE_URL").expect("DATABASE_URL not set");
-    let db = SqlitePool::connect(&db_url)
-        .await
-        .expect("failed to connect DB");
-    sqlx::query("PRAGMA foreign_keys = ON;")
-        .execute(&db)
-        .await
-        .expect("enable FKs");
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let (cli, rest) = match CliArgs::parse(&args) {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("❌ {e}\n");
+            usage()
+        }
+    };
+    let rest: Vec<&str> = rest.iter().map(String::as_str).collect();
+
+    if matches!(rest.as_slice(), ["-h" | "--help" | "help"]) {
+        println!("{USAGE}");
+        return;
+    }
+
+    // Image distroless : ni curl ni shell, le binaire se sonde lui-même.
+    // Pas de chargement de la config complète : seule l'adresse d'écoute compte.
+    if let ["healthcheck", url @ ..] = rest.as_slice() {
+        let url = url.first().map(|u| u.to_string()).unwrap_or_else(|| default_ready_url(&cli));
+        if let Err(e) = healthcheck(&url).await {
+            eprintln!("❌ {url} : {e:#}");
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    // Validée une fois pour toutes : on liste tous les problèmes avant de quitter.
+    let config = match Config::load(&cli) {
+        Ok(c) => c,
+        Err(report) => {
+            eprint!("❌ {report}");
+            std::process::exit(1);
+        }
+    };
+
+    rustfolio::telemetry::init(config.log_format);
+
+    let res = match rest.as_slice() {
+        ["migrate", "status"] => migrate_status(&config).await,
+        ["migrate", "run"] => migrate_run(&config).await,
+        ["config"] => {
+            println!("{config}");
+            Ok(())
+        }
+        [] | ["serve"] => serve_app(config).await,
+        _ => usage(),
+    };
+
+    if let Err(e) = res {
+        eprintln!("❌ {e:#}");
+        std::process::exit(1);
+    }
+}
+
+fn usage() -> ! {
+    eprintln!("{USAGE}");
+    std::process::exit(2);
+}
+
+async fn serve_app(config: Config) -> anyhow::Result<()> {
+    let mut db = db::connect(&config.database_url).await?;
+
+    if config.run_migrations {
+        db = db::run(&config.database_url, db, config.dev_reset_db_on_migration_mismatch).await?;
+    } else {
+        let pending = db::status(&db)
+            .await?
+            .iter()
+            .filter(|m| m.state == MigrationState::Pending)
+            .count();
+        if pending > 0 {
+            tracing::warn!(pending, "migration(s) en attente : lance `rustfolio migrate run` ou démarre avec --migrate");
+        }
+    }
 
     // --- State partagé ---
-    let state = AppState {
-        db,
-        _experiences: Arc::new(Vec::<data::Experience>::new()),
-        projects:     Arc::new(Vec::<data::Project>::new()),
-        skills:       Arc::new(Vec::<data::Skill>::new()),
+    let addr = config.bind_addr;
+    let grace = config.shutdown_grace;
+    let mailer = mail::from_config(&config.email)?;
+    let state = AppState::new(db.clone(), config, mailer);
+    let worker = state.outbox.spawn_worker();
+    let app = rustfolio::app(state);
+
+    tracing::info!(%addr, "Listening on http://{addr}");
+    let listener = TcpListener::bind(addr).await?;
+
+    // SIGTERM/Ctrl+C : on n'accepte plus de connexions et on laisse finir les requêtes
+    // en cours, au plus `grace` ; au-delà, arrêt forcé.
+    let (stopping_tx, stopping_rx) = tokio::sync::oneshot::channel::<()>();
+    let server = serve(listener, app).with_graceful_shutdown(async move {
+        shutdown_signal().await;
+        let _ = stopping_tx.send(());
+    });
+    tokio::select! {
+        res = server => res?,
+        _ = async {
+            let _ = stopping_rx.await;
+            tokio::time::sleep(grace).await;
+        } => tracing::warn!(grace_secs = grace.as_secs(), "requêtes encore en cours après le délai de grâce : arrêt forcé"),
+    }
+
+    // Le worker peut être coupé en plein envoi : l'e-mail reste dû dans l'outbox.
+    worker.abort();
+    db.close().await;
+    tracing::info!("arrêt propre");
+    Ok(())
+}
+
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        let _ = tokio::signal::ctrl_c().await;
+    };
+    #[cfg(unix)]
+    let term = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut s) => {
+                s.recv().await;
+            }
+            Err(e) => {
+                tracing::error!(error = %e, "impossible d'écouter SIGTERM");
+                std::future::pending::<()>().await
+            }
+        }
+    };
+    #[cfg(not(unix))]
+    let term = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => tracing::info!("Ctrl+C reçu, arrêt en cours"),
+        _ = term => tracing::info!("SIGTERM reçu, arrêt en cours"),
+    }
+}
+
+async fn migrate_status(config: &Config) -> anyhow::Result<()> {
+    let db = db::connect(&config.database_url).await?;
+    for m in db::status(&db).await? {
+        let state = match m.state {
+            MigrationState::Applied => "appliquée",
+            MigrationState::Pending => "en attente",
+            MigrationState::Modified => "MODIFIÉE",
+            MigrationState::Missing => "ABSENTE du binaire",
+        };
+        println!("{:04}  {:<40} {state}", m.version, m.description);
+    }
+    Ok(())
+}
+
+async fn migrate_run(config: &Config) -> anyhow::Result<()> {
+    let db = db::connect(&config.database_url).await?;
+    db::run(&config.database_url, db, config.dev_reset_db_on_migration_mismatch).await?;
+    println!("✅ migrations à jour");
+    Ok(())
+}
+
+fn default_ready_url(cli: &CliArgs) -> String {
+    let port = cli
+        .bind
+        .clone()
+        .or_else(|| std::env::var("BIND_ADDR").ok())
+        .and_then(|b| b.parse::<std::net::SocketAddr>().ok())
+        .map_or(8080, |a| a.port());
+    format!("http://127.0.0.1:{port}/health/ready")
+}
+
+/// GET HTTP/1.1 minimal (pas de client HTTP dans les dépendances) ; Ok si 2xx.
+async fn healthcheck(url: &str) -> anyhow::Result<()> {
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    let rest = url.strip_prefix("http://").context("seul http:// est supporté")?;
+    let (host, path) = rest.split_once('/').map_or((rest, "/".to_string()), |(h, p)| (h, format!("/{p}")));
+
+    let io = async {
+        let mut stream = tokio::net::TcpStream::connect(host).await?;
+        let req = format!("GET {path} HTTP/1.1\r\nHost: {host}\r\nConnection: close\r\n\r\n");
+        stream.write_all(req.as_bytes()).await?;
+        let mut res = Vec::new();
+        stream.read_to_end(&mut res).await?;
+        anyhow::Ok(res)
     };
+    let res = tokio::time::timeout(std::time::Duration::from_secs(5), io).await.context("timeout")??;
 
-    // --- Assets statiques globaux ---
-    // Sert /assets depuis le dossier local "assets"
-    // et applique automatiquement le bon Content-Type (dont application/wasm)
-    // + support des fichiers précompressés .br/.gz
-    let assets_router = Router::new()
-        .nest_service(
-            "/assets",
-            ServeDir::new("assets")
-                .precompressed_br()
-                .precompressed_gzip(),
-        );
-
-    let dashboard_router = Router::new()
-        .route("/dashboard", get(pages::dashboard_shell))
-        .route("/dashboard/*rest", get(pages::dashboard_shell))
-        .route_layer(from_fn_with_state(state.clone(), require_auth));
-
-    let app = Router::new()
-        .route("/", get(pages::home))
-        .route("/api/info", get(api::info_handler))
-        .route("/api/projects", get(api::api_projects))
-        .route("/health", get(health::health))
-        .nest("/auth", auth::router())
-        .nest("/api", profile::router())
-        .nest("/api", routes::cv::router())
-        .nest("/api", routes::cv_normalized::router())
-        .nest("/api/cv", cv_normalized::router())
-        .nest("/api", skills::routes()) 
-        .merge(assets_router)
-        .merge(dashboard_router)
-        .with_state(state);
-
-    let addr = SocketAddr::from(([0, 0, 0, 0], 8080));
-    println!("Listening on http://{}", addr);
-    let listener = TcpListener::bind(addr).await.unwrap();
-    serve(listener, app).await.unwrap();
+    let res = String::from_utf8_lossy(&res);
+    let status_line = res.lines().next().unwrap_or_default();
+    let code: u16 = status_line.split_whitespace().nth(1).and_then(|c| c.parse().ok()).unwrap_or(0);
+    anyhow::ensure!((200..300).contains(&code), "{status_line}");
+    println!("✅ {status_line}");
+    Ok(())
 }
@@ -0,0 +1,130 @@
+//! Métriques Prometheus exposées sur `GET /metrics` (format OpenMetrics).
+//!
+//! - `http_requests_total` / `http_request_duration_seconds` par méthode, route
+//!   (le motif `/api/cv/projects/:id`, pas l'URL) et statut ;
+//! - compteurs métier : inscriptions, connexions (succès/échec) et exports.
+
+use std::time::Instant;
+
+use axum::{
+    extract::{MatchedPath, Request, State},
+    http::header,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use prometheus_client::{
+    encoding::{text::encode, EncodeLabelSet},
+    metrics::{
+        counter::Counter,
+        family::Family,
+        histogram::{exponential_buckets, Histogram},
+    },
+    registry::Registry,
+};
+
+use crate::state::AppState;
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct HttpLabels {
+    method: String,
+    route:  String,
+    status: u16,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct OutcomeLabels {
+    outcome: &'static str,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq, EncodeLabelSet)]
+struct KindLabels {
+    kind: &'static str,
+}
+
+type HistogramFamily = Family<HttpLabels, Histogram, fn() -> Histogram>;
+
+pub struct Metrics {
+    registry:      Registry,
+    http_requests: Family<HttpLabels, Counter>,
+    http_latency:  HistogramFamily,
+    signups:       Counter,
+    logins:        Family<OutcomeLabels, Counter>,
+    exports:       Family<KindLabels, Counter>,
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        let mut registry = Registry::default();
+
+        let http_requests = Family::<HttpLabels, Counter>::default();
+        registry.register("http_requests", "Requêtes HTTP traitées", http_requests.clone());
+
+        // 5 ms … ~10 s
+        let http_latency: HistogramFamily =
+            Family::new_with_constructor(|| Histogram::new(exponential_buckets(0.005, 2.0, 12)));
+        registry.register("http_request_duration_seconds", "Durée des requêtes HTTP", http_latency.clone());
+
+        let signups = Counter::default();
+        registry.register("signups", "Comptes créés", signups.clone());
+
+        let logins = Family::<OutcomeLabels, Counter>::default();
+        registry.register("logins", "Tentatives de connexion", logins.clone());
+
+        let exports = Family::<KindLabels, Counter>::default();
+        registry.register("exports", "Exports téléchargés", exports.clone());
+
+        Self { registry, http_requests, http_latency, signups, logins, exports }
+    }
+
+    pub fn signup(&self) {
+        self.signups.inc();
+    }
+
+    pub fn login(&self, ok: bool) {
+        let outcome = if ok { "success" } else { "failure" };
+        self.logins.get_or_create(&OutcomeLabels { outcome }).inc();
+    }
+
+    /// `kind` : format exporté (`profile_json`, …).
+    pub fn export(&self, kind: &'static str) {
+        self.exports.get_or_create(&KindLabels { kind }).inc();
+    }
+
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        encode(&mut out, &self.registry).expect("écriture dans une String");
+        out
+    }
+}
+
+/// `route_layer` : compte et chronomètre chaque requête routée.
+pub async fn track(State(st): State<AppState>, req: Request, next: Next) -> Response {
+    let route = req
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|p| p.as_str().to_owned())
+        .unwrap_or_else(|| "unmatched".into());
+    let method = req.method().to_string();
+    let start = Instant::now();
+
+    let res = next.run(req).await;
+
+    let labels = HttpLabels { method, route, status: res.status().as_u16() };
+    st.metrics.http_requests.get_or_create(&labels).inc();
+    st.metrics.http_latency.get_or_create(&labels).observe(start.elapsed().as_secs_f64());
+    res
+}
+
+/// GET /metrics
+pub async fn handler(State(st): State<AppState>) -> impl IntoResponse {
+    (
+        [(header::CONTENT_TYPE, "application/openmetrics-text; version=1.0.0; charset=utf-8")],
+        st.metrics.render(),
+    )
+}
@@ -1,6 +1,6 @@
 use axum::{
     extract::{FromRequestParts, State},
-    http::Request,
+    http::{Request, StatusCode},
     middleware::Next,
     response::{IntoResponse, Redirect, Response},
 };
@@ -29,3 +29,12 @@ pub async fn require_auth(
         Err(_) => Err(Redirect::to("/auth/login").into_response()),
     }
 }
+
+/// Devant le `ServeDir` des uploads : les PDF y sont rangés à côté des images mais
+/// ne passent que par `GET /documents/:id/:nom` (lien encore actif, `Content-Disposition`).
+pub async fn hide_documents(req: Request<axum::body::Body>, next: Next) -> Response {
+    if req.uri().path().to_ascii_lowercase().ends_with(".pdf") {
+        return StatusCode::NOT_FOUND.into_response();
+    }
+    next.run(req).await
+}
@@ -0,0 +1,393 @@
+//! Couche d'accès aux données.
+//!
+//! Les handlers ne parlent plus SQL : ils passent par ces traits, rangés dans
+//! `AppState::repos`. L'implémentation SQLite vit dans `repo::sqlite` ; une autre
+//! implémentation (mock, Postgres…) n'a qu'à fournir les mêmes traits.
+//!
+//! Toutes les méthodes prennent le `user_id` de la session : une ligne d'un autre
+//! utilisateur est traitée comme inexistante (`false` / `None`).
+
+use std::sync::Arc;
+
+use axum::async_trait;
+use serde::Serialize;
+use sqlx::{Pool, Sqlite};
+
+use crate::mail::Email;
+use crate::types::{Experience, PortfolioItem, Profile, Project, SkillIn, SkillOut, TaskItem, TechItem};
+
+pub use crate::types::{CatalogEntry, CvSummary, HiddenItems};
+
+pub mod sqlite;
+
+pub type RepoResult<T> = sqlx::Result<T>;
+
+/* =============================================================================
+   Catalogue de technologies
+============================================================================= */
+
+/// Résultat de la normalisation d'un nom saisi librement.
+#[derive(Debug, Clone)]
+pub struct Canonical {
+    pub name:     String,
+    pub tech_id:  Option<i64>,
+    pub logo_url: Option<String>,
+}
+
+#[async_trait]
+pub trait CatalogRepo: Send + Sync {
+    /// Autocomplete : préfixe d'abord, puis "contient".
+    async fn search(&self, q: &str, limit: i64) -> RepoResult<Vec<CatalogEntry>>;
+    /// Ramène un nom libre à son entrée du catalogue si un alias correspond.
+    async fn canonicalize(&self, raw: &str) -> RepoResult<Canonical>;
+}
+
+/* =============================================================================
+   Profil (table profiles)
+============================================================================= */
+
+#[async_trait]
+pub trait ProfileRepo: Send + Sync {
+    /// Profil de l'utilisateur (traductions comprises), ou un profil vide s'il n'existe
+    /// pas encore.
+    async fn get(&self, user_id: &str) -> RepoResult<Profile>;
+    /// `p.translations` : `None` les garde, `Some` les remplace.
+    async fn upsert(&self, user_id: &str, p: &Profile) -> RepoResult<()>;
+    /// Langues traduites (profil, expériences ou projets), hors langue par défaut.
+    async fn locales(&self, user_id: &str) -> RepoResult<Vec<String>>;
+
+    /// Adresse publique (`/u/<slug>/…`) ; `None` : CV non publié.
+    async fn slug(&self, user_id: &str) -> RepoResult<Option<String>>;
+    /// Un slug déjà pris est une violation d'unicité.
+    async fn set_slug(&self, user_id: &str, slug: Option<&str>) -> RepoResult<()>;
+    async fn by_slug(&self, slug: &str) -> RepoResult<Option<String>>;
+}
+
+/* =============================================================================
+   Document CV libre (cv_data) et CV nommés du builder (cvs, cv_hidden_items)
+============================================================================= */
+
+/// JSON stocké tel quel ; le layout est migré et validé avant (`rustfolio_layout`).
+/// Un nom déjà pris par un autre CV de l'utilisateur est une violation d'unicité.
+#[async_trait]
+pub trait CvRepo: Send + Sync {
+    async fn data(&self, user_id: &str) -> RepoResult<Option<String>>;
+    async fn save_data(&self, user_id: &str, json: &str) -> RepoResult<()>;
+    /// Remplace tout le contenu (profil, expériences avec tasks, compétences, projets avec
+    /// technos, traductions comprises) en une transaction, utilisé par PUT /cv/bulk.
+    /// Les compétences doivent avoir été validées par l'appelant.
+    async fn replace_content(
+        &self,
+        user_id: &str,
+        profile: &Profile,
+        experiences: &[Experience],
+        skills: &[SkillIn],
+        projects: &[Project],
+    ) -> RepoResult<()>;
+
+    /// CV par ordre de création.
+    async fn list_cvs(&self, user_id: &str) -> RepoResult<Vec<CvSummary>>;
+    async fn cv(&self, user_id: &str, id: i64) -> RepoResult<Option<CvSummary>>;
+    /// CV par défaut (`/api/layout`) : le plus ancien.
+    async fn default_cv(&self, user_id: &str) -> RepoResult<Option<i64>>;
+    async fn create_cv(&self, user_id: &str, name: &str, layout: &str) -> RepoResult<i64>;
+    async fn rename_cv(&self, user_id: &str, id: i64, name: &str) -> RepoResult<bool>;
+    async fn delete_cv(&self, user_id: &str, id: i64) -> RepoResult<bool>;
+    /// Copie mise en page, thème et éléments masqués ; `None` si le CV source n'existe pas.
+    async fn duplicate_cv(&self, user_id: &str, id: i64, name: &str) -> RepoResult<Option<i64>>;
+    async fn cv_layout(&self, user_id: &str, id: i64) -> RepoResult<Option<String>>;
+    async fn save_cv_layout(&self, user_id: &str, id: i64, layout: &str) -> RepoResult<bool>;
+    /// Thème (`rustfolio_layout::Theme`) validé avant.
+    async fn cv_theme(&self, user_id: &str, id: i64) -> RepoResult<Option<String>>;
+    async fn save_cv_theme(&self, user_id: &str, id: i64, theme: &str) -> RepoResult<bool>;
+    async fn cv_hidden(&self, user_id: &str, id: i64) -> RepoResult<Option<HiddenItems>>;
+    /// Remplace la sélection ; les ids doivent avoir été vérifiés par l'appelant.
+    async fn set_cv_hidden(&self, user_id: &str, id: i64, hidden: &HiddenItems) -> RepoResult<bool>;
+}
+
+/* =============================================================================
+   Expériences + tasks
+============================================================================= */
+
+#[async_trait]
+pub trait ExperienceRepo: Send + Sync {
+    async fn list(&self, user_id: &str) -> RepoResult<Vec<Experience>>;
+    /// Crée l'expérience (sans ses tasks, avec ses traductions) et renvoie son id.
+    async fn create(&self, user_id: &str, e: &Experience) -> RepoResult<i64>;
+    /// Ne touche pas aux tasks ; remplace les traductions si `e.translations`.
+    async fn update(&self, user_id: &str, id: i64, e: &Experience) -> RepoResult<bool>;
+    async fn delete(&self, user_id: &str, id: i64) -> RepoResult<bool>;
+
+    /// `None` si l'expérience n'appartient pas à l'utilisateur.
+    async fn list_tasks(&self, user_id: &str, exp_id: i64) -> RepoResult<Option<Vec<TaskItem>>>;
+    async fn add_task(&self, user_id: &str, exp_id: i64, task: &str) -> RepoResult<Option<TaskItem>>;
+    async fn update_task(&self, user_id: &str, exp_id: i64, task_id: i64, task: &str) -> RepoResult<bool>;
+    async fn delete_task(&self, user_id: &str, exp_id: i64, task_id: i64) -> RepoResult<bool>;
+}
+
+/* =============================================================================
+   Compétences
+============================================================================= */
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SkillProject {
+    pub id:       i64,
+    pub title:    String,
+    pub category: String,
+}
+
+#[async_trait]
+pub trait SkillRepo: Send + Sync {
+    async fn list(&self, user_id: &str) -> RepoResult<Vec<SkillOut>>;
+    async fn get(&self, user_id: &str, id: i64) -> RepoResult<Option<SkillOut>>;
+    /// Insère une compétence normalisée via le catalogue et renvoie son id.
+    async fn create(&self, user_id: &str, s: &SkillIn) -> RepoResult<i64>;
+    async fn update(&self, user_id: &str, id: i64, s: &SkillIn) -> RepoResult<bool>;
+    async fn delete(&self, user_id: &str, id: i64) -> RepoResult<bool>;
+    async fn categories(&self, user_id: &str) -> RepoResult<Vec<String>>;
+    /// Projets qui utilisent cette compétence (même entrée du catalogue ou même nom).
+    async fn projects_using(&self, user_id: &str, id: i64) -> RepoResult<Vec<SkillProject>>;
+}
+
+/* =============================================================================
+   Projets + technologies
+============================================================================= */
+
+#[async_trait]
+pub trait ProjectRepo: Send + Sync {
+    async fn list(&self, user_id: &str) -> RepoResult<Vec<Project>>;
+    /// Crée le projet et ses technos ; renvoie le projet tel que stocké (id, noms canoniques).
+    async fn create(&self, user_id: &str, p: &Project) -> RepoResult<Project>;
+    /// Met à jour le projet et remplace ses technos (et ses traductions si `p.translations`).
+    async fn update(&self, user_id: &str, id: i64, p: &Project) -> RepoResult<bool>;
+    async fn delete(&self, user_id: &str, id: i64) -> RepoResult<bool>;
+    /// Ne touche qu'à `pdf_link` (upload d'un document).
+    async fn set_pdf_link(&self, user_id: &str, id: i64, url: &str) -> RepoResult<bool>;
+
+    /// `None` si le projet n'appartient pas à l'utilisateur.
+    async fn list_tech(&self, user_id: &str, project_id: i64) -> RepoResult<Option<Vec<TechItem>>>;
+    async fn add_tech(&self, user_id: &str, project_id: i64, raw: &str) -> RepoResult<Option<TechItem>>;
+    async fn update_tech(&self, user_id: &str, project_id: i64, tech_id: i64, raw: &str) -> RepoResult<Option<TechItem>>;
+    async fn delete_tech(&self, user_id: &str, project_id: i64, tech_id: i64) -> RepoResult<bool>;
+
+    /// Recherche plein texte (FTS5) + filtres, une page de résultats et les facettes
+    /// calculées sur l'ensemble des projets trouvés.
+    async fn search(&self, user_id: &str, s: &ProjectSearch) -> RepoResult<ProjectPage>;
+}
+
+/// Ordre des résultats de recherche. `Relevance` sans texte recherché = `Recent`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ProjectSort {
+    #[default]
+    Relevance,
+    Recent,
+    Oldest,
+    Title,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ProjectSearch {
+    /// Texte libre (titre, description, catégorie, technos), accents ignorés.
+    pub q:         Option<String>,
+    /// Catégorie exacte (casse ignorée).
+    pub category:  Option<String>,
+    /// Noms de technos (canoniques) ; `all_techs` : toutes requises, sinon au moins une.
+    pub techs:     Vec<String>,
+    pub all_techs: bool,
+    pub sort:      ProjectSort,
+    pub offset:    i64,
+    pub limit:     i64,
+}
+
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+pub struct FacetCount {
+    pub value: String,
+    pub count: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Default)]
+pub struct ProjectFacets {
+    pub categories:   Vec<FacetCount>,
+    pub technologies: Vec<FacetCount>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ProjectPage {
+    /// Nombre total de projets trouvés (toutes pages).
+    pub total:  i64,
+    pub items:  Vec<Project>,
+    pub facets: ProjectFacets,
+}
+
+/* =============================================================================
+   Portfolio graphique
+============================================================================= */
+
+/// Album = ensemble des œuvres d'un même `type`.
+#[derive(Debug, Clone, Serialize)]
+pub struct Album {
+    #[serde(rename = "type")]
+    pub kind:  String,
+    pub count: i64,
+    /// Image de la première œuvre de l'album.
+    pub cover: String,
+}
+
+#[async_trait]
+pub trait PortfolioRepo: Send + Sync {
+    /// Œuvres triées par `position` ; `kind` filtre un album (casse ignorée).
+    async fn list(&self, user_id: &str, kind: Option<&str>) -> RepoResult<Vec<PortfolioItem>>;
+    async fn get(&self, user_id: &str, id: i64) -> RepoResult<Option<PortfolioItem>>;
+    async fn create(&self, user_id: &str, item: &PortfolioItem) -> RepoResult<i64>;
+    async fn update(&self, user_id: &str, id: i64, item: &PortfolioItem) -> RepoResult<bool>;
+    async fn delete(&self, user_id: &str, id: i64) -> RepoResult<bool>;
+    /// `ids` dans l'ordre voulu ; `false` (rien n'est modifié) si un id est inconnu ou répété.
+    async fn reorder(&self, user_id: &str, ids: &[i64]) -> RepoResult<bool>;
+    /// Albums dans l'ordre de leur première œuvre.
+    async fn albums(&self, user_id: &str) -> RepoResult<Vec<Album>>;
+}
+
+/* =============================================================================
+   Outbox e-mail (pas d'utilisateur : table technique lue par le worker)
+============================================================================= */
+
+#[derive(Debug, Clone)]
+pub struct OutboxItem {
+    pub id:       i64,
+    pub attempts: i64,
+    pub email:    Email,
+}
+
+/// Les dates sont au format `CURRENT_TIMESTAMP` de SQLite (`YYYY-MM-DD HH:MM:SS`, UTC).
+#[async_trait]
+pub trait OutboxRepo: Send + Sync {
+    async fn enqueue(&self, email: &Email) -> RepoResult<i64>;
+    /// E-mails dont `next_attempt_at <= now`, les plus anciens d'abord.
+    async fn due(&self, now: &str, limit: i64) -> RepoResult<Vec<OutboxItem>>;
+    async fn mark_sent(&self, id: i64) -> RepoResult<()>;
+    /// Incrémente `attempts` ; `retry_at = None` abandonne l'envoi.
+    async fn mark_failed(&self, id: i64, error: &str, retry_at: Option<&str>) -> RepoResult<()>;
+}
+
+/* =============================================================================
+   Images téléversées
+============================================================================= */
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Upload {
+    pub id:           i64,
+    pub hash:         String,
+    pub fallback_ext: String,
+    pub width:        i64,
+    pub height:       i64,
+    pub bytes:        i64,
+    pub created_at:   String,
+}
+
+#[async_trait]
+pub trait UploadRepo: Send + Sync {
+    async fn find_by_hash(&self, user_id: &str, hash: &str) -> RepoResult<Option<Upload>>;
+    async fn list(&self, user_id: &str) -> RepoResult<Vec<Upload>>;
+    /// `u.id` et `u.created_at` sont ignorés ; renvoie l'id créé.
+    async fn create(&self, user_id: &str, u: &Upload) -> RepoResult<i64>;
+    async fn get(&self, user_id: &str, id: i64) -> RepoResult<Option<Upload>>;
+    async fn delete(&self, user_id: &str, id: i64) -> RepoResult<bool>;
+    /// Uploads créés avant `before` dont l'empreinte n'apparaît plus ni dans
+    /// `profiles.photo_url`, ni dans `projects.image`, ni dans `skills.logo_url`,
+    /// ni dans `portfolio_items.image`.
+    async fn orphans(&self, user_id: &str, before: &str) -> RepoResult<Vec<Upload>>;
+}
+
+/* =============================================================================
+   Documents PDF
+============================================================================= */
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Document {
+    pub id:             i64,
+    pub user_id:        String,
+    pub hash:           String,
+    pub file_name:      String,
+    pub title:          Option<String>,
+    pub page_count:     i64,
+    pub bytes:          i64,
+    pub preview_width:  Option<i64>,
+    pub preview_height: Option<i64>,
+    pub created_at:     String,
+}
+
+#[async_trait]
+pub trait DocumentRepo: Send + Sync {
+    async fn find_by_hash(&self, user_id: &str, hash: &str) -> RepoResult<Option<Document>>;
+    /// `d.id`, `d.user_id` et `d.created_at` sont ignorés ; renvoie l'id créé.
+    async fn create(&self, user_id: &str, d: &Document) -> RepoResult<i64>;
+    async fn get(&self, user_id: &str, id: i64) -> RepoResult<Option<Document>>;
+    /// Sans filtre utilisateur, mais seulement un document encore référencé par un
+    /// `pdf_link` ou un élément de portfolio de son propriétaire : les autres ne sont
+    /// pas publics, même en devinant leur id.
+    async fn get_public(&self, id: i64) -> RepoResult<Option<Document>>;
+    async fn delete(&self, user_id: &str, id: i64) -> RepoResult<bool>;
+    /// Documents créés avant `before` qu'aucun `projects.pdf_link` ni
+    /// `portfolio_items.image` ne référence plus.
+    async fn orphans(&self, user_id: &str, before: &str) -> RepoResult<Vec<Document>>;
+}
+
+/* =============================================================================
+   Versions des collections (ETags)
+============================================================================= */
+
+/// Collections d'un utilisateur exposées en liste.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Collection {
+    Experiences,
+    Projects,
+    Skills,
+    Portfolio,
+}
+
+#[async_trait]
+pub trait VersionRepo: Send + Sync {
+    /// Empreinte de la collection : les couples `id@updated_at` de ses lignes.
+    /// Change à chaque ajout, modification ou suppression (y compris des tâches et
+    /// technos, qui mettent à jour leur parent).
+    async fn fingerprint(&self, user_id: &str, c: Collection) -> RepoResult<String>;
+}
+
+/* =============================================================================
+   Regroupement (partagé via AppState)
+============================================================================= */
+
+#[derive(Clone)]
+pub struct Repos {
+    pub catalog:     Arc<dyn CatalogRepo>,
+    pub profiles:    Arc<dyn ProfileRepo>,
+    pub cv:          Arc<dyn CvRepo>,
+    pub experiences: Arc<dyn ExperienceRepo>,
+    pub skills:      Arc<dyn SkillRepo>,
+    pub projects:    Arc<dyn ProjectRepo>,
+    pub outbox:      Arc<dyn OutboxRepo>,
+    pub uploads:     Arc<dyn UploadRepo>,
+    pub documents:   Arc<dyn DocumentRepo>,
+    pub portfolio:   Arc<dyn PortfolioRepo>,
+    pub versions:    Arc<dyn VersionRepo>,
+}
+
+impl Repos {
+    /// Tous les dépôts adossés au même pool SQLite.
+    pub fn sqlite(db: Pool<Sqlite>) -> Self {
+        let repo = Arc::new(sqlite::SqliteRepo::new(db));
+        Self {
+            catalog:     repo.clone(),
+            profiles:    repo.clone(),
+            cv:          repo.clone(),
+            experiences: repo.clone(),
+            skills:      repo.clone(),
+            projects:    repo.clone(),
+            outbox:      repo.clone(),
+            uploads:     repo.clone(),
+            documents:   repo.clone(),
+            portfolio:   repo.clone(),
+            versions:    repo,
+        }
+    }
+}
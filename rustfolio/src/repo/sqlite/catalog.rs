@@ -0,0 +1,93 @@
+use axum::async_trait;
+use sqlx::SqliteConnection;
+use tracing::instrument;
+
+use super::SqliteRepo;
+use crate::repo::{Canonical, CatalogEntry, CatalogRepo, RepoResult};
+
+/// Clé d'alias : minuscules, sans espaces, points, tirets ni underscores.
+/// "Next.js", "next js" et "NextJS" → "nextjs". Doit rester alignée avec 0016_tech_catalog.sql.
+pub fn alias_key(raw: &str) -> String {
+    raw.trim()
+        .chars()
+        .filter(|c| !c.is_whitespace() && !matches!(c, '.' | '-' | '_'))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Échappe les jokers de `LIKE` (avec `ESCAPE '\'`).
+fn like_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        if matches!(c, '\\' | '%' | '_') {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    out
+}
+
+#[async_trait]
+impl CatalogRepo for SqliteRepo {
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "catalog.search"))]
+    async fn search(&self, q: &str, limit: i64) -> RepoResult<Vec<CatalogEntry>> {
+        // `%` ou `\` saisis restent littéraux
+        let key = like_escape(&alias_key(q));
+        let prefix = format!("{key}%");
+        let contains = format!("%{key}%");
+
+        sqlx::query_as!(
+            CatalogEntry,
+            r#"
+            SELECT c.id as "id!: i64", c.slug as "slug!", c.name as "name!",
+                   c.category as "category!", c.logo_url as "logo_url!"
+              FROM tech_catalog c
+              JOIN tech_aliases a ON a.tech_id = c.id
+             WHERE a.alias LIKE ? ESCAPE '\'
+             GROUP BY c.id
+             ORDER BY MIN(CASE WHEN a.alias LIKE ? ESCAPE '\' THEN 0 ELSE 1 END), c.name COLLATE NOCASE
+             LIMIT ?
+            "#,
+            contains,
+            prefix,
+            limit
+        )
+        .fetch_all(&self.db)
+        .await
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "catalog.canonicalize"))]
+    async fn canonicalize(&self, raw: &str) -> RepoResult<Canonical> {
+        canonicalize(&mut *self.db.acquire().await?, raw).await
+    }
+}
+
+/// Sans alias correspondant, garde le texte saisi (trimé) sans `tech_id`.
+/// Prend une connexion pour servir aussi dans les transactions d'écriture.
+pub(super) async fn canonicalize(conn: &mut SqliteConnection, raw: &str) -> RepoResult<Canonical> {
+    let key = alias_key(raw);
+    let rec = sqlx::query!(
+        r#"
+        SELECT c.id as "id!: i64", c.name, c.logo_url
+          FROM tech_aliases a
+          JOIN tech_catalog c ON c.id = a.tech_id
+         WHERE a.alias = ?
+        "#,
+        key
+    )
+    .fetch_optional(conn)
+    .await?;
+
+    Ok(match rec {
+        Some(r) => Canonical {
+            name: r.name,
+            tech_id: Some(r.id),
+            logo_url: Some(r.logo_url).filter(|l| !l.is_empty()),
+        },
+        None => Canonical {
+            name: raw.trim().to_string(),
+            tech_id: None,
+            logo_url: None,
+        },
+    })
+}
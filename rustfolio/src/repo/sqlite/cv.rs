@@ -0,0 +1,221 @@
+use axum::async_trait;
+use tracing::instrument;
+
+use super::{experiences, profile, projects, skills, SqliteRepo};
+use crate::repo::{CvRepo, CvSummary, HiddenItems, RepoResult};
+use crate::types::{Experience, Profile, Project, SkillIn};
+
+#[async_trait]
+impl CvRepo for SqliteRepo {
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "cv.data"))]
+    async fn data(&self, user_id: &str) -> RepoResult<Option<String>> {
+        sqlx::query_scalar!("SELECT data FROM cv_data WHERE user_id = ?", user_id)
+            .fetch_optional(&self.db)
+            .await
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "cv.save_data"))]
+    async fn save_data(&self, user_id: &str, json: &str) -> RepoResult<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO cv_data (user_id, data, updated_at)
+            VALUES (?, ?, CURRENT_TIMESTAMP)
+            ON CONFLICT(user_id) DO UPDATE SET data = excluded.data, updated_at = CURRENT_TIMESTAMP
+            "#,
+            user_id,
+            json
+        )
+        .execute(&self.db)
+        .await?;
+        Ok(())
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "cv.replace_content"))]
+    async fn replace_content(
+        &self,
+        user_id: &str,
+        profile: &Profile,
+        experiences: &[Experience],
+        skills: &[SkillIn],
+        projects: &[Project],
+    ) -> RepoResult<()> {
+        // tout ou rien : une erreur au milieu annule aussi les suppressions
+        let mut tx = self.db.begin().await?;
+        profile::upsert_profile(&mut tx, user_id, profile).await?;
+        experiences::replace_experiences(&mut tx, user_id, experiences).await?;
+        skills::replace_skills(&mut tx, user_id, skills).await?;
+        projects::replace_projects(&mut tx, user_id, projects).await?;
+        tx.commit().await
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "cv.list_cvs"))]
+    async fn list_cvs(&self, user_id: &str) -> RepoResult<Vec<CvSummary>> {
+        sqlx::query_as!(
+            CvSummary,
+            r#"SELECT id as "id!: i64", name, created_at, updated_at FROM cvs WHERE user_id = ? ORDER BY id"#,
+            user_id
+        )
+        .fetch_all(&self.db)
+        .await
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "cv.cv"))]
+    async fn cv(&self, user_id: &str, id: i64) -> RepoResult<Option<CvSummary>> {
+        sqlx::query_as!(
+            CvSummary,
+            r#"SELECT id as "id!: i64", name, created_at, updated_at FROM cvs WHERE user_id = ? AND id = ?"#,
+            user_id,
+            id
+        )
+        .fetch_optional(&self.db)
+        .await
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "cv.default_cv"))]
+    async fn default_cv(&self, user_id: &str) -> RepoResult<Option<i64>> {
+        sqlx::query_scalar!(r#"SELECT id as "id!: i64" FROM cvs WHERE user_id = ? ORDER BY id LIMIT 1"#, user_id)
+            .fetch_optional(&self.db)
+            .await
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "cv.create_cv"))]
+    async fn create_cv(&self, user_id: &str, name: &str, layout: &str) -> RepoResult<i64> {
+        let res = sqlx::query!("INSERT INTO cvs (user_id, name, layout) VALUES (?, ?, ?)", user_id, name, layout)
+            .execute(&self.db)
+            .await?;
+        Ok(res.last_insert_rowid())
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "cv.rename_cv"))]
+    async fn rename_cv(&self, user_id: &str, id: i64, name: &str) -> RepoResult<bool> {
+        let res = sqlx::query!("UPDATE cvs SET name = ? WHERE id = ? AND user_id = ?", name, id, user_id)
+            .execute(&self.db)
+            .await?;
+        Ok(res.rows_affected() > 0)
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "cv.delete_cv"))]
+    async fn delete_cv(&self, user_id: &str, id: i64) -> RepoResult<bool> {
+        let res = sqlx::query!("DELETE FROM cvs WHERE id = ? AND user_id = ?", id, user_id)
+            .execute(&self.db)
+            .await?;
+        Ok(res.rows_affected() > 0)
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "cv.duplicate_cv"))]
+    async fn duplicate_cv(&self, user_id: &str, id: i64, name: &str) -> RepoResult<Option<i64>> {
+        let mut tx = self.db.begin().await?;
+        let res = sqlx::query!(
+            "INSERT INTO cvs (user_id, name, layout, theme) SELECT user_id, ?, layout, theme FROM cvs WHERE id = ? AND user_id = ?",
+            name,
+            id,
+            user_id
+        )
+        .execute(&mut *tx)
+        .await?;
+        if res.rows_affected() == 0 {
+            return Ok(None);
+        }
+        let copy = res.last_insert_rowid();
+        sqlx::query!(
+            "INSERT INTO cv_hidden_items (cv_id, kind, item_id) SELECT ?, kind, item_id FROM cv_hidden_items WHERE cv_id = ?",
+            copy,
+            id
+        )
+        .execute(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(Some(copy))
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "cv.cv_layout"))]
+    async fn cv_layout(&self, user_id: &str, id: i64) -> RepoResult<Option<String>> {
+        sqlx::query_scalar!("SELECT layout FROM cvs WHERE id = ? AND user_id = ?", id, user_id)
+            .fetch_optional(&self.db)
+            .await
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "cv.save_cv_layout"))]
+    async fn save_cv_layout(&self, user_id: &str, id: i64, layout: &str) -> RepoResult<bool> {
+        let res = sqlx::query!("UPDATE cvs SET layout = ? WHERE id = ? AND user_id = ?", layout, id, user_id)
+            .execute(&self.db)
+            .await?;
+        Ok(res.rows_affected() > 0)
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "cv.cv_theme"))]
+    async fn cv_theme(&self, user_id: &str, id: i64) -> RepoResult<Option<String>> {
+        sqlx::query_scalar!("SELECT theme FROM cvs WHERE id = ? AND user_id = ?", id, user_id)
+            .fetch_optional(&self.db)
+            .await
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "cv.save_cv_theme"))]
+    async fn save_cv_theme(&self, user_id: &str, id: i64, theme: &str) -> RepoResult<bool> {
+        let res = sqlx::query!("UPDATE cvs SET theme = ? WHERE id = ? AND user_id = ?", theme, id, user_id)
+            .execute(&self.db)
+            .await?;
+        Ok(res.rows_affected() > 0)
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "cv.cv_hidden"))]
+    async fn cv_hidden(&self, user_id: &str, id: i64) -> RepoResult<Option<HiddenItems>> {
+        if self.cv(user_id, id).await?.is_none() {
+            return Ok(None);
+        }
+        let rows = sqlx::query!(
+            r#"SELECT kind, item_id as "item_id!: i64" FROM cv_hidden_items WHERE cv_id = ? ORDER BY kind, item_id"#,
+            id
+        )
+        .fetch_all(&self.db)
+        .await?;
+
+        let mut hidden = HiddenItems::default();
+        for r in rows {
+            match r.kind.as_str() {
+                "experience" => hidden.experiences.push(r.item_id),
+                "skill" => hidden.skills.push(r.item_id),
+                _ => hidden.projects.push(r.item_id),
+            }
+        }
+        Ok(Some(hidden))
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "cv.set_cv_hidden"))]
+    async fn set_cv_hidden(&self, user_id: &str, id: i64, hidden: &HiddenItems) -> RepoResult<bool> {
+        let mut tx = self.db.begin().await?;
+        // modifié à la ms près, comme les tables touchées par trigger
+        let res = sqlx::query!(
+            r#"
+            UPDATE cvs
+               SET updated_at = max(strftime('%Y-%m-%d %H:%M:%f', 'now'), strftime('%Y-%m-%d %H:%M:%f', updated_at, '+0.001 seconds'))
+             WHERE id = ? AND user_id = ?
+            "#,
+            id,
+            user_id
+        )
+        .execute(&mut *tx)
+        .await?;
+        if res.rows_affected() == 0 {
+            return Ok(false);
+        }
+        sqlx::query!("DELETE FROM cv_hidden_items WHERE cv_id = ?", id)
+            .execute(&mut *tx)
+            .await?;
+        let kinds = [("experience", &hidden.experiences), ("skill", &hidden.skills), ("project", &hidden.projects)];
+        for (kind, ids) in kinds {
+            for item_id in ids {
+                sqlx::query!(
+                    "INSERT OR IGNORE INTO cv_hidden_items (cv_id, kind, item_id) VALUES (?, ?, ?)",
+                    id,
+                    kind,
+                    item_id
+                )
+                .execute(&mut *tx)
+                .await?;
+            }
+        }
+        tx.commit().await?;
+        Ok(true)
+    }
+}
@@ -0,0 +1,121 @@
+use axum::async_trait;
+use tracing::instrument;
+
+use super::SqliteRepo;
+use crate::repo::{Document, DocumentRepo, RepoResult};
+
+#[async_trait]
+impl DocumentRepo for SqliteRepo {
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "documents.find_by_hash"))]
+    async fn find_by_hash(&self, user_id: &str, hash: &str) -> RepoResult<Option<Document>> {
+        sqlx::query_as!(
+            Document,
+            r#"
+            SELECT id as "id!: i64", user_id, hash, file_name, title, page_count, bytes,
+                   preview_width, preview_height, created_at
+            FROM documents
+            WHERE user_id = ? AND hash = ?
+            "#,
+            user_id,
+            hash
+        )
+        .fetch_optional(&self.db)
+        .await
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "documents.create"))]
+    async fn create(&self, user_id: &str, d: &Document) -> RepoResult<i64> {
+        let res = sqlx::query!(
+            r#"
+            INSERT INTO documents
+              (user_id, hash, file_name, title, page_count, bytes, preview_width, preview_height)
+            VALUES (?, ?, ?, ?, ?, ?, ?, ?)
+            "#,
+            user_id,
+            d.hash,
+            d.file_name,
+            d.title,
+            d.page_count,
+            d.bytes,
+            d.preview_width,
+            d.preview_height
+        )
+        .execute(&self.db)
+        .await?;
+        Ok(res.last_insert_rowid())
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "documents.get"))]
+    async fn get(&self, user_id: &str, id: i64) -> RepoResult<Option<Document>> {
+        sqlx::query_as!(
+            Document,
+            r#"
+            SELECT id as "id!: i64", user_id, hash, file_name, title, page_count, bytes,
+                   preview_width, preview_height, created_at
+            FROM documents
+            WHERE user_id = ? AND id = ?
+            "#,
+            user_id,
+            id
+        )
+        .fetch_optional(&self.db)
+        .await
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "documents.get_public"))]
+    async fn get_public(&self, id: i64) -> RepoResult<Option<Document>> {
+        // même critère que `orphans`, inversé : encore référencé par son propriétaire
+        sqlx::query_as!(
+            Document,
+            r#"
+            SELECT d.id as "id!: i64", d.user_id, d.hash, d.file_name, d.title, d.page_count, d.bytes,
+                   d.preview_width, d.preview_height, d.created_at
+            FROM documents d
+            WHERE d.id = ?1
+              AND (EXISTS (SELECT 1 FROM projects pr
+                           WHERE pr.user_id = d.user_id
+                             AND instr(pr.pdf_link, '/documents/' || d.id || '/') > 0)
+                   OR EXISTS (SELECT 1 FROM portfolio_items pf
+                              WHERE pf.user_id = d.user_id
+                                AND instr(pf.image, '/documents/' || d.id || '/') > 0))
+            "#,
+            id
+        )
+        .fetch_optional(&self.db)
+        .await
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "documents.delete"))]
+    async fn delete(&self, user_id: &str, id: i64) -> RepoResult<bool> {
+        let res = sqlx::query!("DELETE FROM documents WHERE user_id = ? AND id = ?", user_id, id)
+            .execute(&self.db)
+            .await?;
+        Ok(res.rows_affected() > 0)
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "documents.orphans"))]
+    async fn orphans(&self, user_id: &str, before: &str) -> RepoResult<Vec<Document>> {
+        // URL = "/documents/<id>/<nom>" : on cherche "/documents/<id>/"
+        sqlx::query_as!(
+            Document,
+            r#"
+            SELECT d.id as "id!: i64", d.user_id, d.hash, d.file_name, d.title, d.page_count, d.bytes,
+                   d.preview_width, d.preview_height, d.created_at
+            FROM documents d
+            WHERE d.user_id = ?1
+              AND d.created_at <= ?2
+              AND NOT EXISTS (SELECT 1 FROM projects pr
+                              WHERE pr.user_id = d.user_id
+                                AND instr(pr.pdf_link, '/documents/' || d.id || '/') > 0)
+              AND NOT EXISTS (SELECT 1 FROM portfolio_items pf
+                              WHERE pf.user_id = d.user_id
+                                AND instr(pf.image, '/documents/' || d.id || '/') > 0)
+            ORDER BY d.id
+            "#,
+            user_id,
+            before
+        )
+        .fetch_all(&self.db)
+        .await
+    }
+}
@@ -0,0 +1,305 @@
+use axum::async_trait;
+use sqlx::SqliteConnection;
+use tracing::instrument;
+
+use super::{normalize_date_like, translated, SqliteRepo};
+use crate::repo::{ExperienceRepo, RepoResult};
+use crate::types::{Experience, ExperienceText, TaskItem, Translations};
+
+impl SqliteRepo {
+    async fn owns_experience(&self, user_id: &str, exp_id: i64) -> RepoResult<bool> {
+        let n: i64 = sqlx::query_scalar!(
+            r#"SELECT COUNT(1) AS "n!: i64" FROM experiences WHERE id = ? AND user_id = ?"#,
+            exp_id,
+            user_id
+        )
+        .fetch_one(&self.db)
+        .await?;
+        Ok(n > 0)
+    }
+
+    async fn experience_translations(&self, exp_id: i64) -> RepoResult<Option<Translations<ExperienceText>>> {
+        let rows = sqlx::query!(
+            "SELECT locale, title, tasks FROM experience_translations WHERE experience_id = ? ORDER BY locale",
+            exp_id
+        )
+        .fetch_all(&self.db)
+        .await?;
+        let out: Translations<ExperienceText> = rows
+            .into_iter()
+            .map(|t| {
+                let tasks = t.tasks.and_then(|json| serde_json::from_str(&json).ok());
+                (t.locale, ExperienceText { title: t.title, tasks })
+            })
+            .collect();
+        Ok((!out.is_empty()).then_some(out))
+    }
+}
+
+async fn insert_experience(conn: &mut SqliteConnection, user_id: &str, e: &Experience) -> RepoResult<i64> {
+    let date_start = normalize_date_like(&e.date_start);
+    let date_end = normalize_date_like(&e.date_end);
+
+    let res = sqlx::query!(
+        r#"
+        INSERT INTO experiences
+          (user_id, date_start, date_end, kind, title, company, location, website, updated_at)
+        VALUES
+          (?,?,?,?,?,?,?,?,CURRENT_TIMESTAMP)
+        "#,
+        user_id,
+        date_start,
+        date_end,
+        e.kind,
+        e.title,
+        e.company,
+        e.location,
+        e.website
+    )
+    .execute(&mut *conn)
+    .await?;
+    let id = res.last_insert_rowid();
+    if let Some(t) = &e.translations {
+        replace_experience_translations(conn, id, t).await?;
+    }
+    Ok(id)
+}
+
+async fn replace_experience_translations(conn: &mut SqliteConnection, exp_id: i64, list: &Translations<ExperienceText>) -> RepoResult<()> {
+    sqlx::query!("DELETE FROM experience_translations WHERE experience_id = ?", exp_id)
+        .execute(&mut *conn)
+        .await?;
+    for (locale, t) in list {
+        let title = translated(&t.title);
+        // tâches vides retirées ; liste vide = pas de traduction
+        let tasks: Option<Vec<&str>> = t
+            .tasks
+            .as_ref()
+            .map(|tasks| tasks.iter().map(|t| t.trim()).filter(|t| !t.is_empty()).collect::<Vec<_>>())
+            .filter(|tasks| !tasks.is_empty());
+        if title.is_none() && tasks.is_none() {
+            continue;
+        }
+        let tasks = tasks.map(|t| serde_json::Value::from(t).to_string());
+        sqlx::query!(
+            "INSERT INTO experience_translations (experience_id, locale, title, tasks) VALUES (?, ?, ?, ?)",
+            exp_id,
+            locale,
+            title,
+            tasks
+        )
+        .execute(&mut *conn)
+        .await?;
+    }
+    Ok(())
+}
+
+/// Remplace toutes les expériences, tasks et traductions comprises (PUT /cv/bulk),
+/// dans la transaction de l'appelant.
+pub(super) async fn replace_experiences(conn: &mut SqliteConnection, user_id: &str, list: &[Experience]) -> RepoResult<()> {
+    // purge tasks pour les experiences de l’utilisateur
+    sqlx::query!(
+        "DELETE FROM experience_tasks WHERE experience_id IN (SELECT id FROM experiences WHERE user_id = ?)",
+        user_id
+    )
+    .execute(&mut *conn)
+    .await?;
+
+    // purge experiences
+    sqlx::query!("DELETE FROM experiences WHERE user_id = ?", user_id)
+        .execute(&mut *conn)
+        .await?;
+
+    // réinsert
+    for e in list {
+        let id = insert_experience(&mut *conn, user_id, e).await?;
+        for t in &e.tasks {
+            sqlx::query!("INSERT INTO experience_tasks (experience_id, task) VALUES (?, ?)", id, t)
+                .execute(&mut *conn)
+                .await?;
+        }
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl ExperienceRepo for SqliteRepo {
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "experiences.list"))]
+    async fn list(&self, user_id: &str) -> RepoResult<Vec<Experience>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                id as "id!: i64",
+                date_start,
+                date_end,
+                kind,
+                title,
+                company,
+                location,
+                website
+            FROM experiences
+            WHERE user_id = ?
+            ORDER BY id
+            "#,
+            user_id
+        )
+        .fetch_all(&self.db)
+        .await?;
+
+        let mut out = Vec::with_capacity(rows.len());
+        for r in rows {
+            // pour le bulk, on ne renvoie que les tasks en Vec<String> (pas les IDs)
+            let tasks_rows = sqlx::query!(
+                "SELECT task FROM experience_tasks WHERE experience_id = ? ORDER BY id",
+                r.id
+            )
+            .fetch_all(&self.db)
+            .await?;
+
+            out.push(Experience {
+                id:         Some(r.id),
+                date_start: r.date_start,
+                date_end:   r.date_end,
+                kind:       r.kind.unwrap_or_default(),
+                title:      r.title.unwrap_or_default(),
+                company:    r.company.unwrap_or_default(),
+                location:   r.location.unwrap_or_default(),
+                website:    r.website,
+                tasks:      tasks_rows.into_iter().map(|t| t.task).collect(),
+                translations: self.experience_translations(r.id).await?,
+            });
+        }
+        Ok(out)
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "experiences.create"))]
+    async fn create(&self, user_id: &str, e: &Experience) -> RepoResult<i64> {
+        let mut tx = self.db.begin().await?;
+        let id = insert_experience(&mut tx, user_id, e).await?;
+        tx.commit().await?;
+        Ok(id)
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "experiences.update"))]
+    async fn update(&self, user_id: &str, id: i64, e: &Experience) -> RepoResult<bool> {
+        let date_start = normalize_date_like(&e.date_start);
+        let date_end = normalize_date_like(&e.date_end);
+
+        // l'expérience et ses traductions ensemble
+        let mut tx = self.db.begin().await?;
+        let res = sqlx::query!(
+            r#"
+            UPDATE experiences
+               SET date_start = ?,
+                   date_end   = ?,
+                   kind       = ?,
+                   title      = ?,
+                   company    = ?,
+                   location   = ?,
+                   website    = ?,
+                   updated_at = CURRENT_TIMESTAMP
+             WHERE id = ? AND user_id = ?
+            "#,
+            date_start,
+            date_end,
+            e.kind,
+            e.title,
+            e.company,
+            e.location,
+            e.website,
+            id,
+            user_id
+        )
+        .execute(&mut *tx)
+        .await?;
+        if res.rows_affected() == 0 {
+            return Ok(false);
+        }
+        if let Some(t) = &e.translations {
+            replace_experience_translations(&mut tx, id, t).await?;
+        }
+        tx.commit().await?;
+        Ok(true)
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "experiences.delete"))]
+    async fn delete(&self, user_id: &str, id: i64) -> RepoResult<bool> {
+        if !self.owns_experience(user_id, id).await? {
+            return Ok(false);
+        }
+        // d’abord delete les tasks enfants (FK)
+        sqlx::query!("DELETE FROM experience_tasks WHERE experience_id = ?", id)
+            .execute(&self.db)
+            .await?;
+        sqlx::query!("DELETE FROM experiences WHERE id = ? AND user_id = ?", id, user_id)
+            .execute(&self.db)
+            .await?;
+        Ok(true)
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "experiences.list_tasks"))]
+    async fn list_tasks(&self, user_id: &str, exp_id: i64) -> RepoResult<Option<Vec<TaskItem>>> {
+        if !self.owns_experience(user_id, exp_id).await? {
+            return Ok(None);
+        }
+        let rows = sqlx::query!(
+            r#"SELECT id as "id!: i64", task FROM experience_tasks WHERE experience_id = ? ORDER BY id"#,
+            exp_id
+        )
+        .fetch_all(&self.db)
+        .await?;
+
+        Ok(Some(rows.into_iter().map(|r| TaskItem::new(r.id, r.task)).collect()))
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "experiences.add_task"))]
+    async fn add_task(&self, user_id: &str, exp_id: i64, task: &str) -> RepoResult<Option<TaskItem>> {
+        if !self.owns_experience(user_id, exp_id).await? {
+            return Ok(None);
+        }
+        let res = sqlx::query!(
+            "INSERT INTO experience_tasks (experience_id, task) VALUES (?, ?)",
+            exp_id,
+            task
+        )
+        .execute(&self.db)
+        .await?;
+
+        Ok(Some(TaskItem::new(res.last_insert_rowid(), task.to_string())))
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "experiences.update_task"))]
+    async fn update_task(&self, user_id: &str, exp_id: i64, task_id: i64, task: &str) -> RepoResult<bool> {
+        let res = sqlx::query!(
+            r#"
+            UPDATE experience_tasks SET task = ?
+             WHERE experience_id = ? AND id = ?
+               AND experience_id IN (SELECT id FROM experiences WHERE user_id = ?)
+            "#,
+            task,
+            exp_id,
+            task_id,
+            user_id
+        )
+        .execute(&self.db)
+        .await?;
+        Ok(res.rows_affected() > 0)
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "experiences.delete_task"))]
+    async fn delete_task(&self, user_id: &str, exp_id: i64, task_id: i64) -> RepoResult<bool> {
+        let res = sqlx::query!(
+            r#"
+            DELETE FROM experience_tasks
+             WHERE experience_id = ? AND id = ?
+               AND experience_id IN (SELECT id FROM experiences WHERE user_id = ?)
+            "#,
+            exp_id,
+            task_id,
+            user_id
+        )
+        .execute(&self.db)
+        .await?;
+        Ok(res.rows_affected() > 0)
+    }
+}
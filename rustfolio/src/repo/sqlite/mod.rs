@@ -0,0 +1,39 @@
+//! Implémentation SQLite des dépôts (requêtes vérifiées à la compilation par `sqlx::query!`).
+
+use sqlx::{Pool, Sqlite};
+
+mod catalog;
+mod cv;
+mod documents;
+mod experiences;
+mod outbox;
+mod portfolio;
+mod profile;
+mod projects;
+mod skills;
+mod uploads;
+mod versions;
+
+pub use catalog::alias_key;
+
+/// Un seul type implémente tous les traits : il ne porte que le pool.
+#[derive(Clone)]
+pub struct SqliteRepo {
+    db: Pool<Sqlite>,
+}
+
+impl SqliteRepo {
+    pub fn new(db: Pool<Sqlite>) -> Self {
+        Self { db }
+    }
+}
+
+// Trim simple côté serveur (évite d’introduire regex)
+fn normalize_date_like(s: &str) -> String {
+    s.trim().to_string()
+}
+
+/// Champ traduit : vide = pas de traduction (NULL, repli sur la langue par défaut).
+fn translated(s: &Option<String>) -> Option<&str> {
+    s.as_deref().map(str::trim).filter(|s| !s.is_empty())
+}
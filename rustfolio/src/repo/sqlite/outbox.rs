@@ -0,0 +1,78 @@
+use axum::async_trait;
+use tracing::instrument;
+
+use super::SqliteRepo;
+use crate::mail::Email;
+use crate::repo::{OutboxItem, OutboxRepo, RepoResult};
+
+#[async_trait]
+impl OutboxRepo for SqliteRepo {
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "outbox.enqueue"))]
+    async fn enqueue(&self, email: &Email) -> RepoResult<i64> {
+        let res = sqlx::query!(
+            "INSERT INTO email_outbox (to_addr, subject, text_body, html_body) VALUES (?, ?, ?, ?)",
+            email.to,
+            email.subject,
+            email.text,
+            email.html
+        )
+        .execute(&self.db)
+        .await?;
+        Ok(res.last_insert_rowid())
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "outbox.due"))]
+    async fn due(&self, now: &str, limit: i64) -> RepoResult<Vec<OutboxItem>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT id as "id!: i64", attempts, to_addr, subject, text_body, html_body
+            FROM email_outbox
+            WHERE next_attempt_at IS NOT NULL AND next_attempt_at <= ?
+            ORDER BY next_attempt_at, id
+            LIMIT ?
+            "#,
+            now,
+            limit
+        )
+        .fetch_all(&self.db)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|r| OutboxItem {
+                id: r.id,
+                attempts: r.attempts,
+                email: Email { to: r.to_addr, subject: r.subject, text: r.text_body, html: r.html_body },
+            })
+            .collect())
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "outbox.mark_sent"))]
+    async fn mark_sent(&self, id: i64) -> RepoResult<()> {
+        sqlx::query!(
+            r#"
+            UPDATE email_outbox
+               SET sent_at = CURRENT_TIMESTAMP, next_attempt_at = NULL,
+                   attempts = attempts + 1, last_error = NULL
+             WHERE id = ?
+            "#,
+            id
+        )
+        .execute(&self.db)
+        .await?;
+        Ok(())
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "outbox.mark_failed"))]
+    async fn mark_failed(&self, id: i64, error: &str, retry_at: Option<&str>) -> RepoResult<()> {
+        sqlx::query!(
+            "UPDATE email_outbox SET attempts = attempts + 1, last_error = ?, next_attempt_at = ? WHERE id = ?",
+            error,
+            retry_at,
+            id
+        )
+        .execute(&self.db)
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,159 @@
+use axum::async_trait;
+use tracing::instrument;
+
+use super::SqliteRepo;
+use crate::repo::{Album, PortfolioRepo, RepoResult};
+use crate::types::PortfolioItem;
+
+#[async_trait]
+impl PortfolioRepo for SqliteRepo {
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "portfolio.list"))]
+    async fn list(&self, user_id: &str, kind: Option<&str>) -> RepoResult<Vec<PortfolioItem>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT id as "id!: i64", title, description, kind, image, position
+            FROM portfolio_items
+            WHERE user_id = ?1 AND (?2 IS NULL OR kind = ?2 COLLATE NOCASE)
+            ORDER BY position, id
+            "#,
+            user_id,
+            kind
+        )
+        .fetch_all(&self.db)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|r| PortfolioItem {
+                id: Some(r.id),
+                title: r.title,
+                description: r.description,
+                kind: r.kind,
+                image: r.image,
+                position: Some(r.position),
+            })
+            .collect())
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "portfolio.get"))]
+    async fn get(&self, user_id: &str, id: i64) -> RepoResult<Option<PortfolioItem>> {
+        let row = sqlx::query!(
+            r#"
+            SELECT id as "id!: i64", title, description, kind, image, position
+            FROM portfolio_items
+            WHERE user_id = ? AND id = ?
+            "#,
+            user_id,
+            id
+        )
+        .fetch_optional(&self.db)
+        .await?;
+
+        Ok(row.map(|r| PortfolioItem {
+            id: Some(r.id),
+            title: r.title,
+            description: r.description,
+            kind: r.kind,
+            image: r.image,
+            position: Some(r.position),
+        }))
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "portfolio.create"))]
+    async fn create(&self, user_id: &str, item: &PortfolioItem) -> RepoResult<i64> {
+        let res = sqlx::query!(
+            r#"
+            INSERT INTO portfolio_items (user_id, title, description, kind, image, position)
+            VALUES (?1, ?2, ?3, ?4, ?5,
+                    COALESCE(?6, (SELECT COALESCE(MAX(position) + 1, 0) FROM portfolio_items WHERE user_id = ?1)))
+            "#,
+            user_id,
+            item.title,
+            item.description,
+            item.kind,
+            item.image,
+            item.position
+        )
+        .execute(&self.db)
+        .await?;
+        Ok(res.last_insert_rowid())
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "portfolio.update"))]
+    async fn update(&self, user_id: &str, id: i64, item: &PortfolioItem) -> RepoResult<bool> {
+        let res = sqlx::query!(
+            r#"
+            UPDATE portfolio_items
+               SET title = ?, description = ?, kind = ?, image = ?,
+                   position = COALESCE(?, position),
+                   updated_at = CURRENT_TIMESTAMP
+             WHERE id = ? AND user_id = ?
+            "#,
+            item.title,
+            item.description,
+            item.kind,
+            item.image,
+            item.position,
+            id,
+            user_id
+        )
+        .execute(&self.db)
+        .await?;
+        Ok(res.rows_affected() > 0)
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "portfolio.delete"))]
+    async fn delete(&self, user_id: &str, id: i64) -> RepoResult<bool> {
+        let res = sqlx::query!("DELETE FROM portfolio_items WHERE id = ? AND user_id = ?", id, user_id)
+            .execute(&self.db)
+            .await?;
+        Ok(res.rows_affected() > 0)
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "portfolio.reorder"))]
+    async fn reorder(&self, user_id: &str, ids: &[i64]) -> RepoResult<bool> {
+        let mut tx = self.db.begin().await?;
+        let mut seen = std::collections::HashSet::new();
+        for (pos, id) in ids.iter().enumerate() {
+            let pos = pos as i64;
+            let res = sqlx::query!(
+                "UPDATE portfolio_items SET position = ? WHERE id = ? AND user_id = ?",
+                pos,
+                id,
+                user_id
+            )
+            .execute(&mut *tx)
+            .await?;
+            if res.rows_affected() == 0 || !seen.insert(*id) {
+                // rollback au drop de `tx`
+                return Ok(false);
+            }
+        }
+        tx.commit().await?;
+        Ok(true)
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "portfolio.albums"))]
+    async fn albums(&self, user_id: &str) -> RepoResult<Vec<Album>> {
+        // Première œuvre de chaque album (casse ignorée), puis effectif de l'album.
+        let rows = sqlx::query!(
+            r#"
+            SELECT f.kind, f.image,
+                   (SELECT COUNT(*) FROM portfolio_items c
+                     WHERE c.user_id = f.user_id AND c.kind = f.kind COLLATE NOCASE) as "count!: i64"
+            FROM portfolio_items f
+            WHERE f.user_id = ?1
+              AND f.kind <> ''
+              AND NOT EXISTS (SELECT 1 FROM portfolio_items e
+                              WHERE e.user_id = f.user_id AND e.kind = f.kind COLLATE NOCASE
+                                AND (e.position < f.position OR (e.position = f.position AND e.id < f.id)))
+            ORDER BY f.position, f.id
+            "#,
+            user_id
+        )
+        .fetch_all(&self.db)
+        .await?;
+
+        Ok(rows.into_iter().map(|r| Album { kind: r.kind, count: r.count, cover: r.image }).collect())
+    }
+}
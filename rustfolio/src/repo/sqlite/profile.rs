@@ -0,0 +1,171 @@
+use axum::async_trait;
+use sqlx::SqliteConnection;
+use tracing::instrument;
+
+use super::{translated, SqliteRepo};
+use crate::repo::{ProfileRepo, RepoResult};
+use crate::types::{Profile, ProfileText, Translations};
+
+/// Profil et, si `p.translations`, remplacement de ses traductions.
+pub(super) async fn upsert_profile(conn: &mut SqliteConnection, user_id: &str, p: &Profile) -> RepoResult<()> {
+    sqlx::query!(
+        r#"
+        INSERT INTO profiles
+          (user_id, first_name, last_name, title, email, phone,
+           address, city, country, website, photo_url, summary, updated_at)
+        VALUES
+          (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, CURRENT_TIMESTAMP)
+        ON CONFLICT(user_id) DO UPDATE SET
+          first_name = excluded.first_name,
+          last_name  = excluded.last_name,
+          title      = excluded.title,
+          email      = excluded.email,
+          phone      = excluded.phone,
+          address    = excluded.address,
+          city       = excluded.city,
+          country    = excluded.country,
+          website    = excluded.website,
+          photo_url  = excluded.photo_url,
+          summary    = excluded.summary,
+          updated_at = CURRENT_TIMESTAMP
+        "#,
+        user_id,
+        p.first_name,
+        p.last_name,
+        p.title,
+        p.email,
+        p.phone,
+        p.address,
+        p.city,
+        p.country,
+        p.website,
+        p.photo_url,
+        p.summary
+    )
+    .execute(&mut *conn)
+    .await?;
+
+    if let Some(translations) = &p.translations {
+        sqlx::query!("DELETE FROM profile_translations WHERE user_id = ?", user_id)
+            .execute(&mut *conn)
+            .await?;
+        for (locale, t) in translations {
+            let (title, summary) = (translated(&t.title), translated(&t.summary));
+            if title.is_none() && summary.is_none() {
+                continue;
+            }
+            sqlx::query!(
+                "INSERT INTO profile_translations (user_id, locale, title, summary) VALUES (?, ?, ?, ?)",
+                user_id,
+                locale,
+                title,
+                summary
+            )
+            .execute(&mut *conn)
+            .await?;
+        }
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl ProfileRepo for SqliteRepo {
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "profile.get"))]
+    async fn get(&self, user_id: &str) -> RepoResult<Profile> {
+        let rec = sqlx::query!(
+            r#"
+            SELECT first_name, last_name, title, email, phone,
+                   address, city, country, website, photo_url, summary
+            FROM profiles WHERE user_id = ?
+            "#,
+            user_id
+        )
+        .fetch_optional(&self.db)
+        .await?;
+
+        let translations = sqlx::query!(
+            "SELECT locale, title, summary FROM profile_translations WHERE user_id = ? ORDER BY locale",
+            user_id
+        )
+        .fetch_all(&self.db)
+        .await?;
+        let translations: Translations<ProfileText> = translations
+            .into_iter()
+            .map(|t| (t.locale, ProfileText { title: t.title, summary: t.summary }))
+            .collect();
+
+        Ok(rec
+            .map(|r| Profile {
+                first_name: r.first_name.unwrap_or_default(),
+                last_name: r.last_name.unwrap_or_default(),
+                title: r.title.unwrap_or_default(),
+                email: r.email.unwrap_or_default(),
+                phone: r.phone.unwrap_or_default(),
+                address: r.address.unwrap_or_default(),
+                city: r.city.unwrap_or_default(),
+                country: r.country.unwrap_or_default(),
+                website: r.website.unwrap_or_default(),
+                photo_url: r.photo_url.unwrap_or_default(),
+                summary: r.summary.unwrap_or_default(),
+                translations: None,
+            })
+            .map(|p| Profile { translations: (!translations.is_empty()).then_some(translations), ..p })
+            .unwrap_or_default())
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "profile.upsert"))]
+    async fn upsert(&self, user_id: &str, p: &Profile) -> RepoResult<()> {
+        let mut tx = self.db.begin().await?;
+        upsert_profile(&mut tx, user_id, p).await?;
+        tx.commit().await
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "profile.locales"))]
+    async fn locales(&self, user_id: &str) -> RepoResult<Vec<String>> {
+        sqlx::query_scalar!(
+            r#"
+            SELECT locale AS "locale!" FROM profile_translations WHERE user_id = ?1
+            UNION
+            SELECT t.locale FROM experience_translations t
+              JOIN experiences e ON e.id = t.experience_id WHERE e.user_id = ?1
+            UNION
+            SELECT t.locale FROM project_translations t
+              JOIN projects p ON p.id = t.project_id WHERE p.user_id = ?1
+            ORDER BY 1
+            "#,
+            user_id
+        )
+        .fetch_all(&self.db)
+        .await
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "profile.slug"))]
+    async fn slug(&self, user_id: &str) -> RepoResult<Option<String>> {
+        let slug = sqlx::query_scalar!("SELECT slug FROM profiles WHERE user_id = ?", user_id)
+            .fetch_optional(&self.db)
+            .await?;
+        Ok(slug.flatten())
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "profile.set_slug"))]
+    async fn set_slug(&self, user_id: &str, slug: Option<&str>) -> RepoResult<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO profiles (user_id, slug, updated_at) VALUES (?, ?, CURRENT_TIMESTAMP)
+            ON CONFLICT(user_id) DO UPDATE SET slug = excluded.slug, updated_at = CURRENT_TIMESTAMP
+            "#,
+            user_id,
+            slug
+        )
+        .execute(&self.db)
+        .await?;
+        Ok(())
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "profile.by_slug"))]
+    async fn by_slug(&self, slug: &str) -> RepoResult<Option<String>> {
+        sqlx::query_scalar!(r#"SELECT user_id AS "user_id!" FROM profiles WHERE slug = ?"#, slug)
+            .fetch_optional(&self.db)
+            .await
+    }
+}
@@ -0,0 +1,422 @@
+use axum::async_trait;
+use sqlx::{QueryBuilder, Sqlite, SqliteConnection};
+use tracing::instrument;
+
+use super::{catalog::canonicalize, translated, SqliteRepo};
+use crate::repo::{CatalogRepo, FacetCount, ProjectFacets, ProjectPage, ProjectRepo, ProjectSearch, ProjectSort, RepoResult};
+use crate::types::{Project, ProjectText, TechItem, Translations};
+
+/// Texte libre → requête FTS5 : chaque mot devient un préfixe entre guillemets
+/// (`"rus"*`), les mots sont combinés en ET. La syntaxe FTS5 saisie (`OR`, `-`,
+/// `:`…) n'est donc jamais interprétée. `None` s'il ne reste aucun mot.
+fn fts_query(q: &str) -> Option<String> {
+    let terms: Vec<String> = q
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(|w| format!("\"{w}\"*"))
+        .collect();
+    (!terms.is_empty()).then(|| terms.join(" "))
+}
+
+/// `WITH matched(id, rank) AS (…)` : projets de l'utilisateur qui passent tous les filtres.
+/// Partagé par la page, le total et les facettes.
+fn matched<'a>(user_id: &str, s: &ProjectSearch, fts: Option<&str>) -> QueryBuilder<'a, Sqlite> {
+    let mut qb = QueryBuilder::new("WITH matched(id, rank) AS (SELECT p.id, ");
+    match fts {
+        // poids bm25 : titre > technos > description > catégorie
+        Some(fts) => {
+            qb.push("bm25(projects_fts, 10.0, 2.0, 1.0, 4.0) FROM projects p JOIN projects_fts ON projects_fts.rowid = p.id AND projects_fts MATCH ");
+            qb.push_bind(fts.to_string());
+        }
+        None => {
+            qb.push("0.0 FROM projects p");
+        }
+    }
+    qb.push(" WHERE p.user_id = ").push_bind(user_id.to_string());
+
+    if let Some(cat) = &s.category {
+        qb.push(" AND p.category = ").push_bind(cat.clone()).push(" COLLATE NOCASE");
+    }
+    if !s.techs.is_empty() {
+        qb.push(if s.all_techs {
+            " AND (SELECT COUNT(DISTINCT lower(t.tech)) FROM project_technologies t WHERE t.project_id = p.id AND lower(t.tech) IN ("
+        } else {
+            " AND EXISTS (SELECT 1 FROM project_technologies t WHERE t.project_id = p.id AND lower(t.tech) IN ("
+        });
+        let mut sep = qb.separated(", ");
+        for t in &s.techs {
+            sep.push("lower(").push_bind_unseparated(t.clone()).push_unseparated(")");
+        }
+        qb.push("))");
+        if s.all_techs {
+            qb.push(" = ").push_bind(s.techs.len() as i64);
+        }
+    }
+    qb.push(") ");
+    qb
+}
+
+impl SqliteRepo {
+    async fn owns_project(&self, user_id: &str, project_id: i64) -> RepoResult<bool> {
+        let n: i64 = sqlx::query_scalar!(
+            r#"SELECT COUNT(1) AS "n!: i64" FROM projects WHERE id = ? AND user_id = ?"#,
+            project_id,
+            user_id
+        )
+        .fetch_one(&self.db)
+        .await?;
+        Ok(n > 0)
+    }
+
+    async fn project_translations(&self, project_id: i64) -> RepoResult<Option<Translations<ProjectText>>> {
+        let rows = sqlx::query!(
+            "SELECT locale, description FROM project_translations WHERE project_id = ? ORDER BY locale",
+            project_id
+        )
+        .fetch_all(&self.db)
+        .await?;
+        let out: Translations<ProjectText> =
+            rows.into_iter().map(|t| (t.locale, ProjectText { description: t.description })).collect();
+        Ok((!out.is_empty()).then_some(out))
+    }
+
+    async fn project_techs(&self, project_id: i64) -> RepoResult<Vec<String>> {
+        let rows = sqlx::query!(r#"SELECT tech FROM project_technologies WHERE project_id = ? ORDER BY id"#, project_id)
+            .fetch_all(&self.db)
+            .await?;
+        Ok(rows.into_iter().map(|t| t.tech).collect())
+    }
+}
+
+async fn insert_project(conn: &mut SqliteConnection, user_id: &str, p: &Project) -> RepoResult<i64> {
+    let res = sqlx::query!(
+        r#"
+        INSERT INTO projects
+          (user_id, title, description, category, repo_link, pdf_link, image, updated_at)
+        VALUES
+          (?,?,?,?,?,?,?,CURRENT_TIMESTAMP)
+        "#,
+        user_id,
+        p.title,
+        p.description,
+        p.category,
+        p.repo_link,
+        p.pdf_link,
+        p.image
+    )
+    .execute(&mut *conn)
+    .await?;
+    let id = res.last_insert_rowid();
+    if let Some(t) = &p.translations {
+        replace_project_translations(conn, id, t).await?;
+    }
+    Ok(id)
+}
+
+async fn replace_project_translations(conn: &mut SqliteConnection, project_id: i64, list: &Translations<ProjectText>) -> RepoResult<()> {
+    sqlx::query!("DELETE FROM project_translations WHERE project_id = ?", project_id)
+        .execute(&mut *conn)
+        .await?;
+    for (locale, t) in list {
+        let Some(description) = translated(&t.description) else { continue };
+        sqlx::query!(
+            "INSERT INTO project_translations (project_id, locale, description) VALUES (?, ?, ?)",
+            project_id,
+            locale,
+            description
+        )
+        .execute(&mut *conn)
+        .await?;
+    }
+    Ok(())
+}
+
+// Insère une techno normalisée via le catalogue ("rust" → "Rust" + tech_id)
+async fn insert_project_tech(conn: &mut SqliteConnection, project_id: i64, raw: &str) -> RepoResult<TechItem> {
+    let canon = canonicalize(&mut *conn, raw).await?;
+    let res = sqlx::query!(
+        "INSERT INTO project_technologies (project_id, tech, tech_id) VALUES (?, ?, ?)",
+        project_id,
+        canon.name,
+        canon.tech_id
+    )
+    .execute(conn)
+    .await?;
+
+    Ok(TechItem { id: res.last_insert_rowid(), tech: canon.name })
+}
+
+/// Remplace tous les projets, technos et traductions comprises (PUT /cv/bulk),
+/// dans la transaction de l'appelant.
+pub(super) async fn replace_projects(conn: &mut SqliteConnection, user_id: &str, list: &[Project]) -> RepoResult<()> {
+    // purge enfants d’abord
+    sqlx::query!(
+        "DELETE FROM project_technologies WHERE project_id IN (SELECT id FROM projects WHERE user_id = ?)",
+        user_id
+    )
+    .execute(&mut *conn)
+    .await?;
+
+    sqlx::query!("DELETE FROM projects WHERE user_id = ?", user_id)
+        .execute(&mut *conn)
+        .await?;
+
+    for p in list {
+        let id = insert_project(&mut *conn, user_id, p).await?;
+        for t in &p.technologies {
+            insert_project_tech(&mut *conn, id, t).await?;
+        }
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl ProjectRepo for SqliteRepo {
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "projects.list"))]
+    async fn list(&self, user_id: &str) -> RepoResult<Vec<Project>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT
+                id as "id!: i64",
+                title, description, category, repo_link, pdf_link, image
+            FROM projects
+            WHERE user_id = ?
+            ORDER BY id
+            "#,
+            user_id
+        )
+        .fetch_all(&self.db)
+        .await?;
+
+        let mut out = Vec::with_capacity(rows.len());
+        for r in rows {
+            out.push(Project {
+                id: Some(r.id),
+                title: r.title,
+                description: r.description.unwrap_or_default(),
+                category: r.category.unwrap_or_default(),
+                repo_link: r.repo_link.unwrap_or_default(),
+                pdf_link: r.pdf_link.unwrap_or_default(),
+                image: r.image.unwrap_or_default(),
+                technologies: self.project_techs(r.id).await?,
+                translations: self.project_translations(r.id).await?,
+            });
+        }
+        Ok(out)
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "projects.create"))]
+    async fn create(&self, user_id: &str, p: &Project) -> RepoResult<Project> {
+        let mut tx = self.db.begin().await?;
+        let id = insert_project(&mut tx, user_id, p).await?;
+
+        let mut technologies = Vec::with_capacity(p.technologies.len());
+        for t in &p.technologies {
+            technologies.push(insert_project_tech(&mut tx, id, t).await?.tech);
+        }
+        tx.commit().await?;
+
+        Ok(Project { id: Some(id), technologies, ..p.clone() })
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "projects.update"))]
+    async fn update(&self, user_id: &str, id: i64, p: &Project) -> RepoResult<bool> {
+        // le projet, ses technos et ses traductions ensemble
+        let mut tx = self.db.begin().await?;
+        let res = sqlx::query!(
+            r#"
+            UPDATE projects
+               SET title = ?,
+                   description = ?,
+                   category = ?,
+                   repo_link = ?,
+                   pdf_link = ?,
+                   image = ?,
+                   updated_at = CURRENT_TIMESTAMP
+             WHERE id = ? AND user_id = ?
+            "#,
+            p.title,
+            p.description,
+            p.category,
+            p.repo_link,
+            p.pdf_link,
+            p.image,
+            id,
+            user_id
+        )
+        .execute(&mut *tx)
+        .await?;
+        if res.rows_affected() == 0 {
+            return Ok(false);
+        }
+
+        // refresh ses technologies
+        sqlx::query!("DELETE FROM project_technologies WHERE project_id = ?", id)
+            .execute(&mut *tx)
+            .await?;
+        for t in &p.technologies {
+            insert_project_tech(&mut tx, id, t).await?;
+        }
+        if let Some(t) = &p.translations {
+            replace_project_translations(&mut tx, id, t).await?;
+        }
+        tx.commit().await?;
+        Ok(true)
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "projects.delete"))]
+    async fn delete(&self, user_id: &str, id: i64) -> RepoResult<bool> {
+        if !self.owns_project(user_id, id).await? {
+            return Ok(false);
+        }
+        sqlx::query!("DELETE FROM project_technologies WHERE project_id = ?", id)
+            .execute(&self.db)
+            .await?;
+        sqlx::query!("DELETE FROM projects WHERE id = ? AND user_id = ?", id, user_id)
+            .execute(&self.db)
+            .await?;
+        Ok(true)
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "projects.set_pdf_link"))]
+    async fn set_pdf_link(&self, user_id: &str, id: i64, url: &str) -> RepoResult<bool> {
+        let res = sqlx::query!(
+            "UPDATE projects SET pdf_link = ?, updated_at = CURRENT_TIMESTAMP WHERE id = ? AND user_id = ?",
+            url,
+            id,
+            user_id
+        )
+        .execute(&self.db)
+        .await?;
+        Ok(res.rows_affected() > 0)
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "projects.list_tech"))]
+    async fn list_tech(&self, user_id: &str, project_id: i64) -> RepoResult<Option<Vec<TechItem>>> {
+        if !self.owns_project(user_id, project_id).await? {
+            return Ok(None);
+        }
+        let rows = sqlx::query!(
+            r#"SELECT id as "id!: i64", tech FROM project_technologies WHERE project_id = ? ORDER BY id"#,
+            project_id
+        )
+        .fetch_all(&self.db)
+        .await?;
+
+        Ok(Some(rows.into_iter().map(|r| TechItem { id: r.id, tech: r.tech }).collect()))
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "projects.add_tech"))]
+    async fn add_tech(&self, user_id: &str, project_id: i64, raw: &str) -> RepoResult<Option<TechItem>> {
+        if !self.owns_project(user_id, project_id).await? {
+            return Ok(None);
+        }
+        insert_project_tech(&mut *self.db.acquire().await?, project_id, raw).await.map(Some)
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "projects.update_tech"))]
+    async fn update_tech(&self, user_id: &str, project_id: i64, tech_id: i64, raw: &str) -> RepoResult<Option<TechItem>> {
+        let canon = self.canonicalize(raw).await?;
+        let res = sqlx::query!(
+            r#"
+            UPDATE project_technologies SET tech = ?, tech_id = ?
+             WHERE project_id = ? AND id = ?
+               AND project_id IN (SELECT id FROM projects WHERE user_id = ?)
+            "#,
+            canon.name,
+            canon.tech_id,
+            project_id,
+            tech_id,
+            user_id
+        )
+        .execute(&self.db)
+        .await?;
+
+        Ok((res.rows_affected() > 0).then_some(TechItem { id: tech_id, tech: canon.name }))
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "projects.delete_tech"))]
+    async fn delete_tech(&self, user_id: &str, project_id: i64, tech_id: i64) -> RepoResult<bool> {
+        let res = sqlx::query!(
+            r#"
+            DELETE FROM project_technologies
+             WHERE project_id = ? AND id = ?
+               AND project_id IN (SELECT id FROM projects WHERE user_id = ?)
+            "#,
+            project_id,
+            tech_id,
+            user_id
+        )
+        .execute(&self.db)
+        .await?;
+        Ok(res.rows_affected() > 0)
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "projects.search"))]
+    async fn search(&self, user_id: &str, s: &ProjectSearch) -> RepoResult<ProjectPage> {
+        let fts = s.q.as_deref().and_then(fts_query);
+        let fts = fts.as_deref();
+
+        let total: i64 = matched(user_id, s, fts)
+            .push("SELECT COUNT(*) FROM matched")
+            .build_query_scalar()
+            .fetch_one(&self.db)
+            .await?;
+
+        let mut qb = matched(user_id, s, fts);
+        qb.push(
+            "SELECT p.id, p.title, p.description, p.category, p.repo_link, p.pdf_link, p.image \
+             FROM matched m JOIN projects p ON p.id = m.id ORDER BY ",
+        );
+        qb.push(match s.sort {
+            ProjectSort::Relevance if fts.is_some() => "m.rank, p.id DESC",
+            ProjectSort::Relevance | ProjectSort::Recent => "p.updated_at DESC, p.id DESC",
+            ProjectSort::Oldest => "p.updated_at, p.id",
+            ProjectSort::Title => "p.title COLLATE NOCASE, p.id",
+        });
+        qb.push(" LIMIT ").push_bind(s.limit).push(" OFFSET ").push_bind(s.offset);
+        type Row = (i64, String, Option<String>, Option<String>, Option<String>, Option<String>, Option<String>);
+        let rows: Vec<Row> = qb.build_query_as().fetch_all(&self.db).await?;
+
+        let mut items = Vec::with_capacity(rows.len());
+        for (id, title, description, category, repo_link, pdf_link, image) in rows {
+            items.push(Project {
+                id: Some(id),
+                title,
+                description: description.unwrap_or_default(),
+                category: category.unwrap_or_default(),
+                repo_link: repo_link.unwrap_or_default(),
+                pdf_link: pdf_link.unwrap_or_default(),
+                image: image.unwrap_or_default(),
+                technologies: self.project_techs(id).await?,
+                translations: self.project_translations(id).await?,
+            });
+        }
+
+        let categories: Vec<(String, i64)> = matched(user_id, s, fts)
+            .push(
+                "SELECT MIN(p.category), COUNT(*) AS n FROM matched m JOIN projects p ON p.id = m.id \
+                 WHERE COALESCE(p.category, '') <> '' \
+                 GROUP BY lower(p.category) ORDER BY n DESC, lower(p.category)",
+            )
+            .build_query_as()
+            .fetch_all(&self.db)
+            .await?;
+        let technologies: Vec<(String, i64)> = matched(user_id, s, fts)
+            .push(
+                "SELECT MIN(t.tech), COUNT(DISTINCT m.id) AS n FROM matched m \
+                 JOIN project_technologies t ON t.project_id = m.id \
+                 GROUP BY lower(t.tech) ORDER BY n DESC, lower(t.tech)",
+            )
+            .build_query_as()
+            .fetch_all(&self.db)
+            .await?;
+
+        let facet = |rows: Vec<(String, i64)>| rows.into_iter().map(|(value, count)| FacetCount { value, count }).collect();
+        Ok(ProjectPage {
+            total,
+            items,
+            facets: ProjectFacets { categories: facet(categories), technologies: facet(technologies) },
+        })
+    }
+}
@@ -0,0 +1,208 @@
+use axum::async_trait;
+use sqlx::SqliteConnection;
+use tracing::instrument;
+
+use super::{catalog::canonicalize, SqliteRepo};
+use crate::repo::{CatalogRepo, RepoResult, SkillProject, SkillRepo};
+use crate::types::{SkillIn, SkillLevel, SkillOut};
+
+struct SkillRow {
+    id: i64,
+    name: String,
+    percentage: Option<i64>,
+    level: Option<String>,
+    logo_url: Option<String>,
+    category: String,
+}
+
+impl From<SkillRow> for SkillOut {
+    fn from(r: SkillRow) -> Self {
+        SkillOut {
+            id: r.id,
+            name: r.name,
+            percentage: r.percentage.and_then(|p| u8::try_from(p).ok()),
+            level: r.level.as_deref().and_then(SkillLevel::parse),
+            logo_url: r.logo_url,
+            category: r.category,
+        }
+    }
+}
+
+async fn insert_skill(conn: &mut SqliteConnection, user_id: &str, s: &SkillIn) -> RepoResult<i64> {
+    let perc_i64 = s.percentage.map(i64::from);
+    let level = s.level.map(SkillLevel::as_str);
+    let canon = canonicalize(&mut *conn, &s.name).await?;
+    let logo_url = s.logo_url.clone().filter(|l| !l.is_empty()).or(canon.logo_url);
+
+    let res = sqlx::query!(
+        r#"
+        INSERT INTO skills (user_id, name, percentage, level, logo_url, category, tech_id, updated_at)
+        VALUES (?, ?, ?, ?, ?, ?, ?, CURRENT_TIMESTAMP)
+        "#,
+        user_id,
+        canon.name,
+        perc_i64,
+        level,
+        logo_url,
+        s.category,
+        canon.tech_id
+    )
+    .execute(conn)
+    .await?;
+
+    Ok(res.last_insert_rowid())
+}
+
+/// Remplace toutes les compétences (PUT /cv/bulk), dans la transaction de l'appelant.
+pub(super) async fn replace_skills(conn: &mut SqliteConnection, user_id: &str, list: &[SkillIn]) -> RepoResult<()> {
+    sqlx::query!("DELETE FROM skills WHERE user_id = ?", user_id)
+        .execute(&mut *conn)
+        .await?;
+    for s in list {
+        insert_skill(&mut *conn, user_id, s).await?;
+    }
+    Ok(())
+}
+
+#[async_trait]
+impl SkillRepo for SqliteRepo {
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "skills.list"))]
+    async fn list(&self, user_id: &str) -> RepoResult<Vec<SkillOut>> {
+        let rows = sqlx::query_as!(
+            SkillRow,
+            r#"
+            SELECT
+                id         AS "id!: i64",
+                name       AS "name!: String",
+                percentage AS "percentage?: i64",
+                level      AS "level?: String",
+                logo_url   AS "logo_url?: String",
+                category   AS "category!: String"
+            FROM skills
+            WHERE user_id = ?
+            ORDER BY updated_at DESC, id DESC
+            "#,
+            user_id
+        )
+        .fetch_all(&self.db)
+        .await?;
+
+        Ok(rows.into_iter().map(SkillOut::from).collect())
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "skills.get"))]
+    async fn get(&self, user_id: &str, id: i64) -> RepoResult<Option<SkillOut>> {
+        let row = sqlx::query_as!(
+            SkillRow,
+            r#"
+            SELECT
+                id         AS "id!: i64",
+                name       AS "name!: String",
+                percentage AS "percentage?: i64",
+                level      AS "level?: String",
+                logo_url   AS "logo_url?: String",
+                category   AS "category!: String"
+            FROM skills
+            WHERE id = ? AND user_id = ?
+            "#,
+            id,
+            user_id
+        )
+        .fetch_optional(&self.db)
+        .await?;
+
+        Ok(row.map(SkillOut::from))
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "skills.create"))]
+    async fn create(&self, user_id: &str, s: &SkillIn) -> RepoResult<i64> {
+        insert_skill(&mut *self.db.acquire().await?, user_id, s).await
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "skills.update"))]
+    async fn update(&self, user_id: &str, id: i64, s: &SkillIn) -> RepoResult<bool> {
+        let perc_i64 = s.percentage.map(i64::from);
+        let level = s.level.map(SkillLevel::as_str);
+        let canon = self.canonicalize(&s.name).await?;
+        let logo_url = s.logo_url.clone().filter(|l| !l.is_empty()).or(canon.logo_url);
+
+        let res = sqlx::query!(
+            r#"
+            UPDATE skills
+            SET
+                name        = ?,
+                percentage  = ?,
+                level       = ?,
+                logo_url    = ?,
+                category    = ?,
+                tech_id     = ?,
+                updated_at  = CURRENT_TIMESTAMP
+            WHERE id = ? AND user_id = ?
+            "#,
+            canon.name,
+            perc_i64,
+            level,
+            logo_url,
+            s.category,
+            canon.tech_id,
+            id,
+            user_id
+        )
+        .execute(&self.db)
+        .await?;
+
+        Ok(res.rows_affected() > 0)
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "skills.delete"))]
+    async fn delete(&self, user_id: &str, id: i64) -> RepoResult<bool> {
+        let res = sqlx::query!("DELETE FROM skills WHERE id = ? AND user_id = ?", id, user_id)
+            .execute(&self.db)
+            .await?;
+        Ok(res.rows_affected() > 0)
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "skills.categories"))]
+    async fn categories(&self, user_id: &str) -> RepoResult<Vec<String>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT DISTINCT category AS "category!: String"
+            FROM skills
+            WHERE user_id = ? AND category <> ''
+            ORDER BY category COLLATE NOCASE
+            "#,
+            user_id
+        )
+        .fetch_all(&self.db)
+        .await?;
+
+        Ok(rows.into_iter().map(|r| r.category).collect())
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "skills.projects_using"))]
+    async fn projects_using(&self, user_id: &str, id: i64) -> RepoResult<Vec<SkillProject>> {
+        let rows = sqlx::query!(
+            r#"
+            SELECT DISTINCT
+                p.id       AS "id!: i64",
+                p.title    AS "title!: String",
+                p.category AS "category?: String"
+            FROM skills s
+            JOIN projects p              ON p.user_id = s.user_id
+            JOIN project_technologies pt ON pt.project_id = p.id
+            WHERE s.id = ? AND s.user_id = ?
+              AND (pt.tech_id = s.tech_id OR lower(pt.tech) = lower(s.name))
+            ORDER BY p.id
+            "#,
+            id,
+            user_id
+        )
+        .fetch_all(&self.db)
+        .await?;
+
+        Ok(rows
+            .into_iter()
+            .map(|r| SkillProject { id: r.id, title: r.title, category: r.category.unwrap_or_default() })
+            .collect())
+    }
+}
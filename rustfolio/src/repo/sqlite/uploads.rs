@@ -0,0 +1,106 @@
+use axum::async_trait;
+use tracing::instrument;
+
+use super::SqliteRepo;
+use crate::repo::{RepoResult, Upload, UploadRepo};
+
+#[async_trait]
+impl UploadRepo for SqliteRepo {
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "uploads.find_by_hash"))]
+    async fn find_by_hash(&self, user_id: &str, hash: &str) -> RepoResult<Option<Upload>> {
+        sqlx::query_as!(
+            Upload,
+            r#"
+            SELECT id as "id!: i64", hash, fallback_ext, width, height, bytes, created_at
+            FROM uploads
+            WHERE user_id = ? AND hash = ?
+            "#,
+            user_id,
+            hash
+        )
+        .fetch_optional(&self.db)
+        .await
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "uploads.list"))]
+    async fn list(&self, user_id: &str) -> RepoResult<Vec<Upload>> {
+        sqlx::query_as!(
+            Upload,
+            r#"
+            SELECT id as "id!: i64", hash, fallback_ext, width, height, bytes, created_at
+            FROM uploads
+            WHERE user_id = ?
+            ORDER BY id
+            "#,
+            user_id
+        )
+        .fetch_all(&self.db)
+        .await
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "uploads.create"))]
+    async fn create(&self, user_id: &str, u: &Upload) -> RepoResult<i64> {
+        let res = sqlx::query!(
+            "INSERT INTO uploads (user_id, hash, fallback_ext, width, height, bytes) VALUES (?, ?, ?, ?, ?, ?)",
+            user_id,
+            u.hash,
+            u.fallback_ext,
+            u.width,
+            u.height,
+            u.bytes
+        )
+        .execute(&self.db)
+        .await?;
+        Ok(res.last_insert_rowid())
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "uploads.get"))]
+    async fn get(&self, user_id: &str, id: i64) -> RepoResult<Option<Upload>> {
+        sqlx::query_as!(
+            Upload,
+            r#"
+            SELECT id as "id!: i64", hash, fallback_ext, width, height, bytes, created_at
+            FROM uploads
+            WHERE user_id = ? AND id = ?
+            "#,
+            user_id,
+            id
+        )
+        .fetch_optional(&self.db)
+        .await
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "uploads.delete"))]
+    async fn delete(&self, user_id: &str, id: i64) -> RepoResult<bool> {
+        let res = sqlx::query!("DELETE FROM uploads WHERE user_id = ? AND id = ?", user_id, id)
+            .execute(&self.db)
+            .await?;
+        Ok(res.rows_affected() > 0)
+    }
+
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "uploads.orphans"))]
+    async fn orphans(&self, user_id: &str, before: &str) -> RepoResult<Vec<Upload>> {
+        sqlx::query_as!(
+            Upload,
+            r#"
+            SELECT u.id as "id!: i64", u.hash, u.fallback_ext, u.width, u.height, u.bytes, u.created_at
+            FROM uploads u
+            WHERE u.user_id = ?1
+              AND u.created_at <= ?2
+              AND NOT EXISTS (SELECT 1 FROM profiles p
+                              WHERE p.user_id = u.user_id AND instr(p.photo_url, u.hash) > 0)
+              AND NOT EXISTS (SELECT 1 FROM projects pr
+                              WHERE pr.user_id = u.user_id AND instr(pr.image, u.hash) > 0)
+              AND NOT EXISTS (SELECT 1 FROM skills s
+                              WHERE s.user_id = u.user_id AND instr(s.logo_url, u.hash) > 0)
+              AND NOT EXISTS (SELECT 1 FROM portfolio_items pf
+                              WHERE pf.user_id = u.user_id AND instr(pf.image, u.hash) > 0)
+            ORDER BY u.id
+            "#,
+            user_id,
+            before
+        )
+        .fetch_all(&self.db)
+        .await
+    }
+}
@@ -0,0 +1,52 @@
+use axum::async_trait;
+use tracing::instrument;
+
+use super::SqliteRepo;
+use crate::repo::{Collection, RepoResult, VersionRepo};
+
+#[async_trait]
+impl VersionRepo for SqliteRepo {
+    #[instrument(name = "db", level = "debug", skip_all, fields(op = "versions.fingerprint"))]
+    async fn fingerprint(&self, user_id: &str, c: Collection) -> RepoResult<String> {
+        // requêtes distinctes : le nom de table ne peut pas être lié
+        let fp = match c {
+            Collection::Experiences => {
+                sqlx::query_scalar!(
+                    r#"SELECT COALESCE(group_concat(v, ','), '') as "fp!: String"
+                         FROM (SELECT id || '@' || COALESCE(updated_at, '') AS v FROM experiences WHERE user_id = ? ORDER BY id)"#,
+                    user_id
+                )
+                .fetch_one(&self.db)
+                .await?
+            }
+            Collection::Projects => {
+                sqlx::query_scalar!(
+                    r#"SELECT COALESCE(group_concat(v, ','), '') as "fp!: String"
+                         FROM (SELECT id || '@' || COALESCE(updated_at, '') AS v FROM projects WHERE user_id = ? ORDER BY id)"#,
+                    user_id
+                )
+                .fetch_one(&self.db)
+                .await?
+            }
+            Collection::Skills => {
+                sqlx::query_scalar!(
+                    r#"SELECT COALESCE(group_concat(v, ','), '') as "fp!: String"
+                         FROM (SELECT id || '@' || updated_at AS v FROM skills WHERE user_id = ? ORDER BY id)"#,
+                    user_id
+                )
+                .fetch_one(&self.db)
+                .await?
+            }
+            Collection::Portfolio => {
+                sqlx::query_scalar!(
+                    r#"SELECT COALESCE(group_concat(v, ','), '') as "fp!: String"
+                         FROM (SELECT id || '@' || updated_at AS v FROM portfolio_items WHERE user_id = ? ORDER BY id)"#,
+                    user_id
+                )
+                .fetch_one(&self.db)
+                .await?
+            }
+        };
+        Ok(fp)
+    }
+}
@@ -1,5 +1,15 @@
-use axum::{extract::{Query, State}, Json};
-use crate::{state::AppState, data};
+use axum::{
+    extract::{Query, State},
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::Serialize;
+
+use crate::repo::{Collection, ProjectFacets, ProjectPage, ProjectSearch, ProjectSort};
+use crate::routes::listing;
+use crate::state::AppState;
+use crate::types::Project;
 
 #[derive(serde::Serialize)]
 pub struct Info {
@@ -12,36 +22,111 @@ pub async fn info_handler() -> Json<Info> {
     Json(Info { status: "ok", app: "rustfolio", version: env!("CARGO_PKG_VERSION") })
 }
 
-#[derive(serde::Deserialize, Debug)]
-pub struct ProjectFilter {
-    pub q: Option<String>,
-    pub category: Option<String>,
-    pub tech: Option<String>,
-    pub limit: Option<usize>,
+/// Taille de page par défaut / maximale de `GET /api/projects`.
+const DEFAULT_LIMIT: i64 = 20;
+const MAX_LIMIT: i64 = 100;
+
+/// Page de résultats de `GET /api/projects`, avec les facettes du filtre courant.
+#[derive(Debug, Serialize)]
+pub struct ProjectResults {
+    pub total:       i64,
+    pub offset:      i64,
+    pub limit:       i64,
+    pub next_cursor: Option<String>,
+    pub items:       Vec<Project>,
+    pub facets:      ProjectFacets,
+}
+
+fn bad_request(msg: String) -> (StatusCode, String) {
+    (StatusCode::BAD_REQUEST, msg)
+}
+
+/// Les paramètres arrivent en paires pour accepter les clés répétées (`tech`).
+fn parse_search(params: Vec<(String, String)>) -> Result<(ProjectSearch, Vec<String>), (StatusCode, String)> {
+    let mut s = ProjectSearch { all_techs: true, limit: DEFAULT_LIMIT, ..Default::default() };
+    let mut techs = Vec::new();
+    let number = |k: &str, v: &str| v.parse::<i64>().ok().filter(|n| *n >= 0).ok_or_else(|| bad_request(format!("`{k}` invalide : {v}")));
+
+    for (k, v) in params {
+        let v = v.trim();
+        match k.as_str() {
+            "q" if !v.is_empty() => s.q = Some(v.to_string()),
+            "category" if !v.is_empty() => s.category = Some(v.to_string()),
+            "tech" => techs.extend(v.split(',').map(str::trim).filter(|t| !t.is_empty()).map(String::from)),
+            "tech_mode" => {
+                s.all_techs = match v {
+                    "" | "all" => true,
+                    "any" => false,
+                    _ => return Err(bad_request(format!("`tech_mode` invalide : {v} (all, any)"))),
+                }
+            }
+            "sort" => {
+                s.sort = match v {
+                    "" | "relevance" => ProjectSort::Relevance,
+                    "recent" => ProjectSort::Recent,
+                    "oldest" => ProjectSort::Oldest,
+                    "title" => ProjectSort::Title,
+                    _ => return Err(bad_request(format!("`sort` invalide : {v} (relevance, recent, oldest, title)"))),
+                }
+            }
+            "limit" if !v.is_empty() => s.limit = number("limit", v)?.clamp(1, MAX_LIMIT),
+            "offset" | "cursor" if !v.is_empty() => s.offset = number(&k, v)?,
+            _ => {}
+        }
+    }
+    Ok((s, techs))
 }
 
+/// `GET /api/projects` (public) : projets du propriétaire du site.
+///
+/// - `q` : recherche plein texte (titre, description, catégorie, technos), accents ignorés ;
+/// - `category` : catégorie exacte ;
+/// - `tech` : répétable (`tech=rust&tech=axum`) ou séparé par des virgules ;
+///   `tech_mode=all` (défaut, toutes requises) ou `any` ;
+/// - `sort` : `relevance` (défaut), `recent`, `oldest`, `title` ;
+/// - `limit` (≤ 100), `offset` ou `cursor` (valeur `next_cursor` de la page précédente).
 pub async fn api_projects(
     State(st): State<AppState>,
-    Query(f): Query<ProjectFilter>,
-) -> Json<Vec<data::Project>> {
-    let mut out: Vec<data::Project> = st.projects.iter().cloned().collect();
-    let norm = |s: &str| s.to_lowercase();
-
-    if let Some(q) = &f.q {
-        let qn = norm(q);
-        out.retain(|p| norm(&p.title).contains(&qn) || norm(&p.description).contains(&qn));
-    }
-    if let Some(cat) = &f.category {
-        let cn = norm(cat);
-        out.retain(|p| norm(&p.category) == cn);
-    }
-    if let Some(tech) = &f.tech {
-        let tn = norm(tech);
-        out.retain(|p| p.technologies.iter().any(|t| norm(t) == tn));
+    headers: HeaderMap,
+    Query(params): Query<Vec<(String, String)>>,
+) -> Result<Response, (StatusCode, String)> {
+    let raw: Vec<String> = params.iter().map(|(k, v)| format!("{k}={v}")).collect();
+    let (mut s, techs) = parse_search(params)?;
+    let ise = |e: sqlx::Error| {
+        tracing::error!(error = %e, "recherche projets");
+        (StatusCode::INTERNAL_SERVER_ERROR, "erreur interne".to_string())
+    };
+
+    // « rustlang », « RUST » → « Rust » : même normalisation qu'à l'enregistrement
+    for t in techs {
+        let name = st.repos.catalog.canonicalize(&t).await.map_err(ise)?.name;
+        if !s.techs.iter().any(|n| n.eq_ignore_ascii_case(&name)) {
+            s.techs.push(name);
+        }
     }
-    if let Some(max) = f.limit {
-        out.truncate(max);
+
+    let owner = st.site_owner().await.map_err(ise)?;
+    let fp = match &owner {
+        Some(owner) => st.repos.versions.fingerprint(owner, Collection::Projects).await.map_err(ise)?,
+        None => String::new(),
+    };
+    let etag = listing::weak_etag(&[owner.as_deref().unwrap_or_default(), &fp, &raw.join("&")]);
+    if let Some(res) = listing::not_modified(&headers, &etag, listing::PUBLIC) {
+        return Ok(res);
     }
 
-    Json(out)
+    let page = match owner {
+        Some(owner) => st.repos.projects.search(&owner, &s).await.map_err(ise)?,
+        None => ProjectPage::default(),
+    };
+    let next = s.offset + page.items.len() as i64;
+    let out = ProjectResults {
+        total: page.total,
+        offset: s.offset,
+        limit: s.limit,
+        next_cursor: (next < page.total).then(|| next.to_string()),
+        items: page.items,
+        facets: page.facets,
+    };
+    Ok((listing::cache_headers(&etag, listing::PUBLIC), Json(out)).into_response())
 }
@@ -5,22 +5,19 @@ use axum::{
     routing::{get, post},
     Form, Json, Router,
 };
-use axum_extra::extract::cookie::{Cookie, CookieJar, SameSite};
+use axum_extra::extract::cookie::{Cookie, CookieJar};
 use askama::Template;
 //use askama_axum::IntoResponse as _; // permet aux Templates Askama de devenir une Response
 use chrono::Datelike;
-use serde::{Deserialize, Serialize};
+use serde::Deserialize;
 use uuid::Uuid;
 
 use argon2::{password_hash::PasswordHash, Argon2, PasswordVerifier};
 
+use crate::i18n::{self, Lang, Tr, UiLang};
+use crate::mail;
 use crate::state::AppState;
-
-use lettre::message::{header::ContentType, Mailbox, Message, SinglePart};
-use lettre::transport::smtp::authentication::Credentials;
-use lettre::transport::smtp::extension::ClientId;
-use lettre::transport::smtp::response::Response as SmtpResponse;
-use lettre::{SmtpTransport, Transport};
+use crate::types::Me;
 
 // =====================================================
 // Templates
@@ -28,22 +25,18 @@ use lettre::{SmtpTransport, Transport};
 
 #[derive(Template)]
 #[template(path = "auth/login.html")]
-struct LoginTpl<'a> {
+struct LoginTpl {
     year: i32,
-    name: &'a str,
-    title: &'a str,
-    tagline: &'a str,
-    error: Option<&'a str>,
+    error: Option<String>,
+    tr: Tr,
 }
 
 #[derive(Template)]
 #[template(path = "auth/signup.html")]
-struct SignupTpl<'a> {
+struct SignupTpl {
     year: i32,
-    name: &'a str,
-    title: &'a str,
-    tagline: &'a str,
-    error: Option<&'a str>,
+    error: Option<String>,
+    tr: Tr,
 }
 
 // =====================================================
@@ -57,30 +50,26 @@ pub fn router() -> Router<AppState> {
         .route("/logout", post(logout_post))
         .route("/verify", get(verify_email))
         .route("/session", get(session))
-        .route("/me", get(me))
+        .route("/me", get(me).patch(update_me))
 }
 
 // =====================================================
 // GET pages
 // =====================================================
 
-async fn login_page() -> impl IntoResponse {
+async fn login_page(UiLang(tr): UiLang) -> impl IntoResponse {
     LoginTpl {
         year: chrono::Utc::now().year(),
-        name: "Mon site",
-        title: "Connexion",
-        tagline: "Rust • Web • Cloud",
         error: None,
+        tr,
     }
 }
 
-async fn signup_page() -> impl IntoResponse {
+async fn signup_page(UiLang(tr): UiLang) -> impl IntoResponse {
     SignupTpl {
         year: chrono::Utc::now().year(),
-        name: "Mon site",
-        title: "Créer un compte",
-        tagline: "Rust • Web • Cloud",
         error: None,
+        tr,
     }
 }
 
@@ -119,6 +108,7 @@ struct SignupForm {
 
 async fn signup_post(
     State(st): State<AppState>,
+    UiLang(tr): UiLang,
     jar: CookieJar,
     Form(p): Form<SignupForm>,
 ) -> Result<(CookieJar, Redirect), (StatusCode, Response)> {
@@ -133,10 +123,8 @@ async fn signup_post(
             StatusCode::CONFLICT,
             SignupTpl {
                 year: chrono::Utc::now().year(),
-                name: "Mon site",
-                title: "Créer un compte",
-                tagline: "Rust • Web • Cloud",
-                error: Some("Email déjà utilisé"),
+                error: Some(tr.t("auth-email-taken")),
+                tr,
             },
         ));
     }
@@ -179,11 +167,12 @@ async fn signup_post(
     .await
     .map_err(e500_resp)?;
 
-    let base = std::env::var("PUBLIC_BASE_URL").unwrap_or_else(|_| "http://localhost:8080".into());
-    let verify_url = format!("{}/auth/verify?token={}", base, verify_token);
+    let verify_url = format!("{}/auth/verify?token={}", st.config.public_base_url, verify_token);
 
-    // Envoi mail (ou log)
-    send_verification_email(&p.email, &verify_url)
+    // Mis en file : le worker l'envoie (et réessaie si le SMTP est indisponible)
+    let email = mail::templates::verification(&p.email, &verify_url).map_err(e500_resp)?;
+    st.outbox
+        .enqueue(&email)
         .await
         .map_err(|e| e500_resp(format!("Erreur envoi mail: {e}")))?;
 
@@ -192,6 +181,8 @@ async fn signup_post(
         .await
         .map_err(|e| e500_resp(format!("Erreur session: {e}")))?;
 
+    st.metrics.signup();
+    tracing::info!(user_id = %uid, "inscription");
     Ok((jar, Redirect::to("/")))
 }
 
@@ -207,6 +198,7 @@ struct LoginForm {
 
 async fn login_post(
     State(st): State<AppState>,
+    UiLang(tr): UiLang,
     jar: CookieJar,
     Form(p): Form<LoginForm>,
 ) -> Result<(CookieJar, Redirect), (StatusCode, Response)> {
@@ -223,14 +215,13 @@ async fn login_post(
     .map_err(|e| e500_resp(format!("Erreur DB: {e}")))?;
 
     let Some(u) = row else {
+        st.metrics.login(false);
         return Err(with_status_tpl(
             StatusCode::UNAUTHORIZED,
             LoginTpl {
                 year: chrono::Utc::now().year(),
-                name: "Mon site",
-                title: "Connexion",
-                tagline: "Rust • Web • Cloud",
-                error: Some("Identifiants invalides"),
+                error: Some(tr.t("auth-invalid-credentials")),
+                tr,
             },
         ));
     };
@@ -241,14 +232,13 @@ async fn login_post(
         .is_ok();
 
     if !ok {
+        st.metrics.login(false);
         return Err(with_status_tpl(
             StatusCode::UNAUTHORIZED,
             LoginTpl {
                 year: chrono::Utc::now().year(),
-                name: "Mon site",
-                title: "Connexion",
-                tagline: "Rust • Web • Cloud",
-                error: Some("Identifiants invalides"),
+                error: Some(tr.t("auth-invalid-credentials")),
+                tr,
             },
         ));
     }
@@ -257,6 +247,7 @@ async fn login_post(
         .await
         .map_err(|e| e500_resp(format!("Erreur session: {e}")))?;
 
+    st.metrics.login(true);
     Ok((jar, Redirect::to("/")))
 }
 
@@ -284,6 +275,7 @@ struct VerifyQuery {
 
 async fn verify_email(
     State(st): State<AppState>,
+    UiLang(tr): UiLang,
     Query(q): Query<VerifyQuery>,
 ) -> Result<Redirect, (StatusCode, Html<String>)> {
     let rec = sqlx::query!(
@@ -300,7 +292,11 @@ async fn verify_email(
     .map_err(e500_html)?;
 
     let Some(rec) = rec else {
-        let msg = Html("<h1>Lien invalide ou expiré</h1><p>Demande un nouvel e-mail.</p>".to_string());
+        let msg = Html(format!(
+            "<h1>{}</h1><p>{}</p>",
+            tr.t("auth-verify-invalid-title"),
+            tr.t("auth-verify-invalid-text")
+        ));
         return Err((StatusCode::BAD_REQUEST, msg));
     };
 
@@ -322,23 +318,53 @@ async fn verify_email(
 // GET /me
 // =====================================================
 
-#[derive(Serialize)]
-struct Me {
-    email: String,
-    display_name: Option<String>,
-}
-
-async fn me(State(st): State<AppState>, user: AuthUser) -> Result<Json<Me>, (StatusCode, String)> {
-    let u = sqlx::query!("SELECT email, display_name FROM users WHERE id = ?", user.id)
+async fn me(State(st): State<AppState>, UiLang(tr): UiLang, user: AuthUser) -> Result<Json<Me>, (StatusCode, String)> {
+    let u = sqlx::query!("SELECT email, display_name, locale FROM users WHERE id = ?", user.id)
         .fetch_one(&st.db)
         .await
         .map_err(e500)?;
     Ok(Json(Me {
         email: u.email,
         display_name: u.display_name,
+        locale: u.locale,
+        ui_locale: tr.code().to_string(),
     }))
 }
 
+#[derive(Deserialize)]
+struct MePatch {
+    /// `null` ou `""` : revenir à la négociation `Accept-Language`.
+    locale: Option<String>,
+}
+
+// PATCH /auth/me {locale} : 422 si la langue n'a pas de catalogue
+async fn update_me(
+    State(st): State<AppState>,
+    UiLang(tr): UiLang,
+    headers: HeaderMap,
+    user: AuthUser,
+    Json(p): Json<MePatch>,
+) -> Result<Json<Me>, (StatusCode, String)> {
+    let locale = match p.locale.as_deref().map(str::trim).filter(|l| !l.is_empty()) {
+        None => None,
+        Some(tag) => match Lang::parse(tag) {
+            Some(lang) => Some(lang),
+            None => {
+                let msg = tr.t_args("auth-locale-unsupported", &[("locale", tag)]);
+                return Err((StatusCode::UNPROCESSABLE_ENTITY, msg));
+            }
+        },
+    };
+    let code = locale.map(Lang::code);
+    sqlx::query!("UPDATE users SET locale = ? WHERE id = ?", code, user.id)
+        .execute(&st.db)
+        .await
+        .map_err(e500)?;
+    // la réponse reflète déjà le nouveau choix (ou, sans préférence, le navigateur)
+    let tr = locale.map(Tr).unwrap_or_else(|| i18n::from_headers(&headers));
+    me(State(st), UiLang(tr), user).await
+}
+
 // =====================================================
 // Extracteur d'utilisateur
 // =====================================================
@@ -355,7 +381,7 @@ impl FromRequestParts<AppState> for AuthUser {
     async fn from_request_parts(parts: &mut Parts, state: &AppState) -> Result<Self, Self::Rejection> {
         let jar = CookieJar::from_headers(&parts.headers);
         let Some(sid) = jar.get("sid").map(|c| c.value().to_string()) else {
-            return Err((StatusCode::UNAUTHORIZED, "Non connecté".into()));
+            return Err((StatusCode::UNAUTHORIZED, i18n::from_headers(&parts.headers).t("auth-not-logged-in")));
         };
 
         let rec = sqlx::query!(
@@ -367,7 +393,7 @@ impl FromRequestParts<AppState> for AuthUser {
         .map_err(e500)?;
 
         rec.map(|r| AuthUser { id: r.user_id })
-            .ok_or((StatusCode::UNAUTHORIZED, "Session expirée".into()))
+            .ok_or_else(|| (StatusCode::UNAUTHORIZED, i18n::from_headers(&parts.headers).t("auth-session-expired")))
     }
 }
 
@@ -375,7 +401,7 @@ impl FromRequestParts<AppState> for AuthUser {
 // Helpers
 // =====================================================
 
-use time::{format_description::well_known::Rfc3339, Duration, OffsetDateTime};
+use time::{format_description::well_known::Rfc3339, OffsetDateTime};
 
 async fn create_session_cookie(
     st: &AppState,
@@ -383,7 +409,8 @@ async fn create_session_cookie(
     user_id: &str,
 ) -> Result<(CookieJar, (StatusCode, &'static str)), sqlx::Error> {
     let sid = Uuid::new_v4().to_string();
-    let exp = (OffsetDateTime::now_utc() + Duration::days(30))
+    let ttl = st.config.session_ttl;
+    let exp = (OffsetDateTime::now_utc() + ttl)
         .format(&Rfc3339)
         .unwrap();
 
@@ -391,14 +418,12 @@ async fn create_session_cookie(
         .execute(&st.db)
         .await?;
 
-    let secure = std::env::var("COOKIE_SECURE").ok().as_deref() == Some("true");
-
     let cookie = Cookie::build(("sid", sid))
         .http_only(true)
-        .same_site(SameSite::Lax)
-        .secure(secure)
+        .same_site(st.config.cookie.same_site)
+        .secure(st.config.cookie.secure)
         .path("/")
-        .max_age(time::Duration::days(30))
+        .max_age(ttl)
         .build();
 
     Ok((jar.add(cookie), (StatusCode::OK, "ok")))
@@ -406,16 +431,19 @@ async fn create_session_cookie(
 
 // Erreurs 500 -> texte
 fn e500<E: std::fmt::Display>(e: E) -> (StatusCode, String) {
+    tracing::error!(error = %e, "erreur interne");
     (StatusCode::INTERNAL_SERVER_ERROR, format!("Internal error: {e}"))
 }
 
 // Erreurs 500 -> Html
 fn e500_html<E: std::fmt::Display>(e: E) -> (StatusCode, Html<String>) {
+    tracing::error!(error = %e, "erreur interne");
     (StatusCode::INTERNAL_SERVER_ERROR, Html(format!("Internal error: {e}")))
 }
 
 // Erreurs 500 -> Response
 fn e500_resp<E: std::fmt::Display>(e: E) -> (StatusCode, Response) {
+    tracing::error!(error = %e, "erreur interne");
     (StatusCode::INTERNAL_SERVER_ERROR, Html(format!("Internal error: {e}")).into_response())
 }
 
@@ -426,57 +454,3 @@ where
 {
     (code, tpl.into_response())
 }
-
-// =====================================================
-// Email
-// =====================================================
-
-fn need(var: &str) -> anyhow::Result<String> {
-    std::env::var(var).map_err(|_| anyhow::anyhow!("variable manquante: {}", var))
-}
-
-pub async fn send_verification_email(to: &str, verify_url: &str) -> anyhow::Result<()> {
-    let mode = std::env::var("EMAIL_MODE").unwrap_or_else(|_| "log".into());
-    let from = need("EMAIL_FROM")?;
-
-    let subject = "Vérifie ton adresse email";
-    let body_txt = format!(
-        "Bienvenue !\n\nClique sur ce lien pour vérifier ton email :\n{}\n\n",
-        verify_url
-    );
-
-    if mode == "log" {
-        eprintln!(
-            "--- DEV EMAIL (no send) ---\nFROM: {}\nTO: {}\nSUBJECT: {}\n{}\n---------------------------",
-            from, to, subject, body_txt
-        );
-        return Ok(());
-    }
-
-    let smtp_host = need("SMTP_HOST")?;
-    let smtp_port: u16 = need("SMTP_PORT")?.parse()?;
-    let smtp_user = need("SMTP_USER")?;
-    let smtp_pass = need("SMTP_PASS")?;
-
-    let email = Message::builder()
-        .from(from.parse::<Mailbox>()?)
-        .to(to.parse::<Mailbox>()?)
-        .subject(subject)
-        .singlepart(
-            SinglePart::builder()
-                .header(ContentType::TEXT_PLAIN)
-                .body(body_txt),
-        )?;
-
-    let mailer = SmtpTransport::starttls_relay(&smtp_host)?
-        .port(smtp_port)
-        .credentials(Credentials::new(smtp_user, smtp_pass))
-        .hello_name(ClientId::Domain("engagez-moi.com".to_string()))
-        .build();
-
-    let resp: SmtpResponse = mailer.send(&email)?;
-    let msg = resp.message().collect::<Vec<_>>().join(" ");
-    eprintln!("SMTP response: {} {}", resp.code(), msg);
-
-    Ok(())
-}
@@ -0,0 +1,41 @@
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    routing::get,
+    Json, Router,
+};
+use serde::Deserialize;
+
+use crate::repo::CatalogEntry;
+use crate::state::AppState;
+
+type HandlerResult<T> = std::result::Result<T, (StatusCode, String)>;
+fn ise<E: ToString>(e: E) -> (StatusCode, String) {
+    let e = e.to_string();
+    tracing::error!(error = %e, "erreur interne");
+    (StatusCode::INTERNAL_SERVER_ERROR, e)
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SearchQuery {
+    pub q: Option<String>,
+    pub limit: Option<i64>,
+}
+
+pub fn router() -> Router<AppState> {
+    Router::new().route("/catalog/search", get(search))
+}
+
+// GET /api/catalog/search?q=ru → autocomplete (préfixe d'abord, puis "contient")
+// La normalisation à l'écriture (skills / technos de projet) passe par `CatalogRepo::canonicalize`.
+async fn search(
+    State(st): State<AppState>,
+    Query(q): Query<SearchQuery>,
+) -> HandlerResult<Json<Vec<CatalogEntry>>> {
+    let limit = q.limit.unwrap_or(10).clamp(1, 50);
+    let rows = st.repos.catalog
+        .search(q.q.as_deref().unwrap_or(""), limit)
+        .await
+        .map_err(ise)?;
+    Ok(Json(rows))
+}
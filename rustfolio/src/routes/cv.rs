@@ -1,16 +1,29 @@
-use axum::{extract::State, routing::get, Json, Router};
+//! Document CV libre (`/api/cv`) et mise en page du CV par défaut (`/api/layout`).
+//!
+//! Le layout (`rustfolio_layout`) est accepté dans n'importe quelle version connue,
+//! migré vers la version courante puis validé avant d'être stocké : 422 avec la liste
+//! des erreurs sinon. Les layouts stockés dans un ancien format sont migrés à la
+//! lecture ; un layout illisible est remplacé par un layout vide plutôt que de bloquer
+//! le builder. Les autres CV de l'utilisateur passent par `/api/cvs` (`routes::cvs`).
+
+use axum::{extract::State, http::StatusCode, routing::get, Json, Router};
+use rustfolio_layout::Layout;
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
+use crate::routes::auth::AuthUser;
+use crate::routes::cvs;
 use crate::state::AppState;
 
-#[derive(Serialize, Deserialize, Default)]
-pub struct CvPayload { #[serde(flatten)] pub data: Value }
+type HandlerResult<T> = std::result::Result<T, (StatusCode, String)>;
+fn ise<E: ToString>(e: E) -> (StatusCode, String) {
+    let e = e.to_string();
+    tracing::error!(error = %e, "erreur interne");
+    (StatusCode::INTERNAL_SERVER_ERROR, e)
+}
 
 #[derive(Serialize, Deserialize, Default)]
-pub struct LayoutPayload { #[serde(flatten)] pub layout: Value }
-
-fn uid() -> String { "1".into() } // MVP: user mock
+pub struct CvPayload { #[serde(flatten)] pub data: Value }
 
 pub fn router() -> Router<AppState> {
     Router::new()
@@ -18,44 +31,36 @@ pub fn router() -> Router<AppState> {
         .route("/layout", get(get_layout).put(put_layout))
 }
 
-async fn get_cv(State(st): State<AppState>) -> Json<CvPayload> {
-    let user_id = uid();
-    let rec: Option<(String,)> = sqlx::query_as("SELECT data FROM cv_data WHERE user_id=?")
-        .bind(&user_id).fetch_optional(&st.db).await.unwrap();
-    let data = rec
-        .and_then(|(s,)| serde_json::from_str(&s).ok())
+async fn get_cv(State(st): State<AppState>, auth: AuthUser) -> HandlerResult<Json<CvPayload>> {
+    let data = st.repos.cv.data(&auth.id).await.map_err(ise)?
+        .and_then(|s| serde_json::from_str(&s).ok())
         .unwrap_or(Value::Object(Default::default()));
-    Json(CvPayload { data })
+    Ok(Json(CvPayload { data }))
 }
 
-async fn put_cv(State(st): State<AppState>, Json(p): Json<CvPayload>) -> Json<CvPayload> {
-    let user_id = uid();
-    let s = p.data.to_string();
-    sqlx::query(r#"
-        INSERT INTO cv_data (user_id, data, updated_at)
-        VALUES (?, ?, CURRENT_TIMESTAMP)
-        ON CONFLICT(user_id) DO UPDATE SET data=excluded.data, updated_at=CURRENT_TIMESTAMP
-    "#).bind(&user_id).bind(&s).execute(&st.db).await.unwrap();
-    Json(p)
+async fn put_cv(State(st): State<AppState>, auth: AuthUser, Json(p): Json<CvPayload>) -> HandlerResult<Json<CvPayload>> {
+    st.repos.cv.save_data(&auth.id, &p.data.to_string()).await.map_err(ise)?;
+    Ok(Json(p))
 }
 
-async fn get_layout(State(st): State<AppState>) -> Json<LayoutPayload> {
-    let user_id = uid();
-    let rec: Option<(String,)> = sqlx::query_as("SELECT layout FROM cv_layout WHERE user_id=?")
-        .bind(&user_id).fetch_optional(&st.db).await.unwrap();
-    let layout = rec
-        .and_then(|(s,)| serde_json::from_str(&s).ok())
-        .unwrap_or(serde_json::json!({"rows":[]}));
-    Json(LayoutPayload { layout })
+async fn get_layout(State(st): State<AppState>, auth: AuthUser) -> HandlerResult<Json<Layout>> {
+    let stored = match st.repos.cv.default_cv(&auth.id).await.map_err(ise)? {
+        Some(id) => st.repos.cv.cv_layout(&auth.id, id).await.map_err(ise)?,
+        None => None,
+    };
+    Ok(Json(cvs::stored_layout(&auth.id, stored)))
 }
 
-async fn put_layout(State(st): State<AppState>, Json(p): Json<LayoutPayload>) -> Json<LayoutPayload> {
-    let user_id = uid();
-    let s = p.layout.to_string();
-    sqlx::query(r#"
-        INSERT INTO cv_layout (user_id, layout, updated_at)
-        VALUES (?, ?, CURRENT_TIMESTAMP)
-        ON CONFLICT(user_id) DO UPDATE SET layout=excluded.layout, updated_at=CURRENT_TIMESTAMP
-    "#).bind(&user_id).bind(&s).execute(&st.db).await.unwrap();
-    Json(p)
+/// Premier enregistrement : crée le CV par défaut.
+async fn put_layout(State(st): State<AppState>, auth: AuthUser, Json(v): Json<Value>) -> HandlerResult<Json<Layout>> {
+    let (layout, json) = cvs::accept_layout(v)?;
+    match st.repos.cv.default_cv(&auth.id).await.map_err(ise)? {
+        Some(id) => {
+            st.repos.cv.save_cv_layout(&auth.id, id, &json).await.map_err(ise)?;
+        }
+        None => {
+            st.repos.cv.create_cv(&auth.id, cvs::DEFAULT_NAME, &json).await.map_err(ise)?;
+        }
+    }
+    Ok(Json(layout))
 }
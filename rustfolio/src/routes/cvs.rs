@@ -0,0 +1,290 @@
+//! CV nommés (`/api/cvs`) : un utilisateur adapte son CV à chaque candidature.
+//!
+//! Chaque CV a sa mise en page (`rustfolio_layout`) et masque une partie des
+//! expériences / compétences / projets ; le plus ancien sert de CV par défaut à
+//! `/api/layout`. Les noms sont uniques par utilisateur (409 sinon).
+//!
+//! - `GET /cvs`, `POST /cvs {name, layout?}` ;
+//! - `GET|PATCH|DELETE /cvs/:id` (`PATCH {name?, theme?, hidden?}`) ;
+//! - `POST /cvs/:id/duplicate {name?}` : copie mise en page, thème et éléments masqués ;
+//! - `GET|PUT /cvs/:id/layout` ;
+//! - `GET /themes` : thèmes intégrés ; le thème d'un CV se change par `PATCH {theme}` ;
+//! - `GET /cvs/:id/export[?download=true][&locale=en]` : CV rendu en HTML autonome
+//!   (imprimable en PDF), dans la langue demandée.
+
+use std::collections::HashSet;
+
+use askama::Template;
+use axum::{
+    extract::{Path, Query, State},
+    http::{header, StatusCode},
+    response::{Html, IntoResponse, Response},
+    routing::{get, post},
+    Json, Router,
+};
+use rustfolio_layout::{migrate, Layout, Theme};
+use serde::Deserialize;
+use serde_json::{json, Value};
+
+use crate::cv_page::{CvContent, CvTpl};
+use crate::locale::{LocaleQuery, DEFAULT_LOCALE};
+use crate::repo::{CvSummary, HiddenItems, RepoResult};
+use crate::types::CvDetail;
+use crate::routes::auth::AuthUser;
+use crate::state::AppState;
+
+type HandlerResult<T> = std::result::Result<T, (StatusCode, String)>;
+fn ise<E: ToString>(e: E) -> (StatusCode, String) {
+    let e = e.to_string();
+    tracing::error!(error = %e, "erreur interne");
+    (StatusCode::INTERNAL_SERVER_ERROR, e)
+}
+fn not_found() -> (StatusCode, String) {
+    (StatusCode::NOT_FOUND, "CV introuvable".into())
+}
+/// Nom déjà pris : 409 ; le reste est une erreur interne.
+fn db_err(e: sqlx::Error) -> (StatusCode, String) {
+    if e.as_database_error().is_some_and(|d| d.is_unique_violation()) {
+        return (StatusCode::CONFLICT, "un CV porte déjà ce nom".into());
+    }
+    ise(e)
+}
+
+pub const MAX_NAME: usize = 80;
+/// Nom du CV créé à la première sauvegarde via `/api/layout`.
+pub const DEFAULT_NAME: &str = "Principal";
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/cvs", get(list_cvs).post(create_cv))
+        .route("/cvs/:id", get(get_cv).patch(update_cv).delete(delete_cv))
+        .route("/cvs/:id/duplicate", post(duplicate_cv))
+        .route("/cvs/:id/layout", get(get_layout).put(put_layout))
+        .route("/cvs/:id/export", get(export_cv))
+        .route("/themes", get(list_themes))
+}
+
+/* =============================================================================
+   Mise en page (partagé avec `/api/layout`)
+============================================================================= */
+
+/// Layout stocké, migré vers la version courante ; illisible → layout vide plutôt
+/// que de bloquer le builder.
+pub(crate) fn stored_layout(user_id: &str, stored: Option<String>) -> Layout {
+    match stored {
+        Some(s) => serde_json::from_str::<Value>(&s)
+            .map_err(|e| e.to_string())
+            .and_then(|v| migrate(v).map_err(|e| e.to_string()))
+            .unwrap_or_else(|e| {
+                tracing::warn!(user_id = %user_id, error = %e, "layout stocké illisible, layout vide");
+                Layout::default()
+            }),
+        None => Layout::default(),
+    }
+}
+
+/// Layout reçu, dans n'importe quelle version connue : migré puis validé (422 sinon).
+pub(crate) fn accept_layout(v: Value) -> HandlerResult<(Layout, String)> {
+    let layout = migrate(v).map_err(|e| (StatusCode::UNPROCESSABLE_ENTITY, e.to_string()))?;
+    if let Err(errors) = layout.validate() {
+        return Err((StatusCode::UNPROCESSABLE_ENTITY, format!("layout invalide : {}", errors.join(" ; "))));
+    }
+    let json = serde_json::to_string(&layout).map_err(ise)?;
+    Ok((layout, json))
+}
+
+/// Thème stocké ; illisible → thème par défaut (comme le layout).
+pub(crate) fn stored_theme(user_id: &str, stored: Option<String>) -> Theme {
+    stored
+        .map(|s| {
+            serde_json::from_str::<Theme>(&s).unwrap_or_else(|e| {
+                tracing::warn!(user_id = %user_id, error = %e, "thème stocké illisible, thème par défaut");
+                Theme::default()
+            })
+        })
+        .unwrap_or_default()
+}
+
+/// Thème des pages publiques : celui du CV par défaut de `owner`.
+pub async fn site_theme(st: &AppState, owner: &str) -> RepoResult<Option<Theme>> {
+    let Some(id) = st.repos.cv.default_cv(owner).await? else { return Ok(None) };
+    Ok(Some(stored_theme(owner, st.repos.cv.cv_theme(owner, id).await?)))
+}
+
+/* =============================================================================
+   CRUD
+============================================================================= */
+
+#[derive(Debug, Deserialize)]
+pub struct NewCv {
+    pub name:   String,
+    /// Layout vide par défaut.
+    pub layout: Option<Value>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(default)]
+pub struct CvPatch {
+    pub name:   Option<String>,
+    pub theme:  Option<Theme>,
+    pub hidden: Option<HiddenItems>,
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(default)]
+pub struct DuplicateCv {
+    /// « <nom> (copie) » par défaut.
+    pub name: Option<String>,
+}
+
+fn valid_name(name: &str) -> HandlerResult<String> {
+    let name = name.trim();
+    if name.is_empty() || name.chars().count() > MAX_NAME {
+        return Err((StatusCode::UNPROCESSABLE_ENTITY, format!("nom de CV vide ou trop long (max. {MAX_NAME})")));
+    }
+    Ok(name.to_string())
+}
+
+/// Les éléments masqués doivent appartenir à l'utilisateur.
+async fn check_hidden(st: &AppState, user_id: &str, hidden: &HiddenItems) -> HandlerResult<()> {
+    let experiences: HashSet<i64> =
+        st.repos.experiences.list(user_id).await.map_err(ise)?.into_iter().filter_map(|e| e.id).collect();
+    let skills: HashSet<i64> = st.repos.skills.list(user_id).await.map_err(ise)?.into_iter().map(|s| s.id).collect();
+    let projects: HashSet<i64> =
+        st.repos.projects.list(user_id).await.map_err(ise)?.into_iter().filter_map(|p| p.id).collect();
+
+    let checks = [("expérience", &hidden.experiences, &experiences), ("compétence", &hidden.skills, &skills), ("projet", &hidden.projects, &projects)];
+    for (label, ids, known) in checks {
+        if let Some(id) = ids.iter().find(|id| !known.contains(id)) {
+            return Err((StatusCode::UNPROCESSABLE_ENTITY, format!("{label} inconnue ou d'un autre utilisateur : {id}")));
+        }
+    }
+    Ok(())
+}
+
+pub(crate) async fn detail(st: &AppState, user_id: &str, id: i64) -> HandlerResult<CvDetail> {
+    let cv = st.repos.cv.cv(user_id, id).await.map_err(ise)?.ok_or_else(not_found)?;
+    let layout = stored_layout(user_id, st.repos.cv.cv_layout(user_id, id).await.map_err(ise)?);
+    let theme = stored_theme(user_id, st.repos.cv.cv_theme(user_id, id).await.map_err(ise)?);
+    let hidden = st.repos.cv.cv_hidden(user_id, id).await.map_err(ise)?.unwrap_or_default();
+    Ok(CvDetail { cv, layout, theme, hidden })
+}
+
+async fn list_cvs(State(st): State<AppState>, auth: AuthUser) -> HandlerResult<Json<Vec<CvSummary>>> {
+    Ok(Json(st.repos.cv.list_cvs(&auth.id).await.map_err(ise)?))
+}
+
+async fn create_cv(State(st): State<AppState>, auth: AuthUser, Json(p): Json<NewCv>) -> HandlerResult<Json<CvDetail>> {
+    let name = valid_name(&p.name)?;
+    let (_, layout) = accept_layout(p.layout.unwrap_or_else(|| json!(Layout::default())))?;
+    let id = st.repos.cv.create_cv(&auth.id, &name, &layout).await.map_err(db_err)?;
+    Ok(Json(detail(&st, &auth.id, id).await?))
+}
+
+async fn get_cv(State(st): State<AppState>, auth: AuthUser, Path(id): Path<i64>) -> HandlerResult<Json<CvDetail>> {
+    Ok(Json(detail(&st, &auth.id, id).await?))
+}
+
+async fn update_cv(
+    State(st): State<AppState>,
+    auth: AuthUser,
+    Path(id): Path<i64>,
+    Json(p): Json<CvPatch>,
+) -> HandlerResult<Json<CvDetail>> {
+    st.repos.cv.cv(&auth.id, id).await.map_err(ise)?.ok_or_else(not_found)?;
+    let name = p.name.as_deref().map(valid_name).transpose()?;
+    if let Some(Err(errors)) = p.theme.as_ref().map(Theme::validate) {
+        return Err((StatusCode::UNPROCESSABLE_ENTITY, format!("thème invalide : {}", errors.join(" ; "))));
+    }
+    if let Some(hidden) = &p.hidden {
+        check_hidden(&st, &auth.id, hidden).await?;
+    }
+
+    if let Some(name) = name {
+        if !st.repos.cv.rename_cv(&auth.id, id, &name).await.map_err(db_err)? {
+            return Err(not_found());
+        }
+    }
+    if let Some(theme) = &p.theme {
+        let json = serde_json::to_string(theme).map_err(ise)?;
+        if !st.repos.cv.save_cv_theme(&auth.id, id, &json).await.map_err(ise)? {
+            return Err(not_found());
+        }
+    }
+    if let Some(hidden) = &p.hidden {
+        if !st.repos.cv.set_cv_hidden(&auth.id, id, hidden).await.map_err(ise)? {
+            return Err(not_found());
+        }
+    }
+    Ok(Json(detail(&st, &auth.id, id).await?))
+}
+
+async fn delete_cv(State(st): State<AppState>, auth: AuthUser, Path(id): Path<i64>) -> HandlerResult<Json<Value>> {
+    if !st.repos.cv.delete_cv(&auth.id, id).await.map_err(ise)? {
+        return Err(not_found());
+    }
+    Ok(Json(json!({ "ok": true })))
+}
+
+async fn duplicate_cv(
+    State(st): State<AppState>,
+    auth: AuthUser,
+    Path(id): Path<i64>,
+    body: Option<Json<DuplicateCv>>,
+) -> HandlerResult<Json<CvDetail>> {
+    let source = st.repos.cv.cv(&auth.id, id).await.map_err(ise)?.ok_or_else(not_found)?;
+    let name = match body.and_then(|Json(b)| b.name) {
+        Some(name) => valid_name(&name)?,
+        None => valid_name(&format!("{} (copie)", source.name))?,
+    };
+    let copy = st.repos.cv.duplicate_cv(&auth.id, id, &name).await.map_err(db_err)?.ok_or_else(not_found)?;
+    Ok(Json(detail(&st, &auth.id, copy).await?))
+}
+
+async fn get_layout(State(st): State<AppState>, auth: AuthUser, Path(id): Path<i64>) -> HandlerResult<Json<Layout>> {
+    let stored = st.repos.cv.cv_layout(&auth.id, id).await.map_err(ise)?.ok_or_else(not_found)?;
+    Ok(Json(stored_layout(&auth.id, Some(stored))))
+}
+
+async fn put_layout(
+    State(st): State<AppState>,
+    auth: AuthUser,
+    Path(id): Path<i64>,
+    Json(v): Json<Value>,
+) -> HandlerResult<Json<Layout>> {
+    let (layout, json) = accept_layout(v)?;
+    if !st.repos.cv.save_cv_layout(&auth.id, id, &json).await.map_err(ise)? {
+        return Err(not_found());
+    }
+    Ok(Json(layout))
+}
+
+async fn list_themes(_auth: AuthUser) -> Json<Vec<Theme>> {
+    Json(Theme::builtins())
+}
+
+#[derive(Debug, Deserialize, Default)]
+#[serde(default)]
+pub struct ExportQuery {
+    /// Téléchargement (`Content-Disposition: attachment`) plutôt qu'affichage.
+    pub download: bool,
+}
+
+async fn export_cv(
+    State(st): State<AppState>,
+    auth: AuthUser,
+    Path(id): Path<i64>,
+    Query(q): Query<ExportQuery>,
+    LocaleQuery(locale): LocaleQuery,
+) -> HandlerResult<Response> {
+    let detail = detail(&st, &auth.id, id).await?;
+    let content = CvContent::load(&st, &auth.id, &detail.hidden, locale.as_deref()).await.map_err(ise)?;
+    let lang = locale.as_deref().unwrap_or(DEFAULT_LOCALE);
+    let html = CvTpl::new(&detail.cv.name, lang, &detail.layout, &detail.theme, &content).render().map_err(ise)?;
+    if !q.download {
+        return Ok(Html(html).into_response());
+    }
+    let file: String = detail.cv.name.chars().map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_lowercase() } else { '-' }).collect();
+    let disposition = format!("attachment; filename=\"cv-{}.html\"", file.trim_matches('-'));
+    Ok(([(header::CONTENT_DISPOSITION, disposition)], Html(html)).into_response())
+}
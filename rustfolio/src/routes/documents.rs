@@ -0,0 +1,148 @@
+//! PDF des projets.
+//!
+//! - `POST /api/cv/projects/:id/document` (multipart, champ `file`) : analyse le PDF,
+//!   le stocke et fait pointer `pdf_link` du projet dessus ;
+//! - `GET /documents/:id/:nom` (public, document lié uniquement) : téléchargement avec
+//!   `Content-Disposition`.
+
+use axum::{
+    body::Body,
+    extract::{DefaultBodyLimit, Multipart, Path, State},
+    http::{header, StatusCode},
+    response::{IntoResponse, Response},
+    routing::post,
+    Json, Router,
+};
+
+use crate::config::Config;
+use crate::repo::Document;
+use crate::routes::auth::AuthUser;
+use crate::state::AppState;
+use crate::uploads::{self, pdf::{self, DocumentOut, Rejected}};
+
+type HandlerResult<T> = std::result::Result<T, (StatusCode, String)>;
+fn ise<E: ToString>(e: E) -> (StatusCode, String) {
+    let e = e.to_string();
+    tracing::error!(error = %e, "erreur interne");
+    (StatusCode::INTERNAL_SERVER_ERROR, e)
+}
+fn not_found(what: &str) -> (StatusCode, String) {
+    (StatusCode::NOT_FOUND, format!("{what} introuvable"))
+}
+
+/// Marge pour les en-têtes multipart autour du fichier.
+const MULTIPART_OVERHEAD: usize = 64 * 1024;
+
+pub fn router(config: &Config) -> Router<AppState> {
+    let max = config.uploads.max_pdf_bytes as usize;
+    Router::new().route(
+        "/cv/projects/:id/document",
+        post(upload_document).layer(DefaultBodyLimit::max(max + MULTIPART_OVERHEAD)),
+    )
+}
+
+async fn upload_document(
+    State(st): State<AppState>,
+    auth: AuthUser,
+    Path(project_id): Path<i64>,
+    mut multipart: Multipart,
+) -> HandlerResult<(StatusCode, Json<DocumentOut>)> {
+    let owned = st.repos.projects.list(&auth.id).await.map_err(ise)?;
+    if !owned.iter().any(|p| p.id == Some(project_id)) {
+        return Err(not_found("projet"));
+    }
+
+    let max = st.config.uploads.max_pdf_bytes as usize;
+    let too_large = || {
+        let msg = format!("PDF trop lourd (max {} Mo)", max / (1024 * 1024));
+        (StatusCode::PAYLOAD_TOO_LARGE, msg)
+    };
+
+    let mut upload = None;
+    while let Some(field) = multipart.next_field().await.map_err(|e| (e.status(), e.body_text()))? {
+        if field.name() == Some("file") {
+            let name = pdf::sanitize_file_name(field.file_name());
+            let data = field.bytes().await.map_err(|e| match e.status() {
+                StatusCode::PAYLOAD_TOO_LARGE => too_large(),
+                status => (status, e.body_text()),
+            })?;
+            upload = Some((name, data));
+            break;
+        }
+    }
+    let (file_name, bytes) = upload.ok_or((StatusCode::BAD_REQUEST, "champ multipart `file` manquant".to_string()))?;
+    if bytes.is_empty() {
+        return Err((StatusCode::BAD_REQUEST, "fichier vide".into()));
+    }
+    if bytes.len() > max {
+        return Err(too_large());
+    }
+
+    let hash = uploads::content_hash(&bytes);
+    let (status, doc) = match st.repos.documents.find_by_hash(&auth.id, &hash).await.map_err(ise)? {
+        // Même fichier déjà envoyé (autre projet, ou renvoi) : on le réutilise.
+        Some(existing) => (StatusCode::OK, existing),
+        None => (StatusCode::CREATED, store(&st, &auth.id, file_name, bytes).await?),
+    };
+
+    if !st.repos.projects.set_pdf_link(&auth.id, project_id, &pdf::download_url(&doc)).await.map_err(ise)? {
+        return Err(not_found("projet"));
+    }
+    // l'ancien document du projet n'est peut-être plus référencé
+    uploads::spawn_cleanup(&st, &auth.id);
+
+    Ok((status, Json(DocumentOut::new(&doc))))
+}
+
+async fn store(st: &AppState, user_id: &str, file_name: String, bytes: axum::body::Bytes) -> HandlerResult<Document> {
+    let len = bytes.len();
+    let processed = tokio::task::spawn_blocking(move || pdf::process(&bytes))
+        .await
+        .map_err(ise)?
+        .map_err(|r| match r {
+            Rejected::NotPdf => (StatusCode::UNSUPPORTED_MEDIA_TYPE, r.to_string()),
+            Rejected::Encrypted | Rejected::Invalid(_) => (StatusCode::UNPROCESSABLE_ENTITY, r.to_string()),
+        })?;
+
+    uploads::store(st, user_id, &processed.files).await.map_err(ise)?;
+    let mut doc = Document {
+        id: 0,
+        user_id: user_id.to_string(),
+        hash: processed.hash,
+        file_name,
+        title: processed.title,
+        page_count: processed.page_count.into(),
+        bytes: len as i64,
+        preview_width: processed.preview.map(|(w, _)| w.into()),
+        preview_height: processed.preview.map(|(_, h)| h.into()),
+        created_at: String::new(),
+    };
+    doc.id = st.repos.documents.create(user_id, &doc).await.map_err(ise)?;
+    tracing::info!(user_id, hash = %doc.hash, pages = doc.page_count, bytes = len, "PDF téléversé");
+    Ok(doc)
+}
+
+/// Public : `pdf_link` est affiché sur le portfolio. Seuls les documents encore liés à un
+/// projet ou au portfolio sont servis ; le nom dans l'URL n'est que cosmétique.
+pub async fn download(
+    State(st): State<AppState>,
+    Path((id, _name)): Path<(i64, String)>,
+) -> HandlerResult<Response> {
+    let doc = st.repos.documents.get_public(id).await.map_err(ise)?.ok_or_else(|| not_found("document"))?;
+    // ≤ uploads.max_pdf_bytes : lu d'un bloc
+    let bytes = match tokio::fs::read(pdf::file_path(&st, &doc)).await {
+        Ok(b) => b,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Err(not_found("document")),
+        Err(e) => return Err(ise(e)),
+    };
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "application/pdf".to_string()),
+            (header::CONTENT_DISPOSITION, pdf::content_disposition(&doc.file_name)),
+            (header::CACHE_CONTROL, "public, max-age=3600".to_string()),
+        ],
+        Body::from(bytes),
+    )
+        .into_response())
+}
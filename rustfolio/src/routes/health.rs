@@ -1 +1,177 @@
+//! Sondes de santé.
+//!
+//! - `GET /health/live`  : le process répond (aucune dépendance vérifiée) ;
+//! - `GET /health/ready` : prêt à servir — base joignable, migrations à jour,
+//!   espace disque suffisant, mailer configuré. 503 + détail JSON sinon. La sonde
+//!   n'écrit rien en base ; le détail des erreurs reste dans les logs.
+//!
+//! `GET /health` reste un simple "OK" pour les sondes existantes.
+
+use std::{collections::BTreeMap, path::Path, str::FromStr, time::Duration};
+
+use axum::{extract::State, http::StatusCode, Json};
+use serde::Serialize;
+use serde_json::{json, Value};
+use sqlx::sqlite::SqliteConnectOptions;
+
+use crate::config::EmailTransport;
+use crate::db::{self, MigrationState};
+use crate::state::AppState;
+
+const DB_TIMEOUT: Duration = Duration::from_secs(2);
+
 pub async fn health() -> &'static str { "OK" }
+
+pub async fn live() -> Json<Value> {
+    Json(json!({ "status": "ok" }))
+}
+
+#[derive(Debug, Serialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckStatus {
+    Ok,
+    Fail,
+    /// Non applicable (ex. disque d'une base en mémoire).
+    Skipped,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Check {
+    pub status: CheckStatus,
+    #[serde(flatten)]
+    pub detail: Value,
+}
+
+impl Check {
+    fn ok(detail: Value) -> Self {
+        Self { status: CheckStatus::Ok, detail }
+    }
+
+    fn fail(detail: Value) -> Self {
+        Self { status: CheckStatus::Fail, detail }
+    }
+
+    fn skipped(reason: &str) -> Self {
+        Self { status: CheckStatus::Skipped, detail: json!({ "reason": reason }) }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct Readiness {
+    /// `ok` ou `degraded`
+    pub status: &'static str,
+    pub checks: BTreeMap<&'static str, Check>,
+}
+
+pub async fn ready(State(st): State<AppState>) -> (StatusCode, Json<Readiness>) {
+    let mut checks = BTreeMap::new();
+    checks.insert("database", check_database(&st).await);
+    checks.insert("migrations", check_migrations(&st).await);
+    checks.insert("disk", check_disk(&st));
+    checks.insert("mailer", check_mailer(&st).await);
+
+    let degraded = checks.values().any(|c| c.status == CheckStatus::Fail);
+    if degraded {
+        tracing::warn!(checks = %serde_json::to_string(&checks).unwrap_or_default(), "readiness dégradée");
+    }
+    let (code, status) = if degraded {
+        (StatusCode::SERVICE_UNAVAILABLE, "degraded")
+    } else {
+        (StatusCode::OK, "ok")
+    };
+    (code, Json(Readiness { status, checks }))
+}
+
+async fn check_database(st: &AppState) -> Check {
+    let start = std::time::Instant::now();
+    let probe = sqlx::query_scalar::<_, i64>("SELECT 1").fetch_one(&st.db);
+    match tokio::time::timeout(DB_TIMEOUT, probe).await {
+        Ok(Ok(_)) => Check::ok(json!({ "latency_ms": start.elapsed().as_millis() as u64 })),
+        Ok(Err(e)) => {
+            tracing::error!(error = %e, "sonde base de données");
+            Check::fail(json!({ "error": "base injoignable" }))
+        }
+        Err(_) => Check::fail(json!({ "error": format!("pas de réponse en {} s", DB_TIMEOUT.as_secs()) })),
+    }
+}
+
+async fn check_migrations(st: &AppState) -> Check {
+    let list = match tokio::time::timeout(DB_TIMEOUT, db::status(&st.db)).await {
+        Ok(Ok(list)) => list,
+        Ok(Err(e)) => {
+            tracing::error!(error = %e, "sonde migrations");
+            return Check::fail(json!({ "error": "statut des migrations illisible" }));
+        }
+        Err(_) => return Check::fail(json!({ "error": "timeout" })),
+    };
+    let count = |s: MigrationState| list.iter().filter(|m| m.state == s).count();
+    let (applied, pending) = (count(MigrationState::Applied), count(MigrationState::Pending));
+    let (modified, missing) = (count(MigrationState::Modified), count(MigrationState::Missing));
+
+    let detail = json!({ "applied": applied, "pending": pending, "modified": modified, "missing": missing });
+    if pending + modified + missing == 0 {
+        Check::ok(detail)
+    } else {
+        Check::fail(detail)
+    }
+}
+
+fn check_disk(st: &AppState) -> Check {
+    let url = &st.config.database_url;
+    // sqlx renomme `:memory:` en `file:sqlx-in-memory-…` : on teste l'URL elle-même
+    if url.contains(":memory:") || url.contains("mode=memory") {
+        return Check::skipped("base en mémoire");
+    }
+    let Ok(opts) = SqliteConnectOptions::from_str(url) else {
+        return Check::skipped("DATABASE_URL illisible");
+    };
+    let file = opts.get_filename();
+    let dir = match file.parent() {
+        Some(p) if !p.as_os_str().is_empty() => p,
+        _ => Path::new("."),
+    };
+
+    let min = st.config.min_free_disk_bytes;
+    match free_bytes(dir) {
+        Some(free) => {
+            let detail = json!({ "path": dir.display().to_string(), "free_mb": free / MB, "min_free_mb": min / MB });
+            if free >= min { Check::ok(detail) } else { Check::fail(detail) }
+        }
+        None => Check::skipped("espace libre indisponible"),
+    }
+}
+
+const MB: u64 = 1024 * 1024;
+
+#[cfg(unix)]
+fn free_bytes(dir: &Path) -> Option<u64> {
+    let s = rustix::fs::statvfs(dir).ok()?;
+    Some(s.f_bavail.saturating_mul(s.f_frsize))
+}
+
+#[cfg(not(unix))]
+fn free_bytes(_: &Path) -> Option<u64> {
+    None
+}
+
+/// Vérifie la configuration (pas d'envoi ni de connexion SMTP à chaque sonde).
+async fn check_mailer(st: &AppState) -> Check {
+    let from = &st.config.email.from;
+    match &st.config.email.transport {
+        EmailTransport::Log => Check::ok(json!({ "transport": "log", "from": from })),
+        EmailTransport::File(dir) => {
+            let mut detail = json!({ "transport": "file", "dir": dir.display().to_string() });
+            match tokio::fs::create_dir_all(dir).await {
+                Ok(()) => Check::ok(detail),
+                Err(e) => {
+                    detail["error"] = e.to_string().into();
+                    Check::fail(detail)
+                }
+            }
+        }
+        EmailTransport::Smtp(s) => {
+            let detail = json!({ "transport": "smtp", "host": s.host, "port": s.port, "from": from });
+            if s.host.is_empty() || s.user.is_empty() { Check::fail(detail) } else { Check::ok(detail) }
+        }
+    }
+}
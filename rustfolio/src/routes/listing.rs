@@ -0,0 +1,222 @@
+//! Listes de l'API : `?limit=&cursor=&sort=&fields=` et ETags faibles.
+//!
+//! - `limit` (1..=200) et `cursor` (valeur opaque tirée de l'en-tête `Link` de la page
+//!   précédente) ; sans `limit`, toute la liste ;
+//! - `sort=champ` ou `sort=-champ` (décroissant), champs autorisés par endpoint ;
+//! - `fields=id,title` : ne garde que ces clés (les clés inconnues sont ignorées).
+//!
+//! Le corps reste un tableau JSON ; la pagination passe par `X-Total-Count` et
+//! `Link: <…&cursor=N>; rel="next"`.
+//!
+//! L'ETag est calculé *avant* de charger la liste, à partir de l'empreinte
+//! `id@updated_at` de la collection (`VersionRepo`) et de l'URL : un `If-None-Match`
+//! identique donne un 304 sans lire les lignes. Les collections d'un utilisateur sont
+//! petites, le tri et le découpage se font donc sur le JSON chargé.
+
+use std::{cmp::Ordering, sync::OnceLock};
+
+use axum::{
+    async_trait,
+    extract::{FromRequestParts, OriginalUri, Query},
+    http::{header, request::Parts, HeaderMap, HeaderValue, StatusCode},
+    response::{IntoResponse, Response},
+    Json,
+};
+use serde::Serialize;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+
+pub const MAX_LIMIT: usize = 200;
+pub const TOTAL_COUNT: &str = "x-total-count";
+
+/// Listes authentifiées : jamais dans un cache partagé.
+pub const PRIVATE: &str = "private, no-cache";
+/// Données publiques (portfolio, projets) : cache partagé permis, toujours revalidé.
+pub const PUBLIC: &str = "public, no-cache";
+
+type Rejection = (StatusCode, String);
+
+#[derive(Debug, Clone)]
+pub struct ListQuery {
+    pub limit:  Option<usize>,
+    pub offset: usize,
+    /// `(champ, décroissant)`
+    pub sort:   Option<(String, bool)>,
+    pub fields: Option<Vec<String>>,
+    path:          String,
+    query:         String,
+    if_none_match: Option<String>,
+}
+
+fn bad_request(msg: String) -> Rejection {
+    (StatusCode::BAD_REQUEST, msg)
+}
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for ListQuery {
+    type Rejection = Rejection;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let Query(params) = Query::<Vec<(String, String)>>::try_from_uri(&parts.uri)
+            .map_err(|e| bad_request(e.body_text()))?;
+
+        // sous `.nest("/api", …)`, `parts.uri` a perdu son préfixe
+        let uri = parts.extensions.get::<OriginalUri>().map_or(&parts.uri, |o| &o.0);
+        let mut out = ListQuery {
+            limit: None,
+            offset: 0,
+            sort: None,
+            fields: None,
+            path: uri.path().to_string(),
+            query: uri.query().unwrap_or_default().to_string(),
+            if_none_match: parts.headers.get(header::IF_NONE_MATCH).and_then(|v| v.to_str().ok()).map(String::from),
+        };
+        for (k, v) in params {
+            let v = v.trim();
+            if v.is_empty() {
+                continue;
+            }
+            match k.as_str() {
+                "limit" => {
+                    let n = v.parse::<usize>().ok().filter(|n| (1..=MAX_LIMIT).contains(n));
+                    out.limit = Some(n.ok_or_else(|| bad_request(format!("`limit` invalide : {v} (1..={MAX_LIMIT})")))?);
+                }
+                "cursor" => out.offset = v.parse().map_err(|_| bad_request(format!("`cursor` invalide : {v}")))?,
+                "sort" => {
+                    out.sort = Some(match v.strip_prefix('-') {
+                        Some(f) => (f.to_string(), true),
+                        None => (v.to_string(), false),
+                    })
+                }
+                "fields" => out.fields = Some(v.split(',').map(|f| f.trim().to_string()).filter(|f| !f.is_empty()).collect()),
+                _ => {}
+            }
+        }
+        Ok(out)
+    }
+}
+
+/// ETag faible : la représentation dépend des données (`parts`) et de l'URL, pas de
+/// l'encodage (compression).
+pub fn weak_etag(parts: &[&str]) -> String {
+    let mut h = Sha256::new();
+    for p in parts {
+        h.update(p.as_bytes());
+        h.update([0]);
+    }
+    let hex: String = h.finalize().iter().take(8).map(|b| format!("{b:02x}")).collect();
+    format!("W/\"{hex}\"")
+}
+
+/// Identifiant du processus, pour les ETags de pages HTML : un redéploiement (gabarits
+/// modifiés) les invalide.
+pub fn boot_id() -> &'static str {
+    static BOOT: OnceLock<String> = OnceLock::new();
+    BOOT.get_or_init(|| uuid::Uuid::new_v4().to_string())
+}
+
+/// `If-None-Match` (liste ou `*`) contient-il `etag` ? Comparaison faible : `W/` ignoré.
+pub fn matches(if_none_match: Option<&str>, etag: &str) -> bool {
+    let strip = |t: &str| t.trim().trim_start_matches("W/").to_string();
+    let etag = strip(etag);
+    if_none_match.is_some_and(|v| v.split(',').any(|t| t.trim() == "*" || strip(t) == etag))
+}
+
+/// 304 si `headers` porte déjà `etag`.
+pub fn not_modified(headers: &HeaderMap, etag: &str, cache_control: &'static str) -> Option<Response> {
+    let inm = headers.get(header::IF_NONE_MATCH).and_then(|v| v.to_str().ok());
+    matches(inm, etag).then(|| not_modified_response(etag, cache_control))
+}
+
+fn not_modified_response(etag: &str, cache_control: &'static str) -> Response {
+    (StatusCode::NOT_MODIFIED, cache_headers(etag, cache_control)).into_response()
+}
+
+/// `ETag` + `Cache-Control` (`no-cache` : le navigateur garde la réponse mais revalide).
+pub fn cache_headers(etag: &str, cache_control: &'static str) -> [(header::HeaderName, HeaderValue); 2] {
+    [
+        (header::ETAG, HeaderValue::from_str(etag).expect("ETag ASCII")),
+        (header::CACHE_CONTROL, HeaderValue::from_static(cache_control)),
+    ]
+}
+
+fn compare(a: &Value, b: &Value) -> Ordering {
+    fn rank(v: &Value) -> u8 {
+        match v {
+            Value::Null => 0,
+            Value::Bool(_) => 1,
+            Value::Number(_) => 2,
+            Value::String(_) => 3,
+            _ => 4,
+        }
+    }
+    match (a, b) {
+        (Value::Bool(x), Value::Bool(y)) => x.cmp(y),
+        (Value::Number(x), Value::Number(y)) => {
+            x.as_f64().unwrap_or_default().partial_cmp(&y.as_f64().unwrap_or_default()).unwrap_or(Ordering::Equal)
+        }
+        (Value::String(x), Value::String(y)) => x.to_lowercase().cmp(&y.to_lowercase()),
+        _ => rank(a).cmp(&rank(b)),
+    }
+}
+
+impl ListQuery {
+    /// ETag de cette liste pour la version `fingerprint` de ses données.
+    pub fn etag(&self, fingerprint: &str) -> String {
+        weak_etag(&[fingerprint, &self.path, &self.query])
+    }
+
+    /// 304 si le client a déjà cette version.
+    pub fn not_modified(&self, etag: &str) -> Option<Response> {
+        matches(self.if_none_match.as_deref(), etag).then(|| not_modified_response(etag, PRIVATE))
+    }
+
+    /// Trie (parmi `sortable`), découpe et filtre les champs, puis répond avec l'ETag.
+    pub fn respond<T: Serialize>(&self, etag: &str, items: Vec<T>, sortable: &[&str]) -> Result<Response, Rejection> {
+        if let Some((field, _)) = &self.sort {
+            if !sortable.contains(&field.as_str()) {
+                let allowed = if sortable.is_empty() { "aucun".to_string() } else { sortable.join(", ") };
+                return Err(bad_request(format!("`sort` invalide : {field} (champs : {allowed})")));
+            }
+        }
+
+        let mut values: Vec<Value> = items
+            .into_iter()
+            .map(serde_json::to_value)
+            .collect::<Result<_, _>>()
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+        if let Some((field, desc)) = &self.sort {
+            // tri stable : l'ordre du dépôt départage les égalités
+            values.sort_by(|a, b| {
+                let o = compare(&a[field.as_str()], &b[field.as_str()]);
+                if *desc { o.reverse() } else { o }
+            });
+        }
+
+        let total = values.len();
+        let end = self.limit.map_or(total, |l| self.offset.saturating_add(l).min(total));
+        let mut page: Vec<Value> = values.into_iter().take(end).skip(self.offset).collect();
+        if let Some(fields) = &self.fields {
+            for v in &mut page {
+                if let Value::Object(map) = v {
+                    map.retain(|k, _| fields.iter().any(|f| f == k));
+                }
+            }
+        }
+
+        let mut res = (cache_headers(etag, PRIVATE), Json(page)).into_response();
+        res.headers_mut().insert(TOTAL_COUNT, HeaderValue::from(total));
+        if end < total {
+            res.headers_mut().insert(header::LINK, self.next_link(end));
+        }
+        Ok(res)
+    }
+
+    /// Même URL, `cursor` remplacé.
+    fn next_link(&self, offset: usize) -> HeaderValue {
+        let mut query: Vec<&str> = self.query.split('&').filter(|p| !p.is_empty() && !p.starts_with("cursor=")).collect();
+        let cursor = format!("cursor={offset}");
+        query.push(&cursor);
+        HeaderValue::from_str(&format!("<{}?{}>; rel=\"next\"", self.path, query.join("&"))).expect("URL ASCII")
+    }
+}
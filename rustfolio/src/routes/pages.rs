@@ -2,6 +2,13 @@ use askama::Template;              // <- pour avoir .render()
 //use askama_axum::IntoResponse;     // <- pour home() si tu retournes un Template
 use chrono::Datelike;
 
+use axum::http::StatusCode;
+
+use axum::response::IntoResponse;
+
+use crate::i18n::UiLang;
+use crate::routes::{cvs, listing};
+use crate::routes::portfolio::{self, Feed, FeedQuery};
 use crate::templates::{HomeTpl, ProjectsTpl, PortfolioTpl};
 use crate::state::AppState;
 
@@ -10,10 +17,12 @@ use crate::state::AppState;
 pub struct DashboardTpl {
     pub year: i32,
     pub message: &'static str,
+    /// Langue de l'interface : `<html lang>`, que le SPA reprend au démarrage.
+    pub tr: crate::i18n::Tr,
 }
 
-pub async fn dashboard_shell() -> DashboardTpl {
-    DashboardTpl { year: chrono::Utc::now().year(), message: "Template Askama OK ! 🚀", }
+pub async fn dashboard_shell(UiLang(tr): UiLang) -> DashboardTpl {
+    DashboardTpl { year: chrono::Utc::now().year(), message: "Template Askama OK ! 🚀", tr }
 }
 
 
@@ -29,20 +38,17 @@ pub async fn debug_inline() -> InlineTpl {
 }
 
 // Pas d'emprunt -> on peut retourner directement le Template
-pub async fn home() -> HomeTpl {
-    HomeTpl { year: chrono::Utc::now().year() }
+pub async fn home(UiLang(tr): UiLang) -> HomeTpl {
+    HomeTpl { year: chrono::Utc::now().year(), tr }
 }
 
-// Emprunts -> on rend en String puis Html<String>
-pub async fn projects_page(
-    axum::extract::State(st): axum::extract::State<AppState>,
-) -> axum::response::Html<String> {
+// La grille est remplie côté client depuis /api/projects
+pub async fn projects_page() -> axum::response::Html<String> {
     let html = ProjectsTpl {
         year: chrono::Utc::now().year(),
         name: "Gaëtan Renaud",
         title: "Développeur Rust",
         tagline: "Rust • Web • Cloud",
-        projects: &st.projects, // <- emprunt
     }
     .render()
     .expect("Askama render projects.html");
@@ -50,17 +56,51 @@ pub async fn projects_page(
     axum::response::Html(html)
 }
 
+// Portfolio graphique du propriétaire du site, filtré côté serveur (?type=&q=)
+// ETag : données du flux + démarrage du processus (le gabarit a pu changer) + année du pied de page
+// + thème du propriétaire + langue de l'interface
 pub async fn portfolio_page(
-    axum::extract::State(_st): axum::extract::State<AppState>,
-) -> axum::response::Html<String> {
+    axum::extract::State(st): axum::extract::State<AppState>,
+    UiLang(tr): UiLang,
+    headers: axum::http::HeaderMap,
+    axum::extract::Query(f): axum::extract::Query<FeedQuery>,
+) -> Result<axum::response::Response, (StatusCode, String)> {
+    let e500 = |e: sqlx::Error| {
+        tracing::error!(error = %e, "portfolio");
+        (StatusCode::INTERNAL_SERVER_ERROR, "erreur interne".to_string())
+    };
+    let owner = st.site_owner().await.map_err(e500)?;
+    let year = chrono::Utc::now().year();
+    let data = portfolio::feed_etag(&st, owner.as_deref(), &f).await.map_err(e500)?;
+    let theme_css = match owner.as_deref() {
+        Some(owner) => cvs::site_theme(&st, owner).await.map_err(e500)?.map(|t| t.css_vars()).unwrap_or_default(),
+        None => String::new(),
+    };
+    let etag = listing::weak_etag(&[&data, listing::boot_id(), &year.to_string(), &theme_css, tr.code()]);
+    if let Some(res) = listing::not_modified(&headers, &etag, listing::PUBLIC) {
+        return Ok(res);
+    }
+
+    let feed = match owner {
+        Some(owner) => portfolio::feed(&st, &owner, &f).await.map_err(e500)?,
+        None => Feed { albums: Vec::new(), items: Vec::new() },
+    };
+
     let html = PortfolioTpl {
-        year: chrono::Utc::now().year(),
+        year,
         name: "Gaëtan Renaud",
         title: "Développeur Rust",
         tagline: "Rust • Web • Cloud",
+        albums: &feed.albums,
+        items: &feed.items,
+        selected: f.kind.as_deref().unwrap_or_default(),
+        q: f.q.as_deref().unwrap_or_default(),
+        theme_css: &theme_css,
+        tr,
     }
     .render()
     .expect("Askama render portfolio.html");
 
-    axum::response::Html(html)
+    let vary = [(axum::http::header::VARY, "Accept-Language, Cookie")];
+    Ok((listing::cache_headers(&etag, listing::PUBLIC), vary, axum::response::Html(html)).into_response())
 }
@@ -0,0 +1,261 @@
+//! Portfolio graphique.
+//!
+//! - CRUD (authentifié) sous `/api/portfolio` ; les images passent par
+//!   `POST /api/uploads/images` puis l'URL renvoyée est enregistrée dans `image` ;
+//! - `GET /api/portfolio/feed?type=&q=` (public) : œuvres du propriétaire du site,
+//!   prêtes pour une lightbox (src haute définition, miniature, dimensions).
+//!   La page `/portfolio` est rendue à partir du même flux.
+
+use axum::{
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+    routing::{get, post, put},
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::repo::{Album, Collection, RepoResult, Upload};
+use crate::routes::auth::AuthUser;
+use crate::routes::listing::{self, ListQuery};
+use crate::state::AppState;
+use crate::types::PortfolioItem;
+use crate::uploads::{self, Variant};
+
+type HandlerResult<T> = std::result::Result<T, (StatusCode, String)>;
+fn ise<E: ToString>(e: E) -> (StatusCode, String) {
+    let e = e.to_string();
+    tracing::error!(error = %e, "erreur interne");
+    (StatusCode::INTERNAL_SERVER_ERROR, e)
+}
+fn not_found() -> (StatusCode, String) {
+    (StatusCode::NOT_FOUND, "œuvre introuvable".into())
+}
+
+pub fn router() -> Router<AppState> {
+    Router::new()
+        .route("/portfolio", get(list_items).post(create_item))
+        .route("/portfolio/:id", put(update_item).delete(delete_item))
+        .route("/portfolio/order", put(reorder_items))
+        .route("/portfolio/albums", get(list_albums))
+        .route("/portfolio/import", post(import_items))
+        .route("/portfolio/feed", get(public_feed))
+}
+
+fn validate(item: &PortfolioItem) -> HandlerResult<()> {
+    if item.title.trim().is_empty() {
+        return Err((StatusCode::UNPROCESSABLE_ENTITY, "titre vide".into()));
+    }
+    Ok(())
+}
+
+/// `type` vide ou « Tous » (valeur historique du filtre) : pas de filtre.
+fn album_filter(kind: &Option<String>) -> Option<&str> {
+    kind.as_deref().map(str::trim).filter(|k| !k.is_empty() && !k.eq_ignore_ascii_case("tous"))
+}
+
+/* =============================================================================
+   CRUD
+============================================================================= */
+
+#[derive(Debug, Deserialize, Default)]
+pub struct FeedQuery {
+    #[serde(rename = "type")]
+    pub kind: Option<String>,
+    pub q:    Option<String>,
+}
+
+async fn list_items(
+    State(st): State<AppState>,
+    auth: AuthUser,
+    Query(f): Query<FeedQuery>,
+    list: ListQuery,
+) -> HandlerResult<Response> {
+    let etag = list.etag(&st.repos.versions.fingerprint(&auth.id, Collection::Portfolio).await.map_err(ise)?);
+    if let Some(res) = list.not_modified(&etag) {
+        return Ok(res);
+    }
+    let items = st.repos.portfolio.list(&auth.id, album_filter(&f.kind)).await.map_err(ise)?;
+    list.respond(&etag, items, &["id", "title", "type", "position"])
+}
+
+async fn create_item(
+    State(st): State<AppState>,
+    auth: AuthUser,
+    Json(item): Json<PortfolioItem>,
+) -> HandlerResult<Json<PortfolioItem>> {
+    validate(&item)?;
+    let id = st.repos.portfolio.create(&auth.id, &item).await.map_err(ise)?;
+    let out = st.repos.portfolio.get(&auth.id, id).await.map_err(ise)?.ok_or_else(|| ise("œuvre créée introuvable"))?;
+    Ok(Json(out))
+}
+
+async fn update_item(
+    State(st): State<AppState>,
+    auth: AuthUser,
+    Path(id): Path<i64>,
+    Json(item): Json<PortfolioItem>,
+) -> HandlerResult<Json<PortfolioItem>> {
+    validate(&item)?;
+    if !st.repos.portfolio.update(&auth.id, id, &item).await.map_err(ise)? {
+        return Err(not_found());
+    }
+    uploads::spawn_cleanup(&st, &auth.id);
+    let out = st.repos.portfolio.get(&auth.id, id).await.map_err(ise)?.ok_or_else(not_found)?;
+    Ok(Json(out))
+}
+
+async fn delete_item(
+    State(st): State<AppState>,
+    auth: AuthUser,
+    Path(id): Path<i64>,
+) -> HandlerResult<Json<serde_json::Value>> {
+    if !st.repos.portfolio.delete(&auth.id, id).await.map_err(ise)? {
+        return Err(not_found());
+    }
+    uploads::spawn_cleanup(&st, &auth.id);
+    Ok(Json(json!({ "ok": true })))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Order {
+    pub ids: Vec<i64>,
+}
+
+/// `PUT /api/portfolio/order` `{ "ids": [3, 1, 2] }` : positions 0, 1, 2…
+/// Les œuvres absentes de la liste gardent leur position.
+async fn reorder_items(
+    State(st): State<AppState>,
+    auth: AuthUser,
+    Json(order): Json<Order>,
+) -> HandlerResult<Json<Vec<PortfolioItem>>> {
+    if !st.repos.portfolio.reorder(&auth.id, &order.ids).await.map_err(ise)? {
+        return Err((StatusCode::UNPROCESSABLE_ENTITY, "ids inconnus ou répétés".into()));
+    }
+    Ok(Json(st.repos.portfolio.list(&auth.id, None).await.map_err(ise)?))
+}
+
+async fn list_albums(State(st): State<AppState>, auth: AuthUser, list: ListQuery) -> HandlerResult<Response> {
+    let etag = list.etag(&st.repos.versions.fingerprint(&auth.id, Collection::Portfolio).await.map_err(ise)?);
+    if let Some(res) = list.not_modified(&etag) {
+        return Ok(res);
+    }
+    let items = st.repos.portfolio.albums(&auth.id).await.map_err(ise)?;
+    list.respond(&etag, items, &["type", "count"])
+}
+
+/// Reprise de l'ancien `data/graphicPortfolio.json` (`[{title, image, description, type}]`) :
+/// les œuvres sont ajoutées à la suite, dans l'ordre du fichier.
+async fn import_items(
+    State(st): State<AppState>,
+    auth: AuthUser,
+    Json(items): Json<Vec<PortfolioItem>>,
+) -> HandlerResult<Json<serde_json::Value>> {
+    for item in &items {
+        validate(item)?;
+    }
+    for item in &items {
+        let item = PortfolioItem { id: None, position: None, ..item.clone() };
+        st.repos.portfolio.create(&auth.id, &item).await.map_err(ise)?;
+    }
+    Ok(Json(json!({ "imported": items.len() })))
+}
+
+/* =============================================================================
+   Flux public
+============================================================================= */
+
+#[derive(Debug, Serialize)]
+pub struct FeedItem {
+    pub id:          i64,
+    pub title:       String,
+    pub description: String,
+    #[serde(rename = "type")]
+    pub kind:        String,
+    /// `image` ou `pdf`
+    pub media:       &'static str,
+    /// Image pleine taille (ou le PDF).
+    pub src:         String,
+    /// Image de la grille.
+    pub thumb:       String,
+    /// Dimensions de `src`, connues pour les images téléversées.
+    pub width:       Option<u32>,
+    pub height:      Option<u32>,
+    pub srcset:      Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Feed {
+    pub albums: Vec<Album>,
+    pub items:  Vec<FeedItem>,
+}
+
+/// Œuvres de `owner`, filtrées par album et par texte (titre, description).
+pub async fn feed(st: &AppState, owner: &str, f: &FeedQuery) -> RepoResult<Feed> {
+    let albums = st.repos.portfolio.albums(owner).await?;
+    let mut items = st.repos.portfolio.list(owner, album_filter(&f.kind)).await?;
+    if let Some(q) = f.q.as_deref().map(|q| q.trim().to_lowercase()).filter(|q| !q.is_empty()) {
+        items.retain(|i| i.title.to_lowercase().contains(&q) || i.description.to_lowercase().contains(&q));
+    }
+
+    let uploaded = st.repos.uploads.list(owner).await?;
+    let items = items.into_iter().map(|i| feed_item(owner, &uploaded, i)).collect();
+    Ok(Feed { albums, items })
+}
+
+fn feed_item(owner: &str, uploaded: &[Upload], i: PortfolioItem) -> FeedItem {
+    let lower = i.image.to_lowercase();
+    let media = if lower.ends_with(".pdf") || lower.starts_with("/documents/") { "pdf" } else { "image" };
+    let mut out = FeedItem {
+        id: i.id.unwrap_or_default(),
+        title: i.title,
+        description: i.description,
+        kind: i.kind,
+        media,
+        src: i.image.clone(),
+        thumb: i.image.clone(),
+        width: None,
+        height: None,
+        srcset: None,
+    };
+
+    // Image téléversée : on sert les variantes plutôt que l'URL enregistrée.
+    let prefix = format!("{}/{owner}/", uploads::PUBLIC_PREFIX);
+    let upload = i.image.strip_prefix(&prefix).and_then(|file| uploaded.iter().find(|u| file.starts_with(&u.hash)));
+    if let Some(u) = upload {
+        let url = |v: Variant| uploads::public_url(owner, &uploads::file_name(&u.hash, v, "webp"));
+        let (w, h) = (u.width as u32, u.height as u32);
+        let (rw, rh) = Variant::Retina.dims(w, h);
+        let (cw, _) = Variant::Card.dims(w, h);
+        out.src = url(Variant::Retina);
+        out.thumb = url(Variant::Card);
+        out.width = Some(rw);
+        out.height = Some(rh);
+        out.srcset = Some(format!("{} {cw}w, {} {rw}w", url(Variant::Card), url(Variant::Retina)));
+    }
+    out
+}
+
+/// ETag public du portfolio de `owner` (ou de son absence) pour ces filtres.
+pub async fn feed_etag(st: &AppState, owner: Option<&str>, f: &FeedQuery) -> RepoResult<String> {
+    let fp = match owner {
+        Some(owner) => st.repos.versions.fingerprint(owner, Collection::Portfolio).await?,
+        None => String::new(),
+    };
+    let (kind, q) = (f.kind.as_deref().unwrap_or_default(), f.q.as_deref().unwrap_or_default());
+    Ok(listing::weak_etag(&[owner.unwrap_or_default(), &fp, kind, q]))
+}
+
+async fn public_feed(State(st): State<AppState>, headers: HeaderMap, Query(f): Query<FeedQuery>) -> HandlerResult<Response> {
+    let owner = st.site_owner().await.map_err(ise)?;
+    let etag = feed_etag(&st, owner.as_deref(), &f).await.map_err(ise)?;
+    if let Some(res) = listing::not_modified(&headers, &etag, listing::PUBLIC) {
+        return Ok(res);
+    }
+    let out = match owner {
+        Some(owner) => feed(&st, &owner, &f).await.map_err(ise)?,
+        None => Feed { albums: Vec::new(), items: Vec::new() },
+    };
+    Ok((listing::cache_headers(&etag, listing::PUBLIC), Json(out)).into_response())
+}
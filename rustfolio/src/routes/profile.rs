@@ -111,6 +111,7 @@ async fn export_profile(
     let mut headers = axum::http::HeaderMap::new();
     headers.insert(axum::http::header::CONTENT_TYPE, "application/json".parse().unwrap());
     headers.insert(axum::http::header::CONTENT_DISPOSITION, "attachment; filename=profile.json".parse().unwrap());
+    st.metrics.export("profile_json");
     Ok((headers, json))
 }
 
@@ -134,5 +135,6 @@ async fn import_profile(
 }
 
 fn e500<E: std::fmt::Display>(e: E) -> (StatusCode, String) {
+    tracing::error!(error = %e, "erreur interne");
     (StatusCode::INTERNAL_SERVER_ERROR, format!("Internal error: {e}"))
 }
@@ -0,0 +1,165 @@
+//! CV publiés : `/u/<slug>/<langue>` rend le CV par défaut de l'utilisateur (mise en
+//! page, thème, éléments masqués) dans cette langue, avec les liens `hreflang` des
+//! autres langues disponibles (langue par défaut + langues traduites).
+//!
+//! - `GET /u/:slug` : redirige vers la langue par défaut ;
+//! - `GET /u/:slug/:lang` : 404 si le slug n'est pas publié ou la langue absente ;
+//! - `GET|PUT /api/cv/public {slug}` : publication (`slug: null` la retire ; 409 si le
+//!   slug est pris).
+
+use askama::Template;
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::{Html, IntoResponse, Redirect, Response},
+    routing::get,
+    Json, Router,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::cv_page::{Alternate, CvContent, CvTpl};
+use crate::locale::{normalize, DEFAULT_LOCALE};
+use crate::routes::auth::AuthUser;
+use crate::routes::cvs;
+use crate::state::AppState;
+
+type HandlerResult<T> = std::result::Result<T, (StatusCode, String)>;
+/// Aussi servi aux visiteurs anonymes : le détail reste dans les logs.
+fn ise<E: ToString>(e: E) -> (StatusCode, String) {
+    let e = e.to_string();
+    tracing::error!(error = %e, "erreur interne");
+    (StatusCode::INTERNAL_SERVER_ERROR, "erreur interne".into())
+}
+fn not_found() -> (StatusCode, String) {
+    (StatusCode::NOT_FOUND, "CV introuvable".into())
+}
+
+pub const MIN_SLUG: usize = 3;
+pub const MAX_SLUG: usize = 40;
+
+/// `/api/cv/public`
+pub fn router() -> Router<AppState> {
+    Router::new().route("/cv/public", get(get_public).put(put_public))
+}
+
+/// `/u/…`
+pub fn pages() -> Router<AppState> {
+    Router::new()
+        .route("/u/:slug", get(default_page))
+        .route("/u/:slug/:lang", get(page))
+}
+
+/* =============================================================================
+   Publication
+============================================================================= */
+
+#[derive(Debug, Serialize)]
+pub struct Publication {
+    pub slug:    Option<String>,
+    /// Langue par défaut d'abord.
+    pub locales: Vec<String>,
+    /// Adresse publique par langue (vide si non publié).
+    pub urls:    Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PublicationPatch {
+    pub slug: Option<String>,
+}
+
+/// Minuscules, chiffres et tirets (pas aux extrémités), `MIN_SLUG..=MAX_SLUG`.
+fn valid_slug(raw: &str) -> HandlerResult<String> {
+    let slug = raw.trim().to_lowercase();
+    let ok = (MIN_SLUG..=MAX_SLUG).contains(&slug.len())
+        && slug.bytes().all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-')
+        && !slug.starts_with('-')
+        && !slug.ends_with('-');
+    if !ok {
+        return Err((
+            StatusCode::UNPROCESSABLE_ENTITY,
+            format!("slug invalide : {MIN_SLUG} à {MAX_SLUG} caractères, lettres minuscules, chiffres et tirets"),
+        ));
+    }
+    Ok(slug)
+}
+
+/// Langues disponibles pour `user_id`, langue par défaut d'abord.
+async fn locales(st: &AppState, user_id: &str) -> HandlerResult<Vec<String>> {
+    let mut out = vec![DEFAULT_LOCALE.to_string()];
+    out.extend(st.repos.profiles.locales(user_id).await.map_err(ise)?);
+    Ok(out)
+}
+
+fn page_url(st: &AppState, slug: &str, lang: &str) -> String {
+    format!("{}/u/{slug}/{lang}", st.config.public_base_url.trim_end_matches('/'))
+}
+
+async fn publication(st: &AppState, user_id: &str) -> HandlerResult<Publication> {
+    let slug = st.repos.profiles.slug(user_id).await.map_err(ise)?;
+    let locales = locales(st, user_id).await?;
+    let urls = match &slug {
+        Some(slug) => locales.iter().map(|l| page_url(st, slug, l)).collect(),
+        None => Vec::new(),
+    };
+    Ok(Publication { slug, locales, urls })
+}
+
+async fn get_public(State(st): State<AppState>, auth: AuthUser) -> HandlerResult<Json<Publication>> {
+    Ok(Json(publication(&st, &auth.id).await?))
+}
+
+async fn put_public(
+    State(st): State<AppState>,
+    auth: AuthUser,
+    Json(p): Json<PublicationPatch>,
+) -> HandlerResult<Json<Publication>> {
+    let slug = p.slug.as_deref().map(str::trim).filter(|s| !s.is_empty()).map(valid_slug).transpose()?;
+    st.repos.profiles.set_slug(&auth.id, slug.as_deref()).await.map_err(|e| {
+        if e.as_database_error().is_some_and(|d| d.is_unique_violation()) {
+            return (StatusCode::CONFLICT, "ce slug est déjà pris".to_string());
+        }
+        ise(e)
+    })?;
+    Ok(Json(publication(&st, &auth.id).await?))
+}
+
+/* =============================================================================
+   Pages
+============================================================================= */
+
+/// Seul un slug publié est repris dans l'en-tête `Location`.
+async fn default_page(State(st): State<AppState>, Path(slug): Path<String>) -> HandlerResult<Redirect> {
+    st.repos.profiles.by_slug(&slug).await.map_err(ise)?.ok_or_else(not_found)?;
+    Ok(Redirect::temporary(&format!("/u/{slug}/{DEFAULT_LOCALE}")))
+}
+
+async fn page(State(st): State<AppState>, Path((slug, lang)): Path<(String, String)>) -> HandlerResult<Response> {
+    let user_id = st.repos.profiles.by_slug(&slug).await.map_err(ise)?.ok_or_else(not_found)?;
+    // une seule adresse par langue : `/u/x/EN` ou `/u/x/en_gb` redirigent
+    let locale = normalize(&lang).ok_or_else(not_found)?;
+    let locales = locales(&st, &user_id).await?;
+    if !locales.contains(&locale) {
+        return Err(not_found());
+    }
+    if locale != lang {
+        return Ok(Redirect::permanent(&format!("/u/{slug}/{locale}")).into_response());
+    }
+
+    let id = st.repos.cv.default_cv(&user_id).await.map_err(ise)?.ok_or_else(not_found)?;
+    // erreurs déjà journalisées par `cvs::detail`, sans leur message ici
+    let detail = cvs::detail(&st, &user_id, id).await.map_err(|(status, msg)| match status {
+        StatusCode::INTERNAL_SERVER_ERROR => (status, "erreur interne".into()),
+        _ => (status, msg),
+    })?;
+    let content = CvContent::load(&st, &user_id, &detail.hidden, Some(&locale)).await.map_err(ise)?;
+    let name = [content.profile.first_name.trim(), content.profile.last_name.trim()].join(" ");
+    let mut tpl = CvTpl::new(name.trim(), &locale, &detail.layout, &detail.theme, &content);
+    if locales.len() > 1 {
+        tpl.alternates = locales
+            .iter()
+            .map(|l| Alternate { hreflang: l.clone(), href: page_url(&st, &slug, l) })
+            .chain([Alternate { hreflang: "x-default".into(), href: page_url(&st, &slug, DEFAULT_LOCALE) }])
+            .collect();
+    }
+    Ok(Html(tpl.render().map_err(ise)?).into_response())
+}
@@ -1,15 +1,23 @@
 use axum::{
     extract::{Path, State},
     http::StatusCode,
+    response::Response,
     routing::{get, put},
     Json, Router,
 };
+
+use crate::repo::Collection;
+use crate::routes::auth::AuthUser;
+use crate::routes::listing::ListQuery;
 use crate::state::AppState;
 use crate::types::{SkillIn, SkillOut};
+use crate::uploads;
 
 type HandlerResult<T> = std::result::Result<T, (StatusCode, String)>;
 fn ise<E: ToString>(e: E) -> (StatusCode, String) {
-    (StatusCode::INTERNAL_SERVER_ERROR, e.to_string())
+    let e = e.to_string();
+    tracing::error!(error = %e, "erreur interne");
+    (StatusCode::INTERNAL_SERVER_ERROR, e)
 }
 
 pub fn routes() -> Router<AppState> {
@@ -17,188 +25,109 @@ pub fn routes() -> Router<AppState> {
         .route("/cv/skills", get(list_skills).post(create_skill))
         .route("/cv/skills/:id", put(update_skill).delete(delete_skill))
         .route("/cv/skills/categories", get(list_skill_categories))
+        .route("/cv/skills/:id/projects", get(list_skill_projects))
 }
 
-async fn list_skills(
-    State(st): State<AppState>,
-    auth: crate::auth::AuthUser,
-) -> HandlerResult<Json<Vec<SkillOut>>> {
-    let rows = sqlx::query!(
-        r#"
-        SELECT
-            id         AS "id!: i64",
-            name       AS "name!: String",
-            percentage AS "percentage?: i64",
-            logo_url   AS "logo_url?: String",
-            category   AS "category?: String"
-        FROM skills
-        WHERE user_id = ?
-        ORDER BY updated_at DESC
-        "#,
-        auth.id
-    )
-    .fetch_all(&st.db)
-    .await
-    .map_err(ise)?;
+/// Refuse un pourcentage hors 0..=100 (la contrainte CHECK renverrait une 500).
+pub(crate) fn validate(s: &SkillIn) -> HandlerResult<()> {
+    if s.name.trim().is_empty() {
+        return Err((StatusCode::UNPROCESSABLE_ENTITY, "nom de compétence vide".into()));
+    }
+    if s.percentage.is_some_and(|p| p > 100) {
+        return Err((StatusCode::UNPROCESSABLE_ENTITY, "percentage doit être entre 0 et 100".into()));
+    }
+    Ok(())
+}
 
-    let out = rows
-        .into_iter()
-        .map(|r| SkillOut {
-            id: r.id,
-            name: r.name,
-            percentage: r.percentage.and_then(|p| u8::try_from(p).ok()),
-            logo_url: r.logo_url,
-            category: r.category.unwrap_or_default(),
-        })
-        .collect();
+/* =============================================================================
+   Handlers
+============================================================================= */
 
-    Ok(Json(out))
+const SKILL_SORTS: &[&str] = &["id", "name", "percentage", "level", "category"];
+
+async fn list_skills(
+    State(st): State<AppState>,
+    auth: AuthUser,
+    list: ListQuery,
+) -> HandlerResult<Response> {
+    let etag = list.etag(&st.repos.versions.fingerprint(&auth.id, Collection::Skills).await.map_err(ise)?);
+    if let Some(res) = list.not_modified(&etag) {
+        return Ok(res);
+    }
+    let items = st.repos.skills.list(&auth.id).await.map_err(ise)?;
+    list.respond(&etag, items, SKILL_SORTS)
 }
 
 async fn create_skill(
     State(st): State<AppState>,
-    auth: crate::auth::AuthUser,
+    auth: AuthUser,
     Json(s): Json<SkillIn>,
 ) -> HandlerResult<Json<SkillOut>> {
-    let perc_i64 = s.percentage.map(|p| i64::from(p));
-
-    let res = sqlx::query!(
-        r#"
-        INSERT INTO skills (user_id, name, percentage, logo_url, category, updated_at)
-        VALUES (?, ?, ?, ?, ?, CURRENT_TIMESTAMP)
-        "#,
-        auth.id,
-        s.name,
-        perc_i64,
-        s.logo_url,
-        s.category
-    )
-    .execute(&st.db)
-    .await
-    .map_err(ise)?;
-
-    let new_id = res.last_insert_rowid();
+    validate(&s)?;
+    let new_id = st.repos.skills.create(&auth.id, &s).await.map_err(ise)?;
 
-    let r = sqlx::query!(
-        r#"
-        SELECT
-            id         AS "id!: i64",
-            name       AS "name!: String",
-            percentage AS "percentage?: i64",
-            logo_url   AS "logo_url?: String",
-            category   AS "category?: String"
-        FROM skills
-        WHERE id = ? AND user_id = ?
-        "#,
-        new_id,
-        auth.id
-    )
-    .fetch_one(&st.db)
-    .await
-    .map_err(ise)?;
-
-    Ok(Json(SkillOut {
-        id: r.id,
-        name: r.name,
-        percentage: r.percentage.and_then(|p| u8::try_from(p).ok()),
-        logo_url: r.logo_url,
-        category: r.category.unwrap_or_default(),
-    }))
+    let out = st.repos.skills.get(&auth.id, new_id)
+        .await
+        .map_err(ise)?
+        .ok_or_else(|| ise("compétence créée introuvable"))?;
+    Ok(Json(out))
 }
 
 async fn update_skill(
     State(st): State<AppState>,
-    auth: crate::auth::AuthUser,
+    auth: AuthUser,
     Path(id): Path<i64>,
     Json(s): Json<SkillIn>,
 ) -> HandlerResult<Json<SkillOut>> {
-    let perc_i64 = s.percentage.map(|p| i64::from(p));
-
-    sqlx::query!(
-        r#"
-        UPDATE skills
-        SET
-            name        = ?,
-            percentage  = ?,
-            logo_url    = ?,
-            category    = ?,
-            updated_at  = CURRENT_TIMESTAMP
-        WHERE id = ? AND user_id = ?
-        "#,
-        s.name,
-        perc_i64,
-        s.logo_url,
-        s.category,
-        id,
-        auth.id
-    )
-    .execute(&st.db)
-    .await
-    .map_err(ise)?;
-
-    let r = sqlx::query!(
-        r#"
-        SELECT
-            id         AS "id!: i64",
-            name       AS "name!: String",
-            percentage AS "percentage?: i64",
-            logo_url   AS "logo_url?: String",
-            category   AS "category?: String"
-        FROM skills
-        WHERE id = ? AND user_id = ?
-        "#,
-        id,
-        auth.id
-    )
-    .fetch_one(&st.db)
-    .await
-    .map_err(ise)?;
-
-    Ok(Json(SkillOut {
-        id: r.id,
-        name: r.name,
-        percentage: r.percentage.and_then(|p| u8::try_from(p).ok()),
-        logo_url: r.logo_url,
-        category: r.category.unwrap_or_default(),
-    }))
+    validate(&s)?;
+    st.repos.skills.update(&auth.id, id, &s).await.map_err(ise)?;
+    uploads::spawn_cleanup(&st, &auth.id);
+
+    let out = st.repos.skills.get(&auth.id, id)
+        .await
+        .map_err(ise)?
+        .ok_or((StatusCode::NOT_FOUND, "compétence introuvable".to_string()))?;
+    Ok(Json(out))
 }
 
 async fn delete_skill(
     State(st): State<AppState>,
-    auth: crate::auth::AuthUser,
+    auth: AuthUser,
     Path(id): Path<i64>,
 ) -> HandlerResult<()> {
-    sqlx::query!(
-        r#"
-        DELETE FROM skills
-        WHERE id = ? AND user_id = ?
-        "#,
-        id,
-        auth.id
-    )
-    .execute(&st.db)
-    .await
-    .map_err(ise)?;
-
+    st.repos.skills.delete(&auth.id, id).await.map_err(ise)?;
+    uploads::spawn_cleanup(&st, &auth.id);
     Ok(())
 }
 
 async fn list_skill_categories(
     State(st): State<AppState>,
-    auth: crate::auth::AuthUser,
-) -> HandlerResult<Json<Vec<String>>> {
-    let rows = sqlx::query!(
-        r#"
-        SELECT DISTINCT category AS "category?: String"
-        FROM skills
-        WHERE user_id = ? AND category IS NOT NULL
-        ORDER BY category COLLATE NOCASE
-        "#,
-        auth.id
-    )
-    .fetch_all(&st.db)
-    .await
-    .map_err(ise)?;
+    auth: AuthUser,
+    list: ListQuery,
+) -> HandlerResult<Response> {
+    let etag = list.etag(&st.repos.versions.fingerprint(&auth.id, Collection::Skills).await.map_err(ise)?);
+    if let Some(res) = list.not_modified(&etag) {
+        return Ok(res);
+    }
+    let items = st.repos.skills.categories(&auth.id).await.map_err(ise)?;
+    list.respond(&etag, items, &[])
+}
 
-    Ok(Json(rows.into_iter().map(|r| r.category.unwrap_or_default()).collect()))
+// Projets de l'utilisateur qui utilisent cette compétence
+// (même entrée du catalogue, ou même nom si la compétence n'y est pas rattachée)
+async fn list_skill_projects(
+    State(st): State<AppState>,
+    auth: AuthUser,
+    Path(id): Path<i64>,
+    list: ListQuery,
+) -> HandlerResult<Response> {
+    let v = &st.repos.versions;
+    let skills = v.fingerprint(&auth.id, Collection::Skills).await.map_err(ise)?;
+    let projects = v.fingerprint(&auth.id, Collection::Projects).await.map_err(ise)?;
+    let etag = list.etag(&format!("{skills}|{projects}"));
+    if let Some(res) = list.not_modified(&etag) {
+        return Ok(res);
+    }
+    let items = st.repos.skills.projects_using(&auth.id, id).await.map_err(ise)?;
+    list.respond(&etag, items, &["id", "title", "category"])
 }
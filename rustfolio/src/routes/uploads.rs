@@ -0,0 +1,122 @@
+//! Upload d'images : `POST /api/uploads/images` (multipart, champ `file`).
+//!
+//! La taille est bornée deux fois : par la limite de corps de la route (refus avant
+//! lecture complète) et sur le champ lui-même. Le type est déduit du contenu,
+//! pas du `Content-Type` déclaré par le client.
+
+use axum::{
+    extract::{DefaultBodyLimit, Multipart, Path, State},
+    http::StatusCode,
+    routing::{delete, post},
+    Json, Router,
+};
+
+use crate::config::Config;
+use crate::repo::Upload;
+use crate::routes::auth::AuthUser;
+use crate::state::AppState;
+use crate::uploads::{self, Rejected, UploadOut};
+
+type HandlerResult<T> = std::result::Result<T, (StatusCode, String)>;
+fn ise<E: ToString>(e: E) -> (StatusCode, String) {
+    let e = e.to_string();
+    tracing::error!(error = %e, "erreur interne");
+    (StatusCode::INTERNAL_SERVER_ERROR, e)
+}
+
+/// Marge pour les en-têtes multipart autour du fichier.
+const MULTIPART_OVERHEAD: usize = 64 * 1024;
+
+pub fn router(config: &Config) -> Router<AppState> {
+    let max = config.uploads.max_image_bytes as usize;
+    Router::new()
+        .route(
+            "/uploads/images",
+            post(upload_image).layer(DefaultBodyLimit::max(max + MULTIPART_OVERHEAD)),
+        )
+        .route("/uploads/:id", delete(delete_upload))
+}
+
+async fn upload_image(
+    State(st): State<AppState>,
+    auth: AuthUser,
+    mut multipart: Multipart,
+) -> HandlerResult<(StatusCode, Json<UploadOut>)> {
+    let max = st.config.uploads.max_image_bytes as usize;
+    let too_large = || {
+        let msg = format!("image trop lourde (max {} Mo)", max / (1024 * 1024));
+        (StatusCode::PAYLOAD_TOO_LARGE, msg)
+    };
+
+    let mut bytes = None;
+    while let Some(field) = multipart.next_field().await.map_err(|e| (e.status(), e.body_text()))? {
+        if field.name() == Some("file") {
+            let data = field.bytes().await.map_err(|e| match e.status() {
+                StatusCode::PAYLOAD_TOO_LARGE => too_large(),
+                status => (status, e.body_text()),
+            })?;
+            bytes = Some(data);
+            break;
+        }
+    }
+    let bytes = bytes.ok_or((StatusCode::BAD_REQUEST, "champ multipart `file` manquant".to_string()))?;
+    if bytes.is_empty() {
+        return Err((StatusCode::BAD_REQUEST, "fichier vide".into()));
+    }
+    if bytes.len() > max {
+        return Err(too_large());
+    }
+
+    // Même fichier déjà envoyé : on renvoie l'upload existant.
+    let hash = uploads::content_hash(&bytes);
+    if let Some(existing) = st.repos.uploads.find_by_hash(&auth.id, &hash).await.map_err(ise)? {
+        return Ok((StatusCode::OK, Json(UploadOut::new(&auth.id, &existing))));
+    }
+
+    let len = bytes.len();
+    let processed = tokio::task::spawn_blocking(move || uploads::process(&bytes))
+        .await
+        .map_err(ise)?
+        .map_err(|r| match r {
+            Rejected::Format => (StatusCode::UNSUPPORTED_MEDIA_TYPE, r.to_string()),
+            Rejected::Decode(_) => (StatusCode::UNPROCESSABLE_ENTITY, r.to_string()),
+        })?;
+
+    uploads::store(&st, &auth.id, &processed.files).await.map_err(ise)?;
+    let mut upload = Upload {
+        id: 0,
+        hash: processed.hash,
+        fallback_ext: processed.fallback_ext.to_string(),
+        width: processed.width.into(),
+        height: processed.height.into(),
+        bytes: len as i64,
+        created_at: String::new(),
+    };
+    upload.id = st.repos.uploads.create(&auth.id, &upload).await.map_err(ise)?;
+    tracing::info!(user_id = %auth.id, hash = %upload.hash, bytes = len, "image téléversée");
+
+    Ok((StatusCode::CREATED, Json(UploadOut::new(&auth.id, &upload))))
+}
+
+/// Supprime un upload qui n'est plus référencé (409 sinon).
+async fn delete_upload(
+    State(st): State<AppState>,
+    auth: AuthUser,
+    Path(id): Path<i64>,
+) -> HandlerResult<StatusCode> {
+    let upload = st.repos.uploads.get(&auth.id, id)
+        .await
+        .map_err(ise)?
+        .ok_or((StatusCode::NOT_FOUND, "upload introuvable".to_string()))?;
+
+    // `orphans` sans délai de grâce : tout ce qui n'est référencé nulle part
+    let now = chrono::Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+    let orphans = st.repos.uploads.orphans(&auth.id, &now).await.map_err(ise)?;
+    if !orphans.iter().any(|u| u.id == id) {
+        return Err((StatusCode::CONFLICT, "image encore utilisée (profil, projet ou compétence)".into()));
+    }
+
+    uploads::remove_files(&st, &auth.id, &upload).await.map_err(ise)?;
+    st.repos.uploads.delete(&auth.id, id).await.map_err(ise)?;
+    Ok(StatusCode::NO_CONTENT)
+}
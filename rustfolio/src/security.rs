@@ -0,0 +1,57 @@
+//! Couches HTTP transverses : CORS, en-têtes de sécurité, compression.
+
+use std::time::Duration;
+
+use axum::http::{header, HeaderName, HeaderValue, Method};
+use tower_http::{
+    compression::CompressionLayer,
+    cors::{AllowOrigin, CorsLayer},
+    set_header::SetResponseHeaderLayer,
+};
+
+use crate::config::Config;
+use crate::routes::listing::TOTAL_COUNT;
+use crate::telemetry::REQUEST_ID;
+
+/// CORS avec cookies : uniquement les origines listées (jamais `*`).
+pub fn cors(cfg: &Config) -> CorsLayer {
+    let origins: Vec<HeaderValue> = cfg
+        .cors_allowed_origins
+        .iter()
+        .filter_map(|o| HeaderValue::from_str(o).ok()) // validées au chargement de la config
+        .collect();
+
+    CorsLayer::new()
+        .allow_origin(AllowOrigin::list(origins))
+        .allow_methods([Method::GET, Method::POST, Method::PUT, Method::PATCH, Method::DELETE])
+        .allow_headers([header::CONTENT_TYPE, header::ACCEPT, header::IF_NONE_MATCH])
+        .allow_credentials(true)
+        .expose_headers([REQUEST_ID, header::ETAG, header::LINK, HeaderName::from_static(TOTAL_COUNT)])
+        .max_age(Duration::from_secs(3600))
+}
+
+/// En-têtes posés sur toutes les réponses (sauf si le handler les a déjà fixés).
+/// HSTS seulement quand le site est servi en HTTPS (cookies `Secure`).
+pub fn headers(cfg: &Config) -> Vec<SetResponseHeaderLayer<HeaderValue>> {
+    let mut out = vec![
+        set(header::CONTENT_SECURITY_POLICY, &cfg.content_security_policy),
+        set(header::X_FRAME_OPTIONS, "DENY"),
+        set(header::REFERRER_POLICY, "strict-origin-when-cross-origin"),
+        set(header::X_CONTENT_TYPE_OPTIONS, "nosniff"),
+    ];
+    if cfg.cookie.secure {
+        out.push(set(header::STRICT_TRANSPORT_SECURITY, "max-age=31536000; includeSubDomains"));
+    }
+    out
+}
+
+fn set(name: HeaderName, value: &str) -> SetResponseHeaderLayer<HeaderValue> {
+    let value = HeaderValue::from_str(value).expect("en-tête validé par la config");
+    SetResponseHeaderLayer::if_not_present(name, value)
+}
+
+/// gzip/br à la volée selon `Accept-Encoding` ; les assets déjà précompressés
+/// (`Content-Encoding` posé par ServeDir) ne sont pas recompressés.
+pub fn compression() -> CompressionLayer {
+    CompressionLayer::new()
+}
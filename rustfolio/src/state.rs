@@ -1,11 +1,38 @@
 use std::sync::Arc;
-use crate::data;
+use crate::{config::Config, mail::{Mailer, Outbox}, metrics::Metrics, repo::Repos};
 use sqlx::{Pool, Sqlite}; 
 
 #[derive(Clone)]
 pub struct AppState {
     pub db: Pool<Sqlite>,
-    pub _experiences: Arc<Vec<data::Experience>>,
-    pub projects: Arc<Vec<data::Project>>,
-    pub skills: Arc<Vec<data::Skill>>,
+    pub repos: Repos,
+    pub config: Arc<Config>,
+    pub outbox: Outbox,
+    pub metrics: Arc<Metrics>,
+}
+
+impl AppState {
+    pub fn new(db: Pool<Sqlite>, config: Config, mailer: Arc<dyn Mailer>) -> Self {
+        let repos = Repos::sqlite(db.clone());
+        Self {
+            outbox: Outbox::new(repos.outbox.clone(), mailer),
+            metrics: Arc::new(Metrics::new()),
+            repos,
+            db,
+            config: Arc::new(config),
+        }
+    }
+
+    /// Compte affiché par les pages publiques : `site_owner_email` s'il est configuré,
+    /// sinon le plus ancien compte. `None` tant qu'aucun compte n'existe.
+    pub async fn site_owner(&self) -> sqlx::Result<Option<String>> {
+        match &self.config.site_owner_email {
+            Some(email) => sqlx::query_scalar!(r#"SELECT id as "id!" FROM users WHERE email = ? COLLATE NOCASE"#, email)
+                .fetch_optional(&self.db)
+                .await,
+            None => sqlx::query_scalar!(r#"SELECT id as "id!" FROM users ORDER BY created_at, rowid LIMIT 1"#)
+                .fetch_optional(&self.db)
+                .await,
+        }
+    }
 }
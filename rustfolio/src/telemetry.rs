@@ -0,0 +1,61 @@
+//! Logs structurés (`tracing`) et identifiant de requête.
+//!
+//! Le niveau vient de `RUST_LOG` (ex. `info,rustfolio::repo=debug` pour voir la durée
+//! de chaque requête DB), le format de `LOG_FORMAT` (`pretty` ou `json`).
+//! Chaque requête HTTP reçoit un `x-request-id` (repris s'il est fourni), présent
+//! dans tous les logs émis pendant la requête et renvoyé dans la réponse.
+
+use axum::http::{HeaderName, Request};
+use tower_http::{
+    classify::{ServerErrorsAsFailures, SharedClassifier},
+    request_id::{MakeRequestUuid, PropagateRequestIdLayer, SetRequestIdLayer},
+    trace::{DefaultOnResponse, MakeSpan, TraceLayer},
+    LatencyUnit,
+};
+use tracing::Level;
+use tracing_subscriber::{fmt::format::FmtSpan, layer::SubscriberExt, util::SubscriberInitExt, EnvFilter, Layer};
+
+use crate::config::LogFormat;
+
+pub const REQUEST_ID: HeaderName = HeaderName::from_static("x-request-id");
+
+const DEFAULT_FILTER: &str = "info,sqlx=warn";
+
+/// Installe le subscriber global (sans effet s'il y en a déjà un, ex. en test).
+pub fn init(format: LogFormat) {
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(DEFAULT_FILTER));
+    // CLOSE : un événement à la fin de chaque span, avec sa durée (time.busy / time.idle)
+    let fmt = tracing_subscriber::fmt::layer().with_span_events(FmtSpan::CLOSE);
+    let fmt = match format {
+        LogFormat::Pretty => fmt.boxed(),
+        LogFormat::Json => fmt.json().with_current_span(true).boxed(),
+    };
+    let _ = tracing_subscriber::registry().with(filter).with(fmt).try_init();
+}
+
+/// Span `http` racine : méthode, chemin et request-id.
+#[derive(Clone, Copy)]
+pub struct HttpSpan;
+
+impl<B> MakeSpan<B> for HttpSpan {
+    fn make_span(&mut self, req: &Request<B>) -> tracing::Span {
+        let request_id = req.headers().get(&REQUEST_ID).and_then(|v| v.to_str().ok()).unwrap_or("-");
+        tracing::info_span!("http", method = %req.method(), path = %req.uri().path(), request_id)
+    }
+}
+
+/// Couches à poser autour du `Router` (de l'extérieur vers l'intérieur).
+pub fn set_request_id() -> SetRequestIdLayer<MakeRequestUuid> {
+    SetRequestIdLayer::new(REQUEST_ID, MakeRequestUuid)
+}
+
+pub fn trace() -> TraceLayer<SharedClassifier<ServerErrorsAsFailures>, HttpSpan, (), DefaultOnResponse> {
+    TraceLayer::new_for_http()
+        .make_span_with(HttpSpan)
+        .on_request(())
+        .on_response(DefaultOnResponse::new().level(Level::INFO).latency_unit(LatencyUnit::Millis))
+}
+
+pub fn propagate_request_id() -> PropagateRequestIdLayer {
+    PropagateRequestIdLayer::new(REQUEST_ID)
+}
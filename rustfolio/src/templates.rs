@@ -1,10 +1,13 @@
 use askama::Template;
 
+use crate::i18n::Tr;
+
 // Accueil
 #[derive(Template)]
 #[template(path = "index.html")]
 pub struct HomeTpl {
     pub year: i32,
+    pub tr: Tr,
 }
 
 // Laisse ProjectsTpl et PortfolioTpl ici si tu les utilises encore :
@@ -15,7 +18,6 @@ pub struct ProjectsTpl<'a> {
     pub name: &'a str,
     pub title: &'a str,
     pub tagline: &'a str,
-    pub projects: &'a [crate::data::Project],
 }
 
 #[derive(Template)]
@@ -25,4 +27,12 @@ pub struct PortfolioTpl<'a> {
     pub name: &'a str,
     pub title: &'a str,
     pub tagline: &'a str,
+    pub albums: &'a [crate::repo::Album],
+    pub items: &'a [crate::routes::portfolio::FeedItem],
+    /// Album sélectionné (`?type=`), vide pour « Tous »
+    pub selected: &'a str,
+    pub q: &'a str,
+    /// Variables CSS du thème (`Theme::css_vars`), vide : styles par défaut.
+    pub theme_css: &'a str,
+    pub tr: Tr,
 }
@@ -0,0 +1,333 @@
+//! Images téléversées (photo de profil, image de projet, logo de compétence).
+//!
+//! Chaque image est décodée (jamais servie telle quelle), réorientée selon l'EXIF
+//! puis ré-encodée : les métadonnées (EXIF, GPS…) disparaissent au passage.
+//! On produit trois variantes, chacune en WebP + un format de repli (JPEG, ou PNG
+//! si l'image a de la transparence), rangées dans
+//! `<uploads.dir>/<user_id>/<empreinte>-<variante>.<ext>` et servies sous
+//! `/assets/uploads/…` avec un cache long : le nom change quand le contenu change.
+//!
+//! Les lignes qui référencent une image (`photo_url`, `image`, `logo_url`) ne
+//! stockent que l'URL ; un upload dont l'empreinte n'apparaît plus nulle part
+//! est orphelin et supprimé par [`collect_orphans`]. Les PDF de projets
+//! ([`pdf`]) suivent le même principe avec `pdf_link`.
+
+use std::{io::Cursor, path::PathBuf, time::Duration};
+
+use anyhow::Context;
+use image::{
+    codecs::{jpeg::JpegEncoder, png::PngEncoder, webp::WebPEncoder},
+    imageops::FilterType,
+    DynamicImage, ImageDecoder, ImageFormat, ImageReader, Limits,
+};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+use crate::repo::Upload;
+use crate::state::AppState;
+
+pub mod pdf;
+
+/// Préfixe public des fichiers (cf. `app()`).
+pub const PUBLIC_PREFIX: &str = "/assets/uploads";
+
+/// Un upload récent n'est pas encore référencé (le formulaire n'est pas enregistré) :
+/// on ne le considère orphelin qu'après ce délai.
+pub const ORPHAN_GRACE: Duration = Duration::from_secs(60 * 60);
+
+/// Garde-fou contre les « bombes » de décompression (petit fichier, image immense).
+const MAX_SIDE: u32 = 12_000;
+const MAX_ALLOC: u64 = 256 * 1024 * 1024;
+
+const JPEG_QUALITY: u8 = 85;
+
+/* =============================================================================
+   Variantes
+============================================================================= */
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Variant {
+    /// Carré 160 px (listes, avatars).
+    Thumb,
+    /// 640 px de large (cartes).
+    Card,
+    /// 1280 px de large (écrans haute densité).
+    Retina,
+}
+
+impl Variant {
+    pub const ALL: [Variant; 3] = [Variant::Thumb, Variant::Card, Variant::Retina];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Variant::Thumb => "thumb",
+            Variant::Card => "card",
+            Variant::Retina => "retina",
+        }
+    }
+
+    /// Dimensions de sortie pour une source `w`×`h` ; jamais d'agrandissement.
+    pub fn dims(self, w: u32, h: u32) -> (u32, u32) {
+        let fit = |max: u32| {
+            if w <= max {
+                (w, h)
+            } else {
+                (max, ((h as u64 * max as u64) / w as u64).max(1) as u32)
+            }
+        };
+        match self {
+            Variant::Thumb => {
+                let side = w.min(h).min(160);
+                (side, side)
+            }
+            Variant::Card => fit(640),
+            Variant::Retina => fit(1280),
+        }
+    }
+
+    pub(crate) fn render(self, img: &DynamicImage) -> DynamicImage {
+        let (w, h) = self.dims(img.width(), img.height());
+        match self {
+            Variant::Thumb => img.resize_to_fill(w, h, FilterType::Lanczos3),
+            _ if (w, h) == (img.width(), img.height()) => img.clone(),
+            _ => img.resize_exact(w, h, FilterType::Lanczos3),
+        }
+    }
+}
+
+pub fn file_name(hash: &str, variant: Variant, ext: &str) -> String {
+    format!("{hash}-{}.{ext}", variant.name())
+}
+
+pub fn public_url(user_id: &str, file: &str) -> String {
+    format!("{PUBLIC_PREFIX}/{user_id}/{file}")
+}
+
+/* =============================================================================
+   Traitement
+============================================================================= */
+
+#[derive(Debug)]
+pub enum Rejected {
+    /// Format non reconnu ou non accepté (SVG, TIFF, PDF…).
+    Format,
+    /// Reconnu mais illisible (fichier tronqué, dimensions excessives…).
+    Decode(String),
+}
+
+impl std::fmt::Display for Rejected {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Rejected::Format => f.write_str("format d'image non supporté (JPEG, PNG, WebP ou GIF)"),
+            Rejected::Decode(e) => write!(f, "image illisible : {e}"),
+        }
+    }
+}
+
+/// Résultat du traitement, prêt à écrire sur disque.
+pub struct Processed {
+    pub hash:         String,
+    pub fallback_ext: &'static str,
+    pub width:        u32,
+    pub height:       u32,
+    /// `(nom de fichier, contenu)`
+    pub files:        Vec<(String, Vec<u8>)>,
+}
+
+/// Empreinte du fichier envoyé (16 caractères hexadécimaux de SHA-256).
+pub fn content_hash(bytes: &[u8]) -> String {
+    let digest = format!("{:x}", Sha256::digest(bytes));
+    digest[..16].to_string()
+}
+
+/// Décode, réoriente et produit toutes les variantes. Coûteux en CPU :
+/// à appeler dans `spawn_blocking`.
+pub fn process(bytes: &[u8]) -> Result<Processed, Rejected> {
+    let mut reader = ImageReader::new(Cursor::new(bytes))
+        .with_guessed_format()
+        .map_err(|e| Rejected::Decode(e.to_string()))?;
+    match reader.format() {
+        Some(ImageFormat::Jpeg | ImageFormat::Png | ImageFormat::WebP | ImageFormat::Gif) => {}
+        _ => return Err(Rejected::Format),
+    }
+
+    let mut limits = Limits::default();
+    limits.max_image_width = Some(MAX_SIDE);
+    limits.max_image_height = Some(MAX_SIDE);
+    limits.max_alloc = Some(MAX_ALLOC);
+    reader.limits(limits);
+
+    let decode = |e: image::ImageError| Rejected::Decode(e.to_string());
+    let mut decoder = reader.into_decoder().map_err(decode)?;
+    let orientation = decoder.orientation().map_err(decode)?;
+    let mut img = DynamicImage::from_decoder(decoder).map_err(decode)?;
+    img.apply_orientation(orientation);
+
+    let alpha = img.color().has_alpha();
+    let fallback_ext = if alpha { "png" } else { "jpg" };
+    let hash = content_hash(bytes);
+
+    let mut files = Vec::with_capacity(Variant::ALL.len() * 2);
+    for variant in Variant::ALL {
+        let out = variant.render(&img);
+        files.push((file_name(&hash, variant, "webp"), encode_webp(&out, alpha).map_err(decode)?));
+        files.push((file_name(&hash, variant, fallback_ext), encode_fallback(&out, alpha).map_err(decode)?));
+    }
+
+    Ok(Processed { hash, fallback_ext, width: img.width(), height: img.height(), files })
+}
+
+/// Le crate `image` n'encode le WebP qu'en sans perte.
+pub(crate) fn encode_webp(img: &DynamicImage, alpha: bool) -> image::ImageResult<Vec<u8>> {
+    let mut buf = Vec::new();
+    let enc = WebPEncoder::new_lossless(&mut buf);
+    if alpha {
+        img.to_rgba8().write_with_encoder(enc)?;
+    } else {
+        img.to_rgb8().write_with_encoder(enc)?;
+    }
+    Ok(buf)
+}
+
+pub(crate) fn encode_fallback(img: &DynamicImage, alpha: bool) -> image::ImageResult<Vec<u8>> {
+    let mut buf = Vec::new();
+    if alpha {
+        img.to_rgba8().write_with_encoder(PngEncoder::new(&mut buf))?;
+    } else {
+        img.to_rgb8().write_with_encoder(JpegEncoder::new_with_quality(&mut buf, JPEG_QUALITY))?;
+    }
+    Ok(buf)
+}
+
+/* =============================================================================
+   Stockage
+============================================================================= */
+
+fn user_dir(st: &AppState, user_id: &str) -> PathBuf {
+    st.config.uploads.dir.join(user_id)
+}
+
+/// Écrit les fichiers (via un fichier temporaire + rename : jamais de fichier à moitié servi).
+pub async fn store(st: &AppState, user_id: &str, files: &[(String, Vec<u8>)]) -> anyhow::Result<()> {
+    let dir = user_dir(st, user_id);
+    tokio::fs::create_dir_all(&dir)
+        .await
+        .with_context(|| format!("création de {}", dir.display()))?;
+    for (name, bytes) in files {
+        let path = dir.join(name);
+        let tmp = dir.join(format!(".{name}.tmp"));
+        tokio::fs::write(&tmp, bytes).await.with_context(|| format!("écriture de {}", tmp.display()))?;
+        tokio::fs::rename(&tmp, &path).await.with_context(|| format!("écriture de {}", path.display()))?;
+    }
+    Ok(())
+}
+
+/// Supprime les fichiers d'un upload (les absents sont ignorés).
+pub async fn remove_files(st: &AppState, user_id: &str, u: &Upload) -> anyhow::Result<()> {
+    let names = Variant::ALL
+        .into_iter()
+        .flat_map(|v| ["webp", u.fallback_ext.as_str()].map(|ext| file_name(&u.hash, v, ext)));
+    remove_named(st, user_id, names).await
+}
+
+async fn remove_named(st: &AppState, user_id: &str, names: impl IntoIterator<Item = String>) -> anyhow::Result<()> {
+    let dir = user_dir(st, user_id);
+    for name in names {
+        let path = dir.join(name);
+        match tokio::fs::remove_file(&path).await {
+            Ok(()) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e).with_context(|| format!("suppression de {}", path.display())),
+        }
+    }
+    Ok(())
+}
+
+/* =============================================================================
+   Description JSON
+============================================================================= */
+
+#[derive(Debug, Serialize)]
+pub struct VariantUrls {
+    pub width:    u32,
+    pub height:   u32,
+    pub webp:     String,
+    pub fallback: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct UploadOut {
+    pub id:       i64,
+    pub hash:     String,
+    pub width:    u32,
+    pub height:   u32,
+    /// URL à enregistrer dans `photo_url` / `image` / `logo_url` (variante `card`, WebP).
+    pub url:      String,
+    pub variants: std::collections::BTreeMap<&'static str, VariantUrls>,
+}
+
+impl UploadOut {
+    pub fn new(user_id: &str, u: &Upload) -> Self {
+        let (w, h) = (u.width as u32, u.height as u32);
+        let variants = Variant::ALL
+            .into_iter()
+            .map(|v| {
+                let (width, height) = v.dims(w, h);
+                let urls = VariantUrls {
+                    width,
+                    height,
+                    webp: public_url(user_id, &file_name(&u.hash, v, "webp")),
+                    fallback: public_url(user_id, &file_name(&u.hash, v, &u.fallback_ext)),
+                };
+                (v.name(), urls)
+            })
+            .collect();
+        Self {
+            id: u.id,
+            hash: u.hash.clone(),
+            width: w,
+            height: h,
+            url: public_url(user_id, &file_name(&u.hash, Variant::Card, "webp")),
+            variants,
+        }
+    }
+}
+
+/* =============================================================================
+   Orphelins
+============================================================================= */
+
+/// Supprime les uploads (images et PDF) de l'utilisateur qui ne sont plus référencés
+/// et ont plus de `grace`. Renvoie le nombre d'uploads supprimés.
+pub async fn collect_orphans(st: &AppState, user_id: &str, grace: Duration) -> anyhow::Result<usize> {
+    let grace = chrono::Duration::from_std(grace).context("délai de grâce")?;
+    let before = (chrono::Utc::now() - grace).format("%Y-%m-%d %H:%M:%S").to_string();
+
+    let images = st.repos.uploads.orphans(user_id, &before).await?;
+    for u in &images {
+        remove_files(st, user_id, u).await?;
+        st.repos.uploads.delete(user_id, u.id).await?;
+    }
+    let documents = st.repos.documents.orphans(user_id, &before).await?;
+    for d in &documents {
+        pdf::remove_files(st, d).await?;
+        st.repos.documents.delete(user_id, d.id).await?;
+    }
+
+    let count = images.len() + documents.len();
+    if count > 0 {
+        tracing::info!(user_id, images = images.len(), documents = documents.len(), "uploads orphelins supprimés");
+    }
+    Ok(count)
+}
+
+/// Lance [`collect_orphans`] en tâche de fond après une suppression ou un remplacement
+/// d'URL : la réponse HTTP n'attend pas le disque.
+pub fn spawn_cleanup(st: &AppState, user_id: &str) {
+    let (st, user_id) = (st.clone(), user_id.to_string());
+    tokio::spawn(async move {
+        if let Err(e) = collect_orphans(&st, &user_id, ORPHAN_GRACE).await {
+            tracing::warn!(error = %e, user_id, "nettoyage des uploads orphelins");
+        }
+    });
+}
@@ -0,0 +1,231 @@
+//! PDF des projets : validation, métadonnées (nombre de pages, titre) et aperçu.
+//!
+//! Il n'existe pas de moteur de rendu PDF complet en Rust pur : l'aperçu est la
+//! plus grande image de la première page, ce qui couvre les scans (cas du
+//! portfolio graphique). Elle est décodée si elle est en JPEG (`DCTDecode`) ou en
+//! pixels bruts 8 bits RVB / niveaux de gris ; sinon le document n'a pas d'aperçu.
+//!
+//! Le PDF est stocké tel quel à côté des images (`<user_id>/<empreinte>.pdf`) mais
+//! n'est servi que par `GET /documents/:id/:nom`, avec un `Content-Disposition`
+//! propre : `/assets/uploads/…` répond 404 pour les `.pdf` (cf. `hide_documents`).
+
+use image::{DynamicImage, GrayImage, ImageFormat, RgbImage};
+use lopdf::{xobject::PdfImage, Document as Pdf};
+use serde::Serialize;
+
+use super::{content_hash, encode_fallback, encode_webp, public_url, remove_named, Variant, VariantUrls};
+use crate::repo::Document;
+use crate::state::AppState;
+
+/// Même garde-fou que pour les images : pas de pixmap démesurée.
+const MAX_SIDE: i64 = 12_000;
+
+#[derive(Debug)]
+pub enum Rejected {
+    /// Pas de signature `%PDF-`.
+    NotPdf,
+    /// Chiffré : ni métadonnées ni aperçu lisibles.
+    Encrypted,
+    Invalid(String),
+}
+
+impl std::fmt::Display for Rejected {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Rejected::NotPdf => f.write_str("le fichier n'est pas un PDF"),
+            Rejected::Encrypted => f.write_str("PDF protégé par mot de passe non supporté"),
+            Rejected::Invalid(e) => write!(f, "PDF illisible : {e}"),
+        }
+    }
+}
+
+/// Résultat de l'analyse, prêt à écrire sur disque.
+pub struct Processed {
+    pub hash:       String,
+    pub title:      Option<String>,
+    pub page_count: u32,
+    /// Dimensions de l'aperçu (variante `card`), s'il existe.
+    pub preview:    Option<(u32, u32)>,
+    /// `(nom de fichier, contenu)` : le PDF puis l'aperçu éventuel.
+    pub files:      Vec<(String, Vec<u8>)>,
+}
+
+pub fn pdf_name(hash: &str) -> String {
+    format!("{hash}.pdf")
+}
+
+pub fn preview_name(hash: &str, ext: &str) -> String {
+    format!("{hash}-preview.{ext}")
+}
+
+/// Coûteux (parsing complet + décodage d'image) : à appeler dans `spawn_blocking`.
+pub fn process(bytes: &[u8]) -> Result<Processed, Rejected> {
+    if !bytes.starts_with(b"%PDF-") {
+        return Err(Rejected::NotPdf);
+    }
+    let doc = Pdf::load_mem(bytes).map_err(|e| Rejected::Invalid(e.to_string()))?;
+    if doc.is_encrypted() {
+        return Err(Rejected::Encrypted);
+    }
+
+    let pages = doc.get_pages();
+    if pages.is_empty() {
+        return Err(Rejected::Invalid("aucune page".into()));
+    }
+
+    let hash = content_hash(bytes);
+    let mut files = vec![(pdf_name(&hash), bytes.to_vec())];
+
+    let preview = match first_page_image(&doc) {
+        Some(img) => {
+            let out = Variant::Card.render(&img);
+            let encode = |e: image::ImageError| Rejected::Invalid(e.to_string());
+            files.push((preview_name(&hash, "webp"), encode_webp(&out, false).map_err(encode)?));
+            files.push((preview_name(&hash, "jpg"), encode_fallback(&out, false).map_err(encode)?));
+            Some((out.width(), out.height()))
+        }
+        None => None,
+    };
+
+    Ok(Processed { hash, title: title(&doc), page_count: pages.len() as u32, preview, files })
+}
+
+/// `/Title` du dictionnaire `/Info` (PDFDocEncoding ou UTF-16BE), vide → `None`.
+fn title(doc: &Pdf) -> Option<String> {
+    let info = doc.trailer.get(b"Info").ok()?;
+    let (_, info) = doc.dereference(info).ok()?;
+    let raw = info.as_dict().ok()?.get(b"Title").ok()?;
+    let (_, raw) = doc.dereference(raw).ok()?;
+    let title = lopdf::decode_text_string(raw).ok()?;
+    let title: String = title.chars().filter(|c| !c.is_control()).collect();
+    let title = title.trim();
+    (!title.is_empty()).then(|| title.chars().take(200).collect())
+}
+
+fn first_page_image(doc: &Pdf) -> Option<DynamicImage> {
+    let (_, page) = doc.get_pages().into_iter().next()?;
+    let images = doc.get_page_images(page).ok()?;
+    let largest = images
+        .iter()
+        .filter(|i| (1..=MAX_SIDE).contains(&i.width) && (1..=MAX_SIDE).contains(&i.height))
+        .max_by_key(|i| i.width * i.height)?;
+    decode_image(doc, largest)
+}
+
+fn decode_image(doc: &Pdf, img: &PdfImage) -> Option<DynamicImage> {
+    let filters: Vec<&str> = img.filters.iter().flatten().map(String::as_str).collect();
+    if filters == ["DCTDecode"] {
+        let mut decoded = image::load_from_memory_with_format(img.content, ImageFormat::Jpeg).ok()?;
+        // JPEG CMJN (souvent inversé dans les PDF) : on renonce plutôt que d'afficher un négatif
+        if img.color_space.as_deref() == Some("DeviceCMYK") {
+            return None;
+        }
+        if decoded.color().has_alpha() {
+            decoded = DynamicImage::ImageRgb8(decoded.to_rgb8());
+        }
+        return Some(decoded);
+    }
+
+    let raw = match filters.as_slice() {
+        [] => img.content.to_vec(),
+        ["FlateDecode"] => doc.get_object(img.id).ok()?.as_stream().ok()?.decompressed_content().ok()?,
+        _ => return None,
+    };
+    if img.bits_per_component != Some(8) {
+        return None;
+    }
+    let (w, h) = (img.width as u32, img.height as u32);
+    match img.color_space.as_deref()? {
+        "DeviceRGB" => RgbImage::from_raw(w, h, raw).map(DynamicImage::ImageRgb8),
+        "DeviceGray" => GrayImage::from_raw(w, h, raw).map(DynamicImage::ImageLuma8),
+        _ => None,
+    }
+}
+
+/* =============================================================================
+   Noms de fichiers, URLs, en-têtes
+============================================================================= */
+
+/// Nom d'origine sans chemin ni caractères de contrôle, toujours en `.pdf`.
+pub fn sanitize_file_name(raw: Option<&str>) -> String {
+    let base = raw.unwrap_or_default().rsplit(['/', '\\']).next().unwrap_or_default();
+    let clean: String = base.chars().filter(|c| !c.is_control() && *c != '"').take(100).collect();
+    let clean = clean.trim().trim_start_matches('.').trim();
+    let stem = match clean.len() {
+        n if n > 4 && clean.to_ascii_lowercase().ends_with(".pdf") => &clean[..n - 4],
+        _ => clean,
+    };
+    if stem.trim().is_empty() { "document.pdf".into() } else { format!("{}.pdf", stem.trim()) }
+}
+
+/// Encodage RFC 5987 / segment d'URL : tout sauf `attr-char` en `%XX`.
+fn pct_encode(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for b in s.bytes() {
+        if b.is_ascii_alphanumeric() || b"!#$&+-.^_`|~".contains(&b) {
+            out.push(b as char);
+        } else {
+            out.push_str(&format!("%{b:02X}"));
+        }
+    }
+    out
+}
+
+/// `attachment; filename="…"; filename*=UTF-8''…` : repli ASCII pour les vieux clients.
+pub fn content_disposition(file_name: &str) -> String {
+    let ascii: String = file_name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || " .-_()".contains(c) { c } else { '_' })
+        .collect();
+    format!("attachment; filename=\"{ascii}\"; filename*=UTF-8''{}", pct_encode(file_name))
+}
+
+/// URL à enregistrer dans `pdf_link`.
+pub fn download_url(d: &Document) -> String {
+    format!("/documents/{}/{}", d.id, pct_encode(&d.file_name))
+}
+
+pub async fn remove_files(st: &AppState, d: &Document) -> anyhow::Result<()> {
+    let names = [pdf_name(&d.hash), preview_name(&d.hash, "webp"), preview_name(&d.hash, "jpg")];
+    remove_named(st, &d.user_id, names).await
+}
+
+pub fn file_path(st: &AppState, d: &Document) -> std::path::PathBuf {
+    st.config.uploads.dir.join(&d.user_id).join(pdf_name(&d.hash))
+}
+
+/* =============================================================================
+   Description JSON
+============================================================================= */
+
+#[derive(Debug, Serialize)]
+pub struct DocumentOut {
+    pub id:         i64,
+    pub file_name:  String,
+    pub title:      Option<String>,
+    pub page_count: i64,
+    pub bytes:      i64,
+    /// URL de téléchargement, recopiée dans `pdf_link`.
+    pub url:        String,
+    pub preview:    Option<VariantUrls>,
+}
+
+impl DocumentOut {
+    pub fn new(d: &Document) -> Self {
+        let preview = d.preview_width.zip(d.preview_height).map(|(w, h)| VariantUrls {
+            width: w as u32,
+            height: h as u32,
+            webp: public_url(&d.user_id, &preview_name(&d.hash, "webp")),
+            fallback: public_url(&d.user_id, &preview_name(&d.hash, "jpg")),
+        });
+        Self {
+            id: d.id,
+            file_name: d.file_name.clone(),
+            title: d.title.clone(),
+            page_count: d.page_count,
+            bytes: d.bytes,
+            url: download_url(d),
+            preview,
+        }
+    }
+}
@@ -0,0 +1,71 @@
+mod common;
+
+use axum::http::{Method, StatusCode};
+use common::{app, call, form, signup};
+
+#[tokio::test]
+async fn signup_opens_a_session() {
+    let app = app().await;
+    let sid = signup(&app, "alice@example.com").await;
+
+    let me = call(&app, Method::GET, "/auth/me", Some(&sid), None).await;
+    assert_eq!(me.status, StatusCode::OK);
+    assert_eq!(me.json["email"], "alice@example.com");
+
+    let s = call(&app, Method::GET, "/auth/session", Some(&sid), None).await;
+    assert_eq!(s.json["authenticated"], true);
+}
+
+#[tokio::test]
+async fn signup_rejects_duplicate_email() {
+    let app = app().await;
+    signup(&app, "alice@example.com").await;
+
+    let r = form(&app, "/auth/signup", None, &[("email", "alice@example.com"), ("password", "x")]).await;
+    assert_eq!(r.status, StatusCode::CONFLICT);
+}
+
+#[tokio::test]
+async fn login_checks_password() {
+    let app = app().await;
+    signup(&app, "bob@example.com").await;
+
+    let bad = form(&app, "/auth/login", None, &[("email", "bob@example.com"), ("password", "nope")]).await;
+    assert_eq!(bad.status, StatusCode::UNAUTHORIZED);
+    assert!(bad.cookie.is_none());
+
+    let unknown = form(&app, "/auth/login", None, &[("email", "ghost@example.com"), ("password", "nope")]).await;
+    assert_eq!(unknown.status, StatusCode::UNAUTHORIZED);
+
+    let ok = form(&app, "/auth/login", None, &[("email", "bob@example.com"), ("password", "hunter2hunter2")]).await;
+    assert_eq!(ok.status, StatusCode::SEE_OTHER);
+    let sid = ok.cookie.expect("cookie");
+    let me = call(&app, Method::GET, "/auth/me", Some(&sid), None).await;
+    assert_eq!(me.json["email"], "bob@example.com");
+}
+
+#[tokio::test]
+async fn logout_invalidates_session() {
+    let app = app().await;
+    let sid = signup(&app, "carol@example.com").await;
+
+    let r = form(&app, "/auth/logout", Some(&sid), &[]).await;
+    assert_eq!(r.status, StatusCode::SEE_OTHER);
+    assert_eq!(r.location.as_deref(), Some("/auth/login"));
+
+    let me = call(&app, Method::GET, "/auth/me", Some(&sid), None).await;
+    assert_eq!(me.status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn cv_api_requires_a_session() {
+    let app = app().await;
+    for uri in ["/api/cv/profile", "/api/cv/experiences", "/api/cv/skills", "/api/cv/projects", "/api/cv/bulk"] {
+        let r = call(&app, Method::GET, uri, None, None).await;
+        assert_eq!(r.status, StatusCode::UNAUTHORIZED, "{uri}");
+    }
+
+    let dash = call(&app, Method::GET, "/dashboard", None, None).await;
+    assert_eq!(dash.status, StatusCode::SEE_OTHER);
+    assert_eq!(dash.location.as_deref(), Some("/auth/login"));
+}
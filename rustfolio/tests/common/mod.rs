@@ -0,0 +1,193 @@
+//! Harnais commun aux tests d'intégration : le vrai `Router` sur une base
+//! `sqlite::memory:` migrée, appelé en mémoire via `tower::ServiceExt::oneshot`.
+#![allow(dead_code)] // chaque fichier de test n'utilise qu'une partie des helpers
+
+use axum::{
+    body::Body,
+    http::{header, HeaderMap, Method, Request, StatusCode},
+    Router,
+};
+use serde_json::Value;
+use sqlx::{sqlite::SqlitePoolOptions, SqlitePool};
+use std::sync::Arc;
+
+use tower::ServiceExt;
+
+use rustfolio::config::{CliArgs, Config};
+use rustfolio::mail::{LogMailer, Mailer};
+use rustfolio::state::AppState;
+
+/// Application complète sur une base neuve (une par test), e-mails en mode log.
+pub async fn app() -> Router {
+    rustfolio::app(state(log_mailer()).await)
+}
+
+/// Application avec une config particulière (cf. [`config_with`]).
+pub async fn app_with(config: Config) -> Router {
+    rustfolio::app(state_with(config).await)
+}
+
+/// State sur une base neuve avec une config particulière, e-mails en mode log.
+pub async fn state_with(config: Config) -> AppState {
+    let db = memory_pool().await;
+    rustfolio::db::MIGRATOR.run(&db).await.expect("migrations");
+    AppState::new(db, config, log_mailer())
+}
+
+/// State sur une base neuve, avec le transport d'e-mails fourni.
+pub async fn state(mailer: Arc<dyn Mailer>) -> AppState {
+    let db = memory_pool().await;
+    rustfolio::db::MIGRATOR.run(&db).await.expect("migrations");
+
+    AppState::new(db, config(), mailer)
+}
+
+/// Base `:memory:` vide (non migrée).
+pub async fn memory_pool() -> SqlitePool {
+    // Une seule connexion, jamais recyclée : chaque connexion `:memory:` a sa propre base.
+    SqlitePoolOptions::new()
+        .max_connections(1)
+        .idle_timeout(None)
+        .max_lifetime(None)
+        .connect("sqlite::memory:")
+        .await
+        .expect("sqlite memory")
+}
+
+pub fn log_mailer() -> Arc<dyn Mailer> {
+    Arc::new(LogMailer::new("test@rustfolio.local".parse().unwrap()))
+}
+
+/// Config de test : uniquement des défauts (e-mails en mode log), sans lire l'environnement.
+pub fn config() -> Config {
+    config_with(&[])
+}
+
+/// Défauts + les variables d'environnement données.
+pub fn config_with(vars: &[(&str, &str)]) -> Config {
+    let env = |k: &str| match k {
+        "DATABASE_URL" => Some("sqlite::memory:".to_string()),
+        _ => vars.iter().find(|(name, _)| *name == k).map(|(_, v)| v.to_string()),
+    };
+    Config::from_sources(None, env, &CliArgs::default()).expect("config de test")
+}
+
+pub struct Resp {
+    pub status: StatusCode,
+    pub cookie: Option<String>,
+    pub location: Option<String>,
+    pub headers: HeaderMap,
+    pub text: String,
+    pub json: Value,
+}
+
+/// Envoie une requête avec un corps JSON optionnel.
+pub async fn call(app: &Router, method: Method, uri: &str, cookie: Option<&str>, body: Option<Value>) -> Resp {
+    let mut req = Request::builder().method(method).uri(uri);
+    if let Some(c) = cookie {
+        req = req.header(header::COOKIE, c);
+    }
+    let req = match body {
+        Some(v) => req
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(v.to_string())),
+        None => req.body(Body::empty()),
+    }
+    .unwrap();
+    send(app, req).await
+}
+
+/// GET conditionnel (`If-None-Match`).
+pub async fn get_if_none_match(app: &Router, uri: &str, cookie: Option<&str>, etag: &str) -> Resp {
+    let mut req = Request::get(uri).header(header::IF_NONE_MATCH, etag);
+    if let Some(c) = cookie {
+        req = req.header(header::COOKIE, c);
+    }
+    send(app, req.body(Body::empty()).unwrap()).await
+}
+
+/// GET avec des en-têtes supplémentaires (`Accept-Language`…).
+pub async fn get_with(app: &Router, uri: &str, cookie: Option<&str>, headers: &[(&str, &str)]) -> Resp {
+    let mut req = Request::get(uri);
+    if let Some(c) = cookie {
+        req = req.header(header::COOKIE, c);
+    }
+    for (k, v) in headers {
+        req = req.header(*k, *v);
+    }
+    send(app, req.body(Body::empty()).unwrap()).await
+}
+
+/// POST `application/x-www-form-urlencoded` (pages /auth).
+pub async fn form(app: &Router, uri: &str, cookie: Option<&str>, fields: &[(&str, &str)]) -> Resp {
+    form_with(app, uri, cookie, &[], fields).await
+}
+
+/// Comme [`form`], avec des en-têtes supplémentaires.
+pub async fn form_with(
+    app: &Router,
+    uri: &str,
+    cookie: Option<&str>,
+    headers: &[(&str, &str)],
+    fields: &[(&str, &str)],
+) -> Resp {
+    let body = fields
+        .iter()
+        .map(|(k, v)| format!("{k}={}", v.replace('@', "%40").replace(' ', "+")))
+        .collect::<Vec<_>>()
+        .join("&");
+    let mut req = Request::post(uri).header(header::CONTENT_TYPE, "application/x-www-form-urlencoded");
+    if let Some(c) = cookie {
+        req = req.header(header::COOKIE, c);
+    }
+    for (k, v) in headers {
+        req = req.header(*k, *v);
+    }
+    send(app, req.body(Body::from(body)).unwrap()).await
+}
+
+/// POST `multipart/form-data` avec un seul fichier.
+pub async fn upload(app: &Router, uri: &str, cookie: Option<&str>, field: &str, filename: &str, bytes: &[u8]) -> Resp {
+    const BOUNDARY: &str = "----rustfolio-test-boundary";
+    let mut body = format!(
+        "--{BOUNDARY}\r\nContent-Disposition: form-data; name=\"{field}\"; filename=\"{filename}\"\r\n\
+         Content-Type: application/octet-stream\r\n\r\n"
+    )
+    .into_bytes();
+    body.extend_from_slice(bytes);
+    body.extend_from_slice(format!("\r\n--{BOUNDARY}--\r\n").as_bytes());
+
+    let mut req = Request::post(uri).header(header::CONTENT_TYPE, format!("multipart/form-data; boundary={BOUNDARY}"));
+    if let Some(c) = cookie {
+        req = req.header(header::COOKIE, c);
+    }
+    send(app, req.body(Body::from(body)).unwrap()).await
+}
+
+async fn send(app: &Router, req: Request<Body>) -> Resp {
+    let res = app.clone().oneshot(req).await.unwrap();
+    let status = res.status();
+    let cookie = res
+        .headers()
+        .get(header::SET_COOKIE)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.split(';').next())
+        .map(str::to_string);
+    let location = res
+        .headers()
+        .get(header::LOCATION)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string);
+    let headers = res.headers().clone();
+    let bytes = axum::body::to_bytes(res.into_body(), usize::MAX).await.unwrap();
+    let json = serde_json::from_slice(&bytes).unwrap_or(Value::Null);
+    let text = String::from_utf8_lossy(&bytes).into_owned();
+    Resp { status, cookie, location, headers, text, json }
+}
+
+/// Crée un compte et renvoie le cookie de session (`sid=…`).
+pub async fn signup(app: &Router, email: &str) -> String {
+    let r = form(app, "/auth/signup", None, &[("email", email), ("password", "hunter2hunter2")]).await;
+    assert_eq!(r.status, StatusCode::SEE_OTHER, "signup {email}");
+    r.cookie.expect("cookie de session")
+}
@@ -0,0 +1,129 @@
+//! Chargement de la config : priorités entre sources et rapport de validation.
+
+use std::collections::HashMap;
+
+use axum_extra::extract::cookie::SameSite;
+use rustfolio::config::{CliArgs, Config, EmailTransport};
+
+fn env(pairs: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+    let map: HashMap<String, String> = pairs.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+    move |k| map.get(k).cloned()
+}
+
+const DB: (&str, &str) = ("DATABASE_URL", "sqlite://data/app.db");
+
+#[test]
+fn defaults_only_need_database_url() {
+    let cfg = Config::from_sources(None, env(&[DB]), &CliArgs::default()).unwrap();
+    assert_eq!(cfg.bind_addr.to_string(), "0.0.0.0:8080");
+    assert_eq!(cfg.public_base_url, "http://localhost:8080");
+    assert_eq!(cfg.session_ttl.whole_days(), 30);
+    assert_eq!(cfg.cookie.same_site, SameSite::Lax);
+    assert!(!cfg.cookie.secure);
+    assert!(matches!(cfg.email.transport, EmailTransport::Log));
+    assert_eq!(cfg.uploads.max_image_bytes, 5 * 1024 * 1024);
+}
+
+#[test]
+fn report_lists_every_problem() {
+    let err = Config::from_sources(
+        None,
+        env(&[
+            ("PUBLIC_BASE_URL", "localhost:8080"),
+            ("COOKIE_SECURE", "peut-être"),
+            ("EMAIL_MODE", "smtp"),
+            ("SMTP_PORT", "abc"),
+        ]),
+        &CliArgs::default(),
+    )
+    .unwrap_err();
+
+    let text = err.to_string();
+    for needle in ["DATABASE_URL", "PUBLIC_BASE_URL", "COOKIE_SECURE", "SMTP_PORT", "SMTP_HOST", "SMTP_USER", "SMTP_PASS"] {
+        assert!(text.contains(needle), "{needle} absent du rapport :\n{text}");
+    }
+}
+
+#[test]
+fn env_overrides_toml_and_cli_overrides_env() {
+    let toml = r#"
+        bind_addr = "127.0.0.1:3000"
+        public_base_url = "https://toml.example/"
+
+        [session]
+        ttl_days = 7
+
+        [email]
+        mode = "smtp"
+        from = "Rustfolio <no-reply@toml.example>"
+
+        [email.smtp]
+        host = "smtp.toml.example"
+        user = "u"
+        pass = "p"
+    "#;
+    let vars = env(&[DB, ("PUBLIC_BASE_URL", "https://env.example"), ("SESSION_TTL_DAYS", "14")]);
+    let cli = CliArgs { bind: Some("127.0.0.1:9000".into()), ..CliArgs::default() };
+
+    let cfg = Config::from_sources(Some(toml), vars, &cli).unwrap();
+    assert_eq!(cfg.bind_addr.to_string(), "127.0.0.1:9000");
+    assert_eq!(cfg.public_base_url, "https://env.example");
+    assert_eq!(cfg.session_ttl.whole_days(), 14);
+
+    let EmailTransport::Smtp(smtp) = &cfg.email.transport else { panic!("smtp attendu") };
+    assert_eq!(smtp.host, "smtp.toml.example");
+    assert_eq!(smtp.port, 587);
+    assert_eq!(smtp.hello_name, "env.example");
+    assert!(!cfg.to_string().contains("pass=p"), "mot de passe SMTP affiché en clair");
+}
+
+#[test]
+fn unknown_toml_key_is_reported() {
+    let err = Config::from_sources(Some("bind = \"0.0.0.0:80\""), env(&[DB]), &CliArgs::default()).unwrap_err();
+    assert!(err.to_string().contains("fichier de config"), "{err}");
+}
+
+#[test]
+fn cli_flags_are_extracted_from_subcommand() {
+    let args: Vec<String> = ["serve", "--migrate", "--bind=127.0.0.1:1", "--config", "x.toml"]
+        .iter()
+        .map(|s| s.to_string())
+        .collect();
+    let (cli, rest) = CliArgs::parse(&args).unwrap();
+    assert!(cli.migrate);
+    assert_eq!(cli.bind.as_deref(), Some("127.0.0.1:1"));
+    assert_eq!(cli.config_path.as_deref(), Some("x.toml"));
+    assert_eq!(rest, ["serve"]);
+    assert!(CliArgs::parse(&["--nope".to_string()]).is_err());
+}
+
+#[test]
+fn cors_wildcard_and_paths_are_rejected() {
+    let err = Config::from_sources(
+        None,
+        env(&[DB, ("CORS_ALLOWED_ORIGINS", "*, http://localhost:8081/app, localhost")]),
+        &CliArgs::default(),
+    )
+    .unwrap_err();
+    assert_eq!(err.problems.len(), 3, "{err}");
+
+    let cfg = Config::from_sources(None, env(&[DB, ("CORS_ALLOWED_ORIGINS", " http://a.test , https://b.test:8443 ")]), &CliArgs::default())
+        .unwrap();
+    assert_eq!(cfg.cors_allowed_origins, ["http://a.test", "https://b.test:8443"]);
+}
+
+#[test]
+fn oversized_megabyte_values_are_rejected() {
+    let huge = u64::MAX.to_string();
+    let err = Config::from_sources(
+        None,
+        env(&[DB, ("UPLOAD_MAX_IMAGE_MB", &huge), ("UPLOAD_MAX_PDF_MB", &huge), ("HEALTH_MIN_FREE_DISK_MB", &huge)]),
+        &CliArgs::default(),
+    )
+    .unwrap_err();
+    assert_eq!(err.problems.len(), 3, "{err}");
+    let text = err.to_string();
+    for needle in ["UPLOAD_MAX_IMAGE_MB", "UPLOAD_MAX_PDF_MB", "HEALTH_MIN_FREE_DISK_MB"] {
+        assert!(text.contains(needle), "{needle} absent du rapport :\n{text}");
+    }
+}
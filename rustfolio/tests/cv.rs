@@ -0,0 +1,190 @@
+mod common;
+
+use axum::http::{Method, StatusCode};
+use serde_json::json;
+
+use common::{app, call, signup};
+
+#[tokio::test]
+async fn profile_put_merges_fields() {
+    let app = app().await;
+    let sid = signup(&app, "alice@example.com").await;
+
+    let empty = call(&app, Method::GET, "/api/cv/profile", Some(&sid), None).await;
+    assert_eq!(empty.json["first_name"], "");
+
+    let body = json!({ "first_name": "Alice", "title": "Dev Rust" });
+    let r = call(&app, Method::PUT, "/api/cv/profile", Some(&sid), Some(body)).await;
+    assert_eq!(r.status, StatusCode::OK);
+
+    // un patch partiel garde les autres champs
+    let body = json!({ "city": "Lyon" });
+    call(&app, Method::PUT, "/api/cv/profile", Some(&sid), Some(body)).await;
+
+    let p = call(&app, Method::GET, "/api/cv/profile", Some(&sid), None).await.json;
+    assert_eq!(p["first_name"], "Alice");
+    assert_eq!(p["title"], "Dev Rust");
+    assert_eq!(p["city"], "Lyon");
+}
+
+#[tokio::test]
+async fn experience_and_tasks_crud() {
+    let app = app().await;
+    let sid = signup(&app, "alice@example.com").await;
+
+    let exp = json!({
+        "date_start": " 2021-09 ", "date_end": "", "kind": "work", "title": "Dev",
+        "company": "Acme", "location": "Paris", "website": "", "tasks": []
+    });
+    let created = call(&app, Method::POST, "/api/cv/experiences", Some(&sid), Some(exp)).await;
+    assert_eq!(created.status, StatusCode::OK);
+    assert_eq!(created.json["date_start"], "2021-09");
+    let id = created.json["id"].as_i64().unwrap();
+
+    let t = call(&app, Method::POST, &format!("/api/cv/experiences/{id}/tasks"), Some(&sid), Some(json!({ "task": "API **Axum**" }))).await;
+    assert_eq!(t.json["html"], "API <strong>Axum</strong>");
+    let task_id = t.json["id"].as_i64().unwrap();
+
+    let uri = format!("/api/cv/experiences/{id}/tasks/{task_id}");
+    let patched = call(&app, Method::PATCH, &uri, Some(&sid), Some(json!({ "task": "API REST" }))).await;
+    assert_eq!(patched.json["task"], "API REST");
+    let empty = call(&app, Method::PATCH, &uri, Some(&sid), Some(json!({ "task": "  " }))).await;
+    assert_eq!(empty.status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let list = call(&app, Method::GET, "/api/cv/experiences", Some(&sid), None).await.json;
+    assert_eq!(list[0]["company"], "Acme");
+    assert_eq!(list[0]["tasks"], json!(["API REST"]));
+
+    let upd = json!({
+        "date_start": "2021-09", "date_end": "2023-01", "kind": "work", "title": "Lead",
+        "company": "Acme", "location": "Paris", "website": "", "tasks": []
+    });
+    let r = call(&app, Method::PUT, &format!("/api/cv/experiences/{id}"), Some(&sid), Some(upd)).await;
+    assert_eq!(r.status, StatusCode::OK);
+
+    let r = call(&app, Method::DELETE, &format!("/api/cv/experiences/{id}"), Some(&sid), None).await;
+    assert_eq!(r.status, StatusCode::OK);
+    let list = call(&app, Method::GET, "/api/cv/experiences", Some(&sid), None).await.json;
+    assert_eq!(list, json!([]));
+}
+
+#[tokio::test]
+async fn skills_are_canonicalized_and_validated() {
+    let app = app().await;
+    let sid = signup(&app, "alice@example.com").await;
+
+    let s = call(&app, Method::POST, "/api/cv/skills", Some(&sid), Some(json!({ "name": "rust", "level": "advanced", "category": "Langages" }))).await;
+    assert_eq!(s.status, StatusCode::OK);
+    assert_eq!(s.json["name"], "Rust");
+    assert_eq!(s.json["level"], "advanced");
+    assert!(s.json["logo_url"].as_str().is_some_and(|l| l.ends_with("rust.svg")));
+    let id = s.json["id"].as_i64().unwrap();
+
+    let bad = call(&app, Method::POST, "/api/cv/skills", Some(&sid), Some(json!({ "name": "Go", "percentage": 140 }))).await;
+    assert_eq!(bad.status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let upd = call(&app, Method::PUT, &format!("/api/cv/skills/{id}"), Some(&sid), Some(json!({ "name": "Rust", "percentage": 80, "category": "Langages" }))).await;
+    assert_eq!(upd.json["percentage"], 80);
+
+    let cats = call(&app, Method::GET, "/api/cv/skills/categories", Some(&sid), None).await;
+    assert_eq!(cats.json, json!(["Langages"]));
+
+    call(&app, Method::DELETE, &format!("/api/cv/skills/{id}"), Some(&sid), None).await;
+    let list = call(&app, Method::GET, "/api/cv/skills", Some(&sid), None).await;
+    assert_eq!(list.json, json!([]));
+}
+
+#[tokio::test]
+async fn catalog_search_treats_wildcards_literally() {
+    let app = app().await;
+    let sid = signup(&app, "alice@example.com").await;
+
+    let ru = call(&app, Method::GET, "/api/catalog/search?q=ru", Some(&sid), None).await;
+    assert_eq!(ru.json[0]["name"], "Rust");
+    for q in ["%25", "%5C", "r%25t"] {
+        let r = call(&app, Method::GET, &format!("/api/catalog/search?q={q}"), Some(&sid), None).await;
+        assert_eq!(r.json, json!([]), "q={q}");
+    }
+}
+
+#[tokio::test]
+async fn projects_technologies_link_to_skills() {
+    let app = app().await;
+    let sid = signup(&app, "alice@example.com").await;
+
+    let p = call(&app, Method::POST, "/api/cv/projects", Some(&sid), Some(json!({ "title": "Rustfolio", "technologies": ["rust", "Next.JS"] }))).await;
+    assert_eq!(p.json["technologies"], json!(["Rust", "Next.js"]));
+    let pid = p.json["id"].as_i64().unwrap();
+
+    let tech = call(&app, Method::POST, &format!("/api/cv/projects/{pid}/tech"), Some(&sid), Some(json!({ "tech": "sqlite" }))).await;
+    assert_eq!(tech.json["tech"], "SQLite");
+    let tech_id = tech.json["id"].as_i64().unwrap();
+    let renamed = call(&app, Method::PATCH, &format!("/api/cv/projects/{pid}/tech/{tech_id}"), Some(&sid), Some(json!({ "tech": "docker" }))).await;
+    assert_eq!(renamed.json["tech"], "Docker");
+
+    let s = call(&app, Method::POST, "/api/cv/skills", Some(&sid), Some(json!({ "name": "RUST" }))).await;
+    let sid_skill = s.json["id"].as_i64().unwrap();
+    let used = call(&app, Method::GET, &format!("/api/cv/skills/{sid_skill}/projects"), Some(&sid), None).await;
+    assert_eq!(used.json[0]["title"], "Rustfolio");
+
+    let r = call(&app, Method::DELETE, &format!("/api/cv/projects/{pid}"), Some(&sid), None).await;
+    assert_eq!(r.status, StatusCode::OK);
+    let list = call(&app, Method::GET, "/api/cv/projects", Some(&sid), None).await;
+    assert_eq!(list.json, json!([]));
+}
+
+#[tokio::test]
+async fn bulk_round_trip() {
+    let app = app().await;
+    let sid = signup(&app, "alice@example.com").await;
+
+    let cv = json!({
+        "profile": { "first_name": "Alice", "last_name": "Martin" },
+        "experiences": [{ "date_start": "2020", "date_end": "2022", "kind": "work", "title": "Dev",
+                          "company": "Acme", "location": "", "website": "", "tasks": ["a", "b"] }],
+        "skills": [{ "name": "typescript", "percentage": 70, "category": "Langages" }],
+        "projects": [{ "title": "Site", "technologies": ["react"] }]
+    });
+    let r = call(&app, Method::PUT, "/api/cv/bulk", Some(&sid), Some(cv)).await;
+    assert_eq!(r.status, StatusCode::OK);
+
+    let got = call(&app, Method::GET, "/api/cv/bulk", Some(&sid), None).await.json;
+    assert_eq!(got["profile"]["last_name"], "Martin");
+    assert_eq!(got["experiences"][0]["tasks"], json!(["a", "b"]));
+    assert_eq!(got["skills"][0]["name"], "TypeScript");
+    assert_eq!(got["projects"][0]["technologies"], json!(["React"]));
+
+    // compétence refusée : 422 et rien n'est remplacé
+    for skill in [json!({ "name": "Rust", "percentage": 150 }), json!({ "name": "  " })] {
+        let bad = json!({ "profile": { "first_name": "Bob" }, "experiences": [], "skills": [skill], "projects": [] });
+        let r = call(&app, Method::PUT, "/api/cv/bulk", Some(&sid), Some(bad)).await;
+        assert_eq!(r.status, StatusCode::UNPROCESSABLE_ENTITY);
+    }
+    let after = call(&app, Method::GET, "/api/cv/bulk", Some(&sid), None).await.json;
+    assert_eq!(after, got);
+}
+
+#[tokio::test]
+async fn users_cannot_touch_each_other_data() {
+    let app = app().await;
+    let alice = signup(&app, "alice@example.com").await;
+    let bob = signup(&app, "bob@example.com").await;
+
+    let exp = json!({ "date_start": "2020", "date_end": "", "kind": "work", "title": "Dev",
+                      "company": "Acme", "location": "", "website": "", "tasks": [] });
+    let id = call(&app, Method::POST, "/api/cv/experiences", Some(&alice), Some(exp.clone())).await.json["id"].as_i64().unwrap();
+    let pid = call(&app, Method::POST, "/api/cv/projects", Some(&alice), Some(json!({ "title": "P" }))).await.json["id"].as_i64().unwrap();
+
+    let list = call(&app, Method::GET, "/api/cv/experiences", Some(&bob), None).await;
+    assert_eq!(list.json, json!([]));
+
+    let put = call(&app, Method::PUT, &format!("/api/cv/experiences/{id}"), Some(&bob), Some(exp)).await;
+    assert_eq!(put.status, StatusCode::NOT_FOUND);
+    let task = call(&app, Method::POST, &format!("/api/cv/experiences/{id}/tasks"), Some(&bob), Some(json!({ "task": "x" }))).await;
+    assert_eq!(task.status, StatusCode::NOT_FOUND);
+    let del = call(&app, Method::DELETE, &format!("/api/cv/projects/{pid}"), Some(&bob), None).await;
+    assert_eq!(del.status, StatusCode::NOT_FOUND);
+
+    let still = call(&app, Method::GET, "/api/cv/experiences", Some(&alice), None).await;
+    assert_eq!(still.json.as_array().map(Vec::len), Some(1));
+}
@@ -0,0 +1,188 @@
+mod common;
+
+use axum::http::{Method, StatusCode};
+use serde_json::json;
+
+use common::{app, call, signup};
+
+fn one_text(text: &str) -> serde_json::Value {
+    json!({
+        "version": 2,
+        "rows": [{ "id": 1, "columns": [{ "id": 1, "width": 1, "widgets": [{ "type": "Text", "text": text }] }] }],
+        "selected_row": null, "selected_column": null, "next_row_id": 1, "next_col_id": 1
+    })
+}
+
+#[tokio::test]
+async fn named_cvs_crud_and_duplicate() {
+    let app = app().await;
+    let sid = signup(&app, "alice@example.com").await;
+    assert_eq!(call(&app, Method::GET, "/api/cvs", None, None).await.status, StatusCode::UNAUTHORIZED);
+    assert_eq!(call(&app, Method::GET, "/api/cvs", Some(&sid), None).await.json, json!([]));
+
+    let backend = call(&app, Method::POST, "/api/cvs", Some(&sid), Some(json!({ "name": " Backend ", "layout": one_text("Rust") }))).await;
+    assert_eq!(backend.status, StatusCode::OK);
+    assert_eq!(backend.json["name"], "Backend");
+    assert_eq!(backend.json["layout"], one_text("Rust"));
+    assert_eq!(backend.json["hidden"], json!({ "experiences": [], "skills": [], "projects": [] }));
+    let id = backend.json["id"].as_i64().unwrap();
+
+    let conseil = call(&app, Method::POST, "/api/cvs", Some(&sid), Some(json!({ "name": "Conseil" }))).await;
+    assert_eq!(conseil.json["layout"]["rows"], json!([]));
+    let conseil_id = conseil.json["id"].as_i64().unwrap();
+
+    // noms uniques par utilisateur, non vides
+    let dup = call(&app, Method::POST, "/api/cvs", Some(&sid), Some(json!({ "name": "Backend" }))).await;
+    assert_eq!(dup.status, StatusCode::CONFLICT);
+    let blank = call(&app, Method::POST, "/api/cvs", Some(&sid), Some(json!({ "name": "  " }))).await;
+    assert_eq!(blank.status, StatusCode::UNPROCESSABLE_ENTITY);
+    let taken = call(&app, Method::PATCH, &format!("/api/cvs/{conseil_id}"), Some(&sid), Some(json!({ "name": "Backend" }))).await;
+    assert_eq!(taken.status, StatusCode::CONFLICT);
+
+    // chaque CV a sa mise en page
+    let put = call(&app, Method::PUT, &format!("/api/cvs/{conseil_id}/layout"), Some(&sid), Some(one_text("Missions"))).await;
+    assert_eq!(put.status, StatusCode::OK);
+    let got = call(&app, Method::GET, &format!("/api/cvs/{id}/layout"), Some(&sid), None).await;
+    assert_eq!(got.json, one_text("Rust"));
+
+    let copy = call(&app, Method::POST, &format!("/api/cvs/{id}/duplicate"), Some(&sid), None).await;
+    assert_eq!(copy.status, StatusCode::OK);
+    assert_eq!(copy.json["name"], "Backend (copie)");
+    assert_eq!(copy.json["layout"], one_text("Rust"));
+    let named = call(&app, Method::POST, &format!("/api/cvs/{id}/duplicate"), Some(&sid), Some(json!({ "name": "Backend senior" }))).await;
+    assert_eq!(named.json["name"], "Backend senior");
+
+    let renamed = call(&app, Method::PATCH, &format!("/api/cvs/{id}"), Some(&sid), Some(json!({ "name": "Backend Rust" }))).await;
+    assert_eq!(renamed.json["name"], "Backend Rust");
+    assert!(renamed.json["updated_at"].as_str() > backend.json["updated_at"].as_str());
+
+    let names: Vec<_> = call(&app, Method::GET, "/api/cvs", Some(&sid), None).await.json
+        .as_array().unwrap().iter().map(|c| c["name"].as_str().unwrap().to_string()).collect();
+    assert_eq!(names, ["Backend Rust", "Conseil", "Backend (copie)", "Backend senior"]);
+
+    assert_eq!(call(&app, Method::DELETE, &format!("/api/cvs/{id}"), Some(&sid), None).await.json, json!({ "ok": true }));
+    assert_eq!(call(&app, Method::GET, &format!("/api/cvs/{id}"), Some(&sid), None).await.status, StatusCode::NOT_FOUND);
+    assert_eq!(call(&app, Method::DELETE, &format!("/api/cvs/{id}"), Some(&sid), None).await.status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn hidden_items_are_checked_and_copied() {
+    let app = app().await;
+    let alice = signup(&app, "alice@example.com").await;
+    let bob = signup(&app, "bob@example.com").await;
+
+    let exp = json!({ "date_start": "2021", "date_end": "", "kind": "work", "title": "Dev", "company": "Acme", "location": "", "website": "", "tasks": [] });
+    let exp_id = call(&app, Method::POST, "/api/cv/experiences", Some(&alice), Some(exp.clone())).await.json["id"].as_i64().unwrap();
+    let skill_id = call(&app, Method::POST, "/api/cv/skills", Some(&alice), Some(json!({ "name": "Rust" }))).await.json["id"].as_i64().unwrap();
+    let bob_exp = call(&app, Method::POST, "/api/cv/experiences", Some(&bob), Some(exp)).await.json["id"].as_i64().unwrap();
+
+    let id = call(&app, Method::POST, "/api/cvs", Some(&alice), Some(json!({ "name": "Conseil" }))).await.json["id"].as_i64().unwrap();
+    let hidden = json!({ "experiences": [exp_id], "skills": [skill_id], "projects": [] });
+    let r = call(&app, Method::PATCH, &format!("/api/cvs/{id}"), Some(&alice), Some(json!({ "hidden": hidden }))).await;
+    assert_eq!(r.status, StatusCode::OK);
+    assert_eq!(r.json["hidden"], hidden);
+    assert_eq!(r.json["name"], "Conseil");
+
+    // éléments inconnus ou d'un autre utilisateur : rien n'est modifié
+    let r = call(&app, Method::PATCH, &format!("/api/cvs/{id}"), Some(&alice), Some(json!({ "name": "X", "hidden": { "experiences": [bob_exp] } }))).await;
+    assert_eq!(r.status, StatusCode::UNPROCESSABLE_ENTITY);
+    let r = call(&app, Method::PATCH, &format!("/api/cvs/{id}"), Some(&alice), Some(json!({ "hidden": { "projects": [999] } }))).await;
+    assert_eq!(r.status, StatusCode::UNPROCESSABLE_ENTITY);
+    let got = call(&app, Method::GET, &format!("/api/cvs/{id}"), Some(&alice), None).await.json;
+    assert_eq!((got["name"].clone(), got["hidden"].clone()), (json!("Conseil"), hidden.clone()));
+
+    let copy = call(&app, Method::POST, &format!("/api/cvs/{id}/duplicate"), Some(&alice), None).await.json;
+    assert_eq!(copy["hidden"], hidden);
+
+    // un élément supprimé disparaît des sélections
+    call(&app, Method::DELETE, &format!("/api/cv/experiences/{exp_id}"), Some(&alice), None).await;
+    let got = call(&app, Method::GET, &format!("/api/cvs/{id}"), Some(&alice), None).await.json;
+    assert_eq!(got["hidden"]["experiences"], json!([]));
+
+    // les CV d'un autre utilisateur n'existent pas
+    for (method, uri) in [(Method::GET, format!("/api/cvs/{id}")), (Method::GET, format!("/api/cvs/{id}/layout")), (Method::POST, format!("/api/cvs/{id}/duplicate")), (Method::DELETE, format!("/api/cvs/{id}"))] {
+        assert_eq!(call(&app, method, &uri, Some(&bob), None).await.status, StatusCode::NOT_FOUND, "{uri}");
+    }
+    let r = call(&app, Method::PUT, &format!("/api/cvs/{id}/layout"), Some(&bob), Some(one_text("x"))).await;
+    assert_eq!(r.status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn legacy_layout_endpoint_uses_the_default_cv() {
+    let app = app().await;
+    let sid = signup(&app, "alice@example.com").await;
+
+    call(&app, Method::PUT, "/api/layout", Some(&sid), Some(one_text("Bonjour"))).await;
+    let list = call(&app, Method::GET, "/api/cvs", Some(&sid), None).await.json;
+    assert_eq!(list.as_array().unwrap().len(), 1);
+    assert_eq!(list[0]["name"], "Principal");
+    let id = list[0]["id"].as_i64().unwrap();
+    assert_eq!(call(&app, Method::GET, &format!("/api/cvs/{id}/layout"), Some(&sid), None).await.json, one_text("Bonjour"));
+
+    // les autres CV ne changent pas le CV par défaut
+    call(&app, Method::POST, "/api/cvs", Some(&sid), Some(json!({ "name": "Autre", "layout": one_text("Autre") }))).await;
+    call(&app, Method::PUT, "/api/layout", Some(&sid), Some(one_text("Salut"))).await;
+    assert_eq!(call(&app, Method::GET, &format!("/api/cvs/{id}/layout"), Some(&sid), None).await.json, one_text("Salut"));
+    assert_eq!(call(&app, Method::GET, "/api/layout", Some(&sid), None).await.json, one_text("Salut"));
+}
+
+#[tokio::test]
+async fn themes_are_stored_per_cv_and_used_by_exports_and_public_pages() {
+    let app = app().await;
+    let sid = signup(&app, "alice@example.com").await;
+
+    let themes = call(&app, Method::GET, "/api/themes", Some(&sid), None).await.json;
+    let classique = themes.as_array().unwrap().iter().find(|t| t["base"] == "classique").unwrap().clone();
+
+    let layout = json!({
+        "version": 2,
+        "rows": [{ "id": 1, "columns": [{ "id": 1, "width": 1, "widgets": [
+            { "type": "Title", "text": "Alice <Dev>", "level": 1 },
+            { "type": "ExperienceTimeline", "heading": "Parcours" },
+            { "type": "SkillsGrid" }
+        ] }] }],
+        "selected_row": null, "selected_column": null, "next_row_id": 1, "next_col_id": 1
+    });
+    let cv = call(&app, Method::POST, "/api/cvs", Some(&sid), Some(json!({ "name": "Conseil", "layout": layout }))).await.json;
+    let id = cv["id"].as_i64().unwrap();
+    assert_eq!(cv["theme"], themes[0]);
+
+    let mut theme = classique.clone();
+    theme["colors"]["accent"] = json!("#aa0000");
+    theme["skill_display"] = json!("tags");
+    let r = call(&app, Method::PATCH, &format!("/api/cvs/{id}"), Some(&sid), Some(json!({ "theme": theme }))).await;
+    assert_eq!(r.status, StatusCode::OK);
+    assert_eq!(r.json["theme"], theme);
+
+    let mut bad = theme.clone();
+    bad["colors"]["text"] = json!("red;}");
+    let r = call(&app, Method::PATCH, &format!("/api/cvs/{id}"), Some(&sid), Some(json!({ "theme": bad }))).await;
+    assert_eq!(r.status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert!(r.text.contains("colors.text"), "{}", r.text);
+
+    let copy = call(&app, Method::POST, &format!("/api/cvs/{id}/duplicate"), Some(&sid), None).await.json;
+    assert_eq!(copy["theme"], theme);
+
+    // export : thème, contenu échappé, éléments masqués absents
+    let exp = json!({ "date_start": "2021", "date_end": "", "kind": "work", "title": "Dev", "company": "Acme", "location": "", "website": "", "tasks": [] });
+    call(&app, Method::POST, "/api/cv/experiences", Some(&sid), Some(exp.clone())).await;
+    let mut secret = exp;
+    secret["title"] = json!("Secret");
+    let hidden = call(&app, Method::POST, "/api/cv/experiences", Some(&sid), Some(secret)).await.json["id"].as_i64().unwrap();
+    call(&app, Method::POST, "/api/cv/skills", Some(&sid), Some(json!({ "name": "Rust" }))).await;
+    call(&app, Method::PATCH, &format!("/api/cvs/{id}"), Some(&sid), Some(json!({ "hidden": { "experiences": [hidden] } }))).await;
+
+    let html = call(&app, Method::GET, &format!("/api/cvs/{id}/export"), Some(&sid), None).await;
+    assert_eq!(html.status, StatusCode::OK);
+    assert!(html.text.contains("--accent:#aa0000;"), "{}", html.text);
+    assert!(html.text.contains("Alice &lt;Dev&gt;"));
+    assert!(html.text.contains("Parcours") && html.text.contains("Acme"));
+    assert!(!html.text.contains("Secret"));
+    assert!(html.text.contains(r#"<ul class="cv-tags">"#));
+    let download = call(&app, Method::GET, &format!("/api/cvs/{id}/export?download=true"), Some(&sid), None).await;
+    assert_eq!(download.headers["content-disposition"], r#"attachment; filename="cv-conseil.html""#);
+
+    // pages publiques : thème du CV par défaut (le plus ancien) du propriétaire
+    let page = call(&app, Method::GET, "/portfolio", None, None).await;
+    assert!(page.text.contains("--accent:#aa0000;"), "{}", page.text);
+}
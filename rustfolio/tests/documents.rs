@@ -0,0 +1,163 @@
+//! PDF des projets : métadonnées, aperçu, téléchargement, orphelins.
+
+mod common;
+
+use std::{io::Cursor, path::PathBuf, time::Duration};
+
+use axum::http::{header, Method, StatusCode};
+use image::{ImageFormat, Rgb, RgbImage};
+use lopdf::{dictionary, Document, Object, Stream};
+use serde_json::json;
+
+use common::{call, config_with, signup, state_with, upload};
+use rustfolio::state::AppState;
+
+async fn setup(extra: &[(&str, &str)]) -> (AppState, axum::Router, PathBuf) {
+    let dir = std::env::temp_dir().join(format!("rustfolio-docs-{}", uuid::Uuid::new_v4()));
+    let mut vars = vec![("UPLOAD_DIR", dir.to_str().unwrap())];
+    vars.extend_from_slice(extra);
+    let st = state_with(config_with(&vars)).await;
+    let app = rustfolio::app(st.clone());
+    (st, app, dir)
+}
+
+/// PDF de deux pages ; la première contient un scan JPEG `w`×`h`, titre UTF-16.
+fn scanned_pdf(w: u32, h: u32) -> Vec<u8> {
+    let scan = RgbImage::from_fn(w, h, |x, y| Rgb([(x % 256) as u8, (y % 256) as u8, 128]));
+    let mut jpeg = Cursor::new(Vec::new());
+    scan.write_to(&mut jpeg, ImageFormat::Jpeg).unwrap();
+
+    let mut doc = Document::with_version("1.5");
+    let pages_id = doc.new_object_id();
+    let image_id = doc.add_object(Stream::new(
+        dictionary! {
+            "Type" => "XObject", "Subtype" => "Image",
+            "Width" => w as i64, "Height" => h as i64,
+            "ColorSpace" => "DeviceRGB", "BitsPerComponent" => 8, "Filter" => "DCTDecode",
+        },
+        jpeg.into_inner(),
+    ));
+    let content = doc.add_object(Stream::new(dictionary! {}, b"q 595 0 0 842 0 0 cm /Im0 Do Q".to_vec()));
+    let resources = doc.add_object(dictionary! { "XObject" => dictionary! { "Im0" => image_id } });
+    let media_box: Vec<Object> = vec![0.into(), 0.into(), 595.into(), 842.into()];
+    let first = doc.add_object(dictionary! {
+        "Type" => "Page", "Parent" => pages_id, "Contents" => content,
+        "Resources" => resources, "MediaBox" => media_box.clone(),
+    });
+    let second = doc.add_object(dictionary! { "Type" => "Page", "Parent" => pages_id, "MediaBox" => media_box });
+    doc.objects.insert(
+        pages_id,
+        Object::Dictionary(dictionary! { "Type" => "Pages", "Kids" => vec![first.into(), second.into()], "Count" => 2 }),
+    );
+    let catalog = doc.add_object(dictionary! { "Type" => "Catalog", "Pages" => pages_id });
+    let info = doc.add_object(dictionary! { "Title" => lopdf::text_string("Portfolio graphique — 2024") });
+    doc.trailer.set("Root", catalog);
+    doc.trailer.set("Info", info);
+
+    let mut out = Vec::new();
+    doc.save_to(&mut out).unwrap();
+    out
+}
+
+async fn project(app: &axum::Router, sid: &str) -> i64 {
+    let p = call(app, Method::POST, "/api/cv/projects", Some(sid), Some(json!({ "title": "Affiches" }))).await;
+    assert_eq!(p.status, StatusCode::OK, "{}", p.text);
+    p.json["id"].as_i64().unwrap()
+}
+
+#[tokio::test]
+async fn pdf_upload_extracts_metadata_preview_and_sets_pdf_link() {
+    let (_st, app, dir) = setup(&[]).await;
+    let sid = signup(&app, "pdf@example.com").await;
+    let id = project(&app, &sid).await;
+    let uri = format!("/api/cv/projects/{id}/document");
+
+    let r = upload(&app, &uri, Some(&sid), "file", "../../Mon Book été.PDF", &scanned_pdf(800, 1100)).await;
+    assert_eq!(r.status, StatusCode::CREATED, "{}", r.text);
+    assert_eq!(r.json["page_count"], 2);
+    assert_eq!(r.json["title"], "Portfolio graphique — 2024");
+    assert_eq!(r.json["file_name"], "Mon Book été.pdf");
+    assert_eq!((r.json["preview"]["width"].as_u64(), r.json["preview"]["height"].as_u64()), (Some(640), Some(880)));
+
+    let url = r.json["url"].as_str().unwrap().to_string();
+    let projects = call(&app, Method::GET, "/api/cv/projects", Some(&sid), None).await;
+    assert_eq!(projects.json[0]["pdf_link"], url.as_str());
+
+    // téléchargement public, nom d'origine dans Content-Disposition
+    let dl = call(&app, Method::GET, &url, None, None).await;
+    assert_eq!(dl.status, StatusCode::OK);
+    assert_eq!(dl.headers[header::CONTENT_TYPE], "application/pdf");
+    let cd = dl.headers[header::CONTENT_DISPOSITION].to_str().unwrap();
+    assert!(cd.starts_with("attachment; filename=\"Mon Book _t_.pdf\""), "{cd}");
+    assert!(cd.contains("filename*=UTF-8''Mon%20Book%20%C3%A9t%C3%A9.pdf"), "{cd}");
+    assert!(dl.text.starts_with("%PDF-"));
+
+    let preview = call(&app, Method::GET, r.json["preview"]["webp"].as_str().unwrap(), None, None).await;
+    assert_eq!(preview.status, StatusCode::OK);
+    assert_eq!(preview.headers[header::CONTENT_TYPE], "image/webp");
+
+    // le fichier brut, voisin de l'aperçu, n'est pas servi par /assets/uploads
+    let raw = r.json["preview"]["webp"].as_str().unwrap().replace("-preview.webp", ".pdf");
+    assert!(raw.starts_with("/assets/uploads/"), "{raw}");
+    assert!(dir.join(raw.trim_start_matches("/assets/uploads/")).exists());
+    for path in [raw.clone(), raw.replace(".pdf", ".PDF")] {
+        let direct = call(&app, Method::GET, &path, None, None).await;
+        assert_eq!(direct.status, StatusCode::NOT_FOUND, "{path}");
+    }
+
+    let _ = std::fs::remove_dir_all(dir);
+}
+
+#[tokio::test]
+async fn rejects_non_pdf_oversized_and_foreign_projects() {
+    let (_st, app, dir) = setup(&[("UPLOAD_MAX_PDF_MB", "1")]).await;
+    let sid = signup(&app, "owner@example.com").await;
+    let other = signup(&app, "other@example.com").await;
+    let id = project(&app, &sid).await;
+    let uri = format!("/api/cv/projects/{id}/document");
+
+    let r = upload(&app, &uri, Some(&sid), "file", "x.pdf", b"<html>pas un pdf</html>").await;
+    assert_eq!(r.status, StatusCode::UNSUPPORTED_MEDIA_TYPE, "{}", r.text);
+
+    let r = upload(&app, &uri, Some(&sid), "file", "x.pdf", b"%PDF-1.4\n garbage").await;
+    assert_eq!(r.status, StatusCode::UNPROCESSABLE_ENTITY, "{}", r.text);
+
+    let mut big = b"%PDF-1.4\n".to_vec();
+    big.resize(1024 * 1024 + 1, b' ');
+    let r = upload(&app, &uri, Some(&sid), "file", "big.pdf", &big).await;
+    assert_eq!(r.status, StatusCode::PAYLOAD_TOO_LARGE, "{}", r.text);
+
+    let r = upload(&app, &uri, Some(&other), "file", "x.pdf", &scanned_pdf(20, 20)).await;
+    assert_eq!(r.status, StatusCode::NOT_FOUND);
+
+    let _ = std::fs::remove_dir_all(dir);
+}
+
+#[tokio::test]
+async fn replaced_document_becomes_orphan() {
+    let (st, app, dir) = setup(&[]).await;
+    let sid = signup(&app, "swap@example.com").await;
+    let id = project(&app, &sid).await;
+    let uri = format!("/api/cv/projects/{id}/document");
+
+    let first = upload(&app, &uri, Some(&sid), "file", "v1.pdf", &scanned_pdf(40, 40)).await;
+    assert_eq!(first.status, StatusCode::CREATED);
+    let second = upload(&app, &uri, Some(&sid), "file", "v2.pdf", &scanned_pdf(60, 40)).await;
+    assert_eq!(second.status, StatusCode::CREATED);
+    // plus lié à aucun projet : déjà privé, avant même le nettoyage
+    let unlinked = call(&app, Method::GET, first.json["url"].as_str().unwrap(), None, None).await;
+    assert_eq!(unlinked.status, StatusCode::NOT_FOUND);
+
+    let uid: String = sqlx::query_scalar("SELECT id FROM users WHERE email = 'swap@example.com'")
+        .fetch_one(&st.db)
+        .await
+        .unwrap();
+    assert_eq!(rustfolio::uploads::collect_orphans(&st, &uid, Duration::ZERO).await.unwrap(), 1);
+
+    let gone = call(&app, Method::GET, first.json["url"].as_str().unwrap(), None, None).await;
+    assert_eq!(gone.status, StatusCode::NOT_FOUND);
+    let kept = call(&app, Method::GET, second.json["url"].as_str().unwrap(), None, None).await;
+    assert_eq!(kept.status, StatusCode::OK);
+
+    let _ = std::fs::remove_dir_all(dir);
+}
@@ -0,0 +1,63 @@
+//! Sondes /health/live et /health/ready.
+
+mod common;
+
+use axum::http::{Method, StatusCode};
+use rustfolio::state::AppState;
+
+use common::{app, call, config, log_mailer, memory_pool};
+
+#[tokio::test]
+async fn live_and_ready_on_a_migrated_database() {
+    let app = app().await;
+
+    let r = call(&app, Method::GET, "/health/live", None, None).await;
+    assert_eq!(r.status, StatusCode::OK);
+    assert_eq!(r.json["status"], "ok");
+
+    let r = call(&app, Method::GET, "/health/ready", None, None).await;
+    assert_eq!(r.status, StatusCode::OK, "{}", r.json);
+    assert_eq!(r.json["status"], "ok");
+    assert_eq!(r.json["checks"]["database"]["status"], "ok");
+    assert_eq!(r.json["checks"]["migrations"]["pending"], 0);
+    assert_eq!(r.json["checks"]["disk"]["status"], "skipped", "base en mémoire");
+    assert_eq!(r.json["checks"]["mailer"]["transport"], "log");
+}
+
+#[tokio::test]
+async fn pending_migrations_make_ready_fail() {
+    let db = memory_pool().await;
+    let app = rustfolio::app(AppState::new(db.clone(), config(), log_mailer()));
+
+    let r = call(&app, Method::GET, "/health/ready", None, None).await;
+    assert_eq!(r.status, StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(r.json["status"], "degraded");
+    assert_eq!(r.json["checks"]["database"]["status"], "ok");
+    assert_eq!(r.json["checks"]["migrations"]["status"], "fail");
+    assert!(r.json["checks"]["migrations"]["pending"].as_u64().unwrap() > 0);
+
+    // sonde en lecture seule : la table de suivi n'est pas créée
+    let tables: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM sqlite_master WHERE name = '_sqlx_migrations'")
+        .fetch_one(&db)
+        .await
+        .unwrap();
+    assert_eq!(tables, 0);
+
+    // live ne dépend de rien
+    let r = call(&app, Method::GET, "/health/live", None, None).await;
+    assert_eq!(r.status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn unreachable_database_makes_ready_fail() {
+    let db = memory_pool().await;
+    let app = rustfolio::app(AppState::new(db.clone(), config(), log_mailer()));
+    db.close().await;
+
+    let r = call(&app, Method::GET, "/health/ready", None, None).await;
+    assert_eq!(r.status, StatusCode::SERVICE_UNAVAILABLE);
+    assert_eq!(r.json["checks"]["database"]["status"], "fail");
+    // détail de sqlx dans les logs seulement
+    assert_eq!(r.json["checks"]["database"]["error"], "base injoignable");
+    assert_eq!(r.json["checks"]["migrations"]["error"], "statut des migrations illisible");
+}
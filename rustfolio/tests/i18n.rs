@@ -0,0 +1,70 @@
+mod common;
+
+use axum::http::{Method, StatusCode};
+use serde_json::json;
+
+use common::{app, call, form_with, get_with, signup};
+
+const EN: (&str, &str) = ("accept-language", "en-US,en;q=0.9,fr;q=0.5");
+
+#[tokio::test]
+async fn pages_follow_accept_language() {
+    let app = app().await;
+
+    let fr = get_with(&app, "/auth/login", None, &[]).await;
+    assert!(fr.text.contains(r#"<html lang="fr""#));
+    assert!(fr.text.contains("Se connecter"));
+
+    let en = get_with(&app, "/auth/login", None, &[EN]).await;
+    assert!(en.text.contains(r#"<html lang="en""#));
+    assert!(en.text.contains("Log in") && en.text.contains("Password"));
+    assert!(!en.text.contains("Mot de passe"));
+
+    // langue non livrée : langue par défaut
+    let de = get_with(&app, "/auth/signup", None, &[("accept-language", "de-DE")]).await;
+    assert!(de.text.contains("Créer mon compte"));
+
+    // messages d'erreur
+    signup(&app, "alice@example.com").await;
+    let r = form_with(&app, "/auth/login", None, &[EN], &[("email", "alice@example.com"), ("password", "nope")]).await;
+    assert_eq!(r.status, StatusCode::UNAUTHORIZED);
+    assert!(r.text.contains("Invalid credentials"));
+    let r = form_with(&app, "/auth/signup", None, &[EN], &[("email", "alice@example.com"), ("password", "hunter2hunter2")]).await;
+    assert_eq!(r.status, StatusCode::CONFLICT);
+    assert!(r.text.contains("Email already in use"));
+    let r = get_with(&app, "/auth/me", None, &[EN]).await;
+    assert_eq!(r.status, StatusCode::UNAUTHORIZED);
+    assert_eq!(r.text, "Not logged in");
+}
+
+#[tokio::test]
+async fn saved_preference_wins_over_the_browser() {
+    let app = app().await;
+    let sid = signup(&app, "alice@example.com").await;
+
+    let me = call(&app, Method::GET, "/auth/me", Some(&sid), None).await.json;
+    assert_eq!(me["locale"], serde_json::Value::Null);
+    assert_eq!(me["ui_locale"], "fr");
+
+    let r = call(&app, Method::PATCH, "/auth/me", Some(&sid), Some(json!({ "locale": "en-GB" }))).await;
+    assert_eq!(r.status, StatusCode::OK);
+    assert_eq!(r.json["locale"], "en");
+    assert_eq!(r.json["ui_locale"], "en");
+
+    // préférence enregistrée, même si le navigateur préfère le français
+    let page = get_with(&app, "/", Some(&sid), &[("accept-language", "fr")]).await;
+    assert!(page.text.contains(r#"<html lang="en""#));
+    assert!(page.text.contains("How does it work?"));
+    let shell = get_with(&app, "/dashboard", Some(&sid), &[]).await;
+    assert!(shell.text.contains(r#"<html lang="en""#));
+
+    let r = call(&app, Method::PATCH, "/auth/me", Some(&sid), Some(json!({ "locale": "de" }))).await;
+    assert_eq!(r.status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_eq!(r.text, "Unsupported language: de");
+
+    // retour à la négociation
+    let r = call(&app, Method::PATCH, "/auth/me", Some(&sid), Some(json!({ "locale": null }))).await;
+    assert_eq!(r.json["locale"], serde_json::Value::Null);
+    let page = get_with(&app, "/", Some(&sid), &[]).await;
+    assert!(page.text.contains("Comment ça marche ?"));
+}
@@ -0,0 +1,100 @@
+mod common;
+
+use axum::http::{Method, StatusCode};
+use serde_json::json;
+
+use common::{app, call, signup};
+
+fn two_columns() -> serde_json::Value {
+    json!({
+        "version": 2,
+        "rows": [{ "id": 1, "columns": [
+            { "id": 1, "width": 2, "widgets": [{ "type": "ProfileBasic" }, { "type": "Text", "text": "Bonjour" }] },
+            { "id": 2, "width": 1, "widgets": [{ "type": "SkillsGrid", "category": "Backend", "limit": 5 }] }
+        ] }],
+        "selected_row": 1, "selected_column": 2, "next_row_id": 1, "next_col_id": 2
+    })
+}
+
+#[tokio::test]
+async fn layout_round_trip_per_user() {
+    let app = app().await;
+    assert_eq!(call(&app, Method::GET, "/api/layout", None, None).await.status, StatusCode::UNAUTHORIZED);
+
+    let alice = signup(&app, "alice@example.com").await;
+    let bob = signup(&app, "bob@example.com").await;
+
+    let empty = call(&app, Method::GET, "/api/layout", Some(&alice), None).await;
+    assert_eq!(empty.status, StatusCode::OK);
+    assert_eq!(empty.json["rows"], json!([]));
+    assert_eq!(empty.json["next_row_id"], 0);
+
+    let saved = call(&app, Method::PUT, "/api/layout", Some(&alice), Some(two_columns())).await;
+    assert_eq!(saved.status, StatusCode::OK);
+    let got = call(&app, Method::GET, "/api/layout", Some(&alice), None).await.json;
+    assert_eq!(got, two_columns());
+
+    // chaque utilisateur a son propre layout
+    let other = call(&app, Method::GET, "/api/layout", Some(&bob), None).await.json;
+    assert_eq!(other["rows"], json!([]));
+}
+
+#[tokio::test]
+async fn invalid_layouts_are_rejected() {
+    let app = app().await;
+    let sid = signup(&app, "alice@example.com").await;
+    call(&app, Method::PUT, "/api/layout", Some(&sid), Some(two_columns())).await;
+
+    let mut dup = two_columns();
+    dup["rows"][0]["columns"][1]["id"] = json!(1);
+    let r = call(&app, Method::PUT, "/api/layout", Some(&sid), Some(dup)).await;
+    assert_eq!(r.status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert!(r.text.contains("rows[0].columns[1].id"), "{}", r.text);
+
+    let cols: Vec<_> = (1..=7).map(|id| json!({ "id": id, "widgets": [] })).collect();
+    let wide = json!({ "rows": [{ "id": 1, "columns": cols }], "next_row_id": 1, "next_col_id": 7 });
+    let r = call(&app, Method::PUT, "/api/layout", Some(&sid), Some(wide)).await;
+    assert_eq!(r.status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let mut dangling = two_columns();
+    dangling["selected_column"] = json!(9);
+    let r = call(&app, Method::PUT, "/api/layout", Some(&sid), Some(dangling)).await;
+    assert_eq!(r.status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    let mut newer = two_columns();
+    newer["version"] = json!(3);
+    let r = call(&app, Method::PUT, "/api/layout", Some(&sid), Some(newer)).await;
+    assert_eq!(r.status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert!(r.text.contains("version"), "{}", r.text);
+
+    let mut unknown = two_columns();
+    unknown["theme"] = json!("dark");
+    assert_eq!(call(&app, Method::PUT, "/api/layout", Some(&sid), Some(unknown)).await.status, StatusCode::UNPROCESSABLE_ENTITY);
+    let mut widget = two_columns();
+    widget["rows"][0]["columns"][0]["widgets"] = json!([{ "type": "Carousel" }]);
+    assert_eq!(call(&app, Method::PUT, "/api/layout", Some(&sid), Some(widget)).await.status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    // le layout valide précédent est intact
+    let got = call(&app, Method::GET, "/api/layout", Some(&sid), None).await.json;
+    assert_eq!(got, two_columns());
+}
+
+#[tokio::test]
+async fn builder_layout_without_version_is_migrated() {
+    let app = app().await;
+    let sid = signup(&app, "alice@example.com").await;
+
+    // format du builder avant l'ajout de `version` (widgets en représentation externe)
+    let v1 = json!({
+        "rows": [{ "id": 1, "columns": [{ "id": 1, "widgets": ["ExperienceTimeline", { "Text": "Hello" }] }] }],
+        "selected_row": 1, "selected_column": null, "next_row_id": 1, "next_col_id": 1
+    });
+    let r = call(&app, Method::PUT, "/api/layout", Some(&sid), Some(v1)).await;
+    assert_eq!(r.status, StatusCode::OK, "{}", r.text);
+    assert_eq!(r.json["version"], 2);
+    assert_eq!(
+        r.json["rows"][0]["columns"][0]["widgets"],
+        json!([{ "type": "ExperienceTimeline" }, { "type": "Text", "text": "Hello" }])
+    );
+    assert_eq!(call(&app, Method::GET, "/api/layout", Some(&sid), None).await.json, r.json);
+}
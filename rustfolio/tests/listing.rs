@@ -0,0 +1,124 @@
+//! Listes /api/cv/* : limit/cursor/sort/fields, ETags faibles et 304.
+
+mod common;
+
+use axum::http::{header, Method, StatusCode};
+use serde_json::{json, Value};
+
+use common::{app, call, get_if_none_match, signup};
+
+fn experience(title: &str) -> Value {
+    json!({
+        "date_start": "2023-01", "date_end": "", "kind": "work", "title": title,
+        "company": "Acme", "location": "Genève", "website": "", "tasks": []
+    })
+}
+
+fn etag(r: &common::Resp) -> String {
+    r.headers[header::ETAG].to_str().unwrap().to_string()
+}
+
+#[tokio::test]
+async fn sort_fields_and_cursor_pagination() {
+    let app = app().await;
+    let sid = signup(&app, "list@example.com").await;
+    for title in ["Bravo", "alpha", "Charlie"] {
+        call(&app, Method::POST, "/api/cv/projects", Some(&sid), Some(json!({ "title": title, "category": "web" }))).await;
+    }
+
+    let first = call(&app, Method::GET, "/api/cv/projects?sort=-title&limit=2&fields=id,title", Some(&sid), None).await;
+    assert_eq!(first.status, StatusCode::OK, "{}", first.text);
+    assert_eq!(first.json.as_array().unwrap().iter().map(|p| p["title"].as_str().unwrap()).collect::<Vec<_>>(), ["Charlie", "Bravo"]);
+    assert_eq!(first.json[0].as_object().unwrap().keys().collect::<Vec<_>>(), ["id", "title"]);
+    assert_eq!(first.headers["x-total-count"], "3");
+    let link = first.headers[header::LINK].to_str().unwrap();
+    assert_eq!(link, "</api/cv/projects?sort=-title&limit=2&fields=id,title&cursor=2>; rel=\"next\"");
+
+    let next = link.trim_start_matches('<').split('>').next().unwrap();
+    let rest = call(&app, Method::GET, next, Some(&sid), None).await;
+    assert_eq!(rest.json, json!([{ "id": first.json[0]["id"].as_i64().unwrap() - 1, "title": "alpha" }]));
+    assert!(rest.headers.get(header::LINK).is_none());
+
+    // sans paramètres : tout, dans l'ordre du dépôt
+    let all = call(&app, Method::GET, "/api/cv/projects", Some(&sid), None).await;
+    assert_eq!(all.json.as_array().unwrap().len(), 3);
+    assert_eq!(all.json[0]["category"], "web");
+
+    for bad in ["sort=repo_link", "limit=0", "limit=1000", "cursor=abc"] {
+        let r = call(&app, Method::GET, &format!("/api/cv/projects?{bad}"), Some(&sid), None).await;
+        assert_eq!(r.status, StatusCode::BAD_REQUEST, "{bad}");
+    }
+    let r = call(&app, Method::GET, "/api/cv/skills/categories?sort=name", Some(&sid), None).await;
+    assert_eq!(r.status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn etag_changes_with_every_write_including_children() {
+    let app = app().await;
+    let sid = signup(&app, "etag@example.com").await;
+    let other = signup(&app, "other@example.com").await;
+    let e = call(&app, Method::POST, "/api/cv/experiences", Some(&sid), Some(experience("Dev"))).await;
+    let id = e.json["id"].as_i64().unwrap();
+
+    let r = call(&app, Method::GET, "/api/cv/experiences", Some(&sid), None).await;
+    let tag = etag(&r);
+    assert!(tag.starts_with("W/\""), "{tag}");
+    assert_eq!(r.headers[header::CACHE_CONTROL], "private, no-cache");
+
+    let cached = get_if_none_match(&app, "/api/cv/experiences", Some(&sid), &tag).await;
+    assert_eq!(cached.status, StatusCode::NOT_MODIFIED);
+    assert!(cached.text.is_empty());
+    assert_eq!(etag(&cached), tag);
+    // liste de `*` ou de plusieurs ETags, forme forte acceptée
+    let strong = tag.trim_start_matches("W/");
+    assert_eq!(get_if_none_match(&app, "/api/cv/experiences", Some(&sid), &format!("\"x\", {strong}")).await.status, StatusCode::NOT_MODIFIED);
+    // autre URL (paramètres), autre compte : autre représentation
+    assert_eq!(get_if_none_match(&app, "/api/cv/experiences?limit=1", Some(&sid), &tag).await.status, StatusCode::OK);
+    assert_eq!(get_if_none_match(&app, "/api/cv/experiences", Some(&other), &tag).await.status, StatusCode::OK);
+
+    // écritures rapprochées (même milliseconde possible) : l'ETag change à chaque fois
+    let mut seen = vec![tag];
+    let task = call(&app, Method::POST, &format!("/api/cv/experiences/{id}/tasks"), Some(&sid), Some(json!({ "task": "A" }))).await;
+    let task_id = task.json["id"].as_i64().unwrap();
+    let writes: Vec<(Method, String, Value)> = vec![
+        (Method::PATCH, format!("/api/cv/experiences/{id}/tasks/{task_id}"), json!({ "task": "B" })),
+        (Method::PATCH, format!("/api/cv/experiences/{id}/tasks/{task_id}"), json!({ "task": "C" })),
+        (Method::PUT, format!("/api/cv/experiences/{id}"), experience("Lead")),
+        (Method::DELETE, format!("/api/cv/experiences/{id}/tasks/{task_id}"), Value::Null),
+    ];
+    for (method, uri, body) in writes {
+        let body = (!body.is_null()).then_some(body);
+        assert!(call(&app, method, &uri, Some(&sid), body).await.status.is_success(), "{uri}");
+        let r = get_if_none_match(&app, "/api/cv/experiences", Some(&sid), seen.last().unwrap()).await;
+        assert_eq!(r.status, StatusCode::OK, "{uri}");
+        assert!(!seen.contains(&etag(&r)), "{uri}");
+        seen.push(etag(&r));
+    }
+
+    // les tâches partagent l'empreinte de leur expérience
+    let tasks = call(&app, Method::GET, &format!("/api/cv/experiences/{id}/tasks"), Some(&sid), None).await;
+    assert_eq!(get_if_none_match(&app, &format!("/api/cv/experiences/{id}/tasks"), Some(&sid), &etag(&tasks)).await.status, StatusCode::NOT_MODIFIED);
+}
+
+#[tokio::test]
+async fn public_endpoints_revalidate() {
+    let app = app().await;
+    let sid = signup(&app, "owner@example.com").await;
+    call(&app, Method::POST, "/api/cv/projects", Some(&sid), Some(json!({ "title": "Rustfolio", "technologies": ["Rust"] }))).await;
+    call(&app, Method::POST, "/api/portfolio", Some(&sid), Some(json!({ "title": "Affiche", "type": "print" }))).await;
+
+    for uri in ["/api/projects?q=rust", "/api/portfolio/feed", "/portfolio?type=print"] {
+        let r = call(&app, Method::GET, uri, None, None).await;
+        assert_eq!(r.status, StatusCode::OK, "{uri}");
+        assert_eq!(r.headers[header::CACHE_CONTROL], "public, no-cache", "{uri}");
+        assert_eq!(get_if_none_match(&app, uri, None, &etag(&r)).await.status, StatusCode::NOT_MODIFIED, "{uri}");
+    }
+
+    let r = call(&app, Method::GET, "/api/projects?q=rust", None, None).await;
+    let projects = call(&app, Method::GET, "/api/cv/projects", Some(&sid), None).await;
+    let id = projects.json[0]["id"].as_i64().unwrap();
+    call(&app, Method::POST, &format!("/api/cv/projects/{id}/tech"), Some(&sid), Some(json!({ "tech": "axum" }))).await;
+    let after = get_if_none_match(&app, "/api/projects?q=rust", None, &etag(&r)).await;
+    assert_eq!(after.status, StatusCode::OK);
+    assert_eq!(after.json["items"][0]["technologies"], json!(["Rust", "Axum"]));
+}
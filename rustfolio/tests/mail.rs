@@ -0,0 +1,121 @@
+//! E-mails : rendu texte + HTML, outbox et retries, transport fichier (.eml).
+
+mod common;
+
+use std::{
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+use axum::{
+    async_trait,
+    http::{Method, StatusCode},
+};
+use rustfolio::mail::{outbox::MAX_ATTEMPTS, templates, Email, FileMailer, Mailer};
+
+use common::{call, signup, state};
+
+fn mail_dir() -> PathBuf {
+    std::env::temp_dir().join(format!("rustfolio-mails-{}", uuid::Uuid::new_v4()))
+}
+
+fn eml_files(dir: &PathBuf) -> Vec<String> {
+    let Ok(entries) = std::fs::read_dir(dir) else { return Vec::new() };
+    entries
+        .map(|e| std::fs::read_to_string(e.unwrap().path()).unwrap())
+        .collect()
+}
+
+/// Échoue les `n` premiers envois.
+struct Flaky {
+    failures: usize,
+    calls:    AtomicUsize,
+}
+
+#[async_trait]
+impl Mailer for Flaky {
+    async fn send(&self, _: &Email) -> anyhow::Result<()> {
+        let n = self.calls.fetch_add(1, Ordering::SeqCst);
+        anyhow::ensure!(n >= self.failures, "SMTP indisponible");
+        Ok(())
+    }
+}
+
+#[test]
+fn verification_email_has_text_and_html() {
+    let e = templates::verification("a@b.c", "http://x/auth/verify?token=t&x=1").unwrap();
+    assert_eq!(e.to, "a@b.c");
+    assert!(e.text.contains("http://x/auth/verify?token=t&x=1"), "texte non échappé");
+    assert!(e.html.contains("http://x/auth/verify?token=t&amp;x=1"), "HTML échappé");
+    assert!(e.html.contains("<html"));
+}
+
+#[tokio::test]
+async fn signup_queues_email_and_file_mailer_writes_eml() {
+    let dir = mail_dir();
+    let st = state(Arc::new(FileMailer::new(&dir, "Rustfolio <no-reply@rustfolio.local>".parse().unwrap()))).await;
+    let app = rustfolio::app(st.clone());
+
+    signup(&app, "mail@example.com").await;
+    assert!(eml_files(&dir).is_empty(), "rien n'est envoyé avant le worker");
+
+    let d = st.outbox.deliver_due().await.unwrap();
+    assert_eq!((d.sent, d.failed), (1, 0));
+
+    let files = eml_files(&dir);
+    assert_eq!(files.len(), 1);
+    let eml = &files[0];
+    assert!(eml.contains("To: mail@example.com"));
+    assert!(eml.contains("multipart/alternative"));
+    assert!(eml.contains("text/plain") && eml.contains("text/html"));
+
+    // Déjà envoyé : rien de plus au passage suivant
+    assert_eq!(st.outbox.deliver_due().await.unwrap().sent, 0);
+
+    // Le lien du mail valide bien le compte
+    // corps en quoted-printable : coupures douces `=\r\n` et `=` encodé en `=3D`
+    let body = eml.replace("=\r\n", "").replace("=\n", "").replace("=3D", "=");
+    let token: String = body.split("token=").nth(1).unwrap().chars().take(36).collect();
+    let r = call(&app, Method::GET, &format!("/auth/verify?token={token}"), None, None).await;
+    assert_eq!(r.status, StatusCode::SEE_OTHER);
+    assert_eq!(r.location.as_deref(), Some("/?verified=1"));
+
+    let _ = std::fs::remove_dir_all(dir);
+}
+
+#[tokio::test]
+async fn failed_send_does_not_fail_signup_and_is_retried_with_backoff() {
+    let flaky = Arc::new(Flaky { failures: 1, calls: AtomicUsize::new(0) });
+    let st = state(flaky.clone()).await;
+    let app = rustfolio::app(st.clone());
+
+    signup(&app, "retry@example.com").await;
+
+    let now = chrono::Utc::now();
+    assert_eq!(st.outbox.deliver_due_at(now).await.unwrap().failed, 1);
+    // replanifié plus tard : pas retenté tout de suite
+    assert_eq!(st.outbox.deliver_due_at(now).await.unwrap(), Default::default());
+
+    let later = now + chrono::Duration::minutes(1);
+    assert_eq!(st.outbox.deliver_due_at(later).await.unwrap().sent, 1);
+    assert_eq!(flaky.calls.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn gives_up_after_max_attempts() {
+    let flaky = Arc::new(Flaky { failures: usize::MAX, calls: AtomicUsize::new(0) });
+    let st = state(flaky.clone()).await;
+    let app = rustfolio::app(st.clone());
+    signup(&app, "dead@example.com").await;
+
+    // le backoff est plafonné à 6 h : avancer de 7 h rend chaque essai dû
+    let mut at = chrono::Utc::now();
+    for _ in 0..MAX_ATTEMPTS + 2 {
+        st.outbox.deliver_due_at(at).await.unwrap();
+        at += chrono::Duration::hours(7);
+    }
+    assert_eq!(flaky.calls.load(Ordering::SeqCst), MAX_ATTEMPTS as usize);
+}
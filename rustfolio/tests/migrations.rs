@@ -0,0 +1,40 @@
+use rustfolio::db::{self, MigrationState, MIGRATOR};
+use sqlx::sqlite::SqlitePoolOptions;
+
+async fn memory_db() -> sqlx::SqlitePool {
+    SqlitePoolOptions::new()
+        .max_connections(1)
+        .idle_timeout(None)
+        .max_lifetime(None)
+        .connect("sqlite::memory:")
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn status_reports_pending_then_applied() {
+    let db = memory_db().await;
+
+    let before = db::status(&db).await.unwrap();
+    assert!(!before.is_empty());
+    assert!(before.iter().all(|m| m.state == MigrationState::Pending));
+
+    MIGRATOR.run(&db).await.unwrap();
+    let after = db::status(&db).await.unwrap();
+    assert_eq!(after.len(), before.len());
+    assert!(after.iter().all(|m| m.state == MigrationState::Applied));
+}
+
+#[tokio::test]
+async fn status_flags_modified_migrations() {
+    let db = memory_db().await;
+    MIGRATOR.run(&db).await.unwrap();
+    sqlx::query("UPDATE _sqlx_migrations SET checksum = x'00' WHERE version = 1")
+        .execute(&db)
+        .await
+        .unwrap();
+
+    let st = db::status(&db).await.unwrap();
+    assert_eq!(st[0].state, MigrationState::Modified);
+    assert!(MIGRATOR.run(&db).await.is_err());
+}
@@ -0,0 +1,100 @@
+//! Portfolio graphique : CRUD, albums, ordre, flux public et page /portfolio.
+
+mod common;
+
+use std::io::Cursor;
+
+use axum::http::{Method, StatusCode};
+use image::{ImageFormat, Rgb, RgbImage};
+use serde_json::{json, Value};
+
+use common::{app, app_with, call, config_with, signup, upload};
+
+fn titles(v: &Value) -> Vec<&str> {
+    v.as_array().unwrap().iter().map(|i| i["title"].as_str().unwrap()).collect()
+}
+
+#[tokio::test]
+async fn crud_albums_and_ordering() {
+    let app = app().await;
+    let sid = signup(&app, "pf@example.com").await;
+    let intruder = signup(&app, "intrus@example.com").await;
+
+    let mut ids = Vec::new();
+    for (title, kind) in [("Une", "journal"), ("Logo A", "Logo"), ("Deux", "Journal")] {
+        let r = call(&app, Method::POST, "/api/portfolio", Some(&sid), Some(json!({ "title": title, "type": kind, "image": format!("/assets/img/{title}.jpg") }))).await;
+        assert_eq!(r.status, StatusCode::OK, "{}", r.text);
+        ids.push(r.json["id"].as_i64().unwrap());
+    }
+    assert_eq!(call(&app, Method::POST, "/api/portfolio", Some(&sid), Some(json!({ "title": " " }))).await.status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    // albums : casse ignorée, couverture = première œuvre
+    let albums = call(&app, Method::GET, "/api/portfolio/albums", Some(&sid), None).await;
+    assert_eq!(albums.json, json!([
+        { "type": "journal", "count": 2, "cover": "/assets/img/Une.jpg" },
+        { "type": "Logo", "count": 1, "cover": "/assets/img/Logo A.jpg" },
+    ]));
+    let journal = call(&app, Method::GET, "/api/portfolio?type=JOURNAL", Some(&sid), None).await;
+    assert_eq!(titles(&journal.json), ["Une", "Deux"]);
+
+    let order = json!({ "ids": [ids[2], ids[0], ids[1]] });
+    let r = call(&app, Method::PUT, "/api/portfolio/order", Some(&sid), Some(order)).await;
+    assert_eq!(titles(&r.json), ["Deux", "Une", "Logo A"]);
+    let r = call(&app, Method::PUT, "/api/portfolio/order", Some(&intruder), Some(json!({ "ids": [ids[0]] }))).await;
+    assert_eq!(r.status, StatusCode::UNPROCESSABLE_ENTITY);
+    let r = call(&app, Method::PUT, "/api/portfolio/order", Some(&sid), Some(json!({ "ids": [ids[0], ids[0]] }))).await;
+    assert_eq!(r.status, StatusCode::UNPROCESSABLE_ENTITY);
+
+    // mise à jour sans position : l'œuvre reste à sa place
+    let uri = format!("/api/portfolio/{}", ids[0]);
+    let r = call(&app, Method::PUT, &uri, Some(&sid), Some(json!({ "title": "Une (v2)", "type": "journal" }))).await;
+    assert_eq!((r.json["title"].as_str(), r.json["position"].as_i64()), (Some("Une (v2)"), Some(1)));
+    assert_eq!(call(&app, Method::PUT, &uri, Some(&intruder), Some(json!({ "title": "x" }))).await.status, StatusCode::NOT_FOUND);
+
+    assert_eq!(call(&app, Method::DELETE, &uri, Some(&sid), None).await.status, StatusCode::OK);
+    assert_eq!(call(&app, Method::DELETE, &uri, Some(&sid), None).await.status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn public_feed_and_page_show_the_site_owner_items() {
+    let dir = std::env::temp_dir().join(format!("rustfolio-pf-{}", uuid::Uuid::new_v4()));
+    let app = app_with(config_with(&[("UPLOAD_DIR", dir.to_str().unwrap()), ("SITE_OWNER_EMAIL", "owner@example.com")])).await;
+    let other = signup(&app, "first@example.com").await;
+    let sid = signup(&app, "owner@example.com").await;
+    call(&app, Method::POST, "/api/portfolio", Some(&other), Some(json!({ "title": "Pas à moi" }))).await;
+
+    // reprise de l'ancien fichier statique
+    let legacy = json!([
+        { "title": "Journal d'entreprise", "image": "/assets/img/portfolio/p1.jpg", "description": "Journal", "type": "journal" },
+        { "title": "<script>alert(1)</script>", "image": "/assets/img/portfolio/doc.pdf", "description": "", "type": "feuillet" },
+    ]);
+    let r = call(&app, Method::POST, "/api/portfolio/import", Some(&sid), Some(legacy)).await;
+    assert_eq!(r.json["imported"], 2);
+
+    let mut png = Cursor::new(Vec::new());
+    RgbImage::from_pixel(1600, 900, Rgb([200, 30, 30])).write_to(&mut png, ImageFormat::Png).unwrap();
+    let up = upload(&app, "/api/uploads/images", Some(&sid), "file", "a.png", png.get_ref()).await;
+    let item = json!({ "title": "Affiche", "type": "journal", "image": up.json["url"] });
+    call(&app, Method::POST, "/api/portfolio", Some(&sid), Some(item)).await;
+
+    let feed = call(&app, Method::GET, "/api/portfolio/feed", None, None).await;
+    assert_eq!(feed.status, StatusCode::OK);
+    assert_eq!(titles(&feed.json["items"]), ["Journal d'entreprise", "<script>alert(1)</script>", "Affiche"]);
+    let items = &feed.json["items"];
+    assert_eq!((items[1]["media"].as_str(), items[2]["media"].as_str()), (Some("pdf"), Some("image")));
+    assert_eq!((items[2]["width"].as_u64(), items[2]["height"].as_u64()), (Some(1280), Some(720)));
+    assert!(items[2]["src"].as_str().unwrap().ends_with("-retina.webp"));
+    assert!(items[2]["srcset"].as_str().unwrap().contains(" 640w, "));
+    assert_eq!(items[0]["width"], Value::Null);
+
+    let journal = call(&app, Method::GET, "/api/portfolio/feed?type=Journal&q=affi", None, None).await;
+    assert_eq!(titles(&journal.json["items"]), ["Affiche"]);
+
+    let page = call(&app, Method::GET, "/portfolio?type=feuillet", None, None).await;
+    assert_eq!(page.status, StatusCode::OK);
+    assert!(page.text.contains("&lt;script&gt;alert(1)&lt;/script&gt;"), "titre non échappé");
+    assert!(!page.text.contains("Journal d&#x27;entreprise</h3>") && !page.text.contains("Affiche</h3>"));
+    assert!(page.text.contains(r#"<option value="feuillet" selected>"#), "{}", page.text);
+
+    let _ = std::fs::remove_dir_all(dir);
+}
@@ -0,0 +1,105 @@
+//! GET /api/projects : plein texte FTS5, filtres, tri, pagination, facettes.
+
+mod common;
+
+use axum::http::{Method, StatusCode};
+use serde_json::{json, Value};
+
+use common::{app, call, signup};
+
+fn titles(v: &Value) -> Vec<&str> {
+    v["items"].as_array().unwrap().iter().map(|i| i["title"].as_str().unwrap()).collect()
+}
+
+/// Propriétaire du site (plus ancien compte) avec quatre projets, plus un autre compte.
+async fn seeded() -> (axum::Router, String) {
+    let app = app().await;
+    let sid = signup(&app, "owner@example.com").await;
+    let other = signup(&app, "other@example.com").await;
+    let projects = [
+        json!({ "title": "Modélisation 3D", "description": "Rendu temps réel", "category": "Graphisme", "technologies": ["rust", "wgpu"] }),
+        json!({ "title": "Rustfolio", "description": "Portfolio et CV en ligne", "category": "Web", "technologies": ["Rust", "axum", "sqlite"] }),
+        json!({ "title": "Boutique", "description": "Site e-commerce, modélisation des stocks", "category": "web", "technologies": ["php", "mariadb"] }),
+        json!({ "title": "Affiches", "description": "Série d'affiches", "category": "Graphisme", "technologies": ["illustrator"] }),
+    ];
+    for p in projects {
+        let r = call(&app, Method::POST, "/api/cv/projects", Some(&sid), Some(p)).await;
+        assert_eq!(r.status, StatusCode::OK, "{}", r.text);
+    }
+    call(&app, Method::POST, "/api/cv/projects", Some(&other), Some(json!({ "title": "Modélisation privée" }))).await;
+    (app, sid)
+}
+
+#[tokio::test]
+async fn full_text_is_accent_insensitive_ranked_and_faceted() {
+    let (app, _) = seeded().await;
+
+    // accents et casse ignorés, préfixes, titre avant description
+    let r = call(&app, Method::GET, "/api/projects?q=MODELI", None, None).await;
+    assert_eq!(r.status, StatusCode::OK, "{}", r.text);
+    assert_eq!(titles(&r.json), ["Modélisation 3D", "Boutique"]);
+    assert_eq!(r.json["total"], 2);
+
+    // la syntaxe FTS5 saisie n'est pas interprétée
+    let r = call(&app, Method::GET, "/api/projects?q=rust%20OR%20%22", None, None).await;
+    assert_eq!(r.status, StatusCode::OK, "{}", r.text);
+    assert_eq!(r.json["total"], 0);
+
+    let r = call(&app, Method::GET, "/api/projects?sort=title", None, None).await;
+    assert_eq!(titles(&r.json), ["Affiches", "Boutique", "Modélisation 3D", "Rustfolio"]);
+    assert_eq!(r.json["facets"]["categories"], json!([{ "value": "Graphisme", "count": 2 }, { "value": "Web", "count": 2 }]));
+    assert_eq!(r.json["facets"]["technologies"][0], json!({ "value": "Rust", "count": 2 }));
+
+    // facettes calculées sur les résultats filtrés
+    let r = call(&app, Method::GET, "/api/projects?category=WEB&sort=title", None, None).await;
+    assert_eq!(titles(&r.json), ["Boutique", "Rustfolio"]);
+    assert_eq!(r.json["facets"]["categories"], json!([{ "value": "Web", "count": 2 }]));
+
+    assert_eq!(call(&app, Method::GET, "/api/projects?sort=random", None, None).await.status, StatusCode::BAD_REQUEST);
+    assert_eq!(call(&app, Method::GET, "/api/projects?limit=-1", None, None).await.status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn tech_filters_and_pagination() {
+    let (app, _) = seeded().await;
+
+    // alias du catalogue, clés répétées ou virgules, toutes requises par défaut
+    let r = call(&app, Method::GET, "/api/projects?tech=rustlang&tech=Axum", None, None).await;
+    assert_eq!(titles(&r.json), ["Rustfolio"]);
+    let r = call(&app, Method::GET, "/api/projects?tech=rust,php&tech_mode=any&sort=title", None, None).await;
+    assert_eq!(titles(&r.json), ["Boutique", "Modélisation 3D", "Rustfolio"]);
+    assert_eq!(call(&app, Method::GET, "/api/projects?tech=rust,php", None, None).await.json["total"], 0);
+
+    let first = call(&app, Method::GET, "/api/projects?sort=title&limit=3", None, None).await;
+    assert_eq!(titles(&first.json), ["Affiches", "Boutique", "Modélisation 3D"]);
+    assert_eq!((first.json["total"].as_i64(), first.json["next_cursor"].as_str()), (Some(4), Some("3")));
+    let rest = call(&app, Method::GET, "/api/projects?sort=title&limit=3&cursor=3", None, None).await;
+    assert_eq!(titles(&rest.json), ["Rustfolio"]);
+    assert_eq!(rest.json["next_cursor"], Value::Null);
+    let r = call(&app, Method::GET, "/api/projects?sort=title&limit=2&offset=1", None, None).await;
+    assert_eq!(titles(&r.json), ["Boutique", "Modélisation 3D"]);
+}
+
+#[tokio::test]
+async fn index_follows_project_changes() {
+    let (app, sid) = seeded().await;
+    let list = call(&app, Method::GET, "/api/cv/projects", Some(&sid), None).await;
+    let id = list.json[3]["id"].as_i64().unwrap();
+
+    let uri = format!("/api/cv/projects/{id}");
+    let p = json!({ "title": "Affiches", "description": "Typographie", "category": "Graphisme", "technologies": ["InDesign"] });
+    assert_eq!(call(&app, Method::PUT, &uri, Some(&sid), Some(p)).await.status, StatusCode::OK);
+    assert_eq!(titles(&call(&app, Method::GET, "/api/projects?q=typographie", None, None).await.json), ["Affiches"]);
+    assert_eq!(titles(&call(&app, Method::GET, "/api/projects?q=indesign", None, None).await.json), ["Affiches"]);
+    assert_eq!(call(&app, Method::GET, "/api/projects?q=illustrator", None, None).await.json["total"], 0);
+
+    call(&app, Method::DELETE, &uri, Some(&sid), None).await;
+    assert_eq!(call(&app, Method::GET, "/api/projects?q=affiches", None, None).await.json["total"], 0);
+
+    // PUT /cv/bulk recrée tous les projets
+    let mut bulk = call(&app, Method::GET, "/api/cv/bulk", Some(&sid), None).await.json;
+    bulk["projects"] = json!([{ "title": "Nouveau site", "technologies": ["Next.js"] }]);
+    assert_eq!(call(&app, Method::PUT, "/api/cv/bulk", Some(&sid), Some(bulk)).await.status, StatusCode::OK);
+    let r = call(&app, Method::GET, "/api/projects?q=next", None, None).await;
+    assert_eq!((titles(&r.json), r.json["total"].as_i64()), (vec!["Nouveau site"], Some(1)));
+}
@@ -0,0 +1,88 @@
+//! Pile HTTP : en-têtes de sécurité, CORS et compression.
+
+mod common;
+
+use axum::{
+    body::Body,
+    http::{header, Method, Request, StatusCode},
+    Router,
+};
+use tower::ServiceExt;
+
+use common::{app, app_with, call, config_with};
+
+async fn raw(app: &Router, req: Request<Body>) -> axum::response::Response {
+    app.clone().oneshot(req).await.unwrap()
+}
+
+#[tokio::test]
+async fn security_headers_on_every_response() {
+    let app = app().await;
+
+    for uri in ["/", "/health/live", "/api/cv/profile"] {
+        let r = call(&app, Method::GET, uri, None, None).await;
+        let h = &r.headers;
+        let csp = h[header::CONTENT_SECURITY_POLICY].to_str().unwrap();
+        assert!(csp.contains("default-src 'self'") && csp.contains("frame-ancestors 'none'"), "{uri}: {csp}");
+        assert_eq!(h[header::X_FRAME_OPTIONS], "DENY");
+        assert_eq!(h[header::REFERRER_POLICY], "strict-origin-when-cross-origin");
+        assert_eq!(h[header::X_CONTENT_TYPE_OPTIONS], "nosniff");
+        assert!(h.get(header::STRICT_TRANSPORT_SECURITY).is_none(), "pas de HSTS sans HTTPS");
+    }
+}
+
+#[tokio::test]
+async fn hsts_only_with_secure_cookies() {
+    let app = app_with(config_with(&[("COOKIE_SECURE", "true")])).await;
+    let r = call(&app, Method::GET, "/health/live", None, None).await;
+    assert!(r.headers[header::STRICT_TRANSPORT_SECURITY].to_str().unwrap().starts_with("max-age="));
+}
+
+#[tokio::test]
+async fn cors_allows_only_configured_origins_with_credentials() {
+    let app = app_with(config_with(&[("CORS_ALLOWED_ORIGINS", "http://localhost:8081")])).await;
+
+    let preflight = |origin: &str| {
+        Request::builder()
+            .method(Method::OPTIONS)
+            .uri("/api/cv/profile")
+            .header(header::ORIGIN, origin)
+            .header(header::ACCESS_CONTROL_REQUEST_METHOD, "PUT")
+            .header(header::ACCESS_CONTROL_REQUEST_HEADERS, "content-type")
+            .body(Body::empty())
+            .unwrap()
+    };
+
+    let res = raw(&app, preflight("http://localhost:8081")).await;
+    assert_eq!(res.status(), StatusCode::OK);
+    let h = res.headers();
+    assert_eq!(h[header::ACCESS_CONTROL_ALLOW_ORIGIN], "http://localhost:8081");
+    assert_eq!(h[header::ACCESS_CONTROL_ALLOW_CREDENTIALS], "true");
+    assert!(h[header::ACCESS_CONTROL_ALLOW_METHODS].to_str().unwrap().contains("PUT"));
+
+    let res = raw(&app, preflight("http://evil.example")).await;
+    assert!(res.headers().get(header::ACCESS_CONTROL_ALLOW_ORIGIN).is_none());
+
+    // par défaut : même origine uniquement
+    let res = raw(&app_with(config_with(&[])).await, preflight("http://localhost:8081")).await;
+    assert!(res.headers().get(header::ACCESS_CONTROL_ALLOW_ORIGIN).is_none());
+}
+
+#[tokio::test]
+async fn responses_are_compressed_on_demand() {
+    let app = app().await;
+    let req = |enc: Option<&str>| {
+        let mut r = Request::get("/health/ready");
+        if let Some(e) = enc {
+            r = r.header(header::ACCEPT_ENCODING, e);
+        }
+        r.body(Body::empty()).unwrap()
+    };
+
+    let res = raw(&app, req(Some("gzip"))).await;
+    assert_eq!(res.headers()[header::CONTENT_ENCODING], "gzip");
+    let res = raw(&app, req(Some("br"))).await;
+    assert_eq!(res.headers()[header::CONTENT_ENCODING], "br");
+    let res = raw(&app, req(None)).await;
+    assert!(res.headers().get(header::CONTENT_ENCODING).is_none());
+}
@@ -0,0 +1,49 @@
+//! Request-id et métriques Prometheus.
+
+mod common;
+
+use axum::{
+    body::Body,
+    http::{Method, Request, StatusCode},
+};
+use tower::ServiceExt;
+
+use common::{app, call, form, signup};
+
+#[tokio::test]
+async fn request_id_is_generated_or_propagated() {
+    let app = app().await;
+
+    let r = call(&app, Method::GET, "/health", None, None).await;
+    let id = r.headers.get("x-request-id").expect("x-request-id généré");
+    assert_eq!(id.len(), 36, "uuid attendu : {id:?}");
+
+    let req = Request::get("/api/cv/profile").header("x-request-id", "abc-123").body(Body::empty()).unwrap();
+    let res = app.clone().oneshot(req).await.unwrap();
+    assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+    assert_eq!(res.headers()["x-request-id"], "abc-123", "repris tel quel, y compris sur une erreur");
+}
+
+#[tokio::test]
+async fn metrics_count_routes_and_business_events() {
+    let app = app().await;
+    let sid = signup(&app, "m@example.com").await;
+    form(&app, "/auth/login", None, &[("email", "m@example.com"), ("password", "mauvais")]).await;
+    call(&app, Method::GET, "/api/cv/experiences/42/tasks", Some(&sid), None).await;
+    call(&app, Method::GET, "/api/profile/export", Some(&sid), None).await;
+
+    let r = call(&app, Method::GET, "/metrics", None, None).await;
+    assert_eq!(r.status, StatusCode::OK);
+    assert!(r.headers["content-type"].to_str().unwrap().starts_with("application/openmetrics-text"));
+
+    let m = &r.text;
+    assert!(m.contains("signups_total 1"), "{m}");
+    assert!(m.contains(r#"logins_total{outcome="failure"} 1"#), "{m}");
+    assert!(m.contains(r#"exports_total{kind="profile_json"} 1"#), "{m}");
+    // libellé = motif de route, pas l'URL réelle
+    assert!(
+        m.contains(r#"http_requests_total{method="GET",route="/api/cv/experiences/:id/tasks",status="404"} 1"#),
+        "{m}"
+    );
+    assert!(m.contains("http_request_duration_seconds_bucket"), "{m}");
+}
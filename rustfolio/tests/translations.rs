@@ -0,0 +1,146 @@
+mod common;
+
+use axum::http::{Method, StatusCode};
+use serde_json::json;
+
+use common::{app, call, signup};
+
+#[tokio::test]
+async fn translated_fields_fall_back_to_the_default_locale() {
+    let app = app().await;
+    let sid = signup(&app, "alice@example.com").await;
+
+    let profile = json!({
+        "title": "Développeuse Rust", "summary": "Backend et outillage",
+        "translations": { "EN": { "title": "Rust developer" } }
+    });
+    assert_eq!(call(&app, Method::PUT, "/api/cv/profile", Some(&sid), Some(profile)).await.status, StatusCode::OK);
+
+    let exp = json!({
+        "date_start": "2021-09", "date_end": "", "kind": "work", "title": "Développeuse",
+        "company": "Acme", "location": "Paris", "website": "", "tasks": [],
+        "translations": { "en": { "title": "Developer", "tasks": ["Built the API", " "] }, "de": { "title": "" } }
+    });
+    let exp_id = call(&app, Method::POST, "/api/cv/experiences", Some(&sid), Some(exp)).await.json["id"].as_i64().unwrap();
+    call(&app, Method::POST, &format!("/api/cv/experiences/{exp_id}/tasks"), Some(&sid), Some(json!({ "task": "Conçu l'API" }))).await;
+
+    let project = json!({ "title": "Rustfolio", "description": "Portfolio en Rust", "translations": { "en": { "description": "Rust portfolio" } } });
+    let project_id = call(&app, Method::POST, "/api/cv/projects", Some(&sid), Some(project)).await.json["id"].as_i64().unwrap();
+
+    // sans `locale` : langue par défaut + traductions (codes normalisés, champs vides retirés)
+    let p = call(&app, Method::GET, "/api/cv/profile", Some(&sid), None).await.json;
+    assert_eq!(p["title"], "Développeuse Rust");
+    assert_eq!(p["translations"], json!({ "en": { "title": "Rust developer" } }));
+    let list = call(&app, Method::GET, "/api/cv/experiences", Some(&sid), None).await.json;
+    assert_eq!(list[0]["translations"], json!({ "en": { "title": "Developer", "tasks": ["Built the API"] } }));
+
+    // en (et en-GB, par repli sur en) : champ traduit, sinon langue par défaut
+    for locale in ["en", "en-gb"] {
+        let p = call(&app, Method::GET, &format!("/api/cv/profile?locale={locale}"), Some(&sid), None).await.json;
+        assert_eq!(p["title"], "Rust developer");
+        assert_eq!(p["summary"], "Backend et outillage");
+        assert!(p.get("translations").is_none());
+    }
+    let list = call(&app, Method::GET, "/api/cv/experiences?locale=en", Some(&sid), None).await.json;
+    assert_eq!(list[0]["title"], "Developer");
+    assert_eq!(list[0]["tasks"], json!(["Built the API"]));
+    assert_eq!(list[0]["company"], "Acme");
+    let list = call(&app, Method::GET, "/api/cv/experiences?locale=de", Some(&sid), None).await.json;
+    assert_eq!(list[0]["title"], "Développeuse");
+    assert_eq!(list[0]["tasks"], json!(["Conçu l'API"]));
+    let bulk = call(&app, Method::GET, "/api/cv/bulk?locale=en", Some(&sid), None).await.json;
+    assert_eq!(bulk["projects"][0]["description"], "Rust portfolio");
+    assert_eq!(bulk["profile"]["title"], "Rust developer");
+
+    // une écriture sans `translations` les garde ; avec, les remplace
+    let upd = json!({
+        "date_start": "2021-09", "date_end": "2023-01", "kind": "work", "title": "Lead",
+        "company": "Acme", "location": "Paris", "website": "", "tasks": []
+    });
+    call(&app, Method::PUT, &format!("/api/cv/experiences/{exp_id}"), Some(&sid), Some(upd)).await;
+    let list = call(&app, Method::GET, "/api/cv/experiences?locale=en", Some(&sid), None).await.json;
+    assert_eq!(list[0]["title"], "Developer");
+    let upd = json!({ "title": "Rustfolio", "description": "Portfolio en Rust", "translations": {} });
+    call(&app, Method::PUT, &format!("/api/cv/projects/{project_id}"), Some(&sid), Some(upd)).await;
+    let list = call(&app, Method::GET, "/api/cv/projects?locale=en", Some(&sid), None).await.json;
+    assert_eq!(list[0]["description"], "Portfolio en Rust");
+
+    // langues invalides
+    let r = call(&app, Method::GET, "/api/cv/profile?locale=english!", Some(&sid), None).await;
+    assert_eq!(r.status, StatusCode::BAD_REQUEST);
+    let r = call(&app, Method::PUT, "/api/cv/profile", Some(&sid), Some(json!({ "translations": { "fr": { "title": "x" } } }))).await;
+    assert_eq!(r.status, StatusCode::UNPROCESSABLE_ENTITY);
+    let r = call(&app, Method::PUT, "/api/cv/profile", Some(&sid), Some(json!({ "translations": { "e n": { "title": "x" } } }))).await;
+    assert_eq!(r.status, StatusCode::UNPROCESSABLE_ENTITY);
+}
+
+#[tokio::test]
+async fn published_cv_is_served_per_language_with_hreflang_links() {
+    let app = app().await;
+    let sid = signup(&app, "alice@example.com").await;
+
+    let profile = json!({
+        "first_name": "Alice", "last_name": "Martin", "title": "Développeuse", "summary": "Bonjour",
+        "translations": { "en": { "title": "Developer", "summary": "Hello" } }
+    });
+    call(&app, Method::PUT, "/api/cv/profile", Some(&sid), Some(profile)).await;
+    let exp = json!({ "date_start": "2021", "date_end": "", "kind": "work", "title": "Dev",
+                      "company": "Acme", "location": "", "website": "", "tasks": [] });
+    call(&app, Method::POST, "/api/cv/experiences", Some(&sid), Some(exp)).await;
+    let layout = json!({
+        "version": 2,
+        "rows": [{ "id": 1, "columns": [{ "id": 1, "width": 1, "widgets": [{ "type": "ProfileBasic" }, { "type": "ExperienceTimeline" }] }] }],
+        "selected_row": null, "selected_column": null, "next_row_id": 1, "next_col_id": 1
+    });
+    call(&app, Method::POST, "/api/cvs", Some(&sid), Some(json!({ "name": "Principal", "layout": layout }))).await;
+
+    // non publié
+    let p = call(&app, Method::GET, "/api/cv/public", Some(&sid), None).await.json;
+    assert_eq!(p["slug"], serde_json::Value::Null);
+    assert_eq!(p["locales"], json!(["fr", "en"]));
+    assert_eq!(call(&app, Method::GET, "/u/alice/fr", None, None).await.status, StatusCode::NOT_FOUND);
+
+    let r = call(&app, Method::PUT, "/api/cv/public", Some(&sid), Some(json!({ "slug": "Al" }))).await;
+    assert_eq!(r.status, StatusCode::UNPROCESSABLE_ENTITY);
+    let r = call(&app, Method::PUT, "/api/cv/public", Some(&sid), Some(json!({ "slug": " Alice-Martin " }))).await;
+    assert_eq!(r.status, StatusCode::OK);
+    assert_eq!(r.json["slug"], "alice-martin");
+    assert!(r.json["urls"][1].as_str().unwrap().ends_with("/u/alice-martin/en"));
+
+    let bob = signup(&app, "bob@example.com").await;
+    let r = call(&app, Method::PUT, "/api/cv/public", Some(&bob), Some(json!({ "slug": "alice-martin" }))).await;
+    assert_eq!(r.status, StatusCode::CONFLICT);
+
+    let root = call(&app, Method::GET, "/u/alice-martin", None, None).await;
+    assert_eq!(root.location.as_deref(), Some("/u/alice-martin/fr"));
+    // slug inconnu ou impossible dans un en-tête : 404, pas de redirection
+    for uri in ["/u/inconnu", "/u/%0A"] {
+        assert_eq!(call(&app, Method::GET, uri, None, None).await.status, StatusCode::NOT_FOUND);
+    }
+
+    let fr = call(&app, Method::GET, "/u/alice-martin/fr", None, None).await;
+    assert_eq!(fr.status, StatusCode::OK);
+    assert!(fr.text.contains(r#"<html lang="fr">"#));
+    assert!(fr.text.contains("Développeuse") && fr.text.contains("Bonjour"));
+    assert!(fr.text.contains("Expériences") && fr.text.contains("2021 – aujourd"));
+    assert!(fr.text.contains(r#"hreflang="en" href="http"#));
+    assert!(fr.text.contains(r#"hreflang="x-default""#));
+
+    let en = call(&app, Method::GET, "/u/alice-martin/en", None, None).await;
+    assert!(en.text.contains(r#"<html lang="en">"#));
+    assert!(en.text.contains("Developer") && en.text.contains("Hello"));
+    assert!(!en.text.contains("Bonjour"));
+    // titres par défaut et dates dans la langue du contenu aussi
+    assert!(en.text.contains("Experience") && en.text.contains("2021 – present"));
+    assert!(!en.text.contains("Expériences") && !en.text.contains("aujourd"));
+
+    // une seule adresse par langue ; langue sans contenu : 404
+    let upper = call(&app, Method::GET, "/u/alice-martin/EN", None, None).await;
+    assert_eq!(upper.status, StatusCode::PERMANENT_REDIRECT);
+    assert_eq!(upper.location.as_deref(), Some("/u/alice-martin/en"));
+    assert_eq!(call(&app, Method::GET, "/u/alice-martin/de", None, None).await.status, StatusCode::NOT_FOUND);
+
+    // dépublié
+    call(&app, Method::PUT, "/api/cv/public", Some(&sid), Some(json!({ "slug": null }))).await;
+    assert_eq!(call(&app, Method::GET, "/u/alice-martin/fr", None, None).await.status, StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,204 @@
+//! Upload d'images : validation, variantes, EXIF, déduplication, orphelins.
+
+mod common;
+
+use std::{io::Cursor, path::PathBuf, time::Duration};
+
+use axum::http::{header, Method, StatusCode};
+use image::{ImageFormat, Rgb, RgbImage, Rgba, RgbaImage};
+use serde_json::json;
+
+use common::{call, config_with, signup, state_with, upload};
+use rustfolio::state::AppState;
+
+const UPLOAD: &str = "/api/uploads/images";
+
+fn temp_dir() -> PathBuf {
+    std::env::temp_dir().join(format!("rustfolio-uploads-{}", uuid::Uuid::new_v4()))
+}
+
+async fn setup(extra: &[(&str, &str)]) -> (AppState, axum::Router, PathBuf) {
+    let dir = temp_dir();
+    let mut vars = vec![("UPLOAD_DIR", dir.to_str().unwrap())];
+    vars.extend_from_slice(extra);
+    let st = state_with(config_with(&vars)).await;
+    let app = rustfolio::app(st.clone());
+    (st, app, dir)
+}
+
+async fn user_id(st: &AppState, email: &str) -> String {
+    sqlx::query_scalar::<_, String>("SELECT id FROM users WHERE email = ?")
+        .bind(email)
+        .fetch_one(&st.db)
+        .await
+        .unwrap()
+}
+
+fn png(w: u32, h: u32) -> Vec<u8> {
+    let img = RgbaImage::from_fn(w, h, |x, y| Rgba([(x % 256) as u8, (y % 256) as u8, 90, 200]));
+    let mut buf = Cursor::new(Vec::new());
+    img.write_to(&mut buf, ImageFormat::Png).unwrap();
+    buf.into_inner()
+}
+
+/// JPEG `w`×`h` avec un bloc EXIF : orientation 6 (rotation 90°) + un champ "Artist".
+fn jpeg_with_exif(w: u32, h: u32) -> Vec<u8> {
+    let img = RgbImage::from_fn(w, h, |x, _| Rgb([(x * 4 % 256) as u8, 10, 10]));
+    let mut buf = Cursor::new(Vec::new());
+    img.write_to(&mut buf, ImageFormat::Jpeg).unwrap();
+    let jpeg = buf.into_inner();
+
+    // TIFF little-endian, IFD0 à 2 entrées (Orientation, Artist), texte après l'IFD
+    let artist = b"SECRET-AUTHOR\0";
+    let mut tiff: Vec<u8> = b"II*\0".to_vec();
+    tiff.extend_from_slice(&8u32.to_le_bytes());
+    tiff.extend_from_slice(&2u16.to_le_bytes());
+    tiff.extend_from_slice(&[0x12, 0x01, 3, 0]); // Orientation, SHORT
+    tiff.extend_from_slice(&1u32.to_le_bytes());
+    tiff.extend_from_slice(&[6, 0, 0, 0]);
+    tiff.extend_from_slice(&[0x3b, 0x01, 2, 0]); // Artist, ASCII
+    tiff.extend_from_slice(&(artist.len() as u32).to_le_bytes());
+    tiff.extend_from_slice(&(8 + 2 + 2 * 12 + 4u32).to_le_bytes());
+    tiff.extend_from_slice(&0u32.to_le_bytes());
+    tiff.extend_from_slice(artist);
+
+    let mut app1 = b"Exif\0\0".to_vec();
+    app1.extend_from_slice(&tiff);
+    let mut out = jpeg[..2].to_vec(); // SOI
+    out.extend_from_slice(&[0xFF, 0xE1]);
+    out.extend_from_slice(&((app1.len() + 2) as u16).to_be_bytes());
+    out.extend_from_slice(&app1);
+    out.extend_from_slice(&jpeg[2..]);
+    out
+}
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack.windows(needle.len()).any(|w| w == needle)
+}
+
+#[tokio::test]
+async fn png_upload_produces_variants_served_with_long_cache() {
+    let (st, app, dir) = setup(&[]).await;
+    let sid = signup(&app, "img@example.com").await;
+    let uid = user_id(&st, "img@example.com").await;
+
+    let r = upload(&app, UPLOAD, Some(&sid), "file", "logo.png", &png(1400, 700)).await;
+    assert_eq!(r.status, StatusCode::CREATED, "{}", r.text);
+    assert_eq!((r.json["width"].as_u64(), r.json["height"].as_u64()), (Some(1400), Some(700)));
+    let v = &r.json["variants"];
+    assert_eq!((v["thumb"]["width"].as_u64(), v["thumb"]["height"].as_u64()), (Some(160), Some(160)));
+    assert_eq!((v["card"]["width"].as_u64(), v["card"]["height"].as_u64()), (Some(640), Some(320)));
+    assert_eq!((v["retina"]["width"].as_u64(), v["retina"]["height"].as_u64()), (Some(1280), Some(640)));
+    // transparence : repli en PNG
+    assert!(v["card"]["fallback"].as_str().unwrap().ends_with("-card.png"));
+
+    let hash = r.json["hash"].as_str().unwrap();
+    let files = std::fs::read_dir(dir.join(&uid)).unwrap().count();
+    assert_eq!(files, 6);
+    let card = image::open(dir.join(&uid).join(format!("{hash}-card.webp"))).unwrap();
+    assert_eq!((card.width(), card.height()), (640, 320));
+
+    let url = r.json["url"].as_str().unwrap();
+    let served = call(&app, Method::GET, url, None, None).await;
+    assert_eq!(served.status, StatusCode::OK);
+    assert_eq!(served.headers[header::CONTENT_TYPE], "image/webp");
+    assert!(served.headers[header::CACHE_CONTROL].to_str().unwrap().contains("immutable"));
+
+    // même contenu : pas de nouvel upload
+    let again = upload(&app, UPLOAD, Some(&sid), "file", "autre-nom.png", &png(1400, 700)).await;
+    assert_eq!(again.status, StatusCode::OK);
+    assert_eq!(again.json["id"], r.json["id"]);
+
+    let _ = std::fs::remove_dir_all(dir);
+}
+
+#[tokio::test]
+async fn rejects_unauthenticated_unsupported_and_oversized_files() {
+    let (_st, app, dir) = setup(&[("UPLOAD_MAX_IMAGE_MB", "1")]).await;
+    let sid = signup(&app, "bad@example.com").await;
+
+    let anon = upload(&app, UPLOAD, None, "file", "a.png", &png(10, 10)).await;
+    assert_eq!(anon.status, StatusCode::UNAUTHORIZED);
+
+    let svg = br#"<svg xmlns="http://www.w3.org/2000/svg"><script>alert(1)</script></svg>"#;
+    let r = upload(&app, UPLOAD, Some(&sid), "file", "x.svg", svg).await;
+    assert_eq!(r.status, StatusCode::UNSUPPORTED_MEDIA_TYPE, "{}", r.text);
+
+    let truncated = &png(64, 64)[..60];
+    let r = upload(&app, UPLOAD, Some(&sid), "file", "t.png", truncated).await;
+    assert_eq!(r.status, StatusCode::UNPROCESSABLE_ENTITY, "{}", r.text);
+
+    let mut big = png(8, 8);
+    big.resize(1024 * 1024 + 1, 0);
+    let r = upload(&app, UPLOAD, Some(&sid), "file", "big.png", &big).await;
+    assert_eq!(r.status, StatusCode::PAYLOAD_TOO_LARGE, "{}", r.text);
+
+    let r = upload(&app, UPLOAD, Some(&sid), "autre", "a.png", &png(10, 10)).await;
+    assert_eq!(r.status, StatusCode::BAD_REQUEST);
+
+    let _ = std::fs::remove_dir_all(dir);
+}
+
+#[tokio::test]
+async fn exif_orientation_is_applied_then_stripped() {
+    let (st, app, dir) = setup(&[]).await;
+    let sid = signup(&app, "exif@example.com").await;
+    let uid = user_id(&st, "exif@example.com").await;
+
+    let original = jpeg_with_exif(80, 40);
+    assert!(contains(&original, b"SECRET-AUTHOR"));
+
+    let r = upload(&app, UPLOAD, Some(&sid), "file", "photo.jpg", &original).await;
+    assert_eq!(r.status, StatusCode::CREATED, "{}", r.text);
+    // rotation de 90° appliquée avant redimensionnement
+    assert_eq!((r.json["width"].as_u64(), r.json["height"].as_u64()), (Some(40), Some(80)));
+
+    let hash = r.json["hash"].as_str().unwrap();
+    for name in [format!("{hash}-card.jpg"), format!("{hash}-card.webp")] {
+        let bytes = std::fs::read(dir.join(&uid).join(&name)).unwrap();
+        assert!(!contains(&bytes, b"Exif"), "{name} contient encore de l'EXIF");
+        assert!(!contains(&bytes, b"SECRET-AUTHOR"), "{name}");
+    }
+
+    let _ = std::fs::remove_dir_all(dir);
+}
+
+#[tokio::test]
+async fn orphans_are_removed_once_the_project_is_deleted() {
+    let (st, app, dir) = setup(&[]).await;
+    let sid = signup(&app, "orphan@example.com").await;
+    let uid = user_id(&st, "orphan@example.com").await;
+
+    let up = upload(&app, UPLOAD, Some(&sid), "file", "p.png", &png(50, 50)).await;
+    assert_eq!(up.status, StatusCode::CREATED);
+    let upload_id = up.json["id"].as_i64().unwrap();
+
+    let p = call(
+        &app,
+        Method::POST,
+        "/api/cv/projects",
+        Some(&sid),
+        Some(json!({ "title": "Site", "image": up.json["url"] })),
+    )
+    .await;
+    assert_eq!(p.status, StatusCode::OK, "{}", p.text);
+    let project_id = p.json["id"].as_i64().unwrap();
+
+    // référencée : ni supprimable à la main, ni orpheline
+    let r = call(&app, Method::DELETE, &format!("/api/uploads/{upload_id}"), Some(&sid), None).await;
+    assert_eq!(r.status, StatusCode::CONFLICT);
+    assert_eq!(rustfolio::uploads::collect_orphans(&st, &uid, Duration::ZERO).await.unwrap(), 0);
+
+    let r = call(&app, Method::DELETE, &format!("/api/cv/projects/{project_id}"), Some(&sid), None).await;
+    assert_eq!(r.status, StatusCode::OK);
+    // le nettoyage automatique garde un délai de grâce : l'upload récent est conservé
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert_eq!(std::fs::read_dir(dir.join(&uid)).unwrap().count(), 6);
+
+    assert_eq!(rustfolio::uploads::collect_orphans(&st, &uid, Duration::ZERO).await.unwrap(), 1);
+    assert_eq!(std::fs::read_dir(dir.join(&uid)).unwrap().count(), 0);
+    let r = call(&app, Method::DELETE, &format!("/api/uploads/{upload_id}"), Some(&sid), None).await;
+    assert_eq!(r.status, StatusCode::NOT_FOUND);
+
+    let _ = std::fs::remove_dir_all(dir);
+}
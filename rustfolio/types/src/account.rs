@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+
+/// `GET|PATCH /auth/me`
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(default)]
+pub struct Me {
+    pub email: String,
+    pub display_name: Option<String>,
+    /// Langue de l'interface choisie (`null` : négociée depuis le navigateur).
+    pub locale: Option<String>,
+    /// Langue de l'interface effective pour cette requête.
+    pub ui_locale: String,
+}
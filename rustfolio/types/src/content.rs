@@ -0,0 +1,268 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+
+/* =================== TRADUCTIONS =================== */
+
+/// Traductions d'un élément, par code de langue (`en`, `pt-BR`…), hors langue par
+/// défaut (la langue des colonnes principales, [`DEFAULT_LOCALE`]). En écriture, `None` laisse les traductions stockées
+/// telles quelles, `Some` les remplace toutes.
+pub type Translations<T> = BTreeMap<String, T>;
+
+/// Langue par défaut du contenu (colonnes principales côté serveur).
+pub const DEFAULT_LOCALE: &str = "fr";
+
+/// Champs traduits du profil ; `None` (ou vide) : repli sur la langue par défaut.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(default)]
+pub struct ProfileText {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title:   Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(default)]
+pub struct ExperienceText {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    /// Liste complète des tâches dans cette langue.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tasks: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(default)]
+pub struct ProjectText {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+/* =================== PROFILE =================== */
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(default)]
+pub struct Profile {
+    pub first_name: String,
+    pub last_name:  String,
+    pub title:      String,
+    pub email:      String,
+    pub phone:      String,
+    pub address:    String,
+    pub city:       String,
+    pub country:    String,
+    pub website:    String,
+    pub photo_url:  String,
+    pub summary:    String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub translations: Option<Translations<ProfileText>>,
+}
+
+/* =================== EXPERIENCE =================== */
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+pub struct Experience {
+    pub id: Option<i64>,
+    pub date_start: String,
+    pub date_end: String,
+    pub kind: String,
+    pub title: String,
+    pub company: String,
+    pub location: String,
+    pub website: String,
+    pub tasks: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub translations: Option<Translations<ExperienceText>>,
+}
+
+/// Une puce d'expérience avec son id (endpoints /tasks).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TaskItem {
+    pub id:   i64,
+    pub task: String,
+    /// Rendu HTML (Markdown-lite) de `task`, calculé côté serveur.
+    #[serde(default)]
+    pub html: String,
+}
+
+impl TaskItem {
+    pub fn new(id: i64, task: String) -> Self {
+        let html = crate::rich_text::render_inline(&task);
+        Self { id, task, html }
+    }
+}
+
+/* =================== SKILL =================== */
+
+/// Forme "bulk" d'une compétence (id optionnel), alignée sur SkillIn/SkillOut.
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(default)]
+pub struct Skill {
+    pub id:         Option<i64>,
+    pub name:       String,
+    pub percentage: Option<u8>,
+    pub level:      Option<SkillLevel>,
+    #[serde(alias = "logo")]
+    pub logo_url:   Option<String>,
+    pub category:   String,
+}
+
+impl Skill {
+    /// Pourcentage saisi, sinon déduit du niveau.
+    pub fn percent(&self) -> Option<u8> {
+        self.percentage.or(self.level.map(SkillLevel::percent))
+    }
+}
+
+/// Maîtrise exprimée par niveau plutôt qu'en pourcentage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SkillLevel {
+    Beginner,
+    Intermediate,
+    Advanced,
+    Expert,
+}
+
+impl SkillLevel {
+    pub const ALL: [SkillLevel; 4] =
+        [SkillLevel::Beginner, SkillLevel::Intermediate, SkillLevel::Advanced, SkillLevel::Expert];
+
+    /// Équivalent en pourcentage (barres, points), quand seul le niveau est saisi.
+    pub fn percent(self) -> u8 {
+        match self {
+            SkillLevel::Beginner     => 25,
+            SkillLevel::Intermediate => 50,
+            SkillLevel::Advanced     => 75,
+            SkillLevel::Expert       => 100,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            SkillLevel::Beginner     => "beginner",
+            SkillLevel::Intermediate => "intermediate",
+            SkillLevel::Advanced     => "advanced",
+            SkillLevel::Expert       => "expert",
+        }
+    }
+
+    pub fn parse(s: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|l| l.as_str() == s)
+    }
+}
+
+/* =================== PROJECT =================== */
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(default)]
+pub struct Project {
+    pub id:          Option<i64>,
+    pub title:       String,
+    pub description: String,
+    pub category:    String,
+    pub repo_link:   String,
+    pub pdf_link:    String,
+    pub image:       String,
+    pub technologies: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub translations: Option<Translations<ProjectText>>,
+}
+
+/// Une techno de projet avec son id (endpoints /tech).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TechItem {
+    pub id:   i64,
+    pub tech: String,
+}
+
+/* =================== PORTFOLIO =================== */
+
+/// Œuvre du portfolio graphique. En JSON, l'album s'appelle `type`
+/// (comme dans l'ancien `data/graphicPortfolio.json`, importable tel quel).
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(default)]
+pub struct PortfolioItem {
+    pub id:          Option<i64>,
+    pub title:       String,
+    pub description: String,
+    #[serde(rename = "type")]
+    pub kind:        String,
+    pub image:       String,
+    /// À la création, `None` ajoute l'œuvre en fin de liste ; à la mise à jour, la laisse en place.
+    pub position:    Option<i64>,
+}
+
+/* =================== CV BULK =================== */
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(default)]
+pub struct CvData {
+    pub profile:     Profile,
+    pub experiences: Vec<Experience>,
+    pub skills:      Vec<Skill>,
+    pub projects:    Vec<Project>,
+}
+
+/* =================== SKILL IN/OUT (API) =================== */
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct SkillIn {
+    pub name: String,
+    pub percentage: Option<u8>,     // optionnel
+    #[serde(default)]
+    pub level: Option<SkillLevel>,  // optionnel, alternative au pourcentage
+    #[serde(alias = "logo")]
+    pub logo_url: Option<String>,   // optionnel
+    #[serde(default)]
+    pub category: String,           // libre, mais on proposera des suggestions
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct SkillOut {
+    pub id: i64,
+    pub name: String,
+    pub percentage: Option<u8>,
+    pub level: Option<SkillLevel>,
+    pub logo_url: Option<String>,
+    pub category: String,
+}
+
+impl SkillOut {
+    /// Pourcentage saisi, sinon déduit du niveau.
+    pub fn percent(&self) -> Option<u8> {
+        self.percentage.or(self.level.map(SkillLevel::percent))
+    }
+}
+
+impl From<Skill> for SkillIn {
+    fn from(s: Skill) -> Self {
+        Self { name: s.name, percentage: s.percentage, level: s.level, logo_url: s.logo_url, category: s.category }
+    }
+}
+
+impl From<SkillOut> for Skill {
+    fn from(s: SkillOut) -> Self {
+        Self {
+            id: Some(s.id),
+            name: s.name,
+            percentage: s.percentage,
+            level: s.level,
+            logo_url: s.logo_url,
+            category: s.category,
+        }
+    }
+}
+
+/* =================== CATALOGUE =================== */
+
+/// Entrée du catalogue de technologies (autocomplétion `/api/catalog/search`).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CatalogEntry {
+    pub id:       i64,
+    pub slug:     String,
+    pub name:     String,
+    pub category: String,
+    pub logo_url: String,
+}
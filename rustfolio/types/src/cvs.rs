@@ -0,0 +1,32 @@
+use rustfolio_layout::{Layout, Theme};
+use serde::{Deserialize, Serialize};
+
+/// CV nommé, sans sa mise en page.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CvSummary {
+    pub id:         i64,
+    pub name:       String,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// Éléments masqués d'un CV (ids d'expériences, de compétences, de projets).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HiddenItems {
+    pub experiences: Vec<i64>,
+    pub skills:      Vec<i64>,
+    pub projects:    Vec<i64>,
+}
+
+/// `GET /api/cvs/:id` : le CV avec sa mise en page, son thème et ses éléments masqués.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CvDetail {
+    #[serde(flatten)]
+    pub cv:     CvSummary,
+    pub layout: Layout,
+    /// Absent des réponses d'avant les thèmes : thème par défaut.
+    #[serde(default)]
+    pub theme:  Theme,
+    pub hidden: HiddenItems,
+}
@@ -0,0 +1,19 @@
+//! Corps JSON de l'API, déclarés une seule fois : le serveur les produit et les lit
+//! (handlers, dépôts), le SPA les envoie et les affiche.
+//!
+//! - [`content`] : contenu du CV (profil, expériences, compétences, projets, portfolio)
+//!   et ses traductions ;
+//! - [`cvs`] : CV nommés du builder (mise en page et thème de `rustfolio_layout`) ;
+//! - [`account`] : compte de l'utilisateur connecté ;
+//! - [`rich_text`] : rendu Markdown-lite des puces, identique des deux côtés.
+//!
+//! Tout est réexporté à la racine : `rustfolio_types::{Profile, SkillOut, CvDetail}`.
+
+pub mod account;
+pub mod content;
+pub mod cvs;
+pub mod rich_text;
+
+pub use account::Me;
+pub use content::*;
+pub use cvs::{CvDetail, CvSummary, HiddenItems};
@@ -0,0 +1,139 @@
+//! Rendu "rich-text-lite" des puces (tasks) : un sous-ensemble Markdown sûr.
+//!
+//! Supporté :
+//! - `**gras**`
+//! - `*italique*` ou `_italique_`
+//! - `[libellé](https://exemple.com)` (schémas http, https, mailto ou chemin relatif `/…`)
+//! - `\*` pour échapper un caractère spécial
+//!
+//! Tout le reste est échappé : aucune balise HTML saisie par l'utilisateur ne passe.
+//! Même rendu côté serveur (`TaskItem::html`, pages) et dans l'aperçu du SPA.
+
+/// Convertit une ligne Markdown-lite en HTML échappé.
+pub fn render_inline(src: &str) -> String {
+    let chars: Vec<char> = src.chars().collect();
+    let mut out = String::with_capacity(src.len() + 16);
+    render(&chars, true, &mut out);
+    out
+}
+
+fn render(chars: &[char], allow_links: bool, out: &mut String) {
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        match c {
+            '\\' if i + 1 < chars.len() && is_special(chars[i + 1]) => {
+                push_escaped(chars[i + 1], out);
+                i += 2;
+            }
+            '*' if chars.get(i + 1) == Some(&'*') => {
+                match find_closing(chars, i + 2, &['*', '*']) {
+                    Some(end) if end > i + 2 => {
+                        out.push_str("<strong>");
+                        render(&chars[i + 2..end], allow_links, out);
+                        out.push_str("</strong>");
+                        i = end + 2;
+                    }
+                    _ => {
+                        out.push_str("**");
+                        i += 2;
+                    }
+                }
+            }
+            '*' | '_' => {
+                // `_` seulement en début de mot (évite snake_case)
+                let opens = c == '*' || i == 0 || !chars[i - 1].is_alphanumeric();
+                match find_closing(chars, i + 1, &[c]) {
+                    Some(end) if opens && end > i + 1 && !chars[i + 1].is_whitespace() => {
+                        out.push_str("<em>");
+                        render(&chars[i + 1..end], allow_links, out);
+                        out.push_str("</em>");
+                        i = end + 1;
+                    }
+                    _ => {
+                        push_escaped(c, out);
+                        i += 1;
+                    }
+                }
+            }
+            '[' if allow_links => match parse_link(chars, i) {
+                Some((label_end, url, next)) => {
+                    let label = &chars[i + 1..label_end];
+                    if is_safe_url(&url) {
+                        out.push_str("<a href=\"");
+                        for ch in url.chars() { push_escaped(ch, out); }
+                        out.push_str("\" rel=\"noopener noreferrer\" target=\"_blank\">");
+                        render(label, false, out);
+                        out.push_str("</a>");
+                    } else {
+                        // lien refusé : on garde juste le libellé
+                        render(label, false, out);
+                    }
+                    i = next;
+                }
+                None => {
+                    out.push('[');
+                    i += 1;
+                }
+            },
+            _ => {
+                push_escaped(c, out);
+                i += 1;
+            }
+        }
+    }
+}
+
+fn is_special(c: char) -> bool {
+    matches!(c, '\\' | '*' | '_' | '[' | ']' | '(' | ')')
+}
+
+fn push_escaped(c: char, out: &mut String) {
+    match c {
+        '&' => out.push_str("&amp;"),
+        '<' => out.push_str("&lt;"),
+        '>' => out.push_str("&gt;"),
+        '"' => out.push_str("&quot;"),
+        '\'' => out.push_str("&#39;"),
+        _ => out.push(c),
+    }
+}
+
+/// Cherche `pat` à partir de `from`, en sautant les caractères échappés.
+fn find_closing(chars: &[char], from: usize, pat: &[char]) -> Option<usize> {
+    let mut i = from;
+    while i + pat.len() <= chars.len() {
+        if chars[i] == '\\' {
+            i += 2;
+            continue;
+        }
+        if chars[i..i + pat.len()] == *pat {
+            return Some(i);
+        }
+        i += 1;
+    }
+    None
+}
+
+/// `[label](url)` → (index du `]`, url, index après `)`)
+fn parse_link(chars: &[char], open: usize) -> Option<(usize, String, usize)> {
+    let label_end = find_closing(chars, open + 1, &[']'])?;
+    if chars.get(label_end + 1) != Some(&'(') {
+        return None;
+    }
+    let url_end = find_closing(chars, label_end + 2, &[')'])?;
+    let url: String = chars[label_end + 2..url_end].iter().collect();
+    Some((label_end, url.trim().to_string(), url_end + 1))
+}
+
+fn is_safe_url(url: &str) -> bool {
+    let lower = url.to_ascii_lowercase();
+    if url.is_empty() || url.chars().any(char::is_whitespace) {
+        return false;
+    }
+    // `//hôte` et `/\hôte` : les navigateurs les lisent comme une autre origine
+    lower.starts_with("https://")
+        || lower.starts_with("http://")
+        || lower.starts_with("mailto:")
+        || (url.starts_with('/') && !matches!(url[1..].chars().next(), Some('/' | '\\')))
+}
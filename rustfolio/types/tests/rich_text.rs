@@ -0,0 +1,71 @@
+use rustfolio_types::rich_text::render_inline;
+
+/// Lien accepté, tel que rendu.
+fn link(href: &str, label: &str) -> String {
+    format!(r#"<a href="{href}" rel="noopener noreferrer" target="_blank">{label}</a>"#)
+}
+
+#[test]
+fn emphasis_nests_and_stays_literal_when_unclosed() {
+    assert_eq!(render_inline("**gras** et *italique*"), "<strong>gras</strong> et <em>italique</em>");
+    assert_eq!(render_inline("**gras _ital_**"), "<strong>gras <em>ital</em></strong>");
+    assert_eq!(render_inline("_a **b** c_"), "<em>a <strong>b</strong> c</em>");
+
+    assert_eq!(render_inline("**gras"), "**gras");
+    assert_eq!(render_inline("*ital"), "*ital");
+    assert_eq!(render_inline("_ital"), "_ital");
+    assert_eq!(render_inline("* pas une liste*"), "* pas une liste*");
+    // `_` au milieu d'un mot : pas d'italique
+    assert_eq!(render_inline("snake_case_name"), "snake_case_name");
+}
+
+#[test]
+fn backslash_escapes_specials_only() {
+    assert_eq!(render_inline(r"\*pas italique\*"), "*pas italique*");
+    assert_eq!(render_inline(r"\*\*pas gras\*\*"), "**pas gras**");
+    assert_eq!(render_inline(r"\[a\](https://x.y)"), "[a](https://x.y)");
+    assert_eq!(render_inline(r"\_x\_ et \\"), r"_x_ et \");
+    assert_eq!(render_inline(r"C:\dossier"), r"C:\dossier");
+}
+
+#[test]
+fn html_is_always_escaped() {
+    assert_eq!(render_inline("<script>alert('x')</script> & co"), "&lt;script&gt;alert(&#39;x&#39;)&lt;/script&gt; &amp; co");
+    assert_eq!(render_inline("**<b>**"), "<strong>&lt;b&gt;</strong>");
+}
+
+#[test]
+fn only_safe_links_are_kept() {
+    assert_eq!(render_inline("[site](https://exemple.com)"), link("https://exemple.com", "site"));
+    assert_eq!(render_inline("[mail](mailto:a@b.c)"), link("mailto:a@b.c", "mail"));
+    assert_eq!(render_inline("[cv](/u/alice)"), link("/u/alice", "cv"));
+    // pas de lien dans un libellé
+    assert_eq!(render_inline("[**x**](/a)"), link("/a", "<strong>x</strong>"));
+
+    // schémas dangereux, autre origine, espaces : libellé seul
+    for src in [
+        "[x](javascript:alert)",
+        "[x](JavaScript:alert)",
+        "[x](data:text/html;base64,PHNjcmlwdD4=)",
+        "[x](vbscript:msgbox)",
+        "[x](//evil.example)",
+        r"[x](/\evil.example)",
+        "[x](https://a.b/ onmouseover=y)",
+        "[x]()",
+    ] {
+        assert_eq!(render_inline(src), "x", "{src}");
+    }
+    // lien incomplet : texte brut
+    assert_eq!(render_inline("[x](https://a.b"), "[x](https://a.b");
+    assert_eq!(render_inline("[x] (https://a.b)"), "[x] (https://a.b)");
+}
+
+#[test]
+fn quotes_cannot_leave_the_href() {
+    assert_eq!(
+        render_inline(r#"[x](https://a.b/"onmouseover="alert)"#),
+        link("https://a.b/&quot;onmouseover=&quot;alert", "x")
+    );
+    assert_eq!(render_inline("[x](https://a.b/'y)"), link("https://a.b/&#39;y", "x"));
+    assert_eq!(render_inline("[x](/a?b=1&c=<2>)"), link("/a?b=1&amp;c=&lt;2&gt;", "x"));
+}
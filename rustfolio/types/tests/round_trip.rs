@@ -0,0 +1,130 @@
+use std::collections::BTreeMap;
+
+use rustfolio_layout::{Layout, Theme};
+use rustfolio_types::*;
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::{json, Value};
+
+/// Sérialise, vérifie le JSON attendu, relit et compare.
+fn round_trip<T: Serialize + DeserializeOwned + PartialEq + std::fmt::Debug>(value: &T, expected: Value) {
+    let json = serde_json::to_value(value).unwrap();
+    assert_eq!(json, expected);
+    assert_eq!(&serde_json::from_value::<T>(json).unwrap(), value);
+}
+
+#[test]
+fn cv_content_keeps_the_api_field_names() {
+    let profile = Profile {
+        first_name: "Alice".into(),
+        city: "Lausanne".into(),
+        translations: Some(BTreeMap::from([(
+            "en".to_string(),
+            ProfileText { title: Some("Developer".into()), summary: None },
+        )])),
+        ..Default::default()
+    };
+    round_trip(&profile, json!({
+        "first_name": "Alice", "last_name": "", "title": "", "email": "", "phone": "",
+        "address": "", "city": "Lausanne", "country": "", "website": "", "photo_url": "",
+        "summary": "", "translations": { "en": { "title": "Developer" } },
+    }));
+
+    let exp = Experience {
+        id: Some(3),
+        date_start: "2021-04".into(),
+        kind: "job".into(),
+        title: "Dev".into(),
+        tasks: vec!["**Rust**".into()],
+        ..Default::default()
+    };
+    round_trip(&exp, json!({
+        "id": 3, "date_start": "2021-04", "date_end": "", "kind": "job", "title": "Dev",
+        "company": "", "location": "", "website": "", "tasks": ["**Rust**"],
+    }));
+
+    let skill = Skill { id: Some(1), name: "Rust".into(), level: Some(SkillLevel::Advanced), ..Default::default() };
+    round_trip(&skill, json!({
+        "id": 1, "name": "Rust", "percentage": null, "level": "advanced", "logo_url": null, "category": "",
+    }));
+
+    let project = Project { title: "Rustfolio".into(), technologies: vec!["Yew".into()], ..Default::default() };
+    round_trip(&project, json!({
+        "id": null, "title": "Rustfolio", "description": "", "category": "", "repo_link": "",
+        "pdf_link": "", "image": "", "technologies": ["Yew"],
+    }));
+
+    let item = PortfolioItem { title: "Affiche".into(), kind: "print".into(), ..Default::default() };
+    round_trip(&item, json!({
+        "id": null, "title": "Affiche", "description": "", "type": "print", "image": "", "position": null,
+    }));
+
+    let data = CvData { profile, experiences: vec![exp], skills: vec![skill], projects: vec![project] };
+    let back: CvData = serde_json::from_value(serde_json::to_value(&data).unwrap()).unwrap();
+    assert_eq!(back, data);
+}
+
+#[test]
+fn skills_accept_legacy_logo_and_derive_percent() {
+    let s: Skill = serde_json::from_value(json!({ "name": "Yew", "logo": "/y.svg" })).unwrap();
+    assert_eq!(s.logo_url.as_deref(), Some("/y.svg"));
+    assert_eq!(s.percent(), None);
+
+    let input: SkillIn = serde_json::from_value(json!({ "name": "Axum", "percentage": null, "logo": null })).unwrap();
+    assert_eq!(input, SkillIn { name: "Axum".into(), ..Default::default() });
+
+    let out = SkillOut {
+        id: 7,
+        name: "SQL".into(),
+        percentage: None,
+        level: Some(SkillLevel::Intermediate),
+        logo_url: None,
+        category: "Data".into(),
+    };
+    round_trip(&out, json!({
+        "id": 7, "name": "SQL", "percentage": null, "level": "intermediate", "logo_url": null, "category": "Data",
+    }));
+    assert_eq!(out.percent(), Some(50));
+    assert_eq!(Skill::from(out).id, Some(7));
+
+    for level in SkillLevel::ALL {
+        assert_eq!(SkillLevel::parse(level.as_str()), Some(level));
+        assert_eq!(serde_json::to_value(level).unwrap(), level.as_str());
+    }
+    assert!(serde_json::from_value::<SkillLevel>(json!("guru")).is_err());
+}
+
+#[test]
+fn tasks_are_rendered_once() {
+    let task = TaskItem::new(4, "**Rust** & <b>".into());
+    assert_eq!(task.html, "<strong>Rust</strong> &amp; &lt;b&gt;");
+    round_trip(&task, json!({ "id": 4, "task": "**Rust** & <b>", "html": task.html }));
+    // réponse sans rendu : champ vide
+    let t: TaskItem = serde_json::from_value(json!({ "id": 1, "task": "x" })).unwrap();
+    assert!(t.html.is_empty());
+}
+
+#[test]
+fn cv_detail_is_flattened() {
+    let detail = CvDetail {
+        cv: CvSummary { id: 2, name: "Principal".into(), created_at: "2026-01-01".into(), updated_at: "2026-01-02".into() },
+        layout: Layout::default(),
+        theme: Theme::default(),
+        hidden: HiddenItems { skills: vec![5], ..Default::default() },
+    };
+    let json = serde_json::to_value(&detail).unwrap();
+    assert_eq!(json["id"], 2);
+    assert_eq!(json["name"], "Principal");
+    assert_eq!(json["hidden"], json!({ "experiences": [], "skills": [5], "projects": [] }));
+    assert_eq!(serde_json::from_value::<CvDetail>(json.clone()).unwrap(), detail);
+
+    // réponse d'avant les thèmes
+    let mut old = json;
+    old.as_object_mut().unwrap().remove("theme");
+    assert_eq!(serde_json::from_value::<CvDetail>(old).unwrap().theme, Theme::default());
+}
+
+#[test]
+fn account_round_trips() {
+    let me = Me { email: "a@b.c".into(), display_name: None, locale: Some("en".into()), ui_locale: "en".into() };
+    round_trip(&me, json!({ "email": "a@b.c", "display_name": null, "locale": "en", "ui_locale": "en" }));
+}